@@ -1272,7 +1272,7 @@ pub fn precedence(&self) -> ExprPrecedence {
             ExprKind::Closure(..) => ExprPrecedence::Closure,
             ExprKind::Block(..) => ExprPrecedence::Block,
             ExprKind::TryBlock(..) => ExprPrecedence::TryBlock,
-            ExprKind::Async(..) => ExprPrecedence::Async,
+            ExprKind::Gen(..) => ExprPrecedence::Gen,
             ExprKind::Await(..) => ExprPrecedence::Await,
             ExprKind::Assign(..) => ExprPrecedence::Assign,
             ExprKind::AssignOp(..) => ExprPrecedence::AssignOp,
@@ -1294,6 +1294,7 @@ pub fn precedence(&self) -> ExprPrecedence {
             ExprKind::Try(..) => ExprPrecedence::Try,
             ExprKind::Yield(..) => ExprPrecedence::Yield,
             ExprKind::Yeet(..) => ExprPrecedence::Yeet,
+            ExprKind::Become(..) => ExprPrecedence::Become,
             ExprKind::FormatArgs(..) => ExprPrecedence::FormatArgs,
             ExprKind::Err => ExprPrecedence::Err,
         }
@@ -1440,11 +1441,11 @@ pub enum ExprKind {
     Closure(Box<Closure>),
     /// A block (`'label: { ... }`).
     Block(P<Block>, Option<Label>),
-    /// An async block (`async move { ... }`).
+    /// An `async` block (`async move { ... }`) or a `gen` block (`gen move { ... }`).
     ///
     /// The async block used to have a `NodeId`, which was removed in favor of
     /// using the parent `NodeId` of the parent `Expr`.
-    Async(CaptureBy, P<Block>),
+    Gen(CaptureBy, P<Block>, GenBlockKind),
     /// An await expression (`my_future.await`). Span is of await keyword.
     Await(P<Expr>, Span),
 
@@ -1515,6 +1516,11 @@ pub enum ExprKind {
     /// with an optional value to be returned.
     Yeet(Option<P<Expr>>),
 
+    /// A tail call return, with the value to be returned.
+    ///
+    /// While `.0` must be a function call, we check this later, after parsing.
+    Become(P<Expr>),
+
     /// Bytes included via `include_bytes!`
     /// Added for optimization purposes to avoid the need to escape
     /// large binary blobs - should always behave like [`ExprKind::Lit`]
@@ -1553,7 +1559,31 @@ pub struct QSelf {
     pub position: usize,
 }
 
-/// A capture clause used in closures and `async` blocks.
+/// The kind of block that is lowered to a generator: an `async` block evaluates to a future,
+/// a `gen` block to an iterator.
+#[derive(Clone, Copy, PartialEq, Eq, Encodable, Decodable, Debug, HashStable_Generic)]
+pub enum GenBlockKind {
+    Async,
+    Gen,
+}
+
+impl fmt::Display for GenBlockKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.modifier().fmt(f)
+    }
+}
+
+impl GenBlockKind {
+    /// The keyword introducing the block.
+    pub fn modifier(&self) -> &'static str {
+        match self {
+            GenBlockKind::Async => "async",
+            GenBlockKind::Gen => "gen",
+        }
+    }
+}
+
+/// A capture clause used in closures, `async` and `gen` blocks.
 #[derive(Clone, Copy, PartialEq, Encodable, Decodable, Debug, HashStable_Generic)]
 pub enum CaptureBy {
     /// `move |x| y + x`.
@@ -1909,28 +1939,56 @@ pub struct MutTy {
 pub struct FnSig {
     pub header: FnHeader,
     pub decl: P<FnDecl>,
+    /// The pre- and postconditions attached by `#[requires]` and `#[ensures]`, if any.
+    pub contract: Option<P<FnContract>>,
     pub span: Span,
 }
 
+/// The contract of a function, as attached by the `#[core::contracts::requires]` and
+/// `#[core::contracts::ensures]` attribute macros.
+///
+/// The closure of each precondition takes no parameters and the closure of each postcondition
+/// takes a reference to the returned value, both returning `bool`.
+#[derive(Clone, Encodable, Decodable, Debug, Default)]
+pub struct FnContract {
+    pub requires: ThinVec<ContractClause>,
+    pub ensures: ThinVec<ContractClause>,
+}
+
+/// A precondition or postcondition of a [`FnContract`].
+#[derive(Clone, Encodable, Decodable, Debug)]
+pub struct ContractClause {
+    /// The closure evaluating the predicate.
+    pub closure: P<Expr>,
+    /// The source text of the predicate, reported when its check fails.
+    pub text: Symbol,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[derive(Encodable, Decodable, HashStable_Generic)]
 pub enum FloatTy {
+    F16,
     F32,
     F64,
+    F128,
 }
 
 impl FloatTy {
     pub fn name_str(self) -> &'static str {
         match self {
+            FloatTy::F16 => "f16",
             FloatTy::F32 => "f32",
             FloatTy::F64 => "f64",
+            FloatTy::F128 => "f128",
         }
     }
 
     pub fn name(self) -> Symbol {
         match self {
+            FloatTy::F16 => sym::f16,
             FloatTy::F32 => sym::f32,
             FloatTy::F64 => sym::f64,
+            FloatTy::F128 => sym::f128,
         }
     }
 }
@@ -2106,6 +2164,10 @@ pub enum TyKind {
     ///
     /// Type parameters are stored in the `Path` itself.
     Path(Option<P<QSelf>>, Path),
+    /// A pattern type (`u32 is 1..=100`), the values of a type that match a pattern.
+    ///
+    /// Only produced by the `pattern_type!` macro for now.
+    Pat(P<Ty>, P<Pat>),
     /// A trait object type `Bound1 + Bound2 + Bound3`
     /// where `Bound` is a trait or a lifetime.
     TraitObject(GenericBounds, TraitObjectSyntax),
@@ -2432,6 +2494,26 @@ pub fn opt_return_id(self) -> Option<(NodeId, Span)> {
     }
 }
 
+#[derive(Copy, Clone, Encodable, Decodable, Debug)]
+pub enum Gen {
+    Yes { span: Span, closure_id: NodeId, return_impl_trait_id: NodeId },
+    No,
+}
+
+impl Gen {
+    pub fn is_gen(self) -> bool {
+        matches!(self, Gen::Yes { .. })
+    }
+
+    /// In this case this is a `gen` return, the `NodeId` for the generated `impl Trait` item.
+    pub fn opt_return_id(self) -> Option<(NodeId, Span)> {
+        match self {
+            Gen::Yes { return_impl_trait_id, span, .. } => Some((return_impl_trait_id, span)),
+            Gen::No => None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Encodable, Decodable, Debug)]
 #[derive(HashStable_Generic)]
 pub enum Const {
@@ -2718,6 +2800,8 @@ pub struct FieldDef {
     pub ident: Option<Ident>,
 
     pub ty: P<Ty>,
+    /// The default value of the field, e.g. `3` in `retries: u32 = 3`.
+    pub default: Option<AnonConst>,
     pub is_placeholder: bool,
 }
 
@@ -2824,6 +2908,8 @@ pub struct FnHeader {
     pub unsafety: Unsafe,
     /// The `async` keyword, if any
     pub asyncness: Async,
+    /// The `gen` keyword, if any
+    pub genness: Gen,
     /// The `const` keyword, if any
     pub constness: Const,
     /// The `extern` keyword and corresponding ABI string, if any
@@ -2833,12 +2919,28 @@ pub struct FnHeader {
 impl FnHeader {
     /// Does this function header have any qualifiers or is it empty?
     pub fn has_qualifiers(&self) -> bool {
-        let Self { unsafety, asyncness, constness, ext } = self;
+        let Self { unsafety, asyncness, genness, constness, ext } = self;
         matches!(unsafety, Unsafe::Yes(_))
             || asyncness.is_async()
+            || genness.is_gen()
             || matches!(constness, Const::Yes(_))
             || !matches!(ext, Extern::None)
     }
+
+    /// For an `async fn` or a `gen fn`, the `NodeId` for the generated `impl Trait` return type.
+    pub fn opt_return_id(&self) -> Option<(NodeId, Span)> {
+        self.asyncness.opt_return_id().or(self.genness.opt_return_id())
+    }
+
+    /// Like [`FnHeader::opt_return_id`], but also returns the kind of block the body of the
+    /// function is desugared to.
+    pub fn opt_gen_block_return_id(&self) -> Option<(NodeId, Span, GenBlockKind)> {
+        match (self.asyncness.opt_return_id(), self.genness.opt_return_id()) {
+            (Some((id, span)), _) => Some((id, span, GenBlockKind::Async)),
+            (None, Some((id, span))) => Some((id, span, GenBlockKind::Gen)),
+            (None, None) => None,
+        }
+    }
 }
 
 impl Default for FnHeader {
@@ -2846,6 +2948,7 @@ fn default() -> FnHeader {
         FnHeader {
             unsafety: Unsafe::No,
             asyncness: Async::No,
+            genness: Gen::No,
             constness: Const::No,
             ext: Extern::None,
         }
@@ -3165,7 +3268,7 @@ mod size_asserts {
     static_assert_size!(Block, 32);
     static_assert_size!(Expr, 72);
     static_assert_size!(ExprKind, 40);
-    static_assert_size!(Fn, 152);
+    static_assert_size!(Fn, 176);
     static_assert_size!(ForeignItem, 96);
     static_assert_size!(ForeignItemKind, 24);
     static_assert_size!(GenericArg, 24);
@@ -99,6 +99,24 @@ pub fn has_name(&self, name: Symbol) -> bool {
         }
     }
 
+    /// Returns `true` if the path of this attribute consists of exactly the given segments,
+    /// e.g. `[sym::diagnostic, sym::on_unimplemented]`.
+    pub fn path_matches(&self, name: &[Symbol]) -> bool {
+        match &self.kind {
+            AttrKind::Normal(normal) => {
+                normal.item.path.segments.len() == name.len()
+                    && normal
+                        .item
+                        .path
+                        .segments
+                        .iter()
+                        .zip(name)
+                        .all(|(s, n)| s.args.is_none() && s.ident.name == *n)
+            }
+            AttrKind::DocComment(..) => false,
+        }
+    }
+
     pub fn is_word(&self) -> bool {
         if let AttrKind::Normal(normal) = &self.kind {
             matches!(normal.item.args, AttrArgs::Empty)
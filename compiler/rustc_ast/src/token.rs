@@ -193,6 +193,7 @@ pub fn ident_can_begin_expr(name: Symbol, span: Span, is_raw: bool) -> bool {
         || ident_token.is_path_segment_keyword()
         || [
             kw::Async,
+            kw::Become,
             kw::Do,
             kw::Box,
             kw::Break,
@@ -200,6 +201,7 @@ pub fn ident_can_begin_expr(name: Symbol, span: Span, is_raw: bool) -> bool {
             kw::Continue,
             kw::False,
             kw::For,
+            kw::Gen,
             kw::If,
             kw::Let,
             kw::Loop,
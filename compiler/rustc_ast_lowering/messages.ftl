@@ -30,6 +30,10 @@ ast_lowering_base_expression_double_dot =
     base expression required after `..`
     .label = add a base expression here
 
+ast_lowering_become_in_contract_fn =
+    `become` is not allowed in functions with an `ensures` contract
+    .note = the postcondition cannot be checked after a tail call
+
 ast_lowering_await_only_in_async_fn_and_blocks =
     `await` is only allowed inside `async` functions and blocks
     .label = only allowed inside `async` functions and blocks
@@ -134,6 +138,12 @@ ast_lowering_arbitrary_expression_in_pattern =
 
 ast_lowering_inclusive_range_with_no_end = inclusive range with no end
 
+ast_lowering_pattern_type_unsupported_pattern =
+    pattern types only support range patterns
+
+ast_lowering_pattern_type_non_literal_range_end =
+    the endpoints of a range in a pattern type must be literals
+
 ast_lowering_trait_fn_async =
     functions in traits cannot be declared `async`
     .label = `async` because of this
@@ -103,6 +103,14 @@ pub struct BaseExpressionDoubleDot {
     pub span: Span,
 }
 
+#[derive(Diagnostic, Clone, Copy)]
+#[diag(ast_lowering_become_in_contract_fn)]
+#[note]
+pub struct BecomeInContractFn {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(Diagnostic, Clone, Copy)]
 #[diag(ast_lowering_await_only_in_async_fn_and_blocks, code = "E0728")]
 pub struct AwaitOnlyInAsyncFnAndBlocks {
@@ -337,6 +345,20 @@ pub struct InclusiveRangeWithNoEnd {
     pub span: Span,
 }
 
+#[derive(Diagnostic, Clone, Copy)]
+#[diag(ast_lowering_pattern_type_unsupported_pattern)]
+pub struct PatternTypeUnsupportedPattern {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic, Clone, Copy)]
+#[diag(ast_lowering_pattern_type_non_literal_range_end)]
+pub struct PatternTypeNonLiteralRangeEnd {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(Diagnostic, Clone, Copy)]
 #[diag(ast_lowering_trait_fn_async, code = "E0706")]
 #[note]
@@ -1,8 +1,8 @@
 use super::errors::{
     AsyncGeneratorsNotSupported, AsyncNonMoveClosureNotSupported, AwaitOnlyInAsyncFnAndBlocks,
-    BaseExpressionDoubleDot, ClosureCannotBeStatic, FunctionalRecordUpdateDestructuringAssignment,
-    GeneratorTooManyParameters, InclusiveRangeWithNoEnd, NotSupportedForLifetimeBinderAsyncClosure,
-    UnderscoreExprLhsAssign,
+    BaseExpressionDoubleDot, BecomeInContractFn, ClosureCannotBeStatic,
+    FunctionalRecordUpdateDestructuringAssignment, GeneratorTooManyParameters,
+    InclusiveRangeWithNoEnd, NotSupportedForLifetimeBinderAsyncClosure, UnderscoreExprLhsAssign,
 };
 use super::ResolverAstLoweringExt;
 use super::{ImplTraitContext, LoweringContext, ParamMode, ParenthesizedGenericArgs};
@@ -15,6 +15,7 @@
 use rustc_hir::def::Res;
 use rustc_hir::definitions::DefPathData;
 use rustc_session::errors::report_lit_error;
+use rustc_session::parse::feature_err;
 use rustc_span::source_map::{respan, DesugaringKind, Span, Spanned};
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::DUMMY_SP;
@@ -177,12 +178,20 @@ pub(super) fn lower_expr_mut(&mut self, e: &Expr) -> hir::Expr<'hir> {
                     self.arena.alloc_from_iter(arms.iter().map(|x| self.lower_arm(x))),
                     hir::MatchSource::Normal,
                 ),
-                ExprKind::Async(capture_clause, block) => self.make_async_expr(
+                ExprKind::Gen(capture_clause, block, GenBlockKind::Async) => self
+                    .make_async_expr(
+                        *capture_clause,
+                        e.id,
+                        None,
+                        e.span,
+                        hir::AsyncGeneratorKind::Block,
+                        |this| this.with_new_scopes(|this| this.lower_block_expr(block)),
+                    ),
+                ExprKind::Gen(capture_clause, block, GenBlockKind::Gen) => self.make_gen_expr(
                     *capture_clause,
                     e.id,
-                    None,
                     e.span,
-                    hir::AsyncGeneratorKind::Block,
+                    hir::IterGeneratorKind::Block,
                     |this| this.with_new_scopes(|this| this.lower_block_expr(block)),
                 ),
                 ExprKind::Await(expr, await_kw_span) => self.lower_expr_await(*await_kw_span, expr),
@@ -266,11 +275,18 @@ pub(super) fn lower_expr_mut(&mut self, e: &Expr) -> hir::Expr<'hir> {
                 ExprKind::Continue(opt_label) => {
                     hir::ExprKind::Continue(self.lower_jump_destination(e.id, *opt_label))
                 }
-                ExprKind::Ret(e) => {
-                    let e = e.as_ref().map(|x| self.lower_expr(x));
-                    hir::ExprKind::Ret(e)
+                ExprKind::Ret(sub_expr) => {
+                    let sub_expr = sub_expr.as_ref().map(|x| self.lower_expr(x));
+                    self.checked_return(e.span, sub_expr)
                 }
                 ExprKind::Yeet(sub_expr) => self.lower_expr_yeet(e.span, sub_expr.as_deref()),
+                ExprKind::Become(sub_expr) => {
+                    if !self.contract_ensures.is_empty() {
+                        self.tcx.sess.emit_err(BecomeInContractFn { span: e.span });
+                    }
+                    let sub_expr = self.lower_expr(sub_expr);
+                    hir::ExprKind::Become(sub_expr)
+                }
                 ExprKind::InlineAsm(asm) => {
                     hir::ExprKind::InlineAsm(self.lower_inline_asm(e.span, asm))
                 }
@@ -284,13 +300,16 @@ pub(super) fn lower_expr_mut(&mut self, e: &Expr) -> hir::Expr<'hir> {
                 ),
                 ExprKind::Struct(se) => {
                     let rest = match &se.rest {
-                        StructRest::Base(e) => Some(self.lower_expr(e)),
+                        StructRest::Base(e) => hir::StructTailExpr::Base(self.lower_expr(e)),
+                        StructRest::Rest(sp) if self.tcx.features().default_field_values => {
+                            hir::StructTailExpr::DefaultFields(self.lower_span(*sp))
+                        }
                         StructRest::Rest(sp) => {
                             let guar =
                                 self.tcx.sess.emit_err(BaseExpressionDoubleDot { span: *sp });
-                            Some(&*self.arena.alloc(self.expr_err(*sp, guar)))
+                            hir::StructTailExpr::Base(self.arena.alloc(self.expr_err(*sp, guar)))
                         }
-                        StructRest::None => None,
+                        StructRest::None => hir::StructTailExpr::None,
                     };
                     hir::ExprKind::Struct(
                         self.arena.alloc(self.lower_qpath(
@@ -648,6 +667,57 @@ pub(super) fn make_async_expr(
         }))
     }
 
+    /// Lower a `gen` construct to a generator that implements `Iterator`.
+    ///
+    /// This results in:
+    ///
+    /// ```text
+    /// move? || {
+    ///     <body>
+    /// }
+    /// ```
+    pub(super) fn make_gen_expr(
+        &mut self,
+        capture_clause: CaptureBy,
+        closure_node_id: NodeId,
+        span: Span,
+        iter_gen_kind: hir::IterGeneratorKind,
+        body: impl FnOnce(&mut Self) -> hir::Expr<'hir>,
+    ) -> hir::ExprKind<'hir> {
+        let output = hir::FnRetTy::DefaultReturn(self.lower_span(span));
+
+        // The closure/generator `FnDecl` takes no arguments, `gen` generators are never resumed
+        // with a value.
+        let fn_decl = self.arena.alloc(hir::FnDecl {
+            inputs: &[],
+            output,
+            c_variadic: false,
+            implicit_self: hir::ImplicitSelfKind::None,
+            lifetime_elision_allowed: false,
+        });
+
+        let body = self.lower_body(move |this| {
+            this.generator_kind = Some(hir::GeneratorKind::Iter(iter_gen_kind));
+
+            let res = body(this);
+            (&[], res)
+        });
+
+        // `|| { body }`:
+        hir::ExprKind::Closure(self.arena.alloc(hir::Closure {
+            def_id: self.local_def_id(closure_node_id),
+            binder: hir::ClosureBinder::Default,
+            capture_clause,
+            bound_generic_params: &[],
+            fn_decl,
+            body,
+            fn_decl_span: self.lower_span(span),
+            fn_arg_span: None,
+            movability: Some(hir::Movability::Movable),
+            constness: hir::Constness::NotConst,
+        }))
+    }
+
     /// Forwards a possible `#[track_caller]` annotation from `outer_hir_id` to
     /// `inner_hir_id` in case the `closure_track_caller` feature is enabled.
     pub(super) fn maybe_forward_track_caller(
@@ -700,7 +770,7 @@ fn lower_expr_await(&mut self, await_kw_span: Span, expr: &Expr) -> hir::ExprKin
         let full_span = expr.span.to(await_kw_span);
         match self.generator_kind {
             Some(hir::GeneratorKind::Async(_)) => {}
-            Some(hir::GeneratorKind::Gen) | None => {
+            Some(hir::GeneratorKind::Iter(_) | hir::GeneratorKind::Gen) | None => {
                 self.tcx.sess.emit_err(AwaitOnlyInAsyncFnAndBlocks {
                     await_kw_span,
                     item_span: self.current_item,
@@ -927,6 +997,9 @@ fn generator_movability_for_fn(
             Some(hir::GeneratorKind::Async(_)) => {
                 panic!("non-`async` closure body turned `async` during lowering");
             }
+            Some(hir::GeneratorKind::Iter(_)) => {
+                panic!("non-`gen` closure body turned `gen` during lowering");
+            }
             None => {
                 if movability == Movability::Static {
                     self.tcx.sess.emit_err(ClosureCannotBeStatic { fn_decl_span });
@@ -1342,7 +1415,7 @@ fn lower_expr_range(
         hir::ExprKind::Struct(
             self.arena.alloc(hir::QPath::LangItem(lang_item, self.lower_span(span), None)),
             fields,
-            None,
+            hir::StructTailExpr::None,
         )
     }
 
@@ -1425,12 +1498,30 @@ fn lower_expr_field(&mut self, f: &ExprField) -> hir::ExprField<'hir> {
     }
 
     fn lower_expr_yield(&mut self, span: Span, opt_expr: Option<&Expr>) -> hir::ExprKind<'hir> {
-        match self.generator_kind {
-            Some(hir::GeneratorKind::Gen) => {}
+        let is_generator = match self.generator_kind {
+            Some(hir::GeneratorKind::Iter(_)) => false,
+            Some(hir::GeneratorKind::Gen) => true,
             Some(hir::GeneratorKind::Async(_)) => {
                 self.tcx.sess.emit_err(AsyncGeneratorsNotSupported { span });
+                false
+            }
+            None => {
+                self.generator_kind = Some(hir::GeneratorKind::Gen);
+                true
             }
-            None => self.generator_kind = Some(hir::GeneratorKind::Gen),
+        };
+
+        // `yield` syntax is only gated on `generators` during parsing if `gen_blocks` is
+        // disabled, so that it can be used in `gen` blocks. Gate it here when it is used to
+        // create a generator instead.
+        if is_generator && self.tcx.features().gen_blocks && !self.tcx.features().generators {
+            feature_err(
+                &self.tcx.sess.parse_sess,
+                sym::generators,
+                span,
+                "yield syntax is experimental",
+            )
+            .emit();
         }
 
         let expr =
@@ -1630,7 +1721,8 @@ fn lower_expr_try(&mut self, span: Span, sub_expr: &Expr) -> hir::ExprKind<'hir>
                     ),
                 ))
             } else {
-                self.arena.alloc(self.expr(try_span, hir::ExprKind::Ret(Some(from_residual_expr))))
+                let ret = self.checked_return(try_span, Some(from_residual_expr));
+                self.arena.alloc(self.expr(try_span, ret))
             };
             self.lower_attrs(ret_expr.hir_id, &attrs);
 
@@ -1679,10 +1771,46 @@ fn lower_expr_yeet(&mut self, span: Span, sub_expr: Option<&Expr>) -> hir::ExprK
             let target_id = Ok(self.lower_node_id(catch_node));
             hir::ExprKind::Break(hir::Destination { label: None, target_id }, Some(from_yeet_expr))
         } else {
-            hir::ExprKind::Ret(Some(from_yeet_expr))
+            self.checked_return(span, Some(from_yeet_expr))
         }
     }
 
+    /// Lowers `return <expr>`, passing the returned value through the postcondition checks
+    /// of the enclosing function if it has any, see `lower_contract_body`.
+    fn checked_return(
+        &mut self,
+        span: Span,
+        opt_expr: Option<&'hir hir::Expr<'hir>>,
+    ) -> hir::ExprKind<'hir> {
+        if self.contract_ensures.is_empty() {
+            return hir::ExprKind::Ret(opt_expr);
+        }
+        let expr = opt_expr.unwrap_or_else(|| self.expr_unit(span));
+        hir::ExprKind::Ret(Some(self.check_ensures(expr)))
+    }
+
+    /// Wraps `ret` in a call to `contract_check_ensures` for every postcondition checker of
+    /// the function being lowered.
+    pub(super) fn check_ensures(&mut self, ret: &'hir hir::Expr<'hir>) -> &'hir hir::Expr<'hir> {
+        let checks = self.tcx.sess.opts.unstable_opts.contract_checks;
+        let checkers = self.contract_ensures.clone();
+        checkers.into_iter().fold(ret, |ret, (ident, binding, text)| {
+            let checker = self.expr_ident(ret.span, ident, binding);
+            let checker = self.expr(
+                ret.span,
+                hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, checker),
+            );
+            let text = self.expr_str(ret.span, text);
+            let checks = self.expr_bool(ret.span, checks);
+            self.expr_call_lang_item_fn(
+                ret.span,
+                hir::LangItem::ContractCheckEnsures,
+                arena_vec![self; *ret, checker, text, checks],
+                None,
+            )
+        })
+    }
+
     // =========================================================================
     // Helper methods for building HIR.
     // =========================================================================
@@ -1765,6 +1893,11 @@ pub(super) fn expr_str(&mut self, sp: Span, value: Symbol) -> hir::Expr<'hir> {
         self.expr(sp, hir::ExprKind::Lit(lit))
     }
 
+    pub(super) fn expr_bool(&mut self, sp: Span, value: bool) -> hir::Expr<'hir> {
+        let lit = self.arena.alloc(hir::Lit { span: sp, node: ast::LitKind::Bool(value) });
+        self.expr(sp, hir::ExprKind::Lit(lit))
+    }
+
     pub(super) fn expr_call_mut(
         &mut self,
         span: Span,
@@ -1783,7 +1916,7 @@ pub(super) fn expr_call(
         self.arena.alloc(self.expr_call_mut(span, e, args))
     }
 
-    fn expr_call_lang_item_fn_mut(
+    pub(super) fn expr_call_lang_item_fn_mut(
         &mut self,
         span: Span,
         lang_item: hir::LangItem,
@@ -1794,7 +1927,7 @@ fn expr_call_lang_item_fn_mut(
         self.expr_call_mut(span, path, args)
     }
 
-    fn expr_call_lang_item_fn(
+    pub(super) fn expr_call_lang_item_fn(
         &mut self,
         span: Span,
         lang_item: hir::LangItem,
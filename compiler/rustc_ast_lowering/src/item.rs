@@ -78,6 +78,7 @@ fn with_lctx(
             is_in_dyn_type: false,
             generator_kind: None,
             task_context: None,
+            contract_ensures: Vec::new(),
             current_item: None,
             impl_trait_defs: Vec::new(),
             impl_trait_bounds: Vec::new(),
@@ -234,12 +235,8 @@ fn lower_item_kind(
                 let (ty, body_id) = self.lower_const_item(ty, span, expr.as_deref());
                 hir::ItemKind::Const(ty, body_id)
             }
-            ItemKind::Fn(box Fn {
-                sig: FnSig { decl, header, span: fn_sig_span },
-                generics,
-                body,
-                ..
-            }) => {
+            ItemKind::Fn(box Fn { sig, generics, body, .. }) => {
+                let FnSig { decl, header, span: fn_sig_span, .. } = sig;
                 self.with_new_scopes(|this| {
                     this.current_item = Some(ident.span);
 
@@ -247,18 +244,11 @@ fn lower_item_kind(
                     // `impl Future<Output = T>` here because lower_body
                     // only cares about the input argument patterns in the function
                     // declaration (decl), not the return types.
-                    let asyncness = header.asyncness;
-                    let body_id = this.lower_maybe_async_body(
-                        span,
-                        hir_id,
-                        &decl,
-                        asyncness,
-                        body.as_deref(),
-                    );
+                    let body_id = this.lower_maybe_async_body(span, hir_id, sig, body.as_deref());
 
                     let itctx = ImplTraitContext::Universal;
                     let (generics, decl) = this.lower_generics(generics, id, &itctx, |this| {
-                        let ret_id = asyncness.opt_return_id();
+                        let ret_id = header.opt_gen_block_return_id();
                         this.lower_fn_decl(&decl, id, *fn_sig_span, FnDeclKind::Fn, ret_id)
                     });
                     let sig = hir::FnSig {
@@ -700,6 +690,7 @@ fn lower_field_def(&mut self, (index, f): (usize, &FieldDef)) -> hir::FieldDef<'
             },
             vis_span: self.lower_span(f.vis.span),
             ty,
+            default: f.default.as_ref().map(|default| self.lower_anon_const(default)),
         }
     }
 
@@ -716,27 +707,24 @@ fn lower_trait_item(&mut self, i: &AssocItem) -> &'hir hir::TraitItem<'hir> {
                 (hir::Generics::empty(), hir::TraitItemKind::Const(ty, body), body.is_some())
             }
             AssocItemKind::Fn(box Fn { sig, generics, body: None, .. }) => {
-                let asyncness = sig.header.asyncness;
                 let names = self.lower_fn_params_to_names(&sig.decl);
                 let (generics, sig) = self.lower_method_sig(
                     generics,
                     sig,
                     i.id,
                     FnDeclKind::Trait,
-                    asyncness.opt_return_id(),
+                    sig.header.opt_gen_block_return_id(),
                 );
                 (generics, hir::TraitItemKind::Fn(sig, hir::TraitFn::Required(names)), false)
             }
             AssocItemKind::Fn(box Fn { sig, generics, body: Some(body), .. }) => {
-                let asyncness = sig.header.asyncness;
-                let body_id =
-                    self.lower_maybe_async_body(i.span, hir_id, &sig.decl, asyncness, Some(&body));
+                let body_id = self.lower_maybe_async_body(i.span, hir_id, sig, Some(&body));
                 let (generics, sig) = self.lower_method_sig(
                     generics,
                     sig,
                     i.id,
                     FnDeclKind::Trait,
-                    asyncness.opt_return_id(),
+                    sig.header.opt_gen_block_return_id(),
                 );
                 (generics, hir::TraitItemKind::Fn(sig, hir::TraitFn::Provided(body_id)), true)
             }
@@ -820,20 +808,13 @@ fn lower_impl_item(&mut self, i: &AssocItem) -> &'hir hir::ImplItem<'hir> {
             }
             AssocItemKind::Fn(box Fn { sig, generics, body, .. }) => {
                 self.current_item = Some(i.span);
-                let asyncness = sig.header.asyncness;
-                let body_id = self.lower_maybe_async_body(
-                    i.span,
-                    hir_id,
-                    &sig.decl,
-                    asyncness,
-                    body.as_deref(),
-                );
+                let body_id = self.lower_maybe_async_body(i.span, hir_id, sig, body.as_deref());
                 let (generics, sig) = self.lower_method_sig(
                     generics,
                     sig,
                     i.id,
                     if self.is_in_trait_impl { FnDeclKind::Impl } else { FnDeclKind::Inherent },
-                    asyncness.opt_return_id(),
+                    sig.header.opt_gen_block_return_id(),
                 );
 
                 (generics, hir::ImplItemKind::Fn(sig, body_id))
@@ -937,10 +918,12 @@ pub(super) fn lower_body(
     ) -> hir::BodyId {
         let prev_gen_kind = self.generator_kind.take();
         let task_context = self.task_context.take();
+        let contract_ensures = std::mem::take(&mut self.contract_ensures);
         let (parameters, result) = f(self);
         let body_id = self.record_body(parameters, result);
         self.task_context = task_context;
         self.generator_kind = prev_gen_kind;
+        self.contract_ensures = contract_ensures;
         body_id
     }
 
@@ -972,9 +955,75 @@ fn lower_fn_body_block(
         &mut self,
         span: Span,
         decl: &FnDecl,
+        contract: Option<&FnContract>,
         body: Option<&Block>,
     ) -> hir::BodyId {
-        self.lower_fn_body(decl, |this| this.lower_block_expr_opt(span, body))
+        self.lower_fn_body(decl, |this| match (contract, body) {
+            (Some(contract), Some(body)) => this.lower_contract_body(contract, body),
+            _ => this.lower_block_expr_opt(span, body),
+        })
+    }
+
+    /// Lowers the body of a function with a contract. The body of
+    ///
+    /// ```ignore (illustrative)
+    /// #[requires(PRE)]
+    /// #[ensures(POST)]
+    /// fn f() -> T { BODY }
+    /// ```
+    ///
+    /// is lowered to
+    ///
+    /// ```ignore (illustrative)
+    /// {
+    ///     contract_check_requires(|| PRE, "PRE", CHECKS);
+    ///     let __ensures_checker = contract_build_check_ensures(POST);
+    ///     contract_check_ensures({ BODY }, &__ensures_checker, "POST", CHECKS)
+    /// }
+    /// ```
+    ///
+    /// and every `return EXPR` in `BODY` to
+    /// `return contract_check_ensures(EXPR, &__ensures_checker, "POST", CHECKS)`, where
+    /// `CHECKS` is whether this crate is compiled with `-Z contract-checks`. The check functions
+    /// only evaluate the predicates when `CHECKS` is true, but the calls, and thus the
+    /// predicates, are always part of the MIR of the function.
+    fn lower_contract_body(&mut self, contract: &FnContract, body: &Block) -> hir::Expr<'hir> {
+        let checks = self.tcx.sess.opts.unstable_opts.contract_checks;
+        let mut stmts = Vec::new();
+        for ContractClause { closure, text } in &contract.requires {
+            let span = closure.span;
+            let check = self.lower_expr_mut(closure);
+            let text = self.expr_str(span, *text);
+            let checks = self.expr_bool(span, checks);
+            let check = self.expr_call_lang_item_fn_mut(
+                span,
+                hir::LangItem::ContractCheckRequires,
+                arena_vec![self; check, text, checks],
+                None,
+            );
+            stmts.push(self.stmt_expr(span, check));
+        }
+
+        debug_assert!(self.contract_ensures.is_empty());
+        for ContractClause { closure, text } in &contract.ensures {
+            let span = closure.span;
+            let checker = self.lower_expr_mut(closure);
+            let checker = self.expr_call_lang_item_fn(
+                span,
+                hir::LangItem::ContractBuildCheckEnsures,
+                arena_vec![self; checker],
+                None,
+            );
+            let ident = Ident::from_str_and_span("__ensures_checker", span);
+            let (pat, binding) = self.pat_ident(span, ident);
+            stmts.push(self.stmt_let_pat(None, span, Some(checker), pat, hir::LocalSource::Normal));
+            self.contract_ensures.push((ident, binding, *text));
+        }
+
+        let body = self.arena.alloc(self.lower_block_expr(body));
+        let result = self.check_ensures(body);
+        let block = self.block_all(body.span, self.arena.alloc_from_iter(stmts), Some(result));
+        self.expr_block(block)
     }
 
     fn lower_block_expr_opt(&mut self, span: Span, block: Option<&Block>) -> hir::Expr<'hir> {
@@ -1000,21 +1049,27 @@ fn lower_maybe_async_body(
         &mut self,
         span: Span,
         fn_id: hir::HirId,
-        decl: &FnDecl,
-        asyncness: Async,
+        sig: &FnSig,
         body: Option<&Block>,
     ) -> hir::BodyId {
-        let (closure_id, body) = match (asyncness, body) {
-            (Async::Yes { closure_id, .. }, Some(body)) => (closure_id, body),
-            _ => return self.lower_fn_body_block(span, decl, body),
+        let FnSig { decl, header, contract, .. } = sig;
+        let (closure_id, body) = match (header.asyncness, header.genness, body) {
+            (Async::Yes { closure_id, .. }, _, Some(body))
+            | (Async::No, Gen::Yes { closure_id, .. }, Some(body)) => (closure_id, body),
+            _ => {
+                // Contracts of `async`, `gen` and `const` functions are rejected by AST
+                // validation, don't lower them to avoid follow-up errors.
+                let contract = contract.as_deref().filter(|_| header.constness == Const::No);
+                return self.lower_fn_body_block(span, decl, contract, body);
+            }
         };
 
         self.lower_body(|this| {
             let mut parameters: Vec<hir::Param<'_>> = Vec::new();
             let mut statements: Vec<hir::Stmt<'_>> = Vec::new();
 
-            // Async function parameters are lowered into the closure body so that they are
-            // captured and so that the drop order matches the equivalent non-async functions.
+            // Async and gen function parameters are lowered into the closure body so that they
+            // are captured and so that the drop order matches the equivalent non-async functions.
             //
             // from:
             //
@@ -1148,44 +1203,53 @@ fn lower_maybe_async_body(
                 parameters.push(new_parameter);
             }
 
-            let async_expr = this.make_async_expr(
-                CaptureBy::Value,
-                closure_id,
-                None,
-                body.span,
-                hir::AsyncGeneratorKind::Fn,
-                |this| {
-                    // Create a block from the user's function body:
-                    let user_body = this.lower_block_expr(body);
-
-                    // Transform into `drop-temps { <user-body> }`, an expression:
-                    let desugared_span =
-                        this.mark_span_with_reason(DesugaringKind::Async, user_body.span, None);
-                    let user_body =
-                        this.expr_drop_temps(desugared_span, this.arena.alloc(user_body));
-
-                    // As noted above, create the final block like
-                    //
-                    // ```
-                    // {
-                    //   let $param_pattern = $raw_param;
-                    //   ...
-                    //   drop-temps { <user-body> }
-                    // }
-                    // ```
-                    let body = this.block_all(
-                        desugared_span,
-                        this.arena.alloc_from_iter(statements),
-                        Some(user_body),
-                    );
+            let mkbody = |this: &mut Self| {
+                // Create a block from the user's function body:
+                let user_body = this.lower_block_expr(body);
 
-                    this.expr_block(body)
-                },
-            );
+                // Transform into `drop-temps { <user-body> }`, an expression:
+                let desugared_span =
+                    this.mark_span_with_reason(DesugaringKind::Async, user_body.span, None);
+                let user_body = this.expr_drop_temps(desugared_span, this.arena.alloc(user_body));
+
+                // As noted above, create the final block like
+                //
+                // ```
+                // {
+                //   let $param_pattern = $raw_param;
+                //   ...
+                //   drop-temps { <user-body> }
+                // }
+                // ```
+                let body = this.block_all(
+                    desugared_span,
+                    this.arena.alloc_from_iter(statements),
+                    Some(user_body),
+                );
+
+                this.expr_block(body)
+            };
+            let gen_expr = match header.asyncness {
+                Async::Yes { .. } => this.make_async_expr(
+                    CaptureBy::Value,
+                    closure_id,
+                    None,
+                    body.span,
+                    hir::AsyncGeneratorKind::Fn,
+                    mkbody,
+                ),
+                Async::No => this.make_gen_expr(
+                    CaptureBy::Value,
+                    closure_id,
+                    body.span,
+                    hir::IterGeneratorKind::Fn,
+                    mkbody,
+                ),
+            };
 
             let hir_id = this.lower_node_id(closure_id);
             this.maybe_forward_track_caller(body.span, fn_id, hir_id);
-            let expr = hir::Expr { hir_id, kind: async_expr, span: this.lower_span(body.span) };
+            let expr = hir::Expr { hir_id, kind: gen_expr, span: this.lower_span(body.span) };
 
             (this.arena.alloc_from_iter(parameters), expr)
         })
@@ -1197,7 +1261,7 @@ fn lower_method_sig(
         sig: &FnSig,
         id: NodeId,
         kind: FnDeclKind,
-        is_async: Option<(NodeId, Span)>,
+        is_async: Option<(NodeId, Span, GenBlockKind)>,
     ) -> (&'hir hir::Generics<'hir>, hir::FnSig<'hir>) {
         let header = self.lower_fn_header(sig.header);
         let itctx = ImplTraitContext::Universal;
@@ -114,6 +114,11 @@ struct LoweringContext<'a, 'hir> {
     /// `task_context` local bound to the resume argument of the generator.
     task_context: Option<hir::HirId>,
 
+    /// When inside the body of a function with `#[ensures]` contracts, these are the
+    /// bindings of its postcondition checkers, through which every `return` is routed, with
+    /// the source text of their predicates.
+    contract_ensures: Vec<(Ident, hir::HirId, Symbol)>,
+
     /// Used to get the current `fn`'s def span to point to when using `await`
     /// outside of an `async fn`.
     current_item: Option<Span>,
@@ -1362,6 +1367,9 @@ fn lower_ty_direct(&mut self, t: &Ty, itctx: &ImplTraitContext) -> hir::Ty<'hir>
             TyKind::Array(ty, length) => {
                 hir::TyKind::Array(self.lower_ty(ty, itctx), self.lower_array_length(length))
             }
+            TyKind::Pat(ty, pat) => {
+                hir::TyKind::Pat(self.lower_ty(ty, itctx), self.lower_ty_pat(pat))
+            }
             TyKind::Typeof(expr) => hir::TyKind::Typeof(self.lower_anon_const(expr)),
             TyKind::TraitObject(bounds, kind) => {
                 let mut lifetime_bound = None;
@@ -1743,7 +1751,9 @@ fn lower_fn_params_to_names(&mut self, decl: &FnDecl) -> &'hir [Ident] {
     // `fn_node_id`: `impl Trait` arguments are lowered into generic parameters on the given `NodeId`.
     // `make_ret_async`: if `Some`, converts `-> T` into `-> impl Future<Output = T>` in the
     //      return type. This is used for `async fn` declarations. The `NodeId` is the ID of the
-    //      return type `impl Trait` item, and the `Span` points to the `async` keyword.
+    //      return type `impl Trait` item, and the `Span` points to the `async` keyword. For
+    //      `gen fn` declarations, `-> T` is converted into `-> impl Iterator<Item = T>` instead
+    //      and the `Span` points to the `gen` keyword.
     #[instrument(level = "debug", skip(self))]
     fn lower_fn_decl(
         &mut self,
@@ -1751,7 +1761,7 @@ fn lower_fn_decl(
         fn_node_id: NodeId,
         fn_span: Span,
         kind: FnDeclKind,
-        make_ret_async: Option<(NodeId, Span)>,
+        make_ret_async: Option<(NodeId, Span, GenBlockKind)>,
     ) -> &'hir hir::FnDecl<'hir> {
         let c_variadic = decl.c_variadic();
 
@@ -1780,8 +1790,8 @@ fn lower_fn_decl(
             self.lower_ty_direct(&param.ty, &itctx)
         }));
 
-        let output = if let Some((ret_id, span)) = make_ret_async {
-            if !kind.async_fn_allowed(self.tcx) {
+        let output = if let Some((ret_id, span, gen_kind)) = make_ret_async {
+            if gen_kind == GenBlockKind::Async && !kind.async_fn_allowed(self.tcx) {
                 match kind {
                     FnDeclKind::Trait | FnDeclKind::Impl => {
                         self.tcx
@@ -1802,6 +1812,7 @@ fn lower_fn_decl(
                 &decl.output,
                 fn_node_id,
                 ret_id,
+                gen_kind,
                 matches!(kind, FnDeclKind::Trait),
             )
         } else {
@@ -1870,15 +1881,19 @@ fn lower_fn_decl(
     //
     //     type OpaqueTy<generics_from_parent_fn> = impl Future<Output = T>;
     //
+    // For `gen fn`, the opaque type is `impl Iterator<Item = T>` instead.
+    //
     // `output`: unlowered output type (`T` in `-> T`)
     // `fn_node_id`: `NodeId` of the parent function (used to create child impl trait definition)
     // `opaque_ty_node_id`: `NodeId` of the opaque `impl Trait` type that should be created
+    // `gen_kind`: whether this is an `async fn` or a `gen fn`
     #[instrument(level = "debug", skip(self))]
     fn lower_async_fn_ret_ty(
         &mut self,
         output: &FnRetTy,
         fn_node_id: NodeId,
         opaque_ty_node_id: NodeId,
+        gen_kind: GenBlockKind,
         in_trait: bool,
     ) -> hir::FnRetTy<'hir> {
         let span = output.span();
@@ -2011,6 +2026,7 @@ fn lower_async_fn_ret_ty(
                 // hence the elision takes place at the fn site.
                 let future_bound = this.lower_async_fn_output_type_to_future_bound(
                     output,
+                    gen_kind,
                     span,
                     if in_trait && !this.tcx.features().return_position_impl_trait_in_trait {
                         ImplTraitContext::FeatureGated(
@@ -2109,10 +2125,11 @@ fn lower_async_fn_ret_ty(
         hir::FnRetTy::Return(self.arena.alloc(opaque_ty))
     }
 
-    /// Transforms `-> T` into `Future<Output = T>`.
+    /// Transforms `-> T` into `Future<Output = T>`, or into `Iterator<Item = T>` for `gen fn`.
     fn lower_async_fn_output_type_to_future_bound(
         &mut self,
         output: &FnRetTy,
+        gen_kind: GenBlockKind,
         span: Span,
         nested_impl_trait_context: ImplTraitContext,
     ) -> hir::GenericBound<'hir> {
@@ -2127,17 +2144,22 @@ fn lower_async_fn_output_type_to_future_bound(
             FnRetTy::Default(ret_ty_span) => self.arena.alloc(self.ty_tup(*ret_ty_span, &[])),
         };
 
-        // "<Output = T>"
+        let (assoc_ty_name, trait_lang_item) = match gen_kind {
+            GenBlockKind::Async => (hir::FN_OUTPUT_NAME, hir::LangItem::Future),
+            GenBlockKind::Gen => (sym::Item, hir::LangItem::Iterator),
+        };
+
+        // "<Output = T>" or "<Item = T>"
         let future_args = self.arena.alloc(hir::GenericArgs {
             args: &[],
-            bindings: arena_vec![self; self.output_ty_binding(span, output_ty)],
+            bindings: arena_vec![self; self.assoc_ty_binding(assoc_ty_name, span, output_ty)],
             parenthesized: hir::GenericArgsParentheses::No,
             span_ext: DUMMY_SP,
         });
 
         hir::GenericBound::LangItemTrait(
-            // ::std::future::Future<future_params>
-            hir::LangItem::Future,
+            // ::std::future::Future<future_params> or ::std::iter::Iterator<future_params>
+            trait_lang_item,
             self.lower_span(span),
             self.next_id(),
             future_args,
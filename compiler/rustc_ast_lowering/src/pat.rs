@@ -1,5 +1,6 @@
 use super::errors::{
-    ArbitraryExpressionInPattern, ExtraDoubleDot, MisplacedDoubleDot, SubTupleBinding,
+    ArbitraryExpressionInPattern, ExtraDoubleDot, MisplacedDoubleDot,
+    PatternTypeNonLiteralRangeEnd, PatternTypeUnsupportedPattern, SubTupleBinding,
 };
 use super::ResolverAstLoweringExt;
 use super::{ImplTraitContext, LoweringContext, ParamMode};
@@ -10,8 +11,9 @@
 use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_hir as hir;
 use rustc_hir::def::Res;
+use rustc_session::errors::report_lit_error;
 use rustc_span::symbol::Ident;
-use rustc_span::{source_map::Spanned, Span};
+use rustc_span::{source_map::Spanned, ErrorGuaranteed, Span};
 
 impl<'a, 'hir> LoweringContext<'a, 'hir> {
     pub(crate) fn lower_pat(&mut self, pattern: &Pat) -> &'hir hir::Pat<'hir> {
@@ -298,6 +300,52 @@ fn ban_illegal_rest_pat(&self, sp: Span) -> hir::PatKind<'hir> {
         hir::PatKind::Wild
     }
 
+    /// Lowers the pattern of a pattern type. Only ranges with literal endpoints are supported.
+    pub(crate) fn lower_ty_pat(&mut self, pattern: &Pat) -> &'hir hir::TyPat<'hir> {
+        let kind = match &pattern.kind {
+            PatKind::Range(start, end, Spanned { node: end_kind, .. }) => {
+                let start = start.as_deref().map(|e| self.lower_ty_pat_range_end(e)).transpose();
+                let end = end.as_deref().map(|e| self.lower_ty_pat_range_end(e)).transpose();
+                match (start, end) {
+                    (Ok(start), Ok(end)) => {
+                        let end_kind = self.lower_range_end(end_kind, end.is_some());
+                        hir::TyPatKind::Range(start, end, end_kind)
+                    }
+                    (Err(guar), _) | (_, Err(guar)) => hir::TyPatKind::Err(guar),
+                }
+            }
+            _ => {
+                let span = pattern.span;
+                hir::TyPatKind::Err(self.tcx.sess.emit_err(PatternTypeUnsupportedPattern { span }))
+            }
+        };
+        self.arena.alloc(hir::TyPat { kind, span: self.lower_span(pattern.span) })
+    }
+
+    /// Matches `'-' lit | lit`, like `lower_expr_within_pat` but without paths.
+    fn lower_ty_pat_range_end(
+        &mut self,
+        expr: &Expr,
+    ) -> Result<hir::TyPatRangeEnd<'hir>, ErrorGuaranteed> {
+        let (lit_expr, negated) = match &expr.kind {
+            ExprKind::Unary(UnOp::Neg, inner) => (&**inner, true),
+            _ => (expr, false),
+        };
+        let ExprKind::Lit(token_lit) = lit_expr.kind else {
+            return Err(self.tcx.sess.emit_err(PatternTypeNonLiteralRangeEnd { span: expr.span }));
+        };
+        let lit_kind = match LitKind::from_token_lit(token_lit) {
+            Ok(lit_kind) => lit_kind,
+            Err(err) => {
+                report_lit_error(&self.tcx.sess.parse_sess, err, token_lit, lit_expr.span);
+                LitKind::Err
+            }
+        };
+        let span = self.lower_span(lit_expr.span);
+        let lit = self.arena.alloc(Spanned { node: lit_kind, span });
+        Ok(hir::TyPatRangeEnd { lit, negated })
+    }
+
     fn lower_range_end(&mut self, e: &RangeEnd, has_end: bool) -> hir::RangeEnd {
         match *e {
             RangeEnd::Excluded if has_end => hir::RangeEnd::Excluded,
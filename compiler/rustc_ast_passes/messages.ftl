@@ -213,6 +213,24 @@ ast_passes_const_and_async = functions cannot be both `const` and `async`
     .async = `async` because of this
     .label = {""}
 
+ast_passes_const_and_gen = functions cannot be both `const` and `gen`
+    .const = `const` because of this
+    .gen = `gen` because of this
+    .label = {""}
+
+ast_passes_async_and_gen = functions cannot be both `async` and `gen`
+    .async = `async` because of this
+    .gen = `gen` because of this
+    .label = {""}
+
+ast_passes_gen_fn_in_trait = `gen fn` is not supported in traits or trait impls
+    .help = return an `impl Iterator` from a `gen` block instead
+
+ast_passes_contract_without_body = contracts can only be attached to functions with a body
+
+ast_passes_contract_unsupported_fn = contracts are not supported on `{$kw}` functions
+    .label = `{$kw}` because of this
+
 ast_passes_pattern_in_foreign = patterns aren't allowed in foreign function declarations
     .label = pattern not allowed in foreign function
 
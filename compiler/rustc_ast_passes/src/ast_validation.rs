@@ -1217,6 +1217,59 @@ fn visit_fn(&mut self, fk: FnKind<'a>, span: Span, id: NodeId) {
             });
         }
 
+        // `gen` functions cannot be `const` or `async`, nor be declared in traits, which would
+        // need an `impl Iterator` return type in the trait.
+        if let Some(&FnHeader { genness: Gen::Yes { span: gspan, .. }, constness, asyncness, .. }) =
+            fk.header()
+        {
+            if let Const::Yes(cspan) = constness {
+                self.err_handler().emit_err(errors::ConstAndGen {
+                    spans: vec![cspan, gspan],
+                    cspan,
+                    gspan,
+                    span,
+                });
+            }
+            if let Async::Yes { span: aspan, .. } = asyncness {
+                self.err_handler().emit_err(errors::AsyncAndGen {
+                    spans: vec![aspan, gspan],
+                    aspan,
+                    gspan,
+                    span,
+                });
+            }
+            match fk.ctxt() {
+                Some(FnCtxt::Assoc(AssocCtxt::Trait)) => {
+                    self.err_handler().emit_err(errors::GenFnInTrait { span: gspan });
+                }
+                Some(FnCtxt::Assoc(AssocCtxt::Impl)) if self.in_trait_impl => {
+                    self.err_handler().emit_err(errors::GenFnInTrait { span: gspan });
+                }
+                _ => {}
+            }
+        }
+
+        // Contracts are checked around the body of the function when it is called, so it
+        // needs a body that runs to completion when called.
+        if let FnKind::Fn(_, _, FnSig { header, contract: Some(contract), .. }, _, _, body) = fk {
+            let spans =
+                contract.requires.iter().chain(&contract.ensures).map(|c| c.closure.span).collect();
+            let unsupported = if let Const::Yes(span) = header.constness {
+                Some((span, "const"))
+            } else if let Async::Yes { span, .. } = header.asyncness {
+                Some((span, "async"))
+            } else if let Gen::Yes { span, .. } = header.genness {
+                Some((span, "gen"))
+            } else {
+                None
+            };
+            if body.is_none() {
+                self.err_handler().emit_err(errors::ContractWithoutBody { spans });
+            } else if let Some((kw_span, kw)) = unsupported {
+                self.err_handler().emit_err(errors::ContractUnsupportedFn { spans, kw_span, kw });
+            }
+        }
+
         if let FnKind::Fn(
             _,
             _,
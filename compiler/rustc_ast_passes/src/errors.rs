@@ -583,6 +583,57 @@ pub struct ConstAndAsync {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(ast_passes_const_and_gen)]
+pub struct ConstAndGen {
+    #[primary_span]
+    pub spans: Vec<Span>,
+    #[label(ast_passes_const)]
+    pub cspan: Span,
+    #[label(ast_passes_gen)]
+    pub gspan: Span,
+    #[label]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(ast_passes_async_and_gen)]
+pub struct AsyncAndGen {
+    #[primary_span]
+    pub spans: Vec<Span>,
+    #[label(ast_passes_async)]
+    pub aspan: Span,
+    #[label(ast_passes_gen)]
+    pub gspan: Span,
+    #[label]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(ast_passes_gen_fn_in_trait)]
+#[help]
+pub struct GenFnInTrait {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(ast_passes_contract_without_body)]
+pub struct ContractWithoutBody {
+    #[primary_span]
+    pub spans: Vec<Span>,
+}
+
+#[derive(Diagnostic)]
+#[diag(ast_passes_contract_unsupported_fn)]
+pub struct ContractUnsupportedFn {
+    #[primary_span]
+    pub spans: Vec<Span>,
+    #[label]
+    pub kw_span: Span,
+    pub kw: &'static str,
+}
+
 #[derive(Diagnostic)]
 #[diag(ast_passes_pattern_in_foreign, code = "E0130")]
 pub struct PatternInForeign {
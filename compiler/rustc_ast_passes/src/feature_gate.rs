@@ -1,4 +1,5 @@
 use rustc_ast as ast;
+use rustc_ast::token;
 use rustc_ast::visit::{self, AssocCtxt, FnCtxt, FnKind, Visitor};
 use rustc_ast::{attr, AssocConstraint, AssocConstraintKind, NodeId};
 use rustc_ast::{PatKind, RangeEnd};
@@ -347,6 +348,9 @@ fn visit_ty(&mut self, ty: &'a ast::Ty) {
             ast::TyKind::Never => {
                 gate_feature_post!(&self, never_type, ty.span, "the `!` type is experimental");
             }
+            ast::TyKind::Pat(..) => {
+                gate_feature_post!(&self, pattern_types, ty.span, "pattern types are unstable");
+            }
             _ => {}
         }
         visit::walk_ty(self, ty)
@@ -427,6 +431,17 @@ fn visit_expr(&mut self, e: &'a ast::Expr) {
             ast::ExprKind::TryBlock(_) => {
                 gate_feature_post!(&self, try_blocks, e.span, "`try` expression is experimental");
             }
+            ast::ExprKind::Lit(token::Lit {
+                kind: token::LitKind::Integer | token::LitKind::Float,
+                suffix: Some(suffix),
+                ..
+            }) => match suffix {
+                sym::f16 => gate_feature_post!(&self, f16, e.span, "the type `f16` is unstable"),
+                sym::f128 => {
+                    gate_feature_post!(&self, f128, e.span, "the type `f128` is unstable")
+                }
+                _ => {}
+            },
             _ => {}
         }
         visit::walk_expr(self, e)
@@ -590,7 +605,13 @@ macro_rules! gate_all {
         "consider removing `for<...>`"
     );
     gate_all!(more_qualified_paths, "usage of qualified paths in this context is experimental");
-    gate_all!(generators, "yield syntax is experimental");
+    // `yield` can also be used in `gen` blocks, in which case generator literals are gated
+    // during lowering instead.
+    if !visitor.features.gen_blocks {
+        gate_all!(generators, "yield syntax is experimental");
+    }
+    gate_all!(gen_blocks, "gen blocks are experimental");
+    gate_all!(default_field_values, "default values on fields are experimental");
     gate_all!(raw_ref_op, "raw address of syntax is experimental");
     gate_all!(const_trait_impl, "const trait impls are experimental");
     gate_all!(
@@ -603,6 +624,7 @@ macro_rules! gate_all {
     gate_all!(yeet_expr, "`do yeet` expression is experimental");
     gate_all!(dyn_star, "`dyn*` trait objects are experimental");
     gate_all!(const_closures, "const closures are experimental");
+    gate_all!(contracts_internals, "contract internal machinery is for internal use only");
     gate_all!(builtin_syntax, "`builtin #` syntax is unstable");
 
     if !visitor.features.negative_bounds {
@@ -630,6 +652,7 @@ macro_rules! gate_all {
     gate_all!(box_patterns, "box pattern syntax is experimental");
     gate_all!(exclusive_range_pattern, "exclusive range pattern syntax is experimental");
     gate_all!(try_blocks, "`try` blocks are unstable");
+    gate_all!(explicit_tail_calls, "`become` expression is experimental");
     gate_all!(type_ascription, "type ascription is experimental");
 
     visit::walk_crate(&mut visitor, krate);
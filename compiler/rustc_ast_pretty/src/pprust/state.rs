@@ -1080,6 +1080,11 @@ pub fn print_type(&mut self, ty: &ast::Ty) {
                 self.print_expr(&e.value);
                 self.word(")");
             }
+            ast::TyKind::Pat(ty, pat) => {
+                self.print_type(ty);
+                self.word(" is ");
+                self.print_pat(pat);
+            }
             ast::TyKind::Infer => {
                 self.word("_");
             }
@@ -1743,13 +1748,16 @@ pub(crate) fn print_ty_fn(
             span: DUMMY_SP,
         };
         let header = ast::FnHeader { unsafety, ext, ..ast::FnHeader::default() };
-        self.print_fn(decl, header, name, &generics);
+        self.print_fn(decl, header, name, &generics, None);
         self.end();
     }
 
     pub(crate) fn print_fn_header_info(&mut self, header: ast::FnHeader) {
         self.print_constness(header.constness);
         self.print_asyncness(header.asyncness);
+        if header.genness.is_gen() {
+            self.word_nbsp("gen");
+        }
         self.print_unsafety(header.unsafety);
 
         match header.ext {
@@ -74,7 +74,8 @@ pub(super) fn cond_needs_par(expr: &ast::Expr) -> bool {
             ast::ExprKind::Break(..)
             | ast::ExprKind::Closure(..)
             | ast::ExprKind::Ret(..)
-            | ast::ExprKind::Yeet(..) => true,
+            | ast::ExprKind::Yeet(..)
+            | ast::ExprKind::Become(..) => true,
             _ => parser::contains_exterior_struct_lit(expr),
         }
     }
@@ -445,8 +446,8 @@ pub(super) fn print_expr_outer_attr_style(&mut self, expr: &ast::Expr, is_inline
                 self.ibox(0);
                 self.print_block_with_attrs(blk, attrs);
             }
-            ast::ExprKind::Async(capture_clause, blk) => {
-                self.word_nbsp("async");
+            ast::ExprKind::Gen(capture_clause, blk, kind) => {
+                self.word_nbsp(kind.modifier());
                 self.print_capture_clause(*capture_clause);
                 // cbox/ibox in analogy to the `ExprKind::Block` arm above
                 self.cbox(0);
@@ -537,6 +538,11 @@ pub(super) fn print_expr_outer_attr_style(&mut self, expr: &ast::Expr, is_inline
                     self.print_expr_maybe_paren(expr, parser::PREC_JUMP);
                 }
             }
+            ast::ExprKind::Become(result) => {
+                self.word("become");
+                self.word(" ");
+                self.print_expr_maybe_paren(result, parser::PREC_JUMP);
+            }
             ast::ExprKind::InlineAsm(a) => {
                 // FIXME: This should have its own syntax, distinct from a macro invocation.
                 self.word("asm!");
@@ -450,6 +450,11 @@ fn print_record_struct_body(&mut self, fields: &[ast::FieldDef], span: rustc_spa
                 self.print_ident(field.ident.unwrap());
                 self.word_nbsp(":");
                 self.print_type(&field.ty);
+                if let Some(default) = &field.default {
+                    self.space();
+                    self.word_space("=");
+                    self.print_expr(&default.value);
+                }
                 self.word(",");
             }
         }
@@ -562,7 +567,7 @@ fn print_fn_full(
         }
         self.print_visibility(vis);
         self.print_defaultness(defaultness);
-        self.print_fn(&sig.decl, sig.header, Some(name), generics);
+        self.print_fn(&sig.decl, sig.header, Some(name), generics, sig.contract.as_deref());
         if let Some(body) = body {
             self.nbsp();
             self.print_block_with_attrs(body, attrs);
@@ -577,6 +582,7 @@ pub(crate) fn print_fn(
         header: ast::FnHeader,
         name: Option<Ident>,
         generics: &ast::Generics,
+        contract: Option<&ast::FnContract>,
     ) {
         self.print_fn_header_info(header);
         if let Some(name) = name {
@@ -585,9 +591,27 @@ pub(crate) fn print_fn(
         }
         self.print_generic_params(&generics.params);
         self.print_fn_params_and_ret(decl, false);
+        if let Some(contract) = contract {
+            self.print_contract(contract);
+        }
         self.print_where_clause(&generics.where_clause)
     }
 
+    fn print_contract(&mut self, contract: &ast::FnContract) {
+        let ast::FnContract { requires, ensures } = contract;
+        for (keyword, clauses) in [("contract_requires", requires), ("contract_ensures", ensures)] {
+            for clause in clauses.iter() {
+                self.space();
+                self.word(keyword);
+                self.popen();
+                self.print_expr(&clause.closure);
+                self.word_space(",");
+                self.print_string(clause.text.as_str(), ast::StrStyle::Cooked);
+                self.pclose();
+            }
+        }
+    }
+
     pub(crate) fn print_fn_params_and_ret(&mut self, decl: &ast::FnDecl, is_closure: bool) {
         let (open, close) = if is_closure { ("|", "|") } else { ("(", ")") };
         self.word(open);
@@ -13,7 +13,7 @@ fn fun_to_string(
 ) -> String {
     to_string(|s| {
         s.head("");
-        s.print_fn(decl, header, Some(name), generics);
+        s.print_fn(decl, header, Some(name), generics, None);
         s.end(); // Close the head box.
         s.end(); // Close the outer box.
     })
@@ -580,39 +580,59 @@ pub fn cfg_matches(
     lint_node_id: NodeId,
     features: Option<&Features>,
 ) -> bool {
-    eval_condition(cfg, sess, features, &mut |cfg| {
-        try_gate_cfg(cfg.name, cfg.span, sess, features);
-        match sess.check_config.expecteds.get(&cfg.name) {
-            Some(ExpectedValues::Some(values)) if !values.contains(&cfg.value) => {
-                sess.buffer_lint_with_diagnostic(
-                    UNEXPECTED_CFGS,
-                    cfg.span,
-                    lint_node_id,
-                    "unexpected `cfg` condition value",
-                    BuiltinLintDiagnostics::UnexpectedCfgValue(
-                        (cfg.name, cfg.name_span),
-                        cfg.value.map(|v| (v, cfg.value_span.unwrap())),
-                    ),
-                );
-            }
-            None if sess.check_config.exhaustive_names => {
-                sess.buffer_lint_with_diagnostic(
-                    UNEXPECTED_CFGS,
-                    cfg.span,
-                    lint_node_id,
-                    "unexpected `cfg` condition name",
-                    BuiltinLintDiagnostics::UnexpectedCfgName(
-                        (cfg.name, cfg.name_span),
-                        cfg.value.map(|v| (v, cfg.value_span.unwrap())),
-                    ),
-                );
-            }
-            _ => { /* not unexpected */ }
+    if !sess.cfg_sets.is_empty() {
+        // Rustdoc documents the crate for all of its `--cfg-set`s at once, so it keeps what
+        // exists in any of them. The whole predicate is evaluated for each set, as `all(a, b)`
+        // doesn't hold if `a` and `b` are in different sets.
+        let mut matches = false;
+        for (index, config) in sess.cfg_sets.iter().enumerate() {
+            matches |= eval_condition(cfg, sess, features, &mut |cfg| {
+                if index == 0 {
+                    check_cfg(&cfg, sess, lint_node_id, features);
+                }
+                config.contains(&(cfg.name, cfg.value))
+            });
         }
+        return matches;
+    }
+    eval_condition(cfg, sess, features, &mut |cfg| {
+        check_cfg(&cfg, sess, lint_node_id, features);
         sess.config.contains(&(cfg.name, cfg.value))
     })
 }
 
+/// Gates and lints a single cfg of a cfg-pattern.
+fn check_cfg(cfg: &Condition, sess: &ParseSess, lint_node_id: NodeId, features: Option<&Features>) {
+    try_gate_cfg(cfg.name, cfg.span, sess, features);
+    match sess.check_config.expecteds.get(&cfg.name) {
+        Some(ExpectedValues::Some(values)) if !values.contains(&cfg.value) => {
+            sess.buffer_lint_with_diagnostic(
+                UNEXPECTED_CFGS,
+                cfg.span,
+                lint_node_id,
+                "unexpected `cfg` condition value",
+                BuiltinLintDiagnostics::UnexpectedCfgValue(
+                    (cfg.name, cfg.name_span),
+                    cfg.value.map(|v| (v, cfg.value_span.unwrap())),
+                ),
+            );
+        }
+        None if sess.check_config.exhaustive_names => {
+            sess.buffer_lint_with_diagnostic(
+                UNEXPECTED_CFGS,
+                cfg.span,
+                lint_node_id,
+                "unexpected `cfg` condition name",
+                BuiltinLintDiagnostics::UnexpectedCfgName(
+                    (cfg.name, cfg.name_span),
+                    cfg.value.map(|v| (v, cfg.value_span.unwrap())),
+                ),
+            );
+        }
+        _ => { /* not unexpected */ }
+    }
+}
+
 fn try_gate_cfg(name: Symbol, span: Span, sess: &ParseSess, features: Option<&Features>) {
     let gate = find_gated_cfg(|sym| sym == name);
     if let (Some(feats), Some(gated_cfg)) = (features, gate) {
@@ -1,6 +1,7 @@
 use rustc_errors::{
     struct_span_err, DiagnosticBuilder, DiagnosticId, DiagnosticMessage, ErrorGuaranteed, MultiSpan,
 };
+use rustc_hir as hir;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::Span;
 
@@ -379,13 +380,21 @@ pub(crate) fn cannot_borrow_across_generator_yield(
         span: Span,
         yield_span: Span,
     ) -> DiagnosticBuilder<'tcx, ErrorGuaranteed> {
+        let generator_kind = self.body.generator_kind();
         let mut err = struct_span_err!(
             self,
             span,
             E0626,
-            "borrow may still be in use when generator yields",
+            "borrow may still be in use when {} yields",
+            generator_kind.map_or("generator", |kind| kind.descr()),
         );
         err.span_label(yield_span, "possible yield occurs here");
+        if let Some(hir::GeneratorKind::Iter(_)) = generator_kind {
+            err.note(
+                "the iterator created by a `gen` block is not pinned, so it may be moved while \
+                 the borrow is still in use",
+            );
+        }
         err
     }
 
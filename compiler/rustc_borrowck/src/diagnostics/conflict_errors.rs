@@ -9,7 +9,7 @@
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::intravisit::{walk_block, walk_expr, Visitor};
-use rustc_hir::{AsyncGeneratorKind, GeneratorKind, LangItem};
+use rustc_hir::{AsyncGeneratorKind, GeneratorKind, IterGeneratorKind, LangItem};
 use rustc_infer::traits::ObligationCause;
 use rustc_middle::hir::nested_filter::OnlyBodies;
 use rustc_middle::mir::tcx::PlaceTy;
@@ -2267,6 +2267,10 @@ fn report_escaping_closure_capture(
                     AsyncGeneratorKind::Closure => "async closure",
                     _ => bug!("async block/closure expected, but async function found."),
                 },
+                GeneratorKind::Iter(iter_kind) => match iter_kind {
+                    IterGeneratorKind::Block => "gen block",
+                    IterGeneratorKind::Fn => bug!("gen block expected, but gen function found."),
+                },
                 GeneratorKind::Gen => "generator",
             },
             None => "closure",
@@ -1027,7 +1027,10 @@ fn suggest_move_on_borrowing_closure(&self, diag: &mut Diagnostic) {
                 ..
             }) => {
                 let body = map.body(*body);
-                if !matches!(body.generator_kind, Some(hir::GeneratorKind::Async(..))) {
+                if !matches!(
+                    body.generator_kind,
+                    Some(hir::GeneratorKind::Async(..) | hir::GeneratorKind::Iter(..))
+                ) {
                     closure_span = Some(expr.span.shrink_to_lo());
                 }
             }
@@ -701,6 +701,10 @@ fn give_name_if_anonymous_region_appears_in_output(&self, fr: RegionVid) -> Opti
                             " of async function"
                         }
                     },
+                    Some(hir::GeneratorKind::Iter(gen)) => match gen {
+                        hir::IterGeneratorKind::Block => " of gen block",
+                        hir::IterGeneratorKind::Fn => " of gen function",
+                    },
                     Some(hir::GeneratorKind::Gen) => " of generator",
                     None => " of closure",
                 };
@@ -1323,7 +1323,8 @@ fn check_terminator(
                 }
                 // FIXME: check the values
             }
-            TerminatorKind::Call { func, args, destination, from_hir_call, target, .. } => {
+            TerminatorKind::Call { func, args, .. }
+            | TerminatorKind::TailCall { func, args, .. } => {
                 self.check_operand(func, term_location);
                 for arg in args {
                     self.check_operand(arg, term_location);
@@ -1379,7 +1380,32 @@ fn check_terminator(
                     ConstraintCategory::Boring,
                 );
                 let sig = self.normalize(sig, term_location);
-                self.check_call_dest(body, term, &sig, *destination, *target, term_location);
+                let from_hir_call = match term.kind {
+                    TerminatorKind::Call { destination, target, from_hir_call, .. } => {
+                        self.check_call_dest(body, term, &sig, destination, target, term_location);
+                        from_hir_call
+                    }
+                    _ => {
+                        // The callee of a tail call returns to our caller.
+                        let return_ty = body.local_decls[RETURN_PLACE].ty;
+                        if let Err(terr) = self.sub_types(
+                            sig.output(),
+                            return_ty,
+                            term_location.to_locations(),
+                            ConstraintCategory::Return(ReturnConstraint::Normal),
+                        ) {
+                            span_mirbug!(
+                                self,
+                                term,
+                                "tail call returns {:?} to {:?}: {:?}",
+                                sig.output(),
+                                return_ty,
+                                terr
+                            );
+                        }
+                        true
+                    }
+                };
 
                 // The ordinary liveness rules will ensure that all
                 // regions in the type of the callee are live here. We
@@ -1397,7 +1423,7 @@ fn check_terminator(
                         .add_element(region_vid, term_location);
                 }
 
-                self.check_call_inputs(body, term, &sig, args, term_location, *from_hir_call);
+                self.check_call_inputs(body, term, &sig, args, term_location, from_hir_call);
             }
             TerminatorKind::Assert { cond, msg, .. } => {
                 self.check_operand(cond, term_location);
@@ -1531,10 +1557,11 @@ fn check_call_inputs(
             span_mirbug!(self, term, "call to {:?} with wrong # of args", sig);
         }
 
-        let func_ty = if let TerminatorKind::Call { func, .. } = &term.kind {
-            Some(func.ty(body, self.infcx.tcx))
-        } else {
-            None
+        let func_ty = match &term.kind {
+            TerminatorKind::Call { func, .. } | TerminatorKind::TailCall { func, .. } => {
+                Some(func.ty(body, self.infcx.tcx))
+            }
+            _ => None,
         };
         debug!(?func_ty);
 
@@ -1590,6 +1617,11 @@ fn check_iscleanup(&mut self, body: &Body<'tcx>, block_data: &BasicBlockData<'tc
                     span_mirbug!(self, block_data, "return on cleanup block")
                 }
             }
+            TerminatorKind::TailCall { .. } => {
+                if is_cleanup {
+                    span_mirbug!(self, block_data, "tailcall on cleanup block")
+                }
+            }
             TerminatorKind::GeneratorDrop { .. } => {
                 if is_cleanup {
                     span_mirbug!(self, block_data, "generator_drop in cleanup block")
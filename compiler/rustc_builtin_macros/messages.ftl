@@ -6,6 +6,8 @@ builtin_macros_expected_one_cfg_pattern = expected 1 cfg-pattern
 
 builtin_macros_alloc_error_must_be_fn = alloc_error_handler must be a function
 
+builtin_macros_contract_not_on_fn = contract attributes can only be applied to functions with a body
+
 builtin_macros_assert_requires_boolean = macro requires a boolean expression as an argument
     .label = boolean expression required
 
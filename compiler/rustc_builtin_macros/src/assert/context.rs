@@ -286,8 +286,8 @@ fn manage_cond_expr(&mut self, expr: &mut P<Expr>) {
             // sync with the `rfc-2011-nicer-assert-messages/all-expr-kinds.rs` test.
             ExprKind::Assign(_, _, _)
             | ExprKind::AssignOp(_, _, _)
-            | ExprKind::Async(_, _)
             | ExprKind::Await(_, _)
+            | ExprKind::Become(_)
             | ExprKind::Block(_, _)
             | ExprKind::Break(_, _)
             | ExprKind::Closure(_)
@@ -297,6 +297,7 @@ fn manage_cond_expr(&mut self, expr: &mut P<Expr>) {
             | ExprKind::Field(_, _)
             | ExprKind::FormatArgs(_)
             | ExprKind::ForLoop(_, _, _, _)
+            | ExprKind::Gen(_, _, _)
             | ExprKind::If(_, _, _)
             | ExprKind::IncludedBytes(..)
             | ExprKind::InlineAsm(_)
@@ -0,0 +1,105 @@
+//! Implementation of the `#[requires(PRE)]` and `#[ensures(POST)]` contract attributes.
+//!
+//! Both attributes rewrite the annotated function into the internal contract syntax that the
+//! parser accepts between the signature and the where clause:
+//!
+//! ```ignore (illustrative)
+//! fn foo(x: u32) -> u32 contract_requires(|| PRE, "PRE") contract_ensures(POST, "POST") where ...
+//! ```
+//!
+//! The contract clauses are then carried by the AST and desugared into runtime checks when
+//! lowering the function body to HIR. The source text of each predicate is passed along to be
+//! reported when its check fails.
+
+use crate::errors;
+use rustc_ast::token::{self, Delimiter};
+use rustc_ast::tokenstream::{DelimSpan, TokenStream, TokenTree};
+use rustc_ast::util::literal::escape_string_symbol;
+use rustc_ast_pretty::pprust;
+use rustc_errors::ErrorGuaranteed;
+use rustc_expand::base::{AttrProcMacro, ExtCtxt};
+use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_span::Span;
+
+pub(crate) struct ExpandRequires;
+
+pub(crate) struct ExpandEnsures;
+
+impl AttrProcMacro for ExpandRequires {
+    fn expand<'cx>(
+        &self,
+        ecx: &'cx mut ExtCtxt<'_>,
+        span: Span,
+        annotation: TokenStream,
+        annotated: TokenStream,
+    ) -> Result<TokenStream, ErrorGuaranteed> {
+        // The precondition is evaluated lazily, so that it is skipped entirely when contract
+        // checks are disabled.
+        let span = ecx.with_def_site_ctxt(span);
+        let closure = TokenStream::new(vec![
+            TokenTree::token_alone(token::OrOr, span),
+            TokenTree::Delimited(
+                DelimSpan::from_single(span),
+                Delimiter::Parenthesis,
+                annotation.clone(),
+            ),
+        ]);
+        expand_contract_clause(ecx, span, sym::contract_requires, closure, &annotation, annotated)
+    }
+}
+
+impl AttrProcMacro for ExpandEnsures {
+    fn expand<'cx>(
+        &self,
+        ecx: &'cx mut ExtCtxt<'_>,
+        span: Span,
+        annotation: TokenStream,
+        annotated: TokenStream,
+    ) -> Result<TokenStream, ErrorGuaranteed> {
+        // The postcondition is already a closure taking a reference to the returned value.
+        let span = ecx.with_def_site_ctxt(span);
+        let closure = annotation.clone();
+        expand_contract_clause(ecx, span, sym::contract_ensures, closure, &annotation, annotated)
+    }
+}
+
+/// Inserts `keyword(closure, "annotation")` into the annotated function, right before its where
+/// clause if it has one, and otherwise right before its body.
+fn expand_contract_clause(
+    ecx: &mut ExtCtxt<'_>,
+    span: Span,
+    keyword: Symbol,
+    closure: TokenStream,
+    annotation: &TokenStream,
+    annotated: TokenStream,
+) -> Result<TokenStream, ErrorGuaranteed> {
+    let mut trees: Vec<TokenTree> = annotated.trees().cloned().collect();
+
+    let is_keyword =
+        |tree: &TokenTree, name| matches!(tree, TokenTree::Token(tok, _) if tok.is_keyword(name));
+    let has_body = matches!(trees.last(), Some(TokenTree::Delimited(_, Delimiter::Brace, _)));
+    if !has_body || !trees.iter().any(|tree| is_keyword(tree, kw::Fn)) {
+        return Err(ecx.emit_err(errors::ContractNotOnFn { span }));
+    }
+
+    let text = escape_string_symbol(Symbol::intern(&pprust::tts_to_string(annotation)));
+    let text = token::Lit::new(token::Str, text, None);
+    let mut clause: Vec<TokenTree> = closure.trees().cloned().collect();
+    clause.push(TokenTree::token_alone(token::Comma, span));
+    clause.push(TokenTree::token_alone(token::Literal(text), span));
+
+    let index =
+        trees.iter().position(|tree| is_keyword(tree, kw::Where)).unwrap_or(trees.len() - 1);
+    trees.splice(
+        index..index,
+        [
+            TokenTree::token_alone(token::Ident(keyword, false), span),
+            TokenTree::Delimited(
+                DelimSpan::from_single(span),
+                Delimiter::Parenthesis,
+                TokenStream::new(clause),
+            ),
+        ],
+    );
+    Ok(TokenStream::new(trees))
+}
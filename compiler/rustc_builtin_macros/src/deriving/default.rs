@@ -8,6 +8,7 @@
 use rustc_span::symbol::{kw, sym};
 use rustc_span::Span;
 use smallvec::SmallVec;
+use std::iter;
 use thin_vec::{thin_vec, ThinVec};
 
 pub fn expand_deriving_default(
@@ -38,8 +39,8 @@ pub fn expand_deriving_default(
             fieldless_variants_strategy: FieldlessVariantsStrategy::Default,
             combine_substructure: combine_substructure(Box::new(|cx, trait_span, substr| {
                 match substr.fields {
-                    StaticStruct(_, fields) => {
-                        default_struct_substructure(cx, trait_span, substr, fields)
+                    StaticStruct(struct_def, fields) => {
+                        default_struct_substructure(cx, trait_span, substr, struct_def, fields)
                     }
                     StaticEnum(enum_def, _) => default_enum_substructure(cx, trait_span, enum_def),
                     _ => cx.span_bug(trait_span, "method in `derive(Default)`"),
@@ -56,6 +57,7 @@ fn default_struct_substructure(
     cx: &mut ExtCtxt<'_>,
     trait_span: Span,
     substr: &Substructure<'_>,
+    struct_def: &VariantData,
     summary: &StaticFields,
 ) -> BlockOrExpr {
     // Note that `kw::Default` is "default" and `sym::Default` is "Default"!
@@ -69,9 +71,19 @@ fn default_struct_substructure(
             cx.expr_call_ident(trait_span, substr.type_ident, exprs)
         }
         Named(fields) => {
-            let default_fields = fields
-                .iter()
-                .map(|&(ident, span)| cx.field_imm(span, ident, default_call(span)))
+            // Fields with a default value use it, as an inline const, instead of
+            // `Default::default()`.
+            let default_fields = iter::zip(fields, struct_def.fields())
+                .map(|(&(ident, span), field)| {
+                    let value = match &field.default {
+                        Some(default) => cx.expr(
+                            default.value.span,
+                            ast::ExprKind::ConstBlock(default.clone()),
+                        ),
+                        None => default_call(span),
+                    };
+                    cx.field_imm(span, ident, value)
+                })
                 .collect();
             cx.expr_struct_ident(trait_span, substr.type_ident, default_fields)
         }
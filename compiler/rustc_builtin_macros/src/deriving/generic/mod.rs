@@ -997,7 +997,12 @@ fn create_method(
 
         let trait_lo_sp = span.shrink_to_lo();
 
-        let sig = ast::FnSig { header: ast::FnHeader::default(), decl: fn_decl, span };
+        let sig = ast::FnSig {
+            header: ast::FnHeader::default(),
+            decl: fn_decl,
+            contract: None,
+            span,
+        };
         let defaultness = ast::Defaultness::Final;
 
         // Create the method.
@@ -27,6 +27,13 @@ pub(crate) struct AllocErrorMustBeFn {
     pub(crate) span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(builtin_macros_contract_not_on_fn)]
+pub(crate) struct ContractNotOnFn {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(builtin_macros_assert_requires_boolean)]
 pub(crate) struct AssertRequiresBoolean {
@@ -82,7 +82,7 @@ fn allocator_fn(&self, method: &AllocatorMethod) -> Stmt {
         let (output_ty, output_expr) = self.ret_ty(&method.output, result);
         let decl = self.cx.fn_decl(abi_args, ast::FnRetTy::Ty(output_ty));
         let header = FnHeader { unsafety: Unsafe::Yes(self.span), ..FnHeader::default() };
-        let sig = FnSig { decl, header, span: self.span };
+        let sig = FnSig { decl, header, contract: None, span: self.span };
         let body = Some(self.cx.block_expr(output_expr));
         let kind = ItemKind::Fn(Box::new(Fn {
             defaultness: ast::Defaultness::Final,
@@ -363,6 +363,9 @@ fn codegen_call_argument_operand<'tcx>(
     }
 }
 
+/// Codegens a call to `func`, jumping to `target` afterwards. A tail call returns the result of
+/// the call instead; Cranelift can't guarantee the reuse of the frame yet, so it is a normal
+/// call followed by a return.
 pub(crate) fn codegen_terminator_call<'tcx>(
     fx: &mut FunctionCx<'_, '_, 'tcx>,
     source_info: mir::SourceInfo,
@@ -370,6 +373,7 @@ pub(crate) fn codegen_terminator_call<'tcx>(
     args: &[Operand<'tcx>],
     destination: Place<'tcx>,
     target: Option<BasicBlock>,
+    is_tail_call: bool,
 ) {
     let func = codegen_operand(fx, func);
     let fn_sig = func.layout().ty.fn_sig(fx.tcx);
@@ -382,6 +386,13 @@ pub(crate) fn codegen_terminator_call<'tcx>(
             ty::Instance::expect_resolve(fx.tcx, ty::ParamEnv::reveal_all(), def_id, substs)
                 .polymorphize(fx.tcx);
 
+        if is_tail_call
+            && (matches!(instance.def, InstanceDef::Intrinsic(_))
+                || fx.tcx.symbol_name(instance).name.starts_with("llvm."))
+        {
+            fx.tcx.sess.span_fatal(source_info.span, "tail calls to intrinsics are not supported");
+        }
+
         if fx.tcx.symbol_name(instance).name.starts_with("llvm.") {
             crate::intrinsics::codegen_llvm_intrinsic_call(
                 fx,
@@ -406,6 +417,11 @@ pub(crate) fn codegen_terminator_call<'tcx>(
                 );
                 return;
             }
+            InstanceDef::DropGlue(_, None) if is_tail_call => {
+                // empty drop glue - a nop.
+                codegen_return(fx);
+                return;
+            }
             InstanceDef::DropGlue(_, None) => {
                 // empty drop glue - a nop.
                 let dest = target.expect("Non terminating drop_in_place_real???");
@@ -572,7 +588,9 @@ enum CallTarget {
         call_inst
     });
 
-    if let Some(dest) = target {
+    if is_tail_call {
+        codegen_return(fx);
+    } else if let Some(dest) = target {
         let ret_block = fx.get_block(dest);
         fx.bcx.ins().jump(ret_block, &[]);
     } else {
@@ -430,6 +430,20 @@ fn codegen_fn_body(fx: &mut FunctionCx<'_, '_, '_>, start_block: Block) {
                         args,
                         *destination,
                         *target,
+                        false,
+                    )
+                });
+            }
+            TerminatorKind::TailCall { func, args, fn_span } => {
+                fx.tcx.prof.generic_activity("codegen call").run(|| {
+                    crate::abi::codegen_terminator_call(
+                        fx,
+                        mir::SourceInfo { span: *fn_span, ..source_info },
+                        func,
+                        args,
+                        mir::Place::return_place(),
+                        None,
+                        true,
                     )
                 });
             }
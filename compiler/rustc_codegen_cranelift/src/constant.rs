@@ -553,6 +553,7 @@ pub(crate) fn mir_operand_get_const_val<'tcx>(
                     | TerminatorKind::Resume
                     | TerminatorKind::Terminate
                     | TerminatorKind::Return
+                    | TerminatorKind::TailCall { .. }
                     | TerminatorKind::Unreachable
                     | TerminatorKind::Drop { .. }
                     | TerminatorKind::Assert { .. } => {}
@@ -26,6 +26,7 @@ macro_rules! intrinsic_args {
 use rustc_middle::ty::print::{with_no_trimmed_paths, with_no_visible_paths};
 use rustc_middle::ty::subst::SubstsRef;
 use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_target::abi::WrappingRange;
 
 use crate::prelude::*;
 use cranelift_codegen::ir::AtomicRmwOp;
@@ -715,6 +716,27 @@ fn codegen_regular_intrinsic_call<'tcx>(
             ret.write_cvalue(fx, CValue::by_val(val, fx.layout_of(fx.tcx.types.u8)));
         }
 
+        sym::pattern_type_contains => {
+            intrinsic_args!(fx, args => (value); intrinsic);
+
+            // The values of a pattern type are exactly the valid range of its scalar layout.
+            let pat_ty = substs.type_at(0);
+            let Abi::Scalar(scalar) = fx.layout_of(pat_ty).abi else {
+                bug!("pattern type `{:?}` without a scalar layout", pat_ty);
+            };
+            let WrappingRange { start, end } = scalar.valid_range(fx);
+            let size = scalar.size(fx);
+            let len = ty::ScalarInt::try_from_uint(size.truncate(end.wrapping_sub(start)), size);
+            let start = ty::ScalarInt::try_from_uint(start, size);
+            let base_layout = value.layout();
+            let len = CValue::const_val(fx, base_layout, len.unwrap()).load_scalar(fx);
+            let start = CValue::const_val(fx, base_layout, start.unwrap()).load_scalar(fx);
+            let value = value.load_scalar(fx);
+            let offset = fx.bcx.ins().isub(value, start);
+            let res = fx.bcx.ins().icmp(IntCC::UnsignedLessThanOrEqual, offset, len);
+            ret.write_cvalue(fx, CValue::by_val(res, ret.layout()));
+        }
+
         sym::caller_location => {
             intrinsic_args!(fx, args => (); intrinsic);
 
@@ -64,5 +64,11 @@ codegen_gcc_invalid_monomorphization_unsupported_operation =
 codegen_gcc_invalid_minimum_alignment =
     invalid minimum global alignment: {$err}
 
+codegen_gcc_float_type_not_supported =
+    `{$name}` is not supported by the GCC backend
+
+codegen_gcc_target_clones_not_supported =
+    `#[target_clones]` is not supported by the GCC backend
+
 codegen_gcc_tied_target_features = the target features {$features} must all be either enabled or disabled together
     .help = add the missing features in a `target_feature` attribute
@@ -71,6 +71,8 @@ pub struct Builder<'a: 'gcc, 'gcc, 'tcx> {
     pub cx: &'a CodegenCx<'gcc, 'tcx>,
     pub block: Block<'gcc>,
     stack_var_count: Cell<usize>,
+    /// The most recently emitted call, so that `set_musttail` can mark it as a tail call.
+    last_call: Cell<Option<RValue<'gcc>>>,
 }
 
 impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
@@ -79,6 +81,7 @@ fn with_cx(cx: &'a CodegenCx<'gcc, 'tcx>, block: Block<'gcc>) -> Self {
             cx,
             block,
             stack_var_count: Cell::new(0),
+            last_call: Cell::new(None),
         }
     }
 
@@ -267,14 +270,16 @@ fn function_call(&mut self, func: RValue<'gcc>, args: &[RValue<'gcc>], _funclet:
         let return_type = func.get_return_type();
         let void_type = self.context.new_type::<()>();
         let current_func = self.block.get_function();
+        let call = self.cx.context.new_call(None, func, &args);
+        self.last_call.set(Some(call));
         if return_type != void_type {
             unsafe { RETURN_VALUE_COUNT += 1 };
             let result = current_func.new_local(None, return_type, &format!("returnValue{}", unsafe { RETURN_VALUE_COUNT }));
-            self.block.add_assignment(None, result, self.cx.context.new_call(None, func, &args));
+            self.block.add_assignment(None, result, call);
             result.to_rvalue()
         }
         else {
-            self.block.add_eval(None, self.cx.context.new_call(None, func, &args));
+            self.block.add_eval(None, call);
             // Return dummy value when not having return value.
             self.context.new_rvalue_from_long(self.isize_type, 0)
         }
@@ -302,6 +307,7 @@ fn function_ptr_call(&mut self, func_ptr: RValue<'gcc>, args: &[RValue<'gcc>], _
         if return_type != void_type {
             unsafe { RETURN_VALUE_COUNT += 1 };
             let return_value = self.cx.context.new_call_through_ptr(None, func_ptr, &args);
+            self.last_call.set(Some(return_value));
             let return_value = llvm::adjust_intrinsic_return_value(&self, return_value, &func_name, &args, args_adjusted, orig_args);
             let result = current_func.new_local(None, return_value.get_type(), &format!("ptrReturnValue{}", unsafe { RETURN_VALUE_COUNT }));
             self.block.add_assignment(None, result, return_value);
@@ -309,15 +315,18 @@ fn function_ptr_call(&mut self, func_ptr: RValue<'gcc>, args: &[RValue<'gcc>], _
         }
         else {
             #[cfg(not(feature="master"))]
-            if gcc_func.get_param_count() == 0 {
-                // FIXME(antoyo): As a temporary workaround for unsupported LLVM intrinsics.
-                self.block.add_eval(None, self.cx.context.new_call_through_ptr(None, func_ptr, &[]));
-            }
-            else {
-                self.block.add_eval(None, self.cx.context.new_call_through_ptr(None, func_ptr, &args));
-            }
+            let call =
+                if gcc_func.get_param_count() == 0 {
+                    // FIXME(antoyo): As a temporary workaround for unsupported LLVM intrinsics.
+                    self.cx.context.new_call_through_ptr(None, func_ptr, &[])
+                }
+                else {
+                    self.cx.context.new_call_through_ptr(None, func_ptr, &args)
+                };
             #[cfg(feature="master")]
-            self.block.add_eval(None, self.cx.context.new_call_through_ptr(None, func_ptr, &args));
+            let call = self.cx.context.new_call_through_ptr(None, func_ptr, &args);
+            self.last_call.set(Some(call));
+            self.block.add_eval(None, call);
             // Return dummy value when not having return value.
             let result = current_func.new_local(None, self.isize_type, "dummyValueThatShouldNeverBeUsed");
             self.block.add_assignment(None, result, self.context.new_rvalue_from_long(self.isize_type, 0));
@@ -1396,6 +1405,13 @@ fn do_not_inline(&mut self, _llret: RValue<'gcc>) {
         // FIXME(bjorn3): implement
     }
 
+    fn set_musttail(&mut self, _llret: RValue<'gcc>) {
+        // NOTE: `llret` is the local the result was assigned to, not the call itself.
+        if let Some(call) = self.last_call.take() {
+            call.set_require_tail_call(true);
+        }
+    }
+
     fn set_span(&mut self, _span: Span) {}
 
     fn from_immediate(&mut self, val: Self::Value) -> Self::Value {
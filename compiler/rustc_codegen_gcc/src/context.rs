@@ -19,6 +19,7 @@
 use rustc_target::spec::{HasTargetSpec, Target, TlsModel};
 
 use crate::callee::get_fn;
+use crate::errors::TargetClonesNotSupported;
 
 #[derive(Clone)]
 pub struct FuncSig<'gcc> {
@@ -451,6 +452,11 @@ fn declare_c_main(&self, fn_type: Self::Type) -> Option<Self::Function> {
             None
         }
     }
+
+    fn define_target_clones_dispatcher(&self, instance: Instance<'tcx>, _clones: &[RValue<'gcc>]) {
+        let span = self.tcx.def_span(instance.def_id());
+        self.tcx.sess.emit_fatal(TargetClonesNotSupported { span })
+    }
 }
 
 impl<'gcc, 'tcx> HasTyCtxt<'tcx> for CodegenCx<'gcc, 'tcx> {
@@ -228,6 +228,19 @@ pub(crate) struct InvalidMinimumAlignment {
     pub err: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_gcc_float_type_not_supported)]
+pub(crate) struct FloatTypeNotSupported {
+    pub name: &'static str,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_gcc_target_clones_not_supported)]
+pub(crate) struct TargetClonesNotSupported {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(codegen_gcc_tied_target_features)]
 #[help]
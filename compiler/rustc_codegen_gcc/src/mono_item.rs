@@ -1,5 +1,6 @@
 #[cfg(feature="master")]
 use gccjit::{VarAttribute, FnAttribute};
+use gccjit::RValue;
 use rustc_codegen_ssa::traits::PreDefineMethods;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
@@ -10,6 +11,7 @@
 use crate::attributes;
 use crate::base;
 use crate::context::CodegenCx;
+use crate::errors::TargetClonesNotSupported;
 use crate::type_of::LayoutGccExt;
 
 impl<'gcc, 'tcx> PreDefineMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
@@ -63,4 +65,10 @@ fn predefine_fn(&self, instance: Instance<'tcx>, linkage: Linkage, visibility: V
         self.functions.borrow_mut().insert(symbol_name.to_string(), decl);
         self.function_instances.borrow_mut().insert(instance, unsafe { std::mem::transmute(decl) });
     }
+
+    fn predefine_target_clone(&self, instance: Instance<'tcx>, _index: usize) -> RValue<'gcc> {
+        // TODO(antoyo): use the `target_clones` function attribute once gccjit exposes it.
+        let span = self.tcx.def_span(instance.def_id());
+        self.tcx.sess.emit_fatal(TargetClonesNotSupported { span })
+    }
 }
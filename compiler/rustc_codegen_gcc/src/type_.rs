@@ -7,6 +7,7 @@
 
 use crate::common::TypeReflection;
 use crate::context::CodegenCx;
+use crate::errors::FloatTypeNotSupported;
 use crate::type_of::LayoutGccExt;
 
 impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
@@ -66,8 +67,10 @@ pub fn type_vector(&self, ty: Type<'gcc>, len: u64) -> Type<'gcc> {
 
     pub fn type_float_from_ty(&self, t: ty::FloatTy) -> Type<'gcc> {
         match t {
+            ty::FloatTy::F16 => self.type_f16(),
             ty::FloatTy::F32 => self.type_f32(),
             ty::FloatTy::F64 => self.type_f64(),
+            ty::FloatTy::F128 => self.type_f128(),
         }
     }
 }
@@ -101,6 +104,11 @@ fn type_isize(&self) -> Type<'gcc> {
         self.isize_type
     }
 
+    fn type_f16(&self) -> Type<'gcc> {
+        // FIXME(antoyo): use `_Float16` once the gccjit bindings expose it.
+        self.tcx.sess.emit_fatal(FloatTypeNotSupported { name: "f16" })
+    }
+
     fn type_f32(&self) -> Type<'gcc> {
         self.context.new_type::<f32>()
     }
@@ -109,6 +117,11 @@ fn type_f64(&self) -> Type<'gcc> {
         self.context.new_type::<f64>()
     }
 
+    fn type_f128(&self) -> Type<'gcc> {
+        // FIXME(antoyo): use `__float128` once the gccjit bindings expose it.
+        self.tcx.sess.emit_fatal(FloatTypeNotSupported { name: "f128" })
+    }
+
     fn type_func(&self, params: &[Type<'gcc>], return_type: Type<'gcc>) -> Type<'gcc> {
         self.context.new_function_pointer_type(None, return_type, params, false)
     }
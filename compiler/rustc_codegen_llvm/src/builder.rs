@@ -518,7 +518,7 @@ fn scalar_load_metadata<'a, 'll, 'tcx>(
                         }
                     }
                 }
-                abi::F32 | abi::F64 => {}
+                abi::F16 | abi::F32 | abi::F64 | abi::F128 => {}
             }
         }
 
@@ -1233,6 +1233,10 @@ fn do_not_inline(&mut self, llret: &'ll Value) {
         let noinline = llvm::AttributeKind::NoInline.create_attr(self.llcx);
         attributes::apply_to_callsite(llret, llvm::AttributePlace::Function, &[noinline]);
     }
+
+    fn set_musttail(&mut self, llret: &'ll Value) {
+        unsafe { llvm::LLVMRustSetMustTail(llret) }
+    }
 }
 
 impl<'ll> StaticBuilderMethods for Builder<'_, 'll, '_> {
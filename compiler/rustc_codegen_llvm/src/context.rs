@@ -597,6 +597,10 @@ fn declare_c_main(&self, fn_type: Self::Type) -> Option<Self::Function> {
             None
         }
     }
+
+    fn define_target_clones_dispatcher(&self, instance: Instance<'tcx>, clones: &[&'ll Value]) {
+        self.codegen_target_clones_dispatcher(instance, clones)
+    }
 }
 
 impl<'ll> CodegenCx<'ll, '_> {
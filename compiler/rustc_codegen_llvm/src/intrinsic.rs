@@ -151,10 +151,11 @@ fn codegen_intrinsic_call(
                                     emit_va_arg(self, args[0], ret_ty)
                                 }
                             }
-                            Primitive::F64 | Primitive::Pointer(_) => {
+                            Primitive::F64 | Primitive::F128 | Primitive::Pointer(_) => {
                                 emit_va_arg(self, args[0], ret_ty)
                             }
-                            // `va_arg` should never be used with the return type f32.
+                            // `va_arg` should never be used with the return type f16 or f32.
+                            Primitive::F16 => bug!("the va_arg intrinsic does not work with `f16`"),
                             Primitive::F32 => bug!("the va_arg intrinsic does not work with `f32`"),
                         }
                     }
@@ -1039,8 +1039,10 @@ pub struct AllocKindFlags : u64 {
     pub fn LLVMGetIntTypeWidth(IntegerTy: &Type) -> c_uint;
 
     // Operations on real types
+    pub fn LLVMHalfTypeInContext(C: &Context) -> &Type;
     pub fn LLVMFloatTypeInContext(C: &Context) -> &Type;
     pub fn LLVMDoubleTypeInContext(C: &Context) -> &Type;
+    pub fn LLVMFP128TypeInContext(C: &Context) -> &Type;
 
     // Operations on function types
     pub fn LLVMFunctionType<'a>(
@@ -1255,6 +1257,7 @@ pub fn LLVMRustAddCallSiteAttributes<'a>(
         Attrs: *const &'a Attribute,
         AttrsLen: size_t,
     );
+    pub fn LLVMRustSetMustTail(Instr: &Value);
 
     // Operations on load/store instructions (only)
     pub fn LLVMSetVolatile(MemoryAccessInst: &Value, volatile: Bool);
@@ -1,19 +1,26 @@
+use crate::abi::FnAbiLlvmExt;
 use crate::attributes;
 use crate::base;
+use crate::builder::Builder;
 use crate::context::CodegenCx;
 use crate::errors::SymbolAlreadyDefined;
 use crate::llvm;
 use crate::type_of::LayoutLlvmExt;
+use crate::value::Value;
+use rustc_codegen_ssa::common::{AtomicOrdering, IntPredicate};
 use rustc_codegen_ssa::traits::*;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::LangItem;
 pub use rustc_middle::mir::mono::MonoItem;
 use rustc_middle::mir::mono::{Linkage, Visibility};
 use rustc_middle::ty::layout::{FnAbiOf, LayoutOf};
 use rustc_middle::ty::{self, Instance, TypeVisitableExt};
 use rustc_session::config::CrateType;
+use rustc_target::abi::call::PassMode;
 use rustc_target::spec::RelocModel;
+use std::iter;
 
-impl<'tcx> PreDefineMethods<'tcx> for CodegenCx<'_, 'tcx> {
+impl<'ll, 'tcx> PreDefineMethods<'tcx> for CodegenCx<'ll, 'tcx> {
     fn predefine_static(
         &self,
         def_id: DefId,
@@ -88,9 +95,90 @@ fn predefine_fn(
 
         self.instances.borrow_mut().insert(instance, lldecl);
     }
+
+    fn predefine_target_clone(&self, instance: Instance<'tcx>, index: usize) -> &'ll Value {
+        let features = &self.tcx.codegen_fn_attrs(instance.def_id()).target_clones[index];
+        let suffix = if features.is_empty() {
+            "default".to_string()
+        } else {
+            features.iter().map(|f| f.as_str()).intersperse("_").collect()
+        };
+        let symbol_name = format!("{}.{}", self.tcx.symbol_name(instance).name, suffix);
+
+        // The copies are only ever called through the dispatcher, which lives in the same
+        // codegen unit, so they never need to be visible outside of it.
+        let fn_abi = self.fn_abi_of_instance(instance, ty::List::empty());
+        let lldecl = self.declare_fn(&symbol_name, fn_abi, Some(instance));
+        unsafe { llvm::LLVMRustSetLinkage(lldecl, llvm::Linkage::InternalLinkage) };
+        base::set_link_section(lldecl, self.tcx.codegen_fn_attrs(instance.def_id()));
+        attributes::from_fn_attrs_with_features(self, lldecl, instance, features);
+        lldecl
+    }
 }
 
-impl CodegenCx<'_, '_> {
+impl<'ll, 'tcx> CodegenCx<'ll, 'tcx> {
+    /// Emits the body of a `#[target_clones]` function: the first call asks `std` which of
+    /// the feature sets of `clones` the CPU supports, caches the chosen copy in a private
+    /// global, and every call is then forwarded to it.
+    pub(crate) fn codegen_target_clones_dispatcher(
+        &self,
+        instance: Instance<'tcx>,
+        clones: &[&'ll Value],
+    ) {
+        let tcx = self.tcx;
+        let target_clones = &tcx.codegen_fn_attrs(instance.def_id()).target_clones;
+        let llfn = self.get_fn(instance);
+        let fn_abi = self.fn_abi_of_instance(instance, ty::List::empty());
+        let llfnty = fn_abi.llvm_type(self);
+        let llptrty = self.val_ty(llfn);
+        let ptr_size = tcx.data_layout.pointer_size;
+
+        let cache = self.define_private_global(llptrty);
+        unsafe { llvm::LLVMSetInitializer(cache, self.const_null(llptrty)) };
+
+        let detect = Instance::mono(
+            tcx,
+            tcx.require_lang_item(LangItem::TargetClonesFeaturesDetected, None),
+        );
+        let detect_fn_abi = self.fn_abi_of_instance(detect, ty::List::empty());
+        let detect_llfnty = detect_fn_abi.llvm_type(self);
+        let detect_llfn = self.get_fn_addr(detect);
+
+        let start_llbb = Builder::append_block(self, llfn, "start");
+        let mut bx = Builder::build(self, start_llbb);
+        let resolve_llbb = bx.append_sibling_block("resolve");
+        let call_llbb = bx.append_sibling_block("call");
+
+        let cached = bx.atomic_load(llptrty, cache, AtomicOrdering::Relaxed, ptr_size);
+        let is_resolved = bx.icmp(IntPredicate::IntNE, cached, self.const_null(llptrty));
+        bx.cond_br(is_resolved, call_llbb, resolve_llbb);
+
+        // Walk the feature sets from the back, so that an earlier one that is supported
+        // takes precedence over all the ones after it, down to the `"default"` copy.
+        bx.switch_to_block(resolve_llbb);
+        let (default, clones) = clones.split_last().unwrap();
+        let mut chosen = *default;
+        for (features, &clone) in iter::zip(target_clones, clones).rev() {
+            let features: String = features.iter().map(|f| f.as_str()).intersperse(",").collect();
+            let (ptr, len) = self.const_str(&features);
+            let supported =
+                bx.call(detect_llfnty, None, Some(detect_fn_abi), detect_llfn, &[ptr, len], None);
+            chosen = bx.select(supported, clone, chosen);
+        }
+        bx.atomic_store(chosen, cache, AtomicOrdering::Relaxed, ptr_size);
+        bx.br(call_llbb);
+
+        bx.switch_to_block(call_llbb);
+        let target = bx.phi(llptrty, &[cached, chosen], &[start_llbb, resolve_llbb]);
+        let args: Vec<_> =
+            (0..unsafe { llvm::LLVMCountParams(llfn) }).map(|i| llvm::get_param(llfn, i)).collect();
+        let ret = bx.call(llfnty, None, Some(fn_abi), target, &args, None);
+        match fn_abi.ret.mode {
+            PassMode::Ignore | PassMode::Indirect { .. } => bx.ret_void(),
+            PassMode::Direct(_) | PassMode::Pair(..) | PassMode::Cast(..) => bx.ret(ret),
+        }
+    }
+
     /// Whether a definition or declaration can be assumed to be local to a group of
     /// libraries that form a single DSO or executable.
     pub(crate) unsafe fn should_assume_dso_local(
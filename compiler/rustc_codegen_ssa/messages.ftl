@@ -304,3 +304,10 @@ codegen_ssa_illegal_link_ordinal_format = illegal ordinal format in `link_ordina
 codegen_ssa_target_feature_safe_trait = `#[target_feature(..)]` cannot be applied to safe trait method
     .label = cannot be applied to safe trait method
     .label_def = not an `unsafe` function
+
+codegen_ssa_tail_call_unsupported = {$kind ->
+    [intrinsic] tail calls to intrinsics
+    [virtual] tail calls through trait objects
+    [track_caller] tail calls to `#[track_caller]` functions
+    *[indirect] tail calls passing arguments by reference
+} are not supported yet
@@ -395,7 +395,24 @@ pub fn codegen_instance<'a, 'tcx: 'a, Bx: BuilderMethods<'a, 'tcx>>(
     // release builds.
     info!("codegen_instance({})", instance);
 
-    mir::codegen_mir::<Bx>(cx, instance);
+    let target_clones = match instance.def {
+        ty::InstanceDef::Item(def_id) => &cx.tcx().codegen_fn_attrs(def_id).target_clones[..],
+        _ => &[],
+    };
+    if target_clones.is_empty() {
+        mir::codegen_mir::<Bx>(cx, instance, cx.get_fn(instance));
+    } else {
+        // Compile the body once for every feature set, and turn the function itself into a
+        // dispatcher forwarding to the best of those copies the running CPU supports.
+        let clones: Vec<_> = (0..target_clones.len())
+            .map(|index| {
+                let llfn = cx.predefine_target_clone(instance, index);
+                mir::codegen_mir::<Bx>(cx, instance, llfn);
+                llfn
+            })
+            .collect();
+        cx.define_target_clones_dispatcher(instance, &clones);
+    }
 }
 
 /// Creates the `main` function which will initialize the rust runtime and call
@@ -15,7 +15,7 @@
 use rustc_target::spec::{abi, SanitizerSet};
 
 use crate::errors;
-use crate::target_features::from_target_feature;
+use crate::target_features::{from_target_clones, from_target_feature};
 use crate::{errors::ExpectedUsedSymbol, target_features::check_target_feature_trait_unsafe};
 
 fn linkage_by_name(tcx: TyCtxt<'_>, def_id: LocalDefId, name: &str) -> Linkage {
@@ -65,6 +65,7 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: LocalDefId) -> CodegenFnAttrs {
     let mut inline_span = None;
     let mut link_ordinal_span = None;
     let mut no_sanitize_span = None;
+    let mut target_clones_span = None;
 
     for attr in attrs.iter() {
         // In some cases, attribute are only valid on functions, but it's the `check_attr`
@@ -287,6 +288,11 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: LocalDefId) -> CodegenFnAttrs {
                     &mut codegen_fn_attrs.target_features,
                 );
             }
+            sym::target_clones => {
+                target_clones_span = Some(attr.span);
+                codegen_fn_attrs.target_clones =
+                    from_target_clones(tcx, attr, supported_target_features);
+            }
             sym::linkage => {
                 if let Some(val) = attr.value_str() {
                     let linkage = Some(linkage_by_name(tcx, did, val.as_str()));
@@ -518,6 +524,27 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: LocalDefId) -> CodegenFnAttrs {
         }
     }
 
+    // Every clone of a `#[target_clones]` function is only reachable through the dispatcher
+    // choosing between them at runtime, so none of them can be inlined, and enabling target
+    // features for all of them at once defeats the purpose.
+    if let Some(span) = target_clones_span {
+        if codegen_fn_attrs.inline == InlineAttr::Always {
+            tcx.sess.span_err(span, "cannot use `#[inline(always)]` with `#[target_clones]`");
+        }
+        if !codegen_fn_attrs.target_features.is_empty() {
+            tcx.sess.span_err(span, "cannot use `#[target_feature]` with `#[target_clones]`");
+        }
+        if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::NAKED) {
+            tcx.sess.span_err(span, "cannot use `#[naked]` with `#[target_clones]`");
+        }
+        if tcx.lang_items().get(LangItem::TargetClonesFeaturesDetected).is_none() {
+            tcx.sess
+                .struct_span_err(span, "`#[target_clones]` requires the standard library")
+                .note("runtime detection of target features is provided by `std`")
+                .emit();
+        }
+    }
+
     if !codegen_fn_attrs.no_sanitize.is_empty() {
         if codegen_fn_attrs.inline == InlineAttr::Always {
             if let (Some(no_sanitize_span), Some(inline_span)) = (no_sanitize_span, inline_span) {
@@ -15,7 +15,7 @@
 use rustc_data_structures::stable_hasher::{Hash64, HashStable, StableHasher};
 use rustc_hir::def_id::DefId;
 use rustc_hir::definitions::{DefPathData, DefPathDataName, DisambiguatedDefPathData};
-use rustc_hir::{AsyncGeneratorKind, GeneratorKind, Mutability};
+use rustc_hir::{AsyncGeneratorKind, GeneratorKind, IterGeneratorKind, Mutability};
 use rustc_middle::ty::layout::{IntegerExt, TyAndLayout};
 use rustc_middle::ty::subst::{GenericArgKind, SubstsRef};
 use rustc_middle::ty::{self, ExistentialProjection, ParamEnv, Ty, TyCtxt};
@@ -76,6 +76,10 @@ fn push_debuginfo_type_name<'tcx>(
         ty::Int(int_ty) => output.push_str(int_ty.name_str()),
         ty::Uint(uint_ty) => output.push_str(uint_ty.name_str()),
         ty::Float(float_ty) => output.push_str(float_ty.name_str()),
+        // Debuggers only know about the base type of a pattern type.
+        ty::Pat(inner_type, _) => {
+            push_debuginfo_type_name(tcx, inner_type, qualified, output, visited)
+        }
         ty::Foreign(def_id) => push_item_name(tcx, def_id, qualified, output),
         ty::Adt(def, substs) => {
             // `layout_for_cpp_like_fallback` will be `Some` if we want to use the fallback encoding.
@@ -554,6 +558,8 @@ fn generator_kind_label(generator_kind: Option<GeneratorKind>) -> &'static str {
         Some(GeneratorKind::Async(AsyncGeneratorKind::Block)) => "async_block",
         Some(GeneratorKind::Async(AsyncGeneratorKind::Closure)) => "async_closure",
         Some(GeneratorKind::Async(AsyncGeneratorKind::Fn)) => "async_fn",
+        Some(GeneratorKind::Iter(IterGeneratorKind::Block)) => "gen_block",
+        Some(GeneratorKind::Iter(IterGeneratorKind::Fn)) => "gen_fn",
         Some(GeneratorKind::Gen) => "generator",
         None => "closure",
     }
@@ -1015,3 +1015,11 @@ pub struct TargetFeatureSafeTrait {
     #[label(codegen_ssa_label_def)]
     pub def: Span,
 }
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_tail_call_unsupported)]
+pub struct TailCallUnsupported {
+    #[primary_span]
+    pub span: Span,
+    pub kind: &'static str,
+}
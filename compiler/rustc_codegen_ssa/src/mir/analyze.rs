@@ -288,6 +288,7 @@ fn discover_masters<'tcx>(
                 | TerminatorKind::Resume
                 | TerminatorKind::Terminate
                 | TerminatorKind::Return
+                | TerminatorKind::TailCall { .. }
                 | TerminatorKind::GeneratorDrop
                 | TerminatorKind::Unreachable
                 | TerminatorKind::SwitchInt { .. }
@@ -5,6 +5,7 @@
 
 use crate::base;
 use crate::common::{self, IntPredicate};
+use crate::errors;
 use crate::meth;
 use crate::traits::*;
 use crate::MemFlags;
@@ -1076,6 +1077,159 @@ fn codegen_call_terminator(
         )
     }
 
+    /// Codegens a `become` as a call which is guaranteed to reuse the frame of the caller,
+    /// immediately followed by a return of its result.
+    fn codegen_tail_call_terminator(
+        &mut self,
+        bx: &mut Bx,
+        terminator: &mir::Terminator<'tcx>,
+        func: &mir::Operand<'tcx>,
+        args: &[mir::Operand<'tcx>],
+    ) -> MergingSucc {
+        let span = terminator.source_info.span;
+        let callee = self.codegen_operand(bx, func);
+
+        let instance = match *callee.layout.ty.kind() {
+            ty::FnDef(def_id, substs) => Some(
+                ty::Instance::expect_resolve(bx.tcx(), ty::ParamEnv::reveal_all(), def_id, substs)
+                    .polymorphize(bx.tcx()),
+            ),
+            ty::FnPtr(_) => None,
+            _ => bug!("{} is not callable", callee.layout.ty),
+        };
+
+        // `check_tail_calls` only sees the callee before monomorphization, so anything which is
+        // only known once the instance is resolved has to be rejected here.
+        let unsupported = match instance.map(|i| i.def) {
+            Some(ty::InstanceDef::DropGlue(_, None)) => {
+                // Empty drop glue; a no-op, so there is nothing left to do but return.
+                self.codegen_return_terminator(bx);
+                return MergingSucc::False;
+            }
+            Some(ty::InstanceDef::Intrinsic(_)) => Some("intrinsic"),
+            Some(ty::InstanceDef::Virtual(..)) => Some("virtual"),
+            Some(def) if def.requires_caller_location(bx.tcx()) => Some("track_caller"),
+            _ => None,
+        };
+
+        let fn_abi = match instance {
+            Some(instance) => bx.fn_abi_of_instance(instance, ty::List::empty()),
+            None => {
+                let sig = callee.layout.ty.fn_sig(bx.tcx());
+                bx.fn_abi_of_fn_ptr(sig, ty::List::empty())
+            }
+        };
+
+        // Arguments passed by reference must not point into the frame of the caller, which the
+        // tail call is about to reuse, so they are moved into the matching incoming argument
+        // slots of the caller instead. `become` requires matching signatures, so such a slot
+        // exists unless the caller keeps that argument out of memory.
+        let mut arg_slots = Vec::with_capacity(fn_abi.args.len());
+        for (i, arg_abi) in fn_abi.args.iter().enumerate() {
+            arg_slots.push(match arg_abi.mode {
+                PassMode::Indirect { extra_attrs: None, on_stack: false, .. } => {
+                    self.tail_call_arg_slot(i, arg_abi)
+                }
+                _ => None,
+            });
+        }
+        let unsupported = unsupported.or_else(|| {
+            fn_abi
+                .args
+                .iter()
+                .zip(&arg_slots)
+                .any(|(arg, slot)| {
+                    matches!(arg.mode, PassMode::Indirect { on_stack: false, .. }) && slot.is_none()
+                })
+                .then_some("indirect")
+        });
+        if let Some(kind) = unsupported {
+            bx.tcx().sess.emit_err(errors::TailCallUnsupported { span, kind });
+            bx.unreachable();
+            return MergingSucc::False;
+        }
+
+        let mut llargs = Vec::with_capacity(fn_abi.args.len() + fn_abi.ret.is_indirect() as usize);
+        if fn_abi.ret.is_indirect() {
+            // The callee writes its result directly to the return place of the caller.
+            match self.locals[mir::RETURN_PLACE] {
+                LocalRef::Place(dest) => llargs.push(dest.llval),
+                _ => bug!("indirect return place is not a place"),
+            }
+        }
+        // Arguments may be read from the very slots they are moved into (e.g. when the caller
+        // forwards its own arguments in a different order), so every argument is read before
+        // any slot is written: by-value ones are loaded, by-reference ones copied to temporaries.
+        let mut by_ref_args = Vec::new();
+        for ((arg, arg_abi), slot) in args.iter().zip(&fn_abi.args).zip(arg_slots) {
+            let op = self.codegen_operand(bx, arg);
+            if arg_abi.is_sized_indirect() {
+                let tmp = PlaceRef::alloca(bx, arg_abi.layout);
+                op.val.store(bx, tmp);
+                by_ref_args.push((llargs.len(), tmp, slot));
+                llargs.push(tmp.llval);
+            } else {
+                self.codegen_argument(bx, op, &mut llargs, arg_abi);
+            }
+        }
+        for (idx, tmp, slot) in by_ref_args {
+            if let Some(slot) = slot {
+                base::memcpy_ty(
+                    bx,
+                    slot.llval,
+                    slot.align,
+                    tmp.llval,
+                    tmp.align,
+                    tmp.layout,
+                    MemFlags::empty(),
+                );
+                llargs[idx] = slot.llval;
+            }
+        }
+
+        let fn_ptr = match instance {
+            Some(instance) => bx.get_fn_addr(instance),
+            None => callee.immediate(),
+        };
+        let fn_ty = bx.fn_decl_backend_type(&fn_abi);
+        let fn_attrs = if bx.tcx().def_kind(self.instance.def_id()).has_codegen_attrs() {
+            Some(bx.tcx().codegen_fn_attrs(self.instance.def_id()))
+        } else {
+            None
+        };
+        let llret = bx.call(fn_ty, fn_attrs, Some(&fn_abi), fn_ptr, &llargs, None);
+        bx.set_musttail(llret);
+
+        match fn_abi.ret.mode {
+            PassMode::Ignore | PassMode::Indirect { .. } => bx.ret_void(),
+            PassMode::Direct(_) | PassMode::Pair(..) | PassMode::Cast(..) => bx.ret(llret),
+        }
+        MergingSucc::False
+    }
+
+    /// Returns the incoming argument slot of the current function which the `index`-th argument
+    /// of a tail call, passed by reference as `arg`, can be moved into.
+    fn tail_call_arg_slot(
+        &self,
+        index: usize,
+        arg: &ArgAbi<'tcx, Ty<'tcx>>,
+    ) -> Option<PlaceRef<'tcx, Bx::Value>> {
+        if self.mir.spread_arg.is_some() || index >= self.mir.arg_count {
+            return None;
+        }
+        let caller_arg = self.fn_abi.args.get(index)?;
+        if !matches!(caller_arg.mode, PassMode::Indirect { extra_attrs: None, on_stack: false, .. })
+            || caller_arg.layout.size < arg.layout.size
+            || caller_arg.layout.align.abi < arg.layout.align.abi
+        {
+            return None;
+        }
+        match self.locals[mir::Local::new(index + 1)] {
+            LocalRef::Place(place) => Some(place),
+            _ => None,
+        }
+    }
+
     fn codegen_asm_terminator(
         &mut self,
         helper: TerminatorCodegenHelper<'tcx>,
@@ -1293,6 +1447,9 @@ fn codegen_terminator(
                 fn_span,
                 mergeable_succ(),
             ),
+            mir::TerminatorKind::TailCall { ref func, ref args, fn_span: _ } => {
+                self.codegen_tail_call_terminator(bx, terminator, func, args)
+            }
             mir::TerminatorKind::GeneratorDrop | mir::TerminatorKind::Yield { .. } => {
                 bug!("generator ops in codegen")
             }
@@ -13,7 +13,7 @@
 use rustc_span::{sym, Span};
 use rustc_target::abi::{
     call::{FnAbi, PassMode},
-    WrappingRange,
+    Abi, WrappingRange,
 };
 
 fn copy_intrinsic<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
@@ -321,6 +321,21 @@ pub fn codegen_intrinsic_call(
                 }
             }
 
+            sym::pattern_type_contains => {
+                // The values of a pattern type are exactly the valid range of its scalar layout.
+                let pat_ty = substs.type_at(0);
+                let Abi::Scalar(scalar) = bx.layout_of(pat_ty).abi else {
+                    span_bug!(span, "pattern type `{:?}` without a scalar layout", pat_ty)
+                };
+                let WrappingRange { start, end } = scalar.valid_range(bx.cx());
+                let size = scalar.size(bx.cx());
+                let value = args[0].immediate();
+                let llty = bx.val_ty(value);
+                let offset = bx.sub(value, bx.const_uint_big(llty, start));
+                let len = bx.const_uint_big(llty, size.truncate(end.wrapping_sub(start)));
+                bx.icmp(IntPredicate::IntULE, offset, len)
+            }
+
             sym::const_allocate => {
                 // returns a null pointer at runtime.
                 bx.const_null(bx.type_i8p())
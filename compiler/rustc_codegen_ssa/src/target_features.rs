@@ -351,7 +351,6 @@ pub fn from_target_feature(
             .span_suggestion(span, "must be of the form", code, Applicability::HasPlaceholders)
             .emit();
     };
-    let rust_features = tcx.features();
     for item in list {
         // Only `enable = ...` is accepted in the meta-item list.
         if !item.has_name(sym::enable) {
@@ -367,54 +366,119 @@ pub fn from_target_feature(
 
         // We allow comma separation to enable multiple features.
         target_features.extend(value.as_str().split(',').filter_map(|feature| {
-            let Some(feature_gate) = supported_target_features.get(feature) else {
-                let msg =
-                    format!("the feature named `{}` is not valid for this target", feature);
-                let mut err = tcx.sess.struct_span_err(item.span(), msg);
-                err.span_label(
-                    item.span(),
-                    format!("`{}` is not valid for this target", feature),
-                );
-                if let Some(stripped) = feature.strip_prefix('+') {
-                    let valid = supported_target_features.contains_key(stripped);
-                    if valid {
-                        err.help("consider removing the leading `+` in the feature name");
-                    }
-                }
-                err.emit();
-                return None;
-            };
+            check_target_feature_name(tcx, item.span(), feature, supported_target_features)
+        }));
+    }
+}
 
-            // Only allow features whose feature gates have been enabled.
-            let allowed = match feature_gate.as_ref().copied() {
-                Some(sym::arm_target_feature) => rust_features.arm_target_feature,
-                Some(sym::hexagon_target_feature) => rust_features.hexagon_target_feature,
-                Some(sym::powerpc_target_feature) => rust_features.powerpc_target_feature,
-                Some(sym::mips_target_feature) => rust_features.mips_target_feature,
-                Some(sym::riscv_target_feature) => rust_features.riscv_target_feature,
-                Some(sym::avx512_target_feature) => rust_features.avx512_target_feature,
-                Some(sym::sse4a_target_feature) => rust_features.sse4a_target_feature,
-                Some(sym::tbm_target_feature) => rust_features.tbm_target_feature,
-                Some(sym::wasm_target_feature) => rust_features.wasm_target_feature,
-                Some(sym::rtm_target_feature) => rust_features.rtm_target_feature,
-                Some(sym::ermsb_target_feature) => rust_features.ermsb_target_feature,
-                Some(sym::bpf_target_feature) => rust_features.bpf_target_feature,
-                Some(sym::aarch64_ver_target_feature) => rust_features.aarch64_ver_target_feature,
-                Some(name) => bug!("unknown target feature gate {}", name),
-                None => true,
-            };
-            if !allowed {
-                feature_err(
-                    &tcx.sess.parse_sess,
-                    feature_gate.unwrap(),
-                    item.span(),
-                    format!("the target feature `{}` is currently unstable", feature),
-                )
+/// Parses the feature sets of a `#[target_clones("..", "default")]` attribute. Each string is
+/// a comma-separated list of target features, and the `"default"` set, which enables no extra
+/// features, is moved to the end of the returned list.
+pub fn from_target_clones(
+    tcx: TyCtxt<'_>,
+    attr: &ast::Attribute,
+    supported_target_features: &FxHashMap<String, Option<Symbol>>,
+) -> Vec<Vec<Symbol>> {
+    let Some(list) = attr.meta_item_list() else { return vec![] };
+    let mut target_clones: Vec<Vec<Symbol>> = Vec::with_capacity(list.len());
+    let mut has_default = false;
+    for item in list.iter() {
+        let Some(value) = item.lit().and_then(|lit| lit.kind.str()) else {
+            let msg = "malformed `target_clones` attribute input";
+            tcx.sess
+                .struct_span_err(item.span(), msg)
+                .span_label(item.span(), "expected a string of comma-separated target features")
                 .emit();
+            continue;
+        };
+
+        if value.as_str() == "default" {
+            if has_default {
+                tcx.sess.span_err(item.span(), "`\"default\"` is listed more than once");
             }
-            Some(Symbol::intern(feature))
-        }));
+            has_default = true;
+            continue;
+        }
+
+        let features: Vec<Symbol> = value
+            .as_str()
+            .split(',')
+            .filter_map(|feature| {
+                check_target_feature_name(tcx, item.span(), feature, supported_target_features)
+            })
+            .collect();
+        if target_clones.contains(&features) {
+            let msg = format!("the feature set `{}` is listed more than once", value);
+            tcx.sess.span_err(item.span(), msg);
+        } else if !features.is_empty() {
+            target_clones.push(features);
+        }
+    }
+
+    if !has_default {
+        tcx.sess
+            .struct_span_err(attr.span, "`#[target_clones]` requires a `\"default\"` feature set")
+            .help(
+                "add `\"default\"` to provide the version used when none of the other \
+                 feature sets are supported",
+            )
+            .emit();
+    }
+    target_clones.push(vec![]);
+    target_clones
+}
+
+/// Checks that `feature` is supported by the current target and that its feature gate, if
+/// any, is enabled, reporting an error at `span` otherwise.
+fn check_target_feature_name(
+    tcx: TyCtxt<'_>,
+    span: Span,
+    feature: &str,
+    supported_target_features: &FxHashMap<String, Option<Symbol>>,
+) -> Option<Symbol> {
+    let rust_features = tcx.features();
+    let Some(feature_gate) = supported_target_features.get(feature) else {
+        let msg = format!("the feature named `{}` is not valid for this target", feature);
+        let mut err = tcx.sess.struct_span_err(span, msg);
+        err.span_label(span, format!("`{}` is not valid for this target", feature));
+        if let Some(stripped) = feature.strip_prefix('+') {
+            let valid = supported_target_features.contains_key(stripped);
+            if valid {
+                err.help("consider removing the leading `+` in the feature name");
+            }
+        }
+        err.emit();
+        return None;
+    };
+
+    // Only allow features whose feature gates have been enabled.
+    let allowed = match feature_gate.as_ref().copied() {
+        Some(sym::arm_target_feature) => rust_features.arm_target_feature,
+        Some(sym::hexagon_target_feature) => rust_features.hexagon_target_feature,
+        Some(sym::powerpc_target_feature) => rust_features.powerpc_target_feature,
+        Some(sym::mips_target_feature) => rust_features.mips_target_feature,
+        Some(sym::riscv_target_feature) => rust_features.riscv_target_feature,
+        Some(sym::avx512_target_feature) => rust_features.avx512_target_feature,
+        Some(sym::sse4a_target_feature) => rust_features.sse4a_target_feature,
+        Some(sym::tbm_target_feature) => rust_features.tbm_target_feature,
+        Some(sym::wasm_target_feature) => rust_features.wasm_target_feature,
+        Some(sym::rtm_target_feature) => rust_features.rtm_target_feature,
+        Some(sym::ermsb_target_feature) => rust_features.ermsb_target_feature,
+        Some(sym::bpf_target_feature) => rust_features.bpf_target_feature,
+        Some(sym::aarch64_ver_target_feature) => rust_features.aarch64_ver_target_feature,
+        Some(name) => bug!("unknown target feature gate {}", name),
+        None => true,
+    };
+    if !allowed {
+        feature_err(
+            &tcx.sess.parse_sess,
+            feature_gate.unwrap(),
+            span,
+            format!("the target feature `{}` is currently unstable", feature),
+        )
+        .emit();
     }
+    Some(Symbol::intern(feature))
 }
 
 /// Computes the set of target features used in a function for the purposes of
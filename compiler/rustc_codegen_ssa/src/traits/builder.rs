@@ -333,4 +333,8 @@ fn call(
     fn zext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
 
     fn do_not_inline(&mut self, llret: Self::Value);
+
+    /// Requires the call `llret` to be performed as a tail call, reusing the frame of the
+    /// current function. It must be immediately followed by a return of its result.
+    fn set_musttail(&mut self, llret: Self::Value);
 }
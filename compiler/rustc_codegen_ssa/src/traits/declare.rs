@@ -18,4 +18,7 @@ fn predefine_fn(
         visibility: Visibility,
         symbol_name: &str,
     );
+    /// Declares the internal copy of the `#[target_clones]` function `instance` that is
+    /// compiled with its `index`-th set of target features.
+    fn predefine_target_clone(&self, instance: Instance<'tcx>, index: usize) -> Self::Function;
 }
@@ -19,4 +19,7 @@ fn vtables(
     fn apply_target_cpu_attr(&self, llfn: Self::Function);
     /// Declares the extern "C" main function for the entry point. Returns None if the symbol already exists.
     fn declare_c_main(&self, fn_type: Self::Type) -> Option<Self::Function>;
+    /// Defines the body of the `#[target_clones]` function `instance` so that it forwards to
+    /// the first of `clones` whose target features are supported at runtime.
+    fn define_target_clones_dispatcher(&self, instance: Instance<'tcx>, clones: &[Self::Function]);
 }
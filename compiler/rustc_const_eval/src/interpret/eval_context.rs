@@ -855,6 +855,26 @@ pub(super) fn pop_stack_frame(&mut self, unwinding: bool) -> InterpResult<'tcx>
         }
     }
 
+    /// Pops the current frame to make room for a tail call. Unlike `pop_stack_frame`, this
+    /// neither copies the return value nor jumps anywhere: the tail callee takes over the return
+    /// place and the return target of the popped frame.
+    pub(super) fn pop_stack_frame_for_tail_call(&mut self) -> InterpResult<'tcx> {
+        info!("popping stack frame (tail call)");
+
+        // We need to take the locals out, since we need to mutate while iterating.
+        let locals = mem::take(&mut self.frame_mut().locals);
+        for local in &locals {
+            self.deallocate_local(local.value)?;
+        }
+
+        let frame =
+            self.stack_mut().pop().expect("tried to pop a stack frame, but there were none");
+        // The hook can only take over the jump when unwinding, which a tail call never does, and
+        // there is no jump to take over anyway.
+        M::after_stack_pop(self, frame, /* unwinding */ false)?;
+        Ok(())
+    }
+
     /// Mark a storage as live, killing the previous content.
     pub fn storage_live(&mut self, local: mir::Local) -> InterpResult<'tcx> {
         assert!(local != mir::RETURN_PLACE, "Cannot make return place live");
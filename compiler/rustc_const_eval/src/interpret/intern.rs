@@ -55,6 +55,9 @@ struct InternVisitor<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx, const_ev
     /// This field stores whether we are *currently* inside an `UnsafeCell`. This can affect
     /// the intern mode of references we encounter.
     inside_unsafe_cell: bool,
+    /// Whether we already reported a mutable reference to a heap allocation, to only do so once
+    /// per static.
+    reported_mutable_heap_reference: &'rt mut bool,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Hash, Eq)]
@@ -260,6 +263,24 @@ fn visit_value(&mut self, mplace: &MPlaceTy<'tcx>) -> InterpResult<'tcx> {
             // Check if we have encountered this pointer+layout combination before.
             // Only recurse for allocation-backed pointers.
             if let Some(alloc_id) = mplace.ptr.provenance {
+                // Heap allocations left in the final value are interned as immutable memory, so
+                // they must not be handed out through mutable references.
+                if ref_mutability == Mutability::Mut
+                    && let InternMode::Static(_) = self.mode
+                    && let Some((MemoryKind::Machine(const_eval::MemoryKind::Heap), _)) =
+                        self.ecx.memory.alloc_map.get(&alloc_id)
+                    && !*self.reported_mutable_heap_reference
+                {
+                    *self.reported_mutable_heap_reference = true;
+                    tcx.sess
+                        .struct_span_err(
+                            tcx.span,
+                            "mutable references to heap allocations are not allowed in the final \
+                             value of a static",
+                        )
+                        .help("heap allocations can only be exposed through shared references")
+                        .emit();
+                }
                 // Compute the mode with which we intern this. Our goal here is to make as many
                 // statics as we can immutable so they can be placed in read-only memory by LLVM.
                 let ref_mode = match self.mode {
@@ -357,6 +378,20 @@ pub fn intern_const_alloc_recursive<
     let mut ref_tracking = RefTracking::empty();
     let leftover_allocations = &mut FxIndexSet::default();
 
+    // Heap allocations that the outermost allocation points to without going through a reference
+    // are still owned by the final value, e.g. the buffer of a `Vec`. That value might get dropped
+    // or grown at runtime, which would hand the static memory we are about to create to the
+    // allocator. Allocations only reachable through shared references are fine: they have been
+    // leaked and can never be freed.
+    let root_pointees: FxIndexSet<AllocId> = ecx
+        .memory
+        .alloc_map
+        .get(&ret.ptr.provenance.unwrap())
+        .map(|(_, alloc)| alloc.provenance().ptrs().iter().map(|&(_, alloc_id)| alloc_id).collect())
+        .unwrap_or_default();
+    let mut reported_owned_heap_allocation = false;
+    let mut reported_mutable_heap_reference = false;
+
     // start with the outermost allocation
     intern_shallow(
         ecx,
@@ -377,6 +412,7 @@ pub fn intern_const_alloc_recursive<
             mode,
             leftover_allocations,
             inside_unsafe_cell: false,
+            reported_mutable_heap_reference: &mut reported_mutable_heap_reference,
         }
         .visit_value(&mplace);
         // We deliberately *ignore* interpreter errors here. When there is a problem, the remaining
@@ -403,7 +439,26 @@ pub fn intern_const_alloc_recursive<
     debug!(?todo);
     debug!("dead_alloc_map: {:#?}", ecx.memory.dead_alloc_map);
     while let Some(alloc_id) = todo.pop() {
-        if let Some((_, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
+        if let Some((kind, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
+            let is_heap = kind == MemoryKind::Machine(const_eval::MemoryKind::Heap);
+            if is_heap && root_pointees.contains(&alloc_id) && !reported_owned_heap_allocation {
+                reported_owned_heap_allocation = true;
+                let item = match intern_kind {
+                    InternKind::Static(_) => "static",
+                    InternKind::Constant | InternKind::Promoted => "constant",
+                };
+                ecx.tcx
+                    .sess
+                    .struct_span_err(
+                        ecx.tcx.span,
+                        format!("heap allocations are not allowed in the final value of a {item}"),
+                    )
+                    .help(
+                        "leak them with `Box::leak`, `Vec::leak` or `String::leak` to get a \
+                         `&'static` reference",
+                    )
+                    .emit();
+            }
             // We can't call the `intern_shallow` method here, as its logic is tailored to safe
             // references and a `leftover_allocations` set (where we only have a todo-list here).
             // So we hand-roll the interning logic here again.
@@ -425,6 +480,11 @@ pub fn intern_const_alloc_recursive<
                     // immutability is so important.
                     alloc.mutability = Mutability::Not;
                 }
+                // Leaked heap allocations behind a shared reference, e.g. the buffers of the
+                // `String`s in a `&'static [String]`, are immutable like the rest of the constant.
+                InternKind::Constant if is_heap => {
+                    alloc.mutability = Mutability::Not;
+                }
                 InternKind::Constant => {
                     // If it's a constant, we should not have any "leftovers" as everything
                     // is tracked by const-checking.
@@ -92,6 +92,7 @@ pub(crate) fn eval_nullary_intrinsic<'tcx>(
             | ty::Foreign(_)
             | ty::Str
             | ty::Array(_, _)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(_, _, _)
@@ -224,6 +225,14 @@ pub fn emulate_intrinsic(
                 let discr_val = self.read_discriminant(&place.into())?.0;
                 self.write_scalar(discr_val, dest)?;
             }
+            sym::pattern_type_contains => {
+                let ty::Pat(base, pat) = *substs.type_at(0).kind() else {
+                    bug!("`pattern_type_contains` called on a non-pattern type");
+                };
+                let value = self.read_scalar(&args[0])?.to_bits(args[0].layout.size)?;
+                let contains = pat.contains(*self.tcx, base, value);
+                self.write_scalar(Scalar::from_bool(contains), dest)?;
+            }
             sym::exact_div => {
                 let l = self.read_immediate(&args[0])?;
                 let r = self.read_immediate(&args[1])?;
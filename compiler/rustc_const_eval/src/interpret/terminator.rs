@@ -16,6 +16,17 @@
     PlaceTy, Scalar, StackPopCleanup,
 };
 
+/// The callee and arguments of a `Call` or `TailCall` terminator, ready to be passed on to
+/// `eval_fn_call`.
+struct EvaluatedCalleeAndArgs<'tcx, 'mir, M: Machine<'mir, 'tcx>> {
+    fn_val: FnVal<'tcx, M::ExtraFnVal>,
+    args: Vec<OpTy<'tcx, M::Provenance>>,
+    fn_sig: ty::FnSig<'tcx>,
+    fn_abi: &'tcx FnAbi<'tcx, Ty<'tcx>>,
+    /// Whether the callee needs the location of the call as an extra argument.
+    with_caller_location: bool,
+}
+
 impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
     pub(super) fn eval_terminator(
         &mut self,
@@ -66,36 +77,8 @@ pub(super) fn eval_terminator(
             } => {
                 let old_stack = self.frame_idx();
                 let old_loc = self.frame().loc;
-                let func = self.eval_operand(func, None)?;
-                let args = self.eval_operands(args)?;
-
-                let fn_sig_binder = func.layout.ty.fn_sig(*self.tcx);
-                let fn_sig =
-                    self.tcx.normalize_erasing_late_bound_regions(self.param_env, fn_sig_binder);
-                let extra_args = &args[fn_sig.inputs().len()..];
-                let extra_args =
-                    self.tcx.mk_type_list_from_iter(extra_args.iter().map(|arg| arg.layout.ty));
-
-                let (fn_val, fn_abi, with_caller_location) = match *func.layout.ty.kind() {
-                    ty::FnPtr(_sig) => {
-                        let fn_ptr = self.read_pointer(&func)?;
-                        let fn_val = self.get_ptr_fn(fn_ptr)?;
-                        (fn_val, self.fn_abi_of_fn_ptr(fn_sig_binder, extra_args)?, false)
-                    }
-                    ty::FnDef(def_id, substs) => {
-                        let instance = self.resolve(def_id, substs)?;
-                        (
-                            FnVal::Instance(instance),
-                            self.fn_abi_of_instance(instance, extra_args)?,
-                            instance.def.requires_caller_location(*self.tcx),
-                        )
-                    }
-                    _ => span_bug!(
-                        terminator.source_info.span,
-                        "invalid callee of type {:?}",
-                        func.layout.ty
-                    ),
-                };
+                let EvaluatedCalleeAndArgs { fn_val, args, fn_sig, fn_abi, with_caller_location } =
+                    self.eval_callee_and_args(terminator, func, args)?;
 
                 let destination = self.eval_place(destination)?;
                 self.eval_fn_call(
@@ -114,6 +97,12 @@ pub(super) fn eval_terminator(
                 }
             }
 
+            TailCall { ref func, ref args, fn_span: _ } => {
+                let EvaluatedCalleeAndArgs { fn_val, args, fn_sig, fn_abi, with_caller_location } =
+                    self.eval_callee_and_args(terminator, func, args)?;
+                self.eval_fn_tail_call(fn_val, (fn_sig.abi, fn_abi), &args, with_caller_location)?;
+            }
+
             Drop { place, target, unwind } => {
                 let frame = self.frame();
                 let ty = place.ty(&frame.body.local_decls, *self.tcx).ty;
@@ -184,6 +173,97 @@ pub(super) fn eval_terminator(
         Ok(())
     }
 
+    fn eval_callee_and_args(
+        &self,
+        terminator: &mir::Terminator<'tcx>,
+        func: &mir::Operand<'tcx>,
+        args: &[mir::Operand<'tcx>],
+    ) -> InterpResult<'tcx, EvaluatedCalleeAndArgs<'tcx, 'mir, M>> {
+        let func = self.eval_operand(func, None)?;
+        let args = self.eval_operands(args)?;
+
+        let fn_sig_binder = func.layout.ty.fn_sig(*self.tcx);
+        let fn_sig = self.tcx.normalize_erasing_late_bound_regions(self.param_env, fn_sig_binder);
+        let extra_args = &args[fn_sig.inputs().len()..];
+        let extra_args =
+            self.tcx.mk_type_list_from_iter(extra_args.iter().map(|arg| arg.layout.ty));
+
+        let (fn_val, fn_abi, with_caller_location) = match *func.layout.ty.kind() {
+            ty::FnPtr(_sig) => {
+                let fn_ptr = self.read_pointer(&func)?;
+                let fn_val = self.get_ptr_fn(fn_ptr)?;
+                (fn_val, self.fn_abi_of_fn_ptr(fn_sig_binder, extra_args)?, false)
+            }
+            ty::FnDef(def_id, substs) => {
+                let instance = self.resolve(def_id, substs)?;
+                (
+                    FnVal::Instance(instance),
+                    self.fn_abi_of_instance(instance, extra_args)?,
+                    instance.def.requires_caller_location(*self.tcx),
+                )
+            }
+            _ => span_bug!(
+                terminator.source_info.span,
+                "invalid callee of type {:?}",
+                func.layout.ty
+            ),
+        };
+
+        Ok(EvaluatedCalleeAndArgs { fn_val, args, fn_sig, fn_abi, with_caller_location })
+    }
+
+    /// Replaces the current frame with a call to `fn_val`, which then returns straight to the
+    /// caller of the current function.
+    fn eval_fn_tail_call(
+        &mut self,
+        fn_val: FnVal<'tcx, M::ExtraFnVal>,
+        (caller_abi, caller_fn_abi): (Abi, &FnAbi<'tcx, Ty<'tcx>>),
+        args: &[OpTy<'tcx, M::Provenance>],
+        with_caller_location: bool,
+    ) -> InterpResult<'tcx> {
+        trace!("eval_fn_tail_call: {:#?}", fn_val);
+
+        let StackPopCleanup::Goto { ret, unwind } = self.frame().return_to_block else {
+            throw_unsup_format!("tail calls from the root frame are not supported")
+        };
+        let destination = self.frame().return_place.clone();
+
+        // Arguments in memory may point into locals of the current frame, which are deallocated
+        // before the callee starts, so they are moved to temporary allocations first.
+        let mut temps = Vec::new();
+        let mut callee_args: Vec<OpTy<'tcx, M::Provenance>> = Vec::with_capacity(args.len());
+        for arg in args {
+            if let Operand::Indirect(_) = **arg {
+                if arg.layout.is_unsized() {
+                    throw_unsup_format!("tail calls with unsized arguments are not supported");
+                }
+                let tmp = self.allocate(arg.layout, MemoryKind::Stack)?;
+                self.copy_op(arg, &tmp.into(), /*allow_transmute*/ false)?;
+                callee_args.push(tmp.into());
+                temps.push(tmp);
+            } else {
+                callee_args.push(arg.clone());
+            }
+        }
+
+        self.pop_stack_frame_for_tail_call()?;
+        self.eval_fn_call(
+            fn_val,
+            (caller_abi, caller_fn_abi),
+            &callee_args,
+            with_caller_location,
+            &destination,
+            ret,
+            if caller_fn_abi.can_unwind { unwind } else { mir::UnwindAction::Unreachable },
+        )?;
+
+        // The callee has copied its arguments into its own locals by now.
+        for tmp in temps {
+            self.deallocate_ptr(tmp.ptr, None, MemoryKind::Stack)?;
+        }
+        Ok(())
+    }
+
     fn check_argument_compat(
         caller_abi: &ArgAbi<'tcx, Ty<'tcx>>,
         callee_abi: &ArgAbi<'tcx, Ty<'tcx>>,
@@ -515,7 +515,7 @@ fn try_visit_primitive(
                 );
                 Ok(true)
             }
-            ty::Float(_) | ty::Int(_) | ty::Uint(_) => {
+            ty::Float(_) | ty::Int(_) | ty::Uint(_) | ty::Pat(..) => {
                 // NOTE: Keep this in sync with the array optimization for int/float
                 // types below!
                 let value = self.read_scalar(
@@ -533,7 +533,9 @@ fn try_visit_primitive(
                         { "{:x}", value } expected { "plain (non-pointer) bytes" }
                     )
                 }
-                Ok(true)
+                // Values of pattern types still need to be checked against the valid range of
+                // their layout.
+                Ok(!matches!(ty.kind(), ty::Pat(..)))
             }
             ty::RawPtr(..) => {
                 // We are conservative with uninit for integers, but try to
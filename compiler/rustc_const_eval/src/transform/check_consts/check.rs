@@ -453,7 +453,9 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
 
             Rvalue::Aggregate(kind, ..) => {
                 if let AggregateKind::Generator(def_id, ..) = kind.as_ref()
-                    && let Some(generator_kind @ hir::GeneratorKind::Async(..)) = self.tcx.generator_kind(def_id)
+                    && let Some(generator_kind) = self.tcx.generator_kind(def_id)
+                    && let hir::GeneratorKind::Async(..) | hir::GeneratorKind::Iter(..) =
+                        generator_kind
                 {
                     self.check_op(ops::Generator(generator_kind));
                 }
@@ -704,8 +706,14 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
         self.super_terminator(terminator, location);
 
         match &terminator.kind {
-            TerminatorKind::Call { func, args, fn_span, from_hir_call, .. } => {
+            TerminatorKind::Call { func, args, fn_span, .. }
+            | TerminatorKind::TailCall { func, args, fn_span } => {
                 let ConstCx { tcx, body, param_env, .. } = *self.ccx;
+                // `become` always comes from a call expression.
+                let from_hir_call = match terminator.kind {
+                    TerminatorKind::Call { from_hir_call, .. } => from_hir_call,
+                    _ => true,
+                };
                 let caller = self.def_id();
 
                 let fn_ty = func.ty(body, tcx);
@@ -757,7 +765,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                             callee,
                             substs,
                             span: *fn_span,
-                            from_hir_call: *from_hir_call,
+                            from_hir_call,
                             feature: Some(sym::const_trait_impl),
                         });
                         return;
@@ -790,7 +798,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                                     callee,
                                     substs,
                                     span: *fn_span,
-                                    from_hir_call: *from_hir_call,
+                                    from_hir_call,
                                     feature: None,
                                 });
 
@@ -816,7 +824,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                                     callee,
                                     substs,
                                     span: *fn_span,
-                                    from_hir_call: *from_hir_call,
+                                    from_hir_call,
                                     feature: None,
                                 });
                                 return;
@@ -859,7 +867,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                                     callee,
                                     substs,
                                     span: *fn_span,
-                                    from_hir_call: *from_hir_call,
+                                    from_hir_call,
                                     feature: None,
                                 });
                                 return;
@@ -905,8 +913,17 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                 }
 
                 if Some(callee) == tcx.lang_items().exchange_malloc_fn() {
-                    self.check_op(ops::HeapAllocation);
-                    return;
+                    // With `const_heap`, `exchange_malloc` allocates from the interpreter's heap
+                    // and is checked like any other const fn.
+                    let const_heap = tcx
+                        .features()
+                        .declared_lib_features
+                        .iter()
+                        .any(|&(feature, _)| feature == sym::const_heap);
+                    if !const_heap {
+                        self.check_op(ops::HeapAllocation);
+                        return;
+                    }
                 }
 
                 if !tcx.is_const_fn_raw(callee) {
@@ -919,7 +936,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                             callee,
                             substs,
                             span: *fn_span,
-                            from_hir_call: *from_hir_call,
+                            from_hir_call,
                             feature: None,
                         });
                         return;
@@ -1084,6 +1084,21 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                     self.fail(location, "Cannot `Return` from cleanup basic block")
                 }
             }
+            TerminatorKind::TailCall { func, .. } => {
+                let func_ty = func.ty(&self.body.local_decls, self.tcx);
+                if !matches!(func_ty.kind(), ty::FnPtr(..) | ty::FnDef(..)) {
+                    self.fail(
+                        location,
+                        format!(
+                            "encountered non-callable type {} in `TailCall` terminator",
+                            func_ty
+                        ),
+                    )
+                }
+                if self.body.basic_blocks[location.block].is_cleanup {
+                    self.fail(location, "Cannot `TailCall` from cleanup basic block")
+                }
+            }
             TerminatorKind::Unreachable => {}
         }
 
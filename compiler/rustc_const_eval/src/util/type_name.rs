@@ -41,6 +41,7 @@ fn print_type(mut self, ty: Ty<'tcx>) -> Result<Self::Type, Self::Error> {
             | ty::Float(_)
             | ty::Str
             | ty::Array(_, _)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(_, _, _)
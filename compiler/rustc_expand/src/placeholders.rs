@@ -169,6 +169,7 @@ fn mac_placeholder() -> P<ast::MacCall> {
             span,
             ty: ty(),
             vis,
+            default: None,
             is_placeholder: true,
         }]),
         AstFragmentKind::Variants => AstFragment::Variants(smallvec![ast::Variant {
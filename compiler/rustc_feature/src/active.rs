@@ -152,6 +152,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, anonymous_lifetime_in_impl_trait, "1.63.0", None, None),
     /// Allows identifying the `compiler_builtins` crate.
     (active, compiler_builtins, "1.13.0", None, None),
+    /// Allows the `contract_requires` and `contract_ensures` clauses that the contract
+    /// attribute macros expand to.
+    (active, contracts_internals, "CURRENT_RUSTC_VERSION", None, None),
     /// Allows writing custom MIR
     (active, custom_mir, "1.65.0", None, None),
     /// Outputs useful `assert!` messages
@@ -369,18 +372,26 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, const_trait_impl, "1.42.0", Some(67792), None),
     /// Allows the `?` operator in const contexts.
     (active, const_try, "1.56.0", Some(74935), None),
+    /// Allows `#[requires]` and `#[ensures]` contract attributes on functions.
+    (incomplete, contracts, "CURRENT_RUSTC_VERSION", Some(128044), None),
     /// Allows non-builtin attributes in inner attribute position.
     (active, custom_inner_attributes, "1.30.0", Some(54726), None),
     /// Allows custom test frameworks with `#![test_runner]` and `#[test_case]`.
     (active, custom_test_frameworks, "1.30.0", Some(50297), None),
     /// Allows declarative macros 2.0 (`macro`).
     (active, decl_macro, "1.17.0", Some(39412), None),
+    /// Allows default values on struct fields, and `..` in struct expressions to use them.
+    (active, default_field_values, "CURRENT_RUSTC_VERSION", Some(132162), None),
     /// Allows default type parameters to influence type inference.
     (active, default_type_parameter_fallback, "1.3.0", Some(27336), None),
+    /// Allows patterns to implicitly dereference smart pointers implementing `DerefPure`.
+    (active, deref_patterns, "CURRENT_RUSTC_VERSION", Some(87121), None),
     /// Allows using `#[deprecated_safe]` to deprecate the safeness of a function or trait
     (active, deprecated_safe, "1.61.0", Some(94978), None),
     /// Allows having using `suggestion` in the `#[deprecated]` attribute.
     (active, deprecated_suggestion, "1.61.0", Some(94785), None),
+    /// Allows using the `#[diagnostic]` attribute tool namespace
+    (active, diagnostic_namespace, "CURRENT_RUSTC_VERSION", Some(111996), None),
     /// Controls errors in trait implementations.
     (active, do_not_recommend, "1.67.0", Some(51992), None),
     /// Tells rustdoc to automatically generate `#[doc(cfg(...))]`.
@@ -397,11 +408,17 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, exclusive_range_pattern, "1.11.0", Some(37854), None),
     /// Allows exhaustive pattern matching on types that contain uninhabited types.
     (active, exhaustive_patterns, "1.13.0", Some(51085), None),
+    /// Allows explicit tail calls via `become` expression.
+    (incomplete, explicit_tail_calls, "CURRENT_RUSTC_VERSION", Some(112788), None),
     /// Allows using `efiapi`, `sysv64` and `win64` as calling convention
     /// for functions with varargs.
     (active, extended_varargs_abi_support, "1.65.0", Some(100189), None),
     /// Allows defining `extern type`s.
     (active, extern_types, "1.23.0", Some(43467), None),
+    /// Allows using the `f128` type.
+    (active, f128, "CURRENT_RUSTC_VERSION", Some(116909), None),
+    /// Allows using the `f16` type.
+    (active, f16, "CURRENT_RUSTC_VERSION", Some(116909), None),
     /// Allows the use of `#[ffi_const]` on foreign functions.
     (active, ffi_const, "1.45.0", Some(58328), None),
     /// Allows the use of `#[ffi_pure]` on foreign functions.
@@ -410,6 +427,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, ffi_returns_twice, "1.34.0", Some(58314), None),
     /// Allows using `#[repr(align(...))]` on function items
     (active, fn_align, "1.53.0", Some(82232), None),
+    /// Allows `gen` blocks and `gen fn`, which evaluate to iterators.
+    (active, gen_blocks, "CURRENT_RUSTC_VERSION", Some(117078), None),
     /// Allows generators to be cloned.
     (active, generator_clone, "1.65.0", Some(95360), None),
     /// Allows defining generators.
@@ -487,6 +506,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, optimize_attribute, "1.34.0", Some(54882), None),
     /// Allows `extern "platform-intrinsic" { ... }`.
     (active, platform_intrinsics, "1.4.0", Some(27731), None),
+    /// Allows restricting the values of integer types with patterns, as in `u32 is 1..`.
+    (incomplete, pattern_types, "CURRENT_RUSTC_VERSION", Some(123646), None),
     /// Allows using `#![plugin(myplugin)]`.
     (active, plugin, "1.0.0", Some(29597), None),
     /// Allows exhaustive integer pattern matching on `usize` and `isize`.
@@ -517,6 +538,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, strict_provenance, "1.61.0", Some(95228), None),
     /// Allows string patterns to dereference values to match them.
     (active, string_deref_patterns, "1.67.0", Some(87121), None),
+    /// Allows compiling a function for several sets of target features with `#[target_clones]`,
+    /// picking the best one at runtime.
+    (active, target_clones, "CURRENT_RUSTC_VERSION", Some(44839), None),
     /// Allows the use of `#[target_feature]` on safe functions.
     (active, target_feature_11, "1.45.0", Some(69098), None),
     /// Allows using `#[thread_local]` on `static` items.
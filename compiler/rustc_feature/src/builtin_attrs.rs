@@ -398,6 +398,10 @@ pub struct BuiltinAttribute {
         template!(List: "address, kcfi, memory, thread"), DuplicatesOk,
         experimental!(no_sanitize)
     ),
+    gated!(
+        target_clones, Normal, template!(List: r#""feature", "default""#), ErrorFollowing,
+        @only_local: true, experimental!(target_clones)
+    ),
     gated!(no_coverage, Normal, template!(Word), WarnFollowing, experimental!(no_coverage)),
 
     ungated!(
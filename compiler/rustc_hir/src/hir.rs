@@ -1437,6 +1437,19 @@ pub fn body(&self) -> &'hir Expr<'hir> {
     }
 }
 
+/// What follows the explicitly listed fields of a struct literal expression.
+#[derive(Debug, Clone, Copy, HashStable_Generic)]
+pub enum StructTailExpr<'hir> {
+    /// All the fields are explicitly listed: `Foo { a, b }`.
+    None,
+    /// A "base" expression of the same type as the struct, used to populate the fields that
+    /// are not explicitly listed: `Foo { a, ..base }`.
+    Base(&'hir Expr<'hir>),
+    /// A `..` without a base expression, populating the fields that are not explicitly listed
+    /// from their default values: `Foo { a, .. }`.
+    DefaultFields(Span),
+}
+
 #[derive(Debug, Clone, Copy, HashStable_Generic)]
 pub struct ExprField<'hir> {
     #[stable_hasher(ignore)]
@@ -1509,6 +1522,9 @@ pub enum GeneratorKind {
     /// An explicit `async` block or the body of an async function.
     Async(AsyncGeneratorKind),
 
+    /// An explicit `gen` block or the body of a `gen fn`.
+    Iter(IterGeneratorKind),
+
     /// A generator literal created via a `yield` inside a closure.
     Gen,
 }
@@ -1517,6 +1533,7 @@ impl fmt::Display for GeneratorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             GeneratorKind::Async(k) => fmt::Display::fmt(k, f),
+            GeneratorKind::Iter(k) => fmt::Display::fmt(k, f),
             GeneratorKind::Gen => f.write_str("generator"),
         }
     }
@@ -1526,6 +1543,7 @@ impl GeneratorKind {
     pub fn descr(&self) -> &'static str {
         match self {
             GeneratorKind::Async(ask) => ask.descr(),
+            GeneratorKind::Iter(igk) => igk.descr(),
             GeneratorKind::Gen => "generator",
         }
     }
@@ -1569,6 +1587,36 @@ pub fn descr(&self) -> &'static str {
     }
 }
 
+/// In the case of a generator created as part of a `gen` construct,
+/// which kind of `gen` construct caused it to be created?
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Copy)]
+#[derive(HashStable_Generic, Encodable, Decodable)]
+pub enum IterGeneratorKind {
+    /// An explicit `gen` block written by the user.
+    Block,
+
+    /// The `gen` block generated as the body of a `gen fn`.
+    Fn,
+}
+
+impl fmt::Display for IterGeneratorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IterGeneratorKind::Block => "gen block",
+            IterGeneratorKind::Fn => "gen fn body",
+        })
+    }
+}
+
+impl IterGeneratorKind {
+    pub fn descr(&self) -> &'static str {
+        match self {
+            IterGeneratorKind::Block => "`gen` block",
+            IterGeneratorKind::Fn => "`gen fn` body",
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum BodyOwnerKind {
     /// Functions and methods.
@@ -1711,6 +1759,7 @@ pub fn precedence(&self) -> ExprPrecedence {
             ExprKind::Break(..) => ExprPrecedence::Break,
             ExprKind::Continue(..) => ExprPrecedence::Continue,
             ExprKind::Ret(..) => ExprPrecedence::Ret,
+            ExprKind::Become(..) => ExprPrecedence::Become,
             ExprKind::InlineAsm(..) => ExprPrecedence::InlineAsm,
             ExprKind::OffsetOf(..) => ExprPrecedence::OffsetOf,
             ExprKind::Struct(..) => ExprPrecedence::Struct,
@@ -1768,6 +1817,7 @@ pub fn is_place_expr(&self, mut allow_projections_from: impl FnMut(&Self) -> boo
             | ExprKind::Break(..)
             | ExprKind::Continue(..)
             | ExprKind::Ret(..)
+            | ExprKind::Become(..)
             | ExprKind::Let(..)
             | ExprKind::Loop(..)
             | ExprKind::Assign(..)
@@ -1829,11 +1879,17 @@ pub fn can_have_side_effects(&self) -> bool {
                 // them being used only for its side-effects.
                 base.can_have_side_effects()
             }
-            ExprKind::Struct(_, fields, init) => fields
-                .iter()
-                .map(|field| field.expr)
-                .chain(init.into_iter())
-                .all(|e| e.can_have_side_effects()),
+            ExprKind::Struct(_, fields, init) => {
+                let init = match init {
+                    StructTailExpr::Base(init) => Some(init),
+                    StructTailExpr::None | StructTailExpr::DefaultFields(_) => None,
+                };
+                fields
+                    .iter()
+                    .map(|field| field.expr)
+                    .chain(init)
+                    .all(|e| e.can_have_side_effects())
+            }
 
             ExprKind::Array(args)
             | ExprKind::Tup(args)
@@ -1858,6 +1914,7 @@ pub fn can_have_side_effects(&self) -> bool {
             | ExprKind::Break(..)
             | ExprKind::Continue(..)
             | ExprKind::Ret(..)
+            | ExprKind::Become(..)
             | ExprKind::Let(..)
             | ExprKind::Loop(..)
             | ExprKind::Assign(..)
@@ -2018,6 +2075,9 @@ pub enum ExprKind<'hir> {
     /// A `return`, with an optional value to be returned.
     Ret(Option<&'hir Expr<'hir>>),
 
+    /// A `become`, with the value to be returned.
+    Become(&'hir Expr<'hir>),
+
     /// Inline assembly (from `asm!`), with its outputs and inputs.
     InlineAsm(&'hir InlineAsm<'hir>),
 
@@ -2026,9 +2086,9 @@ pub enum ExprKind<'hir> {
 
     /// A struct or struct-like variant literal expression.
     ///
-    /// E.g., `Foo {x: 1, y: 2}`, or `Foo {x: 1, .. base}`,
-    /// where `base` is the `Option<Expr>`.
-    Struct(&'hir QPath<'hir>, &'hir [ExprField<'hir>], Option<&'hir Expr<'hir>>),
+    /// E.g., `Foo {x: 1, y: 2}`, `Foo {x: 1, .. base}` or `Foo {x: 1, .. }`,
+    /// where the tail is the `StructTailExpr`.
+    Struct(&'hir QPath<'hir>, &'hir [ExprField<'hir>], StructTailExpr<'hir>),
 
     /// An array literal constructed from one repeated element.
     ///
@@ -2232,7 +2292,7 @@ impl From<GeneratorKind> for YieldSource {
     fn from(kind: GeneratorKind) -> Self {
         match kind {
             // Guess based on the kind of the current generator.
-            GeneratorKind::Gen => Self::Yield,
+            GeneratorKind::Gen | GeneratorKind::Iter(_) => Self::Yield,
             GeneratorKind::Async(_) => Self::Await { expr: None },
         }
     }
@@ -2577,8 +2637,10 @@ impl PrimTy {
         Self::Uint(UintTy::U64),
         Self::Uint(UintTy::U128),
         Self::Uint(UintTy::Usize),
+        Self::Float(FloatTy::F16),
         Self::Float(FloatTy::F32),
         Self::Float(FloatTy::F64),
+        Self::Float(FloatTy::F128),
         Self::Bool,
         Self::Char,
         Self::Str,
@@ -2626,8 +2688,10 @@ pub fn from_name(name: Symbol) -> Option<Self> {
             sym::u64 => Self::Uint(UintTy::U64),
             sym::u128 => Self::Uint(UintTy::U128),
             sym::usize => Self::Uint(UintTy::Usize),
+            sym::f16 => Self::Float(FloatTy::F16),
             sym::f32 => Self::Float(FloatTy::F32),
             sym::f64 => Self::Float(FloatTy::F64),
+            sym::f128 => Self::Float(FloatTy::F128),
             sym::bool => Self::Bool,
             sym::char => Self::Char,
             sym::str => Self::Str,
@@ -2700,6 +2764,8 @@ pub enum TyKind<'hir> {
     /// A trait object type `Bound1 + Bound2 + Bound3`
     /// where `Bound` is a trait or a lifetime.
     TraitObject(&'hir [PolyTraitRef<'hir>], &'hir Lifetime, TraitObjectSyntax),
+    /// A pattern type (`u32 is 1..=100`), the values of a type that match a pattern.
+    Pat(&'hir Ty<'hir>, &'hir TyPat<'hir>),
     /// Unused for now.
     Typeof(AnonConst),
     /// `TyKind::Infer` means the type should be inferred instead of it having been
@@ -2709,6 +2775,28 @@ pub enum TyKind<'hir> {
     Err(rustc_span::ErrorGuaranteed),
 }
 
+/// The pattern of a pattern type.
+#[derive(Debug, Clone, Copy, HashStable_Generic)]
+pub struct TyPat<'hir> {
+    pub kind: TyPatKind<'hir>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, HashStable_Generic)]
+pub enum TyPatKind<'hir> {
+    /// A range pattern (e.g., `1..=100` or `..0`). The endpoints are literals.
+    Range(Option<TyPatRangeEnd<'hir>>, Option<TyPatRangeEnd<'hir>>, RangeEnd),
+    /// A pattern that is not supported in pattern types; an error has been reported.
+    Err(rustc_span::ErrorGuaranteed),
+}
+
+/// An endpoint of a range in a pattern type, a possibly negated literal (e.g., `-1`).
+#[derive(Debug, Clone, Copy, HashStable_Generic)]
+pub struct TyPatRangeEnd<'hir> {
+    pub lit: &'hir Lit,
+    pub negated: bool,
+}
+
 #[derive(Debug, Clone, Copy, HashStable_Generic)]
 pub enum InlineAsmOperand<'hir> {
     In {
@@ -2982,6 +3070,8 @@ pub struct FieldDef<'hir> {
     pub hir_id: HirId,
     pub def_id: LocalDefId,
     pub ty: &'hir Ty<'hir>,
+    /// The default value of the field, e.g. `3` in `retries: u32 = 3`.
+    pub default: Option<AnonConst>,
 }
 
 impl FieldDef<'_> {
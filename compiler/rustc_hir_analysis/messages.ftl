@@ -291,3 +291,11 @@ hir_analysis_missing_tilde_const = missing `~const` qualifier for specialization
 hir_analysis_drop_impl_negative = negative `Drop` impls are not supported
 
 hir_analysis_drop_impl_reservation = reservation `Drop` impls are not supported
+
+hir_analysis_pattern_type_non_integer = pattern types can only restrict integer types, not `{$ty}`
+
+hir_analysis_pattern_type_non_integer_literal = the endpoints of the range of a pattern type must be integer literals of type `{$ty}`
+
+hir_analysis_pattern_type_literal_out_of_range = literal out of range for `{$ty}`
+
+hir_analysis_pattern_type_empty_range = this pattern type contains no values of `{$ty}`
@@ -10,11 +10,14 @@
 use crate::bounds::Bounds;
 use crate::collect::HirPlaceholderCollector;
 use crate::errors::{
-    AmbiguousLifetimeBound, MultipleRelaxedDefaultBounds, TraitObjectDeclaredWithNoTraits,
-    TypeofReservedKeywordUsed, ValueOfAssociatedStructAlreadySpecified,
+    AmbiguousLifetimeBound, MultipleRelaxedDefaultBounds, PatternTypeEmptyRange,
+    PatternTypeLiteralOutOfRange, PatternTypeNonInteger, PatternTypeNonIntegerLiteral,
+    TraitObjectDeclaredWithNoTraits, TypeofReservedKeywordUsed,
+    ValueOfAssociatedStructAlreadySpecified,
 };
 use crate::middle::resolve_bound_vars as rbv;
 use crate::require_c_abi_if_c_variadic;
+use rustc_ast as ast;
 use rustc_ast::TraitObjectSyntax;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::{
@@ -31,6 +34,7 @@
 use rustc_infer::traits::ObligationCause;
 use rustc_middle::infer::unify_key::{ConstVariableOrigin, ConstVariableOriginKind};
 use rustc_middle::middle::stability::AllowUnstable;
+use rustc_middle::mir::interpret::{LitToConstError, LitToConstInput};
 use rustc_middle::ty::fold::FnMutDelegate;
 use rustc_middle::ty::subst::{self, GenericArgKind, InternalSubsts, SubstsRef};
 use rustc_middle::ty::GenericParamDefKind;
@@ -3335,6 +3339,10 @@ fn ast_ty_to_ty_inner(&self, ast_ty: &hir::Ty<'_>, borrowed: bool, in_path: bool
 
                 tcx.mk_array_with_const_len(self.ast_ty_to_ty(ty), length)
             }
+            hir::TyKind::Pat(ty, pat) => {
+                let ty = self.ast_ty_to_ty(ty);
+                self.ty_pat_to_ty(ty, pat).unwrap_or_else(|guar| tcx.ty_error(guar))
+            }
             hir::TyKind::Typeof(e) => {
                 let ty_erased = tcx.type_of(e.def_id).subst_identity();
                 let ty = tcx.fold_regions(ty_erased, |r, _| {
@@ -3364,6 +3372,83 @@ fn ast_ty_to_ty_inner(&self, ast_ty: &hir::Ty<'_>, borrowed: bool, in_path: bool
         result_ty
     }
 
+    /// Converts the pattern type `base is pat`, checking that `base` is an integer type and
+    /// that `pat` is a non-empty range of values of `base`.
+    fn ty_pat_to_ty(
+        &self,
+        base: Ty<'tcx>,
+        pat: &hir::TyPat<'_>,
+    ) -> Result<Ty<'tcx>, ErrorGuaranteed> {
+        let tcx = self.tcx();
+        let (start, end, end_kind) = match pat.kind {
+            hir::TyPatKind::Range(start, end, end_kind) => (start, end, end_kind),
+            hir::TyPatKind::Err(guar) => return Err(guar),
+        };
+        base.error_reported()?;
+        if !base.is_integral() {
+            return Err(tcx.sess.emit_err(PatternTypeNonInteger { span: pat.span, ty: base }));
+        }
+
+        // Compare the ends as unsigned numbers, flipping the sign bit of signed ones so that
+        // the order of the values is preserved.
+        let (size, signed) = base.int_size_and_signed(tcx);
+        let bias = if signed { 1u128 << (size.bits() - 1) } else { 0 };
+        let start = start.map(|start| self.ty_pat_range_end_to_const(base, start)).transpose()?;
+        let end = end.map(|end| self.ty_pat_range_end_to_const(base, end)).transpose()?;
+        let lo = start.map_or(0, |(_, bits)| bits ^ bias);
+        let hi = end.map_or(size.unsigned_int_max(), |(_, bits)| bits ^ bias);
+        let include_end = end.is_none() || end_kind == hir::RangeEnd::Included;
+        if lo > hi || (lo == hi && !include_end) {
+            return Err(tcx.sess.emit_err(PatternTypeEmptyRange { span: pat.span, ty: base }));
+        }
+
+        let pat = tcx.mk_pat(ty::PatternKind::Range {
+            start: start.map(|(ct, _)| ct),
+            end: end.map(|(ct, _)| ct),
+            include_end,
+        });
+        Ok(tcx.mk_pat_ty(base, pat))
+    }
+
+    /// Converts an end of the range of a pattern type to a constant of the integer type `ty`,
+    /// also returning its value as raw bits.
+    fn ty_pat_range_end_to_const(
+        &self,
+        ty: Ty<'tcx>,
+        end: hir::TyPatRangeEnd<'_>,
+    ) -> Result<(Const<'tcx>, u128), ErrorGuaranteed> {
+        let tcx = self.tcx();
+        let span = end.lit.span;
+        let n = match end.lit.node {
+            ast::LitKind::Int(n, _) => n,
+            ast::LitKind::Err => {
+                return Err(tcx.sess.delay_span_bug(span, "erroneous literal in pattern type"));
+            }
+            _ => return Err(tcx.sess.emit_err(PatternTypeNonIntegerLiteral { span, ty })),
+        };
+
+        let (size, signed) = ty.int_size_and_signed(tcx);
+        let in_range = match (signed, end.negated) {
+            (false, true) => n == 0,
+            (false, false) => n <= size.unsigned_int_max(),
+            (true, true) => n <= size.signed_int_min().unsigned_abs(),
+            (true, false) => n <= size.signed_int_max() as u128,
+        };
+        if !in_range {
+            return Err(tcx.sess.emit_err(PatternTypeLiteralOutOfRange { span, ty }));
+        }
+
+        let bits = size.truncate(if end.negated { n.wrapping_neg() } else { n });
+        let lit_input = LitToConstInput { lit: &end.lit.node, ty, neg: end.negated };
+        match tcx.lit_to_const(lit_input) {
+            Ok(ct) => Ok((ct, bits)),
+            Err(LitToConstError::Reported(guar)) => Err(guar),
+            Err(LitToConstError::TypeError) => {
+                Err(tcx.sess.emit_err(PatternTypeNonIntegerLiteral { span, ty }))
+            }
+        }
+    }
+
     #[instrument(level = "debug", skip(self), ret)]
     fn impl_trait_ty_to_ty(
         &self,
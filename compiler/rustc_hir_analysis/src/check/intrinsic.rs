@@ -101,6 +101,7 @@ pub fn intrinsic_operation_unsafety(tcx: TyCtxt<'_>, intrinsic_id: DefId) -> hir
         | sym::bswap
         | sym::bitreverse
         | sym::discriminant_value
+        | sym::pattern_type_contains
         | sym::type_id
         | sym::likely
         | sym::unlikely
@@ -397,6 +398,15 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 )
             }
 
+            sym::pattern_type_contains => {
+                let base_def_id = tcx.require_lang_item(hir::LangItem::PatternBase, None);
+                (
+                    1,
+                    vec![tcx.mk_projection(base_def_id, tcx.mk_substs(&[param(0).into()]))],
+                    tcx.types.bool,
+                )
+            }
+
             kw::Try => {
                 let mut_u8 = tcx.mk_mut_ptr(tcx.types.u8);
                 let try_fn_ty = ty::Binder::dummy(tcx.mk_fn_sig(
@@ -196,6 +196,7 @@ fn check_item(&mut self, id: hir::ItemId) {
             | ty::Float(_)
             | ty::Str
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(..)
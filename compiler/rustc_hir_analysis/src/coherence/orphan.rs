@@ -237,6 +237,7 @@ enum NonlocalImpl {
             | ty::Float(..)
             | ty::Str
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(..)
             | ty::RawPtr(..)
             | ty::Ref(..)
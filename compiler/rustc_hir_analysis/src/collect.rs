@@ -75,6 +75,7 @@ pub fn provide(providers: &mut Providers) {
         impl_polarity,
         is_foreign_item,
         generator_kind,
+        fn_contract,
         collect_mod_item_types,
         is_type_alias_impl_trait,
         ..*providers
@@ -802,6 +803,7 @@ fn convert_variant(
                 did: f.def_id.to_def_id(),
                 name: f.ident.name,
                 vis: tcx.visibility(f.def_id),
+                value: f.default.map(|v| v.def_id.to_def_id()),
             }
         })
         .collect();
@@ -1480,6 +1482,35 @@ fn generator_kind(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Option<hir::GeneratorK
     }
 }
 
+/// Finds the closures of the contract of a function in the calls to the contract lang items
+/// that the lowering of the contract puts at the start of the body of the function.
+fn fn_contract(tcx: TyCtxt<'_>, def_id: LocalDefId) -> ty::FnContract {
+    let mut contract = ty::FnContract::default();
+    let Some(body_id) = tcx.hir().maybe_body_owned_by(def_id) else { return contract };
+    let hir::ExprKind::Block(block, _) = tcx.hir().body(body_id).value.kind else {
+        return contract;
+    };
+    for stmt in block.stmts {
+        let call = match stmt.kind {
+            hir::StmtKind::Semi(expr) => expr,
+            hir::StmtKind::Local(hir::Local { init: Some(init), .. }) => init,
+            _ => continue,
+        };
+        let hir::ExprKind::Call(callee, [closure, ..]) = call.kind else { continue };
+        let hir::ExprKind::Path(hir::QPath::LangItem(lang_item, ..)) = callee.kind else {
+            continue;
+        };
+        let hir::ExprKind::Closure(closure) = closure.kind else { continue };
+        let closure = closure.def_id.to_def_id();
+        match lang_item {
+            hir::LangItem::ContractCheckRequires => contract.requires.push(closure),
+            hir::LangItem::ContractBuildCheckEnsures => contract.ensures.push(closure),
+            _ => {}
+        }
+    }
+    contract
+}
+
 fn is_type_alias_impl_trait<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> bool {
     match tcx.hir().get_by_def_id(def_id) {
         Node::Item(hir::Item { kind: hir::ItemKind::OpaqueTy(opaque), .. }) => {
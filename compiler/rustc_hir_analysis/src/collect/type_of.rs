@@ -59,6 +59,11 @@ fn anon_const_type_of<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> Ty<'tcx> {
                 .discr_type()
                 .to_ty(tcx)
         }
+        Node::Field(&FieldDef { default: Some(ref e), def_id: field_def_id, .. })
+            if e.hir_id == hir_id =>
+        {
+            return tcx.type_of(field_def_id).subst_identity();
+        }
         Node::GenericParam(&GenericParam {
             def_id: param_def_id,
             kind: GenericParamKind::Const { default: Some(ct), .. },
@@ -857,3 +857,35 @@ pub(crate) enum DropImplPolarity {
         span: Span,
     },
 }
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_pattern_type_non_integer)]
+pub(crate) struct PatternTypeNonInteger<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_pattern_type_non_integer_literal)]
+pub(crate) struct PatternTypeNonIntegerLiteral<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_pattern_type_literal_out_of_range)]
+pub(crate) struct PatternTypeLiteralOutOfRange<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_pattern_type_empty_range)]
+pub(crate) struct PatternTypeEmptyRange<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+}
@@ -234,7 +234,7 @@ fn add_constraints_from_ty(
                 self.add_constraints_from_ty(current, typ, variance);
             }
 
-            ty::Slice(typ) => {
+            ty::Pat(typ, _) | ty::Slice(typ) => {
                 self.add_constraints_from_ty(current, typ, variance);
             }
 
@@ -353,6 +353,11 @@ pub fn print_type(&mut self, ty: &hir::Ty<'_>) {
                 self.print_array_length(length);
                 self.word("]");
             }
+            hir::TyKind::Pat(ty, pat) => {
+                self.print_type(ty);
+                self.word(" is ");
+                self.print_ty_pat(pat);
+            }
             hir::TyKind::Typeof(ref e) => {
                 self.word("typeof(");
                 self.print_anon_const(e);
@@ -370,6 +375,31 @@ pub fn print_type(&mut self, ty: &hir::Ty<'_>) {
         self.end()
     }
 
+    pub fn print_ty_pat(&mut self, pat: &hir::TyPat<'_>) {
+        match pat.kind {
+            hir::TyPatKind::Range(begin, end, end_kind) => {
+                let print_end = |s: &mut Self, end: hir::TyPatRangeEnd<'_>| {
+                    if end.negated {
+                        s.word("-");
+                    }
+                    s.print_literal(end.lit);
+                };
+                if let Some(begin) = begin {
+                    print_end(self, begin);
+                }
+                self.word(end_kind.to_string());
+                if let Some(end) = end {
+                    print_end(self, end);
+                }
+            }
+            hir::TyPatKind::Err(_) => {
+                self.popen();
+                self.word("/*ERROR*/");
+                self.pclose();
+            }
+        }
+    }
+
     pub fn print_foreign_item(&mut self, item: &hir::ForeignItem<'_>) {
         self.hardbreak_if_not_bol();
         self.maybe_print_comment(item.span.lo());
@@ -799,6 +829,11 @@ pub fn print_struct(
                     self.print_ident(field.ident);
                     self.word_nbsp(":");
                     self.print_type(field.ty);
+                    if let Some(ref d) = field.default {
+                        self.space();
+                        self.word_space("=");
+                        self.print_anon_const(d);
+                    }
                     self.word(",");
                 }
 
@@ -1080,9 +1115,10 @@ fn print_let(&mut self, pat: &hir::Pat<'_>, ty: Option<&hir::Ty<'_>>, init: &hir
     // parses as the erroneous construct `if (return {})`, not `if (return) {}`.
     fn cond_needs_par(expr: &hir::Expr<'_>) -> bool {
         match expr.kind {
-            hir::ExprKind::Break(..) | hir::ExprKind::Closure { .. } | hir::ExprKind::Ret(..) => {
-                true
-            }
+            hir::ExprKind::Break(..)
+            | hir::ExprKind::Closure { .. }
+            | hir::ExprKind::Ret(..)
+            | hir::ExprKind::Become(..) => true,
             _ => contains_exterior_struct_lit(expr),
         }
     }
@@ -1116,22 +1152,36 @@ fn print_expr_struct(
         &mut self,
         qpath: &hir::QPath<'_>,
         fields: &[hir::ExprField<'_>],
-        wth: Option<&hir::Expr<'_>>,
+        wth: hir::StructTailExpr<'_>,
     ) {
         self.print_qpath(qpath, true);
         self.word("{");
         self.commasep_cmnt(Consistent, fields, |s, field| s.print_expr_field(field), |f| f.span);
-        if let Some(expr) = wth {
-            self.ibox(INDENT_UNIT);
-            if !fields.is_empty() {
-                self.word(",");
-                self.space();
+        match wth {
+            hir::StructTailExpr::Base(expr) => {
+                self.ibox(INDENT_UNIT);
+                if !fields.is_empty() {
+                    self.word(",");
+                    self.space();
+                }
+                self.word("..");
+                self.print_expr(expr);
+                self.end();
+            }
+            hir::StructTailExpr::DefaultFields(_) => {
+                self.ibox(INDENT_UNIT);
+                if !fields.is_empty() {
+                    self.word(",");
+                    self.space();
+                }
+                self.word("..");
+                self.end();
+            }
+            hir::StructTailExpr::None => {
+                if !fields.is_empty() {
+                    self.word(",");
+                }
             }
-            self.word("..");
-            self.print_expr(expr);
-            self.end();
-        } else if !fields.is_empty() {
-            self.word(",");
         }
 
         self.word("}");
@@ -1553,6 +1603,11 @@ pub fn print_expr(&mut self, expr: &hir::Expr<'_>) {
                     self.print_expr_maybe_paren(expr, parser::PREC_JUMP);
                 }
             }
+            hir::ExprKind::Become(result) => {
+                self.word("become");
+                self.word(" ");
+                self.print_expr_maybe_paren(result, parser::PREC_JUMP);
+            }
             hir::ExprKind::InlineAsm(asm) => {
                 self.word("asm!");
                 self.print_inline_asm(asm);
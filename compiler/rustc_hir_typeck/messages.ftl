@@ -11,6 +11,9 @@ hir_typeck_return_stmt_outside_of_fn_body =
     .encl_body_label = the return is part of this body...
     .encl_fn_label = ...not the enclosing function body
 
+hir_typeck_become_outside_of_fn_body =
+    `become` outside of function body
+
 hir_typeck_yield_expr_outside_of_generator =
     yield expression outside of generator literal
 
@@ -73,6 +76,8 @@ hir_typeck_const_select_must_be_fn = this argument must be a function item
 hir_typeck_union_pat_multiple_fields = union patterns should have exactly one field
 hir_typeck_union_pat_dotdot = `..` cannot be used in union patterns
 
+hir_typeck_literal_not_in_pattern_type = literal is not a value of the pattern type `{$ty}`
+
 hir_typeck_arg_mismatch_indeterminate = argument type mismatch was detected, but rustc had trouble determining where
     .note = we would appreciate a bug report: https://github.com/rust-lang/rust/issues/new
 
@@ -131,6 +131,7 @@ fn pointer_kind(
             | ty::Uint(..)
             | ty::Float(_)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::GeneratorWitness(..)
             | ty::GeneratorWitnessMIR(..)
             | ty::RawPtr(_)
@@ -55,7 +55,7 @@ pub(super) fn check_fn<'a, 'tcx>(
     fn_maybe_err(tcx, span, fn_sig.abi);
 
     if let Some(kind) = body.generator_kind && can_be_generator.is_some() {
-        let yield_ty = if kind == hir::GeneratorKind::Gen {
+        let yield_ty = if let hir::GeneratorKind::Gen | hir::GeneratorKind::Iter(_) = kind {
             let yield_ty = fcx
                 .next_ty_var(TypeVariableOrigin { kind: TypeVariableOriginKind::TypeInference, span });
             fcx.require_type_is_sized(yield_ty, span, traits::SizedYieldType);
@@ -644,6 +644,10 @@ fn supplied_sig_of_closure(
                     )
                 }
 
+                // `gen` blocks and the bodies of `gen fn`s always evaluate to `()`, the
+                // values they produce are the ones they yield.
+                Some(hir::GeneratorKind::Iter(_)) => self.tcx.mk_unit(),
+
                 _ => astconv.ty_infer(None, decl.output.span()),
             },
         };
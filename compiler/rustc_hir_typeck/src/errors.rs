@@ -31,6 +31,13 @@ pub struct ReturnStmtOutsideOfFnBody {
     pub encl_fn_span: Option<Span>,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_typeck_become_outside_of_fn_body)]
+pub struct BecomeOutsideFnBody {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_typeck_yield_expr_outside_of_generator, code = "E0627")]
 pub struct YieldExprOutsideOfGenerator {
@@ -261,6 +268,14 @@ pub struct UnionPatDotDot {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_typeck_literal_not_in_pattern_type)]
+pub struct LiteralNotInPatternType<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_typeck_arg_mismatch_indeterminate)]
 pub struct ArgMismatchIndeterminate {
@@ -8,8 +8,8 @@
 use crate::errors::TypeMismatchFruTypo;
 use crate::errors::{AddressOfTemporaryTaken, ReturnStmtOutsideOfFnBody, StructExprNonExhaustive};
 use crate::errors::{
-    FieldMultiplySpecifiedInInitializer, FunctionalRecordUpdateOnNonStruct, HelpUseLatestEdition,
-    YieldExprOutsideOfGenerator,
+    BecomeOutsideFnBody, FieldMultiplySpecifiedInInitializer, FunctionalRecordUpdateOnNonStruct,
+    HelpUseLatestEdition, YieldExprOutsideOfGenerator,
 };
 use crate::fatally_break_rust;
 use crate::method::SelfSource;
@@ -324,6 +324,7 @@ fn check_expr_kind(
                 }
             }
             ExprKind::Ret(ref expr_opt) => self.check_expr_return(expr_opt.as_deref(), expr),
+            ExprKind::Become(call) => self.check_expr_become(call, expr),
             ExprKind::Let(let_expr) => self.check_expr_let(let_expr),
             ExprKind::Loop(body, _, source, _) => {
                 self.check_expr_loop(body, source, expected, expr)
@@ -373,6 +374,19 @@ fn check_expr_unary(
         expr: &'tcx hir::Expr<'tcx>,
     ) -> Ty<'tcx> {
         let tcx = self.tcx;
+        // Pattern types have no arithmetic, but a negated literal can still be one of their
+        // values, which is lowered to a single negative constant.
+        if let hir::UnOp::Neg = unop
+            && let hir::ExprKind::Lit(lit) = &oprnd.kind
+            && let ast::LitKind::Int(_, ast::LitIntType::Unsuffixed) = lit.node
+            && let Some(ty) = expected.to_option(self)
+            && let ty::Pat(base, _) = *ty.kind()
+            && base.is_integral()
+        {
+            let ty = self.check_lit_in_pat_ty(lit, true, expr.span, ty);
+            self.write_ty(oprnd.hir_id, ty);
+            return ty;
+        }
         let expected_inner = match unop {
             hir::UnOp::Not | hir::UnOp::Neg => expected,
             hir::UnOp::Deref => NoExpectation,
@@ -817,6 +831,29 @@ fn check_expr_return(
         self.tcx.types.never
     }
 
+    /// Checks `become call`. Unlike `return`, the type of the call is not coerced to the return
+    /// type of the function: it must be the return type itself, since the callee returns directly
+    /// to our caller. Whether `call` is actually a call is checked when building MIR.
+    fn check_expr_become(
+        &self,
+        call: &'tcx hir::Expr<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+    ) -> Ty<'tcx> {
+        match &self.ret_coercion {
+            Some(ret_coercion) => {
+                let ret_ty = ret_coercion.borrow().expected_ty();
+                let call_expr_ty = self.check_expr_with_hint(call, ret_ty);
+                self.demand_suptype(expr.span, ret_ty, call_expr_ty);
+            }
+            None => {
+                self.tcx.sess.emit_err(BecomeOutsideFnBody { span: expr.span });
+                // Still type-check the call, like `check_expr_return` does.
+                self.check_expr(call);
+            }
+        }
+        self.tcx.types.never
+    }
+
     /// `explicit_return` is `true` if we're checking an explicit `return expr`,
     /// and `false` if we're checking a trailing expression.
     pub(super) fn check_return_expr(
@@ -1510,7 +1547,7 @@ fn check_expr_struct(
         expected: Expectation<'tcx>,
         qpath: &QPath<'_>,
         fields: &'tcx [hir::ExprField<'tcx>],
-        base_expr: &'tcx Option<&'tcx hir::Expr<'tcx>>,
+        base_expr: &'tcx hir::StructTailExpr<'tcx>,
     ) -> Ty<'tcx> {
         // Find the relevant variant
         let (variant, adt_ty) = match self.check_struct_path(qpath, expr.hir_id) {
@@ -1552,7 +1589,7 @@ fn check_expr_struct_fields(
         span: Span,
         variant: &'tcx ty::VariantDef,
         ast_fields: &'tcx [hir::ExprField<'tcx>],
-        base_expr: &'tcx Option<&'tcx hir::Expr<'tcx>>,
+        base_expr: &'tcx hir::StructTailExpr<'tcx>,
         expr_span: Span,
     ) {
         let tcx = self.tcx;
@@ -1656,13 +1693,40 @@ fn check_expr_struct_fields(
         // the fields with the base_expr. This could cause us to hit errors later
         // when certain fields are assumed to exist that in fact do not.
         if error_happened {
-            if let Some(base_expr) = base_expr {
+            if let hir::StructTailExpr::Base(base_expr) = base_expr {
                 self.check_expr(base_expr);
             }
             return;
         }
 
-        if let Some(base_expr) = base_expr {
+        if let hir::StructTailExpr::DefaultFields(tail_span) = *base_expr {
+            if adt_kind == AdtKind::Union {
+                self.tcx.sess.emit_err(FunctionalRecordUpdateOnNonStruct { span: tail_span });
+                return;
+            }
+            // Every field that wasn't explicitly provided must have a default value.
+            let missing_fields: FxHashMap<_, _> = remaining_fields
+                .into_iter()
+                .filter(|(_, (_, field))| field.value.is_none())
+                .collect();
+            if !missing_fields.is_empty() {
+                self.report_missing_fields(
+                    adt_ty,
+                    span,
+                    missing_fields,
+                    variant,
+                    ast_fields,
+                    substs,
+                );
+                return;
+            }
+            let fru_tys = variant
+                .fields
+                .iter()
+                .map(|f| self.normalize(expr_span, f.ty(self.tcx, substs)))
+                .collect();
+            self.typeck_results.borrow_mut().fru_field_types_mut().insert(expr_id, fru_tys);
+        } else if let hir::StructTailExpr::Base(base_expr) = base_expr {
             // FIXME: We are currently creating two branches here in order to maintain
             // consistency. But they should be merged as much as possible.
             let fru_tys = if self.tcx.features().type_changing_struct_update {
@@ -1800,12 +1864,12 @@ fn check_expr_struct_fields(
     fn check_struct_fields_on_error(
         &self,
         fields: &'tcx [hir::ExprField<'tcx>],
-        base_expr: &'tcx Option<&'tcx hir::Expr<'tcx>>,
+        base_expr: &'tcx hir::StructTailExpr<'tcx>,
     ) {
         for field in fields {
             self.check_expr(&field.expr);
         }
-        if let Some(base) = *base_expr {
+        if let hir::StructTailExpr::Base(base) = *base_expr {
             self.check_expr(&base);
         }
     }
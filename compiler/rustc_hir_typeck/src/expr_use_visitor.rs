@@ -326,6 +326,10 @@ pub fn walk_expr(&mut self, expr: &hir::Expr<'_>) {
                 }
             }
 
+            hir::ExprKind::Become(call) => {
+                self.consume_expr(call);
+            }
+
             hir::ExprKind::Assign(lhs, rhs, _) => {
                 self.mutate_expr(lhs);
                 self.consume_expr(rhs);
@@ -393,8 +397,18 @@ fn maybe_read_scrutinee<'t>(
         // than wildcards.
         let ExprUseVisitor { ref mc, body_owner: _, delegate: _ } = *self;
         let mut needs_to_be_read = false;
+        let mut needs_mut_borrow = false;
         for pat in pats {
             mc.cat_pattern(discr_place.clone(), pat, |place, pat| {
+                let typeck_results = self.mc.typeck_results;
+                if let Some(adjustments) = typeck_results.pat_adjustments().get(pat.hir_id)
+                    && adjustments.iter().any(|ty| ty.is_adt() && !ty.is_box())
+                {
+                    // Deref patterns call `Deref::deref`, or `DerefMut::deref_mut` if they bind
+                    // anything by mutable reference, on the scrutinee.
+                    needs_to_be_read = true;
+                    needs_mut_borrow |= typeck_results.pat_has_ref_mut_binding(pat);
+                }
                 match &pat.kind {
                     PatKind::Binding(.., opt_sub_pat) => {
                         // If the opt_sub_pat is None, than the binding does not count as
@@ -452,7 +466,9 @@ fn maybe_read_scrutinee<'t>(
             })?
         }
 
-        if needs_to_be_read {
+        if needs_mut_borrow {
+            self.borrow_expr(discr, ty::MutBorrow);
+        } else if needs_to_be_read {
             self.borrow_expr(discr, ty::ImmBorrow);
         } else {
             let closure_def_id = match discr_place.place.base {
@@ -514,7 +530,7 @@ fn walk_block(&mut self, blk: &hir::Block<'_>) {
     fn walk_struct_expr<'hir>(
         &mut self,
         fields: &[hir::ExprField<'_>],
-        opt_with: &Option<&'hir hir::Expr<'_>>,
+        opt_with: &hir::StructTailExpr<'hir>,
     ) {
         // Consume the expressions supplying values for each field.
         for field in fields {
@@ -527,8 +543,8 @@ fn walk_struct_expr<'hir>(
         }
 
         let with_expr = match *opt_with {
-            Some(w) => &*w,
-            None => {
+            hir::StructTailExpr::Base(w) => &*w,
+            hir::StructTailExpr::DefaultFields(_) | hir::StructTailExpr::None => {
                 return;
             }
         };
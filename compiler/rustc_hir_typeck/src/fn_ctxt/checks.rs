@@ -405,7 +405,7 @@ fn variadic_error<'tcx>(
                 // in C but we just error out instead and require explicit casts.
                 let arg_ty = self.structurally_resolved_type(arg.span, arg_ty);
                 match arg_ty.kind() {
-                    ty::Float(ty::FloatTy::F32) => {
+                    ty::Float(ty::FloatTy::F16 | ty::FloatTy::F32) => {
                         variadic_error(tcx.sess, arg.span, arg_ty, "c_double");
                     }
                     ty::Int(ty::IntTy::I8 | ty::IntTy::I16) | ty::Bool => {
@@ -1282,6 +1282,9 @@ pub(in super::super) fn check_lit(
             ast::LitKind::Int(_, ast::LitIntType::Unsuffixed) => {
                 let opt_ty = expected.to_option(self).and_then(|ty| match ty.kind() {
                     ty::Int(_) | ty::Uint(_) => Some(ty),
+                    ty::Pat(base, _) if base.is_integral() => {
+                        Some(self.check_lit_in_pat_ty(lit, false, lit.span, ty))
+                    }
                     ty::Char => Some(tcx.types.u8),
                     ty::RawPtr(..) => Some(tcx.types.usize),
                     ty::FnDef(..) | ty::FnPtr(_) => Some(tcx.types.usize),
@@ -1309,6 +1312,35 @@ pub(in super::super) fn check_lit(
         }
     }
 
+    /// Checks that the unsuffixed integer literal `lit`, negated if `neg`, is a value of the
+    /// pattern type `ty` it is expected to have, and returns `ty` if it is.
+    pub(in super::super) fn check_lit_in_pat_ty(
+        &self,
+        lit: &hir::Lit,
+        neg: bool,
+        span: Span,
+        ty: Ty<'tcx>,
+    ) -> Ty<'tcx> {
+        let tcx = self.tcx;
+        let (&ast::LitKind::Int(n, _), &ty::Pat(base, pat)) = (&lit.node, ty.kind()) else {
+            bug!("expected an integer literal of a pattern type, found `{ty}`");
+        };
+
+        let (size, signed) = base.int_size_and_signed(tcx);
+        let in_base = match (signed, neg) {
+            (false, true) => n == 0,
+            (false, false) => n <= size.unsigned_int_max(),
+            (true, true) => n <= size.signed_int_min().unsigned_abs(),
+            (true, false) => n <= size.signed_int_max() as u128,
+        };
+        let bits = size.truncate(if neg { n.wrapping_neg() } else { n });
+        if in_base && pat.contains(tcx, base, bits) {
+            ty
+        } else {
+            tcx.ty_error(tcx.sess.emit_err(errors::LiteralNotInPatternType { span, ty }))
+        }
+    }
+
     pub fn check_struct_path(
         &self,
         qpath: &QPath<'_>,
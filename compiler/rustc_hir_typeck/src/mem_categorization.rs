@@ -361,6 +361,7 @@ pub(crate) fn cat_expr_unadjusted(
             | hir::ExprKind::AssignOp(..)
             | hir::ExprKind::Closure { .. }
             | hir::ExprKind::Ret(..)
+            | hir::ExprKind::Become(..)
             | hir::ExprKind::Unary(..)
             | hir::ExprKind::Yield(..)
             | hir::ExprKind::MethodCall(..)
@@ -650,8 +651,29 @@ fn cat_pattern_<F>(
         // Then we see that to get the same result, we must start with
         // `deref { deref { place_foo }}` instead of `place_foo` since the pattern is now `Some(x,)`
         // and not `&&Some(x,)`, even though its assigned type is that of `&&Some(x,)`.
-        for _ in 0..self.typeck_results.pat_adjustments().get(pat.hir_id).map_or(0, |v| v.len()) {
+        let adjustments = self.typeck_results.pat_adjustments().get(pat.hir_id);
+        let adjustments = adjustments.map_or(&[][..], |v| &v[..]);
+        for (i, adjust_ty) in adjustments.iter().enumerate() {
             debug!("cat_pattern: applying adjustment to place_with_id={:?}", place_with_id);
+            if let ty::Adt(adt_def, _) = adjust_ty.kind() && !adt_def.is_box() {
+                // A deref pattern on a smart pointer calls `Deref::deref` or
+                // `DerefMut::deref_mut` and matches on the place the resulting temporary
+                // reference points to.
+                let target_ty = match adjustments.get(i + 1) {
+                    Some(ty) => *ty,
+                    None => self.pat_ty_unadjusted(pat)?,
+                };
+                let mutbl = if self.typeck_results.pat_has_ref_mut_binding(pat) {
+                    hir::Mutability::Mut
+                } else {
+                    hir::Mutability::Not
+                };
+                let ref_ty = self.tcx().mk_ref(
+                    self.tcx().lifetimes.re_erased,
+                    ty::TypeAndMut { ty: target_ty, mutbl },
+                );
+                place_with_id = self.cat_rvalue(pat.hir_id, pat.span, ref_ty);
+            }
             place_with_id = self.cat_deref(pat, place_with_id)?;
         }
         let place_with_id = place_with_id; // lose mutability
@@ -6,9 +6,9 @@
     MultiSpan,
 };
 use rustc_hir as hir;
-use rustc_hir::def::{CtorKind, DefKind, Res};
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
 use rustc_hir::pat_util::EnumerateAndAdjustIterator;
-use rustc_hir::{HirId, Pat, PatKind};
+use rustc_hir::{HirId, LangItem, Pat, PatKind};
 use rustc_infer::infer;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_middle::middle::stability::EvalResult;
@@ -20,6 +20,7 @@
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{BytePos, DUMMY_SP};
 use rustc_target::abi::FieldIdx;
+use rustc_trait_selection::infer::InferCtxtExt;
 use rustc_trait_selection::traits::{ObligationCause, Pattern};
 use ty::VariantDef;
 
@@ -203,6 +204,7 @@ fn check_pat(
         };
 
         self.write_ty(pat.hir_id, ty);
+        self.register_deref_mut_bounds_if_needed(pat);
 
         // (note_1): In most of the cases where (note_1) is referenced
         // (literals and constants being the exception), we relate types
@@ -344,11 +346,28 @@ fn peel_off_references(
         // For each ampersand peeled off, update the binding mode and push the original
         // type into the adjustments vector.
         //
+        // With `deref_patterns`, smart pointers whose pointee the pattern matches on are
+        // peeled off too. These don't affect the default binding mode.
+        //
         // See the examples in `ui/match-defbm*.rs`.
         let mut pat_adjustments = vec![];
-        while let ty::Ref(_, inner_ty, inner_mutability) = *expected.kind() {
+        loop {
             debug!("inspecting {:?}", expected);
 
+            let ty::Ref(_, inner_ty, inner_mutability) = *expected.kind() else {
+                // Smart pointers may deref to themselves, so bound the number of derefs.
+                if !self.tcx.recursion_limit().value_within_limit(pat_adjustments.len()) {
+                    break;
+                }
+                let Some(inner_ty) = self.deref_pattern_target(pat, expected) else {
+                    break;
+                };
+                debug!("current discriminant is a smart pointer, inserting implicit deref");
+                pat_adjustments.push(expected);
+                expected = inner_ty;
+                continue;
+            };
+
             debug!("current discriminant is Ref, inserting implicit deref");
             // Preserve the reference type. We'll need it later during THIR lowering.
             pat_adjustments.push(expected);
@@ -378,6 +397,87 @@ fn peel_off_references(
         (expected, def_bm)
     }
 
+    /// With `deref_patterns`, returns the type `pat` should be matched against if it is to be
+    /// matched against the pointee of `expected`, a smart pointer implementing `DerefPure`.
+    fn deref_pattern_target(&self, pat: &'tcx Pat<'tcx>, expected: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        let tcx = self.tcx;
+        if !tcx.features().deref_patterns {
+            return None;
+        }
+        let ty::Adt(adt_def, _) = *expected.kind() else {
+            return None;
+        };
+        if self.pat_matches_adt_directly(pat, adt_def) {
+            return None;
+        }
+        let deref_pure = tcx.lang_items().deref_pure_trait()?;
+        if !self
+            .infcx
+            .type_implements_trait(deref_pure, [expected], self.param_env)
+            .must_apply_modulo_regions()
+        {
+            return None;
+        }
+        let deref_target = tcx.lang_items().deref_target()?;
+        let target = self.normalize(pat.span, tcx.mk_projection(deref_target, [expected]));
+        Some(self.resolve_vars_with_obligations(target))
+    }
+
+    /// Whether `pat` is a pattern for the ADT `adt_def` itself, e.g. `Rc { .. }` or `box _` when
+    /// matching on an `Rc` or a `Box`. Such patterns are never matched against the pointee.
+    fn pat_matches_adt_directly(&self, pat: &'tcx Pat<'tcx>, adt_def: ty::AdtDef<'tcx>) -> bool {
+        let tcx = self.tcx;
+        let res = match &pat.kind {
+            PatKind::Struct(qpath, ..) | PatKind::TupleStruct(qpath, ..) | PatKind::Path(qpath) => {
+                match qpath {
+                    hir::QPath::Resolved(_, path) => path.res,
+                    // We can't know which type a type-relative path refers to before checking it,
+                    // so we don't insert deref patterns.
+                    hir::QPath::TypeRelative(..) | hir::QPath::LangItem(..) => return true,
+                }
+            }
+            PatKind::Box(_) => return adt_def.is_box(),
+            _ => return false,
+        };
+        let pat_adt_did = match res {
+            Res::Def(DefKind::Ctor(CtorOf::Variant, _), did) => tcx.parent(tcx.parent(did)),
+            Res::Def(DefKind::Ctor(CtorOf::Struct, _) | DefKind::Variant, did) => tcx.parent(did),
+            Res::Def(DefKind::Struct | DefKind::Union | DefKind::Enum, did) => did,
+            Res::Def(DefKind::TyAlias, did)
+            | Res::SelfTyAlias { alias_to: did, .. }
+            | Res::SelfCtor(did) => match tcx.type_of(did).subst_identity().kind() {
+                ty::Adt(def, _) => def.did(),
+                _ => return false,
+            },
+            // Don't insert deref patterns on erroneous paths to avoid spurious errors.
+            Res::Err => return true,
+            _ => return false,
+        };
+        pat_adt_did == adt_def.did()
+    }
+
+    /// Deref patterns on a pattern that binds anything by mutable reference go through
+    /// `DerefMut`, so the smart pointers dereferenced by `pat` must implement it.
+    fn register_deref_mut_bounds_if_needed(&self, pat: &'tcx Pat<'tcx>) {
+        let smart_pointer_tys: Vec<_> = {
+            let typeck_results = self.typeck_results.borrow();
+            let Some(adjustments) = typeck_results.pat_adjustments().get(pat.hir_id) else {
+                return;
+            };
+            if !typeck_results.pat_has_ref_mut_binding(pat) {
+                return;
+            }
+            adjustments.iter().copied().filter(|ty| ty.is_adt() && !ty.is_box()).collect()
+        };
+        if smart_pointer_tys.is_empty() {
+            return;
+        }
+        let deref_mut = self.tcx.require_lang_item(LangItem::DerefMut, Some(pat.span));
+        for ty in smart_pointer_tys {
+            self.register_bound(ty, deref_mut, self.misc(pat.span));
+        }
+    }
+
     fn check_pat_lit(
         &self,
         span: Span,
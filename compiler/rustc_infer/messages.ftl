@@ -67,6 +67,8 @@ infer_need_type_info_in_generator =
     [async_block] `async` block
     [async_closure] `async` closure
     [async_fn] `async fn` body
+    [gen_block] `gen` block
+    [gen_fn] `gen fn` body
     *[generator] generator
     } must be known in this context
 
@@ -469,6 +469,7 @@ fn fold_ty(&mut self, mut t: Ty<'tcx>) -> Ty<'tcx> {
             | ty::Str
             | ty::Error(_)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(..)
             | ty::RawPtr(..)
             | ty::Ref(..)
@@ -614,6 +614,8 @@ fn into_diagnostic_arg(self) -> rustc_errors::DiagnosticArgValue<'static> {
             hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block) => "async_block",
             hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Closure) => "async_closure",
             hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Fn) => "async_fn",
+            hir::GeneratorKind::Iter(hir::IterGeneratorKind::Block) => "gen_block",
+            hir::GeneratorKind::Iter(hir::IterGeneratorKind::Fn) => "gen_fn",
             hir::GeneratorKind::Gen => "generator",
         };
         rustc_errors::DiagnosticArgValue::Str(kind.into())
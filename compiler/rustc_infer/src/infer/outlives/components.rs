@@ -97,6 +97,11 @@ fn compute_components<'tcx>(
                 compute_components(tcx, element, out, visited);
             }
 
+            ty::Pat(element, _) => {
+                // The pattern only contains integer constants, which don't affect regions
+                compute_components(tcx, element, out, visited);
+            }
+
             ty::Closure(_, ref substs) => {
                 let tupled_ty = substs.as_closure().tupled_upvars_ty();
                 compute_components(tcx, tupled_ty, out, visited);
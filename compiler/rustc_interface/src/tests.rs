@@ -738,6 +738,7 @@ macro_rules! tracked {
         })
     );
     tracked!(codegen_backend, Some("abc".to_string()));
+    tracked!(contract_checks, true);
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_info_for_profiling, true);
     tracked!(debug_macros, true);
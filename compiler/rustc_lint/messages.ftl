@@ -253,6 +253,9 @@ lint_improper_ctypes_dyn = trait objects have no C equivalent
 lint_improper_ctypes_slice_reason = slices have no C equivalent
 lint_improper_ctypes_slice_help = consider using a raw pointer instead
 
+lint_improper_ctypes_pat_reason = pattern types have no C equivalent
+lint_improper_ctypes_pat_help = consider using the base type instead
+
 lint_improper_ctypes_128bit = 128-bit integers don't currently have a known stable ABI
 
 lint_improper_ctypes_char_reason = the `char` type has no C equivalent
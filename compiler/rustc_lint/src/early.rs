@@ -164,6 +164,9 @@ fn visit_fn(&mut self, fk: ast_visit::FnKind<'a>, span: Span, id: ast::NodeId) {
             if let ast::Async::Yes { closure_id, .. } = sig.header.asyncness {
                 self.check_id(closure_id);
             }
+            if let ast::Gen::Yes { closure_id, .. } = sig.header.genness {
+                self.check_id(closure_id);
+            }
         }
     }
 
@@ -9,6 +9,8 @@
     },
 };
 use crate::{LateContext, LateLintPass, LintContext};
+use rustc_apfloat::ieee::{Half, Quad};
+use rustc_apfloat::Float;
 use rustc_ast as ast;
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashSet;
@@ -462,12 +464,14 @@ fn lint_literal<'tcx>(
         ty::Float(t) => {
             let is_infinite = match lit.node {
                 ast::LitKind::Float(v, _) => match t {
-                    ty::FloatTy::F32 => v.as_str().parse().map(f32::is_infinite),
-                    ty::FloatTy::F64 => v.as_str().parse().map(f64::is_infinite),
+                    ty::FloatTy::F16 => v.as_str().parse::<Half>().ok().map(|f| f.is_infinite()),
+                    ty::FloatTy::F32 => v.as_str().parse().ok().map(f32::is_infinite),
+                    ty::FloatTy::F64 => v.as_str().parse().ok().map(f64::is_infinite),
+                    ty::FloatTy::F128 => v.as_str().parse::<Quad>().ok().map(|f| f.is_infinite()),
                 },
                 _ => bug!(),
             };
-            if is_infinite == Ok(true) {
+            if is_infinite == Some(true) {
                 cx.emit_spanned_lint(
                     OVERFLOWING_LITERALS,
                     e.span,
@@ -1078,6 +1082,12 @@ fn check_type_for_ffi(&self, cache: &mut FxHashSet<Ty<'tcx>>, ty: Ty<'tcx>) -> F
 
             ty::Array(inner_ty, _) => self.check_type_for_ffi(cache, inner_ty),
 
+            ty::Pat(..) => FfiUnsafe {
+                ty,
+                reason: fluent::lint_improper_ctypes_pat_reason,
+                help: Some(fluent::lint_improper_ctypes_pat_help),
+            },
+
             ty::FnPtr(sig) => {
                 if self.is_internal_abi(sig.abi()) {
                     return FfiUnsafe {
@@ -317,14 +317,19 @@ fn is_ty_must_use<'tcx>(
                 },
                 ty::Closure(..) => Some(MustUsePath::Closure(span)),
                 ty::Generator(def_id, ..) => {
-                    // async fn should be treated as "implementor of `Future`"
-                    let must_use = if cx.tcx.generator_is_async(def_id) {
-                        let def_id = cx.tcx.lang_items().future_trait().unwrap();
-                        is_def_must_use(cx, def_id, span)
-                            .map(|inner| MustUsePath::Opaque(Box::new(inner)))
+                    // async fn should be treated as "implementor of `Future`", and
+                    // `gen` blocks as "implementor of `Iterator`"
+                    let trait_def_id = if cx.tcx.generator_is_async(def_id) {
+                        cx.tcx.lang_items().future_trait()
+                    } else if cx.tcx.generator_is_iter(def_id) {
+                        cx.tcx.lang_items().iterator_trait()
                     } else {
                         None
                     };
+                    let must_use = trait_def_id.and_then(|def_id| {
+                        is_def_must_use(cx, def_id, span)
+                            .map(|inner| MustUsePath::Opaque(Box::new(inner)))
+                    });
                     must_use.or(Some(MustUsePath::Generator(span)))
                 }
                 _ => None,
@@ -599,7 +604,10 @@ fn is_expr_delims_necessary(
                 ExprKind::AssignOp(_op, _lhs, rhs) => rhs,
                 ExprKind::Assign(_lhs, rhs, _span) => rhs,
 
-                ExprKind::Ret(_) | ExprKind::Yield(..) | ExprKind::Yeet(..) => return true,
+                ExprKind::Ret(_)
+                | ExprKind::Yield(..)
+                | ExprKind::Yeet(..)
+                | ExprKind::Become(..) => return true,
 
                 ExprKind::Break(_label, None) => return false,
                 ExprKind::Break(_label, Some(break_expr)) => {
@@ -260,6 +260,10 @@ extern "C" void LLVMRustAddCallSiteAttributes(LLVMValueRef Instr, unsigned Index
   AddAttributes(Call, Index, Attrs, AttrsLen);
 }
 
+extern "C" void LLVMRustSetMustTail(LLVMValueRef Instr) {
+  unwrap<CallInst>(Instr)->setTailCallKind(CallInst::TCK_MustTail);
+}
+
 extern "C" LLVMAttributeRef LLVMRustCreateAttrNoValue(LLVMContextRef C,
                                                       LLVMRustAttribute RustAttr) {
   return wrap(Attribute::get(*unwrap(C), fromRust(RustAttr)));
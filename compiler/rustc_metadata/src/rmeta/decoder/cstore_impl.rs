@@ -239,6 +239,15 @@ fn into_args(self) -> (DefId, SimplifiedType) {
     asyncness => { table_direct }
     fn_arg_names => { table }
     generator_kind => { table }
+    fn_contract => {
+        cdata
+            .root
+            .tables
+            .fn_contract
+            .get(cdata, def_id.index)
+            .map(|lazy| lazy.decode((cdata, tcx)))
+            .unwrap_or_default()
+    }
     trait_def => { table }
     deduced_param_attrs => { table }
     is_type_alias_impl_trait => {
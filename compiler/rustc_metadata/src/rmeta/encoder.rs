@@ -1324,6 +1324,12 @@ fn encode_def_ids(&mut self) {
             if let DefKind::Enum | DefKind::Struct | DefKind::Union = def_kind {
                 self.encode_info_for_adt(local_id);
             }
+            if let DefKind::Fn | DefKind::AssocFn = def_kind {
+                let contract = self.tcx.fn_contract(def_id);
+                if !contract.is_empty() {
+                    record!(self.tables.fn_contract[def_id] <- contract);
+                }
+            }
             if tcx.impl_method_has_trait_impl_trait_tys(def_id)
                 && let Ok(table) = self.tcx.collect_return_position_impl_trait_in_trait_tys(def_id)
             {
@@ -1390,6 +1396,12 @@ fn encode_info_for_adt(&mut self, local_def_id: LocalDefId) {
                 f.did.index
             }));
 
+            for field in &variant.fields {
+                if let Some(value) = field.value {
+                    self.tables.default_fields.set_some(field.did.index, value.into());
+                }
+            }
+
             if let Some((CtorKind::Fn, ctor_def_id)) = variant.ctor {
                 self.tables.constness.set_some(ctor_def_id.index, hir::Constness::Const);
                 let fn_sig = tcx.fn_sig(ctor_def_id);
@@ -26,6 +26,11 @@ pub struct CodegenFnAttrs {
     /// The `#[target_feature(enable = "...")]` attribute and the enabled
     /// features (only enabled features are supported right now).
     pub target_features: Vec<Symbol>,
+    /// The `#[target_clones("...", "default")]` attribute: one entry per copy of the function
+    /// to generate, holding the target features that copy is compiled with. The `"default"`
+    /// copy has no extra features and is always the last entry; the others are tried at
+    /// runtime in the order they were written.
+    pub target_clones: Vec<Vec<Symbol>>,
     /// The `#[linkage = "..."]` attribute on Rust-defined items and the value we found.
     pub linkage: Option<Linkage>,
     /// The `#[linkage = "..."]` attribute on foreign items and the value we found.
@@ -115,6 +120,7 @@ pub const fn new() -> CodegenFnAttrs {
             link_name: None,
             link_ordinal: None,
             target_features: vec![],
+            target_clones: vec![],
             linkage: None,
             import_linkage: None,
             link_section: None,
@@ -637,6 +637,25 @@ pub enum TerminatorKind<'tcx> {
         fn_span: Span,
     },
 
+    /// A tail call, from `become`: evaluates the `func` operand and the arguments, pops the stack
+    /// frame of the current function, and starts execution of the referred to function. When
+    /// that function returns, it returns directly to the caller of the current function.
+    ///
+    /// Compared to [`Call`](TerminatorKind::Call), there is no `destination`, since the return
+    /// place is the one of the caller, no `target`, since this never returns, and no `unwind`,
+    /// since the frame which would run the cleanup is gone. The `func` operand and the arguments
+    /// must match the signature of the current function, and no local of the current function may
+    /// need to be dropped.
+    TailCall {
+        /// The function that’s being called.
+        func: Operand<'tcx>,
+        /// Arguments the function is called with.
+        args: Vec<Operand<'tcx>>,
+        /// This `Span` is the span of the function, without the dot and receiver
+        /// (e.g. `foo(a, b)` in `x.foo(a, b)`
+        fn_span: Span,
+    },
+
     /// Evaluates the operand, which must have type `bool`. If it is not equal to `expected`,
     /// initiates a panic. Initiating a panic corresponds to a `Call` terminator with some
     /// unspecified constant as the function to call, all the operands stored in the `AssertMessage`
@@ -657,6 +657,14 @@
         separate_provide_extern
     }
 
+    /// Returns the closures of the `#[requires]` and `#[ensures]` contract of the function
+    /// `def_id`, which are empty if it has no contract.
+    query fn_contract(def_id: DefId) -> &'tcx ty::FnContract {
+        arena_cache
+        desc { |tcx| "looking up the contract of `{}`", tcx.def_path_str(def_id) }
+        separate_provide_extern
+    }
+
     /// Gets a map with the variance of every item; use `item_variance` instead.
     query crate_variances(_: ()) -> &'tcx ty::CrateVariancesMap<'tcx> {
         arena_cache
@@ -1030,6 +1038,12 @@
         cache_on_disk_if { true }
     }
 
+    /// Checks that the `become` expressions of the given body are valid tail calls.
+    query check_tail_calls(key: LocalDefId) -> Result<(), rustc_errors::ErrorGuaranteed> {
+        desc { |tcx| "tail-call-checking `{}`", tcx.def_path_str(key) }
+        cache_on_disk_if { true }
+    }
+
     /// Performs part of the privacy check and computes effective visibilities.
     query effective_visibilities(_: ()) -> &'tcx EffectiveVisibilities {
         eval_always
@@ -157,8 +157,20 @@ pub struct AdtExpr<'tcx> {
     pub user_ty: UserTy<'tcx>,
 
     pub fields: Box<[FieldExpr]>,
-    /// The base, e.g. `Foo {x: 1, .. base}`.
-    pub base: Option<FruInfo<'tcx>>,
+    /// The base, e.g. `Foo {x: 1, .. base}` or `Foo {x: 1, ..}`.
+    pub base: AdtExprBase<'tcx>,
+}
+
+#[derive(Clone, Debug, HashStable)]
+pub enum AdtExprBase<'tcx> {
+    /// All the fields are explicitly listed: `Foo { a, b }`.
+    None,
+    /// The fields that are not explicitly listed are read from a base expression:
+    /// `Foo { a, ..base }`.
+    Base(FruInfo<'tcx>),
+    /// The fields that are not explicitly listed are populated from their default values:
+    /// `Foo { a, .. }`. Holds the types of all the fields of the variant.
+    DefaultFields(Box<[Ty<'tcx>]>),
 }
 
 #[derive(Clone, Debug, HashStable)]
@@ -410,6 +422,11 @@ pub enum ExprKind<'tcx> {
     Return {
         value: Option<ExprId>,
     },
+    /// A `become` expression. `value` is a function call, possibly wrapped in scopes; this is
+    /// checked by `check_tail_calls`.
+    Become {
+        value: ExprId,
+    },
     /// An inline `const` block, e.g. `const {}`.
     ConstBlock {
         did: DefId,
@@ -629,7 +646,8 @@ fn walk_(&self, it: &mut impl FnMut(&Pat<'tcx>) -> bool) {
             Wild | Range(..) | Binding { subpattern: None, .. } | Constant { .. } => {}
             AscribeUserType { subpattern, .. }
             | Binding { subpattern: Some(subpattern), .. }
-            | Deref { subpattern } => subpattern.walk_(it),
+            | Deref { subpattern }
+            | DerefPattern { subpattern, .. } => subpattern.walk_(it),
             Leaf { subpatterns } | Variant { subpatterns, .. } => {
                 subpatterns.iter().for_each(|field| field.pattern.walk_(it))
             }
@@ -725,6 +743,15 @@ pub enum PatKind<'tcx> {
         subpattern: Box<Pat<'tcx>>,
     },
 
+    /// An implicit deref of a smart pointer implementing `DerefPure`, e.g. matching a
+    /// `Box<Option<T>>` or `Rc<Option<T>>` against `Some(_)`. The value is dereferenced through a
+    /// call to `Deref::deref` (or `DerefMut::deref_mut` if `mutability` is `Mut`) before matching
+    /// against `subpattern`.
+    DerefPattern {
+        subpattern: Box<Pat<'tcx>>,
+        mutability: hir::Mutability,
+    },
+
     /// One of the following:
     /// * `&str`, which will be handled as a string pattern and thus exhaustiveness
     ///   checking will detect if you use the same string twice in different patterns.
@@ -890,6 +917,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 }
                 write!(f, "{}", subpattern)
             }
+            PatKind::DerefPattern { ref subpattern, .. } => write!(f, "{}", subpattern),
             PatKind::Constant { value } => write!(f, "{}", value),
             PatKind::Range(box PatRange { lo, hi, end }) => {
                 write!(f, "{}", lo)?;
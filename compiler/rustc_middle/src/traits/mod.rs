@@ -675,6 +675,9 @@ pub enum ImplSource<'tcx, N> {
     /// ImplSource automatically generated for a generator backing an async future.
     Future(ImplSourceFutureData<'tcx, N>),
 
+    /// ImplSource automatically generated for a generator backing a `gen` iterator.
+    Iterator(ImplSourceIteratorData<'tcx, N>),
+
     /// ImplSource for a trait alias.
     TraitAlias(ImplSourceTraitAliasData<'tcx, N>),
 
@@ -692,6 +695,7 @@ pub fn nested_obligations(self) -> Vec<N> {
             ImplSource::Closure(c) => c.nested,
             ImplSource::Generator(c) => c.nested,
             ImplSource::Future(c) => c.nested,
+            ImplSource::Iterator(c) => c.nested,
             ImplSource::Object(d) => d.nested,
             ImplSource::FnPointer(d) => d.nested,
             ImplSource::TraitAlias(d) => d.nested,
@@ -709,6 +713,7 @@ pub fn borrow_nested_obligations(&self) -> &[N] {
             ImplSource::Closure(c) => &c.nested,
             ImplSource::Generator(c) => &c.nested,
             ImplSource::Future(c) => &c.nested,
+            ImplSource::Iterator(c) => &c.nested,
             ImplSource::Object(d) => &d.nested,
             ImplSource::FnPointer(d) => &d.nested,
             ImplSource::TraitAlias(d) => &d.nested,
@@ -755,6 +760,11 @@ pub fn map<M, F>(self, f: F) -> ImplSource<'tcx, M>
                 substs: c.substs,
                 nested: c.nested.into_iter().map(f).collect(),
             }),
+            ImplSource::Iterator(c) => ImplSource::Iterator(ImplSourceIteratorData {
+                generator_def_id: c.generator_def_id,
+                substs: c.substs,
+                nested: c.nested.into_iter().map(f).collect(),
+            }),
             ImplSource::FnPointer(p) => ImplSource::FnPointer(ImplSourceFnPointerData {
                 fn_ty: p.fn_ty,
                 nested: p.nested.into_iter().map(f).collect(),
@@ -818,6 +828,16 @@ pub struct ImplSourceFutureData<'tcx, N> {
     pub nested: Vec<N>,
 }
 
+#[derive(Clone, PartialEq, Eq, TyEncodable, TyDecodable, HashStable, Lift)]
+#[derive(TypeFoldable, TypeVisitable)]
+pub struct ImplSourceIteratorData<'tcx, N> {
+    pub generator_def_id: DefId,
+    pub substs: SubstsRef<'tcx>,
+    /// Nested obligations. This can be non-empty if the generator
+    /// signature contains associated types.
+    pub nested: Vec<N>,
+}
+
 #[derive(Clone, PartialEq, Eq, TyEncodable, TyDecodable, HashStable, Lift)]
 #[derive(TypeFoldable, TypeVisitable)]
 pub struct ImplSourceClosureData<'tcx, N> {
@@ -26,8 +26,9 @@
 use crate::ty::{
     self, AdtDef, AdtDefData, AdtKind, Binder, Const, ConstData, FloatTy, FloatVar, FloatVid,
     GenericParamDefKind, ImplPolarity, InferTy, IntTy, IntVar, IntVid, List, ParamConst, ParamTy,
-    PolyExistentialPredicate, PolyFnSig, Predicate, PredicateKind, Region, RegionKind, ReprOptions,
-    TraitObjectVisitor, Ty, TyKind, TyVar, TyVid, TypeAndMut, UintTy, Visibility,
+    Pattern, PatternKind, PolyExistentialPredicate, PolyFnSig, Predicate, PredicateKind, Region,
+    RegionKind, ReprOptions, TraitObjectVisitor, Ty, TyKind, TyVar, TyVid, TypeAndMut, UintTy,
+    Visibility,
 };
 use crate::ty::{GenericArg, InternalSubsts, SubstsRef};
 use rustc_ast::{self as ast, attr};
@@ -93,6 +94,7 @@ impl<'tcx> Interner for TyCtxt<'tcx> {
     type Binder<T> = Binder<'tcx, T>;
     type Ty = Ty<'tcx>;
     type Const = ty::Const<'tcx>;
+    type Pat = ty::Pattern<'tcx>;
     type Region = Region<'tcx>;
     type Predicate = Predicate<'tcx>;
     type TypeAndMut = TypeAndMut<'tcx>;
@@ -143,6 +145,7 @@ pub struct CtxtInterners<'tcx> {
     layout: InternedSet<'tcx, LayoutS>,
     adt_def: InternedSet<'tcx, AdtDefData>,
     external_constraints: InternedSet<'tcx, ExternalConstraintsData<'tcx>>,
+    pat: InternedSet<'tcx, PatternKind<'tcx>>,
     fields: InternedSet<'tcx, List<FieldIdx>>,
 }
 
@@ -167,6 +170,7 @@ fn new(arena: &'tcx WorkerLocal<Arena<'tcx>>) -> CtxtInterners<'tcx> {
             layout: Default::default(),
             adt_def: Default::default(),
             external_constraints: Default::default(),
+            pat: Default::default(),
             fields: Default::default(),
         }
     }
@@ -268,8 +272,10 @@ pub struct CommonTypes<'tcx> {
     pub u32: Ty<'tcx>,
     pub u64: Ty<'tcx>,
     pub u128: Ty<'tcx>,
+    pub f16: Ty<'tcx>,
     pub f32: Ty<'tcx>,
     pub f64: Ty<'tcx>,
+    pub f128: Ty<'tcx>,
     pub str_: Ty<'tcx>,
     pub never: Ty<'tcx>,
     pub self_param: Ty<'tcx>,
@@ -347,8 +353,10 @@ fn new(
             u32: mk(Uint(ty::UintTy::U32)),
             u64: mk(Uint(ty::UintTy::U64)),
             u128: mk(Uint(ty::UintTy::U128)),
+            f16: mk(Float(ty::FloatTy::F16)),
             f32: mk(Float(ty::FloatTy::F32)),
             f64: mk(Float(ty::FloatTy::F64)),
+            f128: mk(Float(ty::FloatTy::F128)),
             str_: mk(Str),
             self_param: mk(ty::Param(ty::ParamTy { index: 0, name: kw::SelfUpper })),
 
@@ -795,6 +803,11 @@ pub fn generator_is_async(self, def_id: DefId) -> bool {
         matches!(self.generator_kind(def_id), Some(hir::GeneratorKind::Async(_)))
     }
 
+    /// Returns `true` if the node pointed to by `def_id` is a generator for a `gen` construct.
+    pub fn generator_is_iter(self, def_id: DefId) -> bool {
+        matches!(self.generator_kind(def_id), Some(hir::GeneratorKind::Iter(_)))
+    }
+
     pub fn stability(self) -> &'tcx stability::Index {
         self.stability_index(())
     }
@@ -1271,6 +1284,7 @@ fn lift_to_tcx(self, tcx: TyCtxt<'tcx>) -> Option<Self::Lifted> {
 nop_lift! {region; Region<'a> => Region<'tcx>}
 nop_lift! {const_; Const<'a> => Const<'tcx>}
 nop_lift! {const_allocation; ConstAllocation<'a> => ConstAllocation<'tcx>}
+nop_lift! {pat; Pattern<'a> => Pattern<'tcx>}
 nop_lift! {predicate; Predicate<'a> => Predicate<'tcx>}
 
 nop_list_lift! {type_lists; Ty<'a> => Ty<'tcx>}
@@ -1374,6 +1388,7 @@ fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                     self.0,
                     Adt,
                     Array,
+                    Pat,
                     Slice,
                     RawPtr,
                     Ref,
@@ -1524,6 +1539,7 @@ impl<'tcx> TyCtxt<'tcx> {
     adt_def: pub mk_adt_def_from_data(AdtDefData): AdtDef -> AdtDef<'tcx>,
     external_constraints: pub mk_external_constraints(ExternalConstraintsData<'tcx>):
         ExternalConstraints -> ExternalConstraints<'tcx>,
+    pat: pub mk_pat(PatternKind<'tcx>): Pattern -> Pattern<'tcx>,
 }
 
 macro_rules! slice_interners {
@@ -1695,8 +1711,10 @@ pub fn mk_mach_uint(self, tm: UintTy) -> Ty<'tcx> {
 
     pub fn mk_mach_float(self, tm: FloatTy) -> Ty<'tcx> {
         match tm {
+            FloatTy::F16 => self.types.f16,
             FloatTy::F32 => self.types.f32,
             FloatTy::F64 => self.types.f64,
+            FloatTy::F128 => self.types.f128,
         }
     }
 
@@ -1802,6 +1820,11 @@ pub fn mk_slice(self, ty: Ty<'tcx>) -> Ty<'tcx> {
         self.mk_ty_from_kind(Slice(ty))
     }
 
+    #[inline]
+    pub fn mk_pat_ty(self, ty: Ty<'tcx>, pat: Pattern<'tcx>) -> Ty<'tcx> {
+        self.mk_ty_from_kind(Pat(ty, pat))
+    }
+
     #[inline]
     pub fn mk_tup(self, ts: &[Ty<'tcx>]) -> Ty<'tcx> {
         if ts.is_empty() {
@@ -20,6 +20,7 @@ pub enum SimplifiedType {
     ForeignSimplifiedType(DefId),
     StrSimplifiedType,
     ArraySimplifiedType,
+    PatSimplifiedType,
     SliceSimplifiedType,
     RefSimplifiedType(Mutability),
     PtrSimplifiedType(Mutability),
@@ -118,6 +119,7 @@ pub fn simplify_type<'tcx>(
         ty::Adt(def, _) => Some(AdtSimplifiedType(def.did())),
         ty::Str => Some(StrSimplifiedType),
         ty::Array(..) => Some(ArraySimplifiedType),
+        ty::Pat(..) => Some(PatSimplifiedType),
         ty::Slice(..) => Some(SliceSimplifiedType),
         ty::RawPtr(ptr) => Some(PtrSimplifiedType(ptr.mutbl)),
         ty::Dynamic(trait_info, ..) => match trait_info.principal_def_id() {
@@ -223,6 +225,7 @@ pub fn types_may_unify<'tcx>(self, obligation_ty: Ty<'tcx>, impl_ty: Ty<'tcx>) -
             | ty::Adt(..)
             | ty::Str
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(..)
             | ty::RawPtr(..)
             | ty::Dynamic(..)
@@ -274,6 +277,9 @@ pub fn types_may_unify<'tcx>(self, obligation_ty: Ty<'tcx>, impl_ty: Ty<'tcx>) -
                 }
                 _ => false,
             },
+            ty::Pat(obl_ty, _) => {
+                matches!(k, &ty::Pat(impl_ty, _) if self.types_may_unify(obl_ty, impl_ty))
+            }
             ty::Tuple(obl) => match k {
                 &ty::Tuple(imp) => {
                     obl.len() == imp.len()
@@ -128,8 +128,10 @@ impl PrimitiveExt for Primitive {
     fn to_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
         match *self {
             Int(i, signed) => i.to_ty(tcx, signed),
+            F16 => tcx.types.f16,
             F32 => tcx.types.f32,
             F64 => tcx.types.f64,
+            F128 => tcx.types.f128,
             // FIXME(erikdesjardins): handle non-default addrspace ptr sizes
             Pointer(_) => tcx.mk_mut_ptr(tcx.mk_unit()),
         }
@@ -146,7 +148,7 @@ fn to_int_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
                 let signed = false;
                 tcx.data_layout().ptr_sized_integer().to_ty(tcx, signed)
             }
-            F32 | F64 => bug!("floats do not have an int type"),
+            F16 | F32 | F64 | F128 => bug!("floats do not have an int type"),
         }
     }
 }
@@ -778,6 +780,7 @@ fn field_ty_or_layout<'tcx>(
                 | ty::Int(_)
                 | ty::Uint(_)
                 | ty::Float(_)
+                | ty::Pat(..)
                 | ty::FnPtr(_)
                 | ty::Never
                 | ty::FnDef(..)
@@ -89,6 +89,7 @@
 pub use self::instance::{Instance, InstanceDef, ShortInstance, UnusedGenericParams};
 pub use self::list::List;
 pub use self::parameterized::ParameterizedOverTcx;
+pub use self::pattern::{Pattern, PatternKind};
 pub use self::rvalue_scopes::RvalueScopes;
 pub use self::sty::BoundRegionKind::*;
 pub use self::sty::{
@@ -143,6 +144,7 @@
 mod list;
 mod opaque_types;
 mod parameterized;
+mod pattern;
 mod rvalue_scopes;
 mod structural_impls;
 mod sty;
@@ -1737,6 +1739,22 @@ pub struct Destructor {
     pub constness: hir::Constness,
 }
 
+/// The contract of a function, given by its `#[requires]` and `#[ensures]` attributes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, HashStable, TyEncodable, TyDecodable)]
+pub struct FnContract {
+    /// The closures evaluating the preconditions, which take no arguments.
+    pub requires: Vec<DefId>,
+    /// The closures evaluating the postconditions, which take a reference to the returned
+    /// value.
+    pub ensures: Vec<DefId>,
+}
+
+impl FnContract {
+    pub fn is_empty(&self) -> bool {
+        self.requires.is_empty() && self.ensures.is_empty()
+    }
+}
+
 bitflags! {
     #[derive(HashStable, TyEncodable, TyDecodable)]
     pub struct VariantFlags: u8 {
@@ -1903,6 +1921,8 @@ pub struct FieldDef {
     pub did: DefId,
     pub name: Symbol,
     pub vis: Visibility<DefId>,
+    /// The `DefId` of the anonymous constant holding the default value of the field, if any.
+    pub value: Option<DefId>,
 }
 
 impl PartialEq for FieldDef {
@@ -1915,9 +1935,9 @@ fn eq(&self, other: &Self) -> bool {
         // of `FieldDef` changes, a compile-error will be produced, reminding
         // us to revisit this assumption.
 
-        let Self { did: lhs_did, name: _, vis: _ } = &self;
+        let Self { did: lhs_did, name: _, vis: _, value: _ } = &self;
 
-        let Self { did: rhs_did, name: _, vis: _ } = other;
+        let Self { did: rhs_did, name: _, vis: _, value: _ } = other;
 
         lhs_did == rhs_did
     }
@@ -1935,7 +1955,7 @@ fn hash<H: Hasher>(&self, s: &mut H) {
         // of `FieldDef` changes, a compile-error will be produced, reminding
         // us to revisit this assumption.
 
-        let Self { did, name: _, vis: _ } = &self;
+        let Self { did, name: _, vis: _, value: _ } = &self;
 
         did.hash(s)
     }
@@ -2294,6 +2314,24 @@ pub fn get_attrs(
         }
     }
 
+    /// Gets all attributes whose path consists of the given segments, e.g. tool attributes like
+    /// `#[diagnostic::on_unimplemented]`.
+    pub fn get_attrs_by_path<'attr>(
+        self,
+        did: DefId,
+        attr: &'attr [Symbol],
+    ) -> impl Iterator<Item = &'tcx ast::Attribute> + 'attr
+    where
+        'tcx: 'attr,
+    {
+        let filter_fn = move |a: &&ast::Attribute| a.path_matches(attr);
+        if let Some(did) = did.as_local() {
+            self.hir().attrs(self.hir().local_def_id_to_hir_id(did)).iter().filter(filter_fn)
+        } else {
+            self.item_attrs(did).iter().filter(filter_fn)
+        }
+    }
+
     pub fn get_attr(self, did: impl Into<DefId>, attr: Symbol) -> Option<&'tcx ast::Attribute> {
         if cfg!(debug_assertions) && !rustc_feature::is_valid_for_get_attr(attr) {
             let did: DefId = did.into();
@@ -2563,8 +2601,10 @@ pub fn uint_ty(uty: ast::UintTy) -> UintTy {
 
 pub fn float_ty(fty: ast::FloatTy) -> FloatTy {
     match fty {
+        ast::FloatTy::F16 => FloatTy::F16,
         ast::FloatTy::F32 => FloatTy::F32,
         ast::FloatTy::F64 => FloatTy::F64,
+        ast::FloatTy::F128 => FloatTy::F128,
     }
 }
 
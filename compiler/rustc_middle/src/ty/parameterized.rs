@@ -61,6 +61,7 @@ impl $crate::ty::ParameterizedOverTcx for $ty {
     crate::mir::ConstQualifs,
     ty::AssocItemContainer,
     ty::DeducedParamAttrs,
+    ty::FnContract,
     ty::Generics,
     ty::ImplPolarity,
     ty::ImplTraitInTraitData,
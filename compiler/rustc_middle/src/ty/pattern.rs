@@ -0,0 +1,73 @@
+use std::fmt;
+
+use crate::ty::{self, Ty, TyCtxt};
+use rustc_data_structures::intern::Interned;
+
+/// The pattern of a pattern type (`ty::Pat`), which restricts the values of its base type.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, HashStable)]
+#[rustc_pass_by_value]
+pub struct Pattern<'tcx>(pub Interned<'tcx, PatternKind<'tcx>>);
+
+impl<'tcx> std::ops::Deref for Pattern<'tcx> {
+    type Target = PatternKind<'tcx>;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl<'tcx> fmt::Debug for Pattern<'tcx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<'tcx> fmt::Debug for PatternKind<'tcx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PatternKind::Range { start, end, include_end } => {
+                if let Some(start) = start {
+                    write!(f, "{start}")?;
+                }
+                write!(f, "..")?;
+                if include_end {
+                    write!(f, "=")?;
+                }
+                if let Some(end) = end {
+                    write!(f, "{end}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, HashStable)]
+#[derive(TyEncodable, TyDecodable, TypeFoldable, TypeVisitable, Lift)]
+pub enum PatternKind<'tcx> {
+    /// The values in a range. A missing `start` or `end` means the range is unbounded on that
+    /// side, and is then always written with `include_end` set.
+    Range { start: Option<ty::Const<'tcx>>, end: Option<ty::Const<'tcx>>, include_end: bool },
+}
+
+impl<'tcx> PatternKind<'tcx> {
+    /// Returns whether the value with the raw bits `bits` of the integer type `base` matches
+    /// this pattern.
+    pub fn contains(&self, tcx: TyCtxt<'tcx>, base: Ty<'tcx>, bits: u128) -> bool {
+        match *self {
+            PatternKind::Range { start, end, include_end } => {
+                // Compare as unsigned numbers, flipping the sign bit of signed ones so that the
+                // order of the values is preserved.
+                let (size, signed) = base.int_size_and_signed(tcx);
+                let bias = if signed { 1u128 << (size.bits() - 1) } else { 0 };
+                let param_env = ty::ParamEnv::reveal_all();
+                let value = bits ^ bias;
+                let lo = start.map_or(0, |ct| ct.eval_bits(tcx, param_env, base) ^ bias);
+                let hi = end.map_or(size.unsigned_int_max(), |ct| {
+                    ct.eval_bits(tcx, param_env, base) ^ bias
+                });
+                lo <= value && (value < hi || (include_end && value == hi))
+            }
+        }
+    }
+}
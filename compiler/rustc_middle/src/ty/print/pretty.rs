@@ -5,7 +5,7 @@
     TypeSuperFoldable, TypeSuperVisitable, TypeVisitable, TypeVisitableExt,
 };
 use crate::ty::{GenericArg, GenericArgKind};
-use rustc_apfloat::ieee::{Double, Single};
+use rustc_apfloat::ieee::{Double, Half, Quad, Single};
 use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
 use rustc_data_structures::sso::SsoHashSet;
 use rustc_hir as hir;
@@ -899,6 +899,7 @@ fn pretty_print_type(mut self, ty: Ty<'tcx>) -> Result<Self::Type, Self::Error>
                 p!("]");
             }
             ty::Array(ty, sz) => p!("[", print(ty), "; ", print(sz), "]"),
+            ty::Pat(ty, pat) => p!(print(ty), write(" is {:?}", pat)),
             ty::Slice(ty) => p!("[", print(ty), "]"),
         }
 
@@ -1477,12 +1478,18 @@ fn pretty_print_const_scalar_int(
             ty::Bool if int == ScalarInt::FALSE => p!("false"),
             ty::Bool if int == ScalarInt::TRUE => p!("true"),
             // Float
+            ty::Float(ty::FloatTy::F16) => {
+                p!(write("{}f16", Half::try_from(int).unwrap()))
+            }
             ty::Float(ty::FloatTy::F32) => {
                 p!(write("{}f32", Single::try_from(int).unwrap()))
             }
             ty::Float(ty::FloatTy::F64) => {
                 p!(write("{}f64", Double::try_from(int).unwrap()))
             }
+            ty::Float(ty::FloatTy::F128) => {
+                p!(write("{}f128", Quad::try_from(int).unwrap()))
+            }
             // Int
             ty::Uint(_) | ty::Int(_) => {
                 let int =
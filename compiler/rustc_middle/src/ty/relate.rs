@@ -225,6 +225,41 @@ fn relate<R: TypeRelation<'tcx>>(
     }
 }
 
+impl<'tcx> Relate<'tcx> for ty::Pattern<'tcx> {
+    fn relate<R: TypeRelation<'tcx>>(
+        relation: &mut R,
+        a: ty::Pattern<'tcx>,
+        b: ty::Pattern<'tcx>,
+    ) -> RelateResult<'tcx, ty::Pattern<'tcx>> {
+        match (*a, *b) {
+            (
+                ty::PatternKind::Range { start: start_a, end: end_a, include_end: inc_a },
+                ty::PatternKind::Range { start: start_b, end: end_b, include_end: inc_b },
+            ) => {
+                if inc_a != inc_b {
+                    return Err(TypeError::Mismatch);
+                }
+                let start = relate_opt_consts(relation, start_a, start_b)?;
+                let end = relate_opt_consts(relation, end_a, end_b)?;
+                let kind = ty::PatternKind::Range { start, end, include_end: inc_a };
+                Ok(relation.tcx().mk_pat(kind))
+            }
+        }
+    }
+}
+
+fn relate_opt_consts<'tcx, R: TypeRelation<'tcx>>(
+    relation: &mut R,
+    a: Option<ty::Const<'tcx>>,
+    b: Option<ty::Const<'tcx>>,
+) -> RelateResult<'tcx, Option<ty::Const<'tcx>>> {
+    match (a, b) {
+        (None, None) => Ok(None),
+        (Some(a), Some(b)) => relation.relate(a, b).map(Some),
+        _ => Err(TypeError::Mismatch),
+    }
+}
+
 impl<'tcx> Relate<'tcx> for ty::BoundConstness {
     fn relate<R: TypeRelation<'tcx>>(
         relation: &mut R,
@@ -493,6 +528,12 @@ pub fn super_relate_tys<'tcx, R: TypeRelation<'tcx>>(
             Ok(tcx.mk_ref(r, mt))
         }
 
+        (&ty::Pat(a_ty, a_pat), &ty::Pat(b_ty, b_pat)) => {
+            let ty = relation.relate(a_ty, b_ty)?;
+            let pat = relation.relate(a_pat, b_pat)?;
+            Ok(tcx.mk_pat_ty(ty, pat))
+        }
+
         (&ty::Array(a_t, sz_a), &ty::Array(b_t, sz_b)) => {
             let t = relation.relate(a_t, b_t)?;
             match relation.relate(sz_a, sz_b) {
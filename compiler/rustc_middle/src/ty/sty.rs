@@ -2223,6 +2223,7 @@ pub fn discriminant_ty(self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
             | ty::Foreign(_)
             | ty::Str
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(..)
@@ -2269,6 +2270,7 @@ pub fn ptr_metadata_ty(
             | ty::GeneratorWitness(..)
             | ty::GeneratorWitnessMIR(..)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Closure(..)
             | ty::Never
             | ty::Error(_)
@@ -2356,6 +2358,7 @@ pub fn is_trivially_sized(self, tcx: TyCtxt<'tcx>) -> bool {
             | ty::GeneratorWitness(..)
             | ty::GeneratorWitnessMIR(..)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Closure(..)
             | ty::Never
             | ty::Error(_) => true,
@@ -2401,6 +2404,8 @@ pub fn is_trivially_pure_clone_copy(self) -> bool {
 
             ty::Array(element_ty, _len) => element_ty.is_trivially_pure_clone_copy(),
 
+            ty::Pat(ty, _) => ty.is_trivially_pure_clone_copy(),
+
             // A 100-tuple isn't "trivial", so doing this only for reasonable sizes.
             ty::Tuple(field_tys) => {
                 field_tys.len() <= 3 && field_tys.iter().all(Self::is_trivially_pure_clone_copy)
@@ -2439,8 +2444,10 @@ pub fn primitive_symbol(self) -> Option<Symbol> {
             ty::Bool => Some(sym::bool),
             ty::Char => Some(sym::char),
             ty::Float(f) => match f {
+                ty::FloatTy::F16 => Some(sym::f16),
                 ty::FloatTy::F32 => Some(sym::f32),
                 ty::FloatTy::F64 => Some(sym::f64),
+                ty::FloatTy::F128 => Some(sym::f128),
             },
             ty::Int(f) => match f {
                 ty::IntTy::Isize => Some(sym::isize),
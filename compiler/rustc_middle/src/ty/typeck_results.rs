@@ -87,6 +87,9 @@ pub struct TypeckResults<'tcx> {
     /// ```
     /// leads to a `vec![&&Option<i32>, &Option<i32>]`. Empty vectors are not stored.
     ///
+    /// With `deref_patterns`, this may also contain smart pointer types implementing `DerefPure`
+    /// that were implicitly dereferenced, e.g. `vec![Box<Option<i32>>]` for the same pattern.
+    ///
     /// See:
     /// <https://github.com/rust-lang/rfcs/blob/master/text/2005-match-ergonomics.md#definitions>
     pat_adjustments: ItemLocalMap<Vec<Ty<'tcx>>>,
@@ -467,6 +470,21 @@ pub fn extract_binding_mode(&self, s: &Session, id: HirId, sp: Span) -> Option<B
         })
     }
 
+    /// Whether `pat` binds anything by mutable reference. If so, the deref patterns it is wrapped
+    /// in need to go through `DerefMut`.
+    pub fn pat_has_ref_mut_binding(&self, pat: &hir::Pat<'_>) -> bool {
+        let mut has_ref_mut = false;
+        pat.walk_always(|pat| {
+            if let hir::PatKind::Binding(..) = pat.kind
+                && let Some(ty::BindByReference(hir::Mutability::Mut)) =
+                    self.pat_binding_modes().get(pat.hir_id)
+            {
+                has_ref_mut = true;
+            }
+        });
+        has_ref_mut
+    }
+
     pub fn pat_binding_modes(&self) -> LocalTableInContext<'_, BindingMode> {
         LocalTableInContext { hir_owner: self.hir_owner, data: &self.pat_binding_modes }
     }
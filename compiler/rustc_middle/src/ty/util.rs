@@ -798,6 +798,7 @@ pub fn def_kind_descr(self, def_kind: DefKind, def_id: DefId) -> &'static str {
             DefKind::AssocFn if self.associated_item(def_id).fn_has_self_parameter => "method",
             DefKind::Generator => match self.generator_kind(def_id).unwrap() {
                 rustc_hir::GeneratorKind::Async(..) => "async closure",
+                rustc_hir::GeneratorKind::Iter(..) => "gen closure",
                 rustc_hir::GeneratorKind::Gen => "generator",
             },
             _ => def_kind.descr(def_id),
@@ -815,6 +816,7 @@ pub fn def_kind_descr_article(self, def_kind: DefKind, def_id: DefId) -> &'stati
             DefKind::AssocFn if self.associated_item(def_id).fn_has_self_parameter => "a",
             DefKind::Generator => match self.generator_kind(def_id).unwrap() {
                 rustc_hir::GeneratorKind::Async(..) => "an",
+                rustc_hir::GeneratorKind::Iter(..) => "a",
                 rustc_hir::GeneratorKind::Gen => "a",
             },
             _ => def_kind.article(),
@@ -968,8 +970,10 @@ pub fn numeric_max_val(self, tcx: TyCtxt<'tcx>) -> Option<ty::Const<'tcx>> {
             }
             ty::Char => Some(std::char::MAX as u128),
             ty::Float(fty) => Some(match fty {
+                ty::FloatTy::F16 => rustc_apfloat::ieee::Half::INFINITY.to_bits(),
                 ty::FloatTy::F32 => rustc_apfloat::ieee::Single::INFINITY.to_bits(),
                 ty::FloatTy::F64 => rustc_apfloat::ieee::Double::INFINITY.to_bits(),
+                ty::FloatTy::F128 => rustc_apfloat::ieee::Quad::INFINITY.to_bits(),
             }),
             _ => None,
         };
@@ -988,8 +992,10 @@ pub fn numeric_min_val(self, tcx: TyCtxt<'tcx>) -> Option<ty::Const<'tcx>> {
             }
             ty::Char => Some(0),
             ty::Float(fty) => Some(match fty {
+                ty::FloatTy::F16 => (-::rustc_apfloat::ieee::Half::INFINITY).to_bits(),
                 ty::FloatTy::F32 => (-::rustc_apfloat::ieee::Single::INFINITY).to_bits(),
                 ty::FloatTy::F64 => (-::rustc_apfloat::ieee::Double::INFINITY).to_bits(),
+                ty::FloatTy::F128 => (-::rustc_apfloat::ieee::Quad::INFINITY).to_bits(),
             }),
             _ => None,
         };
@@ -1049,6 +1055,7 @@ fn is_trivially_freeze(self) -> bool {
             | ty::FnPtr(_) => true,
             ty::Tuple(fields) => fields.iter().all(Self::is_trivially_freeze),
             ty::Slice(elem_ty) | ty::Array(elem_ty, _) => elem_ty.is_trivially_freeze(),
+            ty::Pat(ty, _) => ty.is_trivially_freeze(),
             ty::Adt(..)
             | ty::Bound(..)
             | ty::Closure(..)
@@ -1089,6 +1096,7 @@ fn is_trivially_unpin(self) -> bool {
             | ty::FnPtr(_) => true,
             ty::Tuple(fields) => fields.iter().all(Self::is_trivially_unpin),
             ty::Slice(elem_ty) | ty::Array(elem_ty, _) => elem_ty.is_trivially_unpin(),
+            ty::Pat(ty, _) => ty.is_trivially_unpin(),
             ty::Adt(..)
             | ty::Bound(..)
             | ty::Closure(..)
@@ -1227,6 +1235,7 @@ pub fn is_structural_eq_shallow(self, tcx: TyCtxt<'tcx>) -> bool {
             ty::Foreign(_)
             | ty::GeneratorWitness(..)
             | ty::GeneratorWitnessMIR(..)
+            | ty::Pat(..)
             | ty::Error(_) => false,
         }
     }
@@ -1318,6 +1327,7 @@ pub fn needs_drop_components<'tcx>(
         | ty::Int(_)
         | ty::Uint(_)
         | ty::Float(_)
+        | ty::Pat(..)
         | ty::Never
         | ty::FnDef(..)
         | ty::FnPtr(_)
@@ -1382,6 +1392,7 @@ pub fn is_trivially_const_drop(ty: Ty<'_>) -> bool {
         | ty::FnDef(..)
         | ty::FnPtr(_)
         | ty::Never
+        | ty::Pat(..)
         | ty::Foreign(_) => true,
 
         ty::Alias(..)
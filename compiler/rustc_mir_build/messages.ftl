@@ -333,6 +333,10 @@ mir_build_non_exhaustive_omitted_pattern = some variants are not matched explici
     .help = ensure that all variants are matched explicitly by adding the suggested match arms
     .note = the matched value is of type `{$scrut_ty}` and the `non_exhaustive_omitted_patterns` attribute was found
 
+mir_build_mixed_deref_pattern_constructors = mix of deref patterns and normal constructors
+    .deref_pattern_label = matches on the result of dereferencing `{$smart_pointer_ty}`
+    .normal_constructor_label = matches directly on `{$smart_pointer_ty}`
+
 mir_build_uncovered = {$count ->
         [1] pattern `{$witness_1}`
         [2] patterns `{$witness_1}` and `{$witness_2}`
@@ -372,3 +376,21 @@ mir_build_rustc_box_attribute_error = `#[rustc_box]` attribute used incorrectly
     .attributes = no other attributes may be applied
     .not_box = `#[rustc_box]` may only be applied to a `Box::new()` call
     .missing_box = `#[rustc_box]` requires the `owned_box` lang item
+
+mir_build_tail_call_not_a_call = `become` requires a function call
+    .label = not a function call
+
+mir_build_tail_call_unsupported = tail calls are not supported {$kind ->
+        [closure] in closures
+        [generator] in async functions and generators
+        [track_caller] for functions marked `#[track_caller]`
+        [variadic] for C-variadic functions
+        *[rust_call] for functions with the "rust-call" ABI
+    }
+
+mir_build_tail_call_abi_mismatch = mismatched function ABIs
+    .note = `become` requires caller and callee to have the same ABI, but the caller uses "{$caller_abi}" and the callee uses "{$callee_abi}"
+
+mir_build_tail_call_signature_mismatch = mismatched signatures
+    .label = this function has signature `{$callee_sig}`
+    .note = `become` requires caller and callee to have matching signatures, but the caller has signature `{$caller_sig}`
@@ -345,26 +345,57 @@ pub(crate) fn expr_into_dest(
                     })
                     .collect();
 
-                let field_names = adt_def.variant(variant_index).fields.indices();
-
-                let fields = if let Some(FruInfo { base, field_types }) = base {
-                    let place_builder =
-                        unpack!(block = this.as_place_builder(block, &this.thir[*base]));
-
-                    // MIR does not natively support FRU, so for each
-                    // base-supplied field, generate an operand that
-                    // reads it from the base.
-                    iter::zip(field_names, &**field_types)
-                        .map(|(n, ty)| match fields_map.get(&n) {
-                            Some(v) => v.clone(),
-                            None => {
-                                let place = place_builder.clone_project(PlaceElem::Field(n, *ty));
-                                this.consume_by_copy_or_move(place.to_place(this))
-                            }
-                        })
-                        .collect()
-                } else {
-                    field_names.filter_map(|n| fields_map.get(&n).cloned()).collect()
+                let variant = adt_def.variant(variant_index);
+                let field_names = variant.fields.indices();
+
+                let fields = match base {
+                    AdtExprBase::Base(FruInfo { base, field_types }) => {
+                        let place_builder =
+                            unpack!(block = this.as_place_builder(block, &this.thir[*base]));
+
+                        // MIR does not natively support FRU, so for each
+                        // base-supplied field, generate an operand that
+                        // reads it from the base.
+                        iter::zip(field_names, &**field_types)
+                            .map(|(n, ty)| match fields_map.get(&n) {
+                                Some(v) => v.clone(),
+                                None => {
+                                    let place =
+                                        place_builder.clone_project(PlaceElem::Field(n, *ty));
+                                    this.consume_by_copy_or_move(place.to_place(this))
+                                }
+                            })
+                            .collect()
+                    }
+                    AdtExprBase::DefaultFields(field_types) => {
+                        // Each field that was not provided is populated from the
+                        // anonymous constant holding its default value.
+                        iter::zip(field_names, &**field_types)
+                            .map(|(n, ty)| match fields_map.get(&n) {
+                                Some(v) => v.clone(),
+                                None => match variant.fields[n].value {
+                                    Some(value) => {
+                                        let uneval = UnevaluatedConst::new(value, substs);
+                                        Operand::Constant(Box::new(Constant {
+                                            span: source_info.span,
+                                            user_ty: None,
+                                            literal: ConstantKind::Unevaluated(uneval, *ty),
+                                        }))
+                                    }
+                                    None => {
+                                        let name = variant.fields[n].name;
+                                        span_bug!(
+                                            source_info.span,
+                                            "missing mandatory field `{name}` of type `{ty}`",
+                                        );
+                                    }
+                                },
+                            })
+                            .collect()
+                    }
+                    AdtExprBase::None => {
+                        field_names.filter_map(|n| fields_map.get(&n).cloned()).collect()
+                    }
                 };
 
                 let inferred_ty = expr.ty;
@@ -489,7 +520,10 @@ pub(crate) fn expr_into_dest(
                 block.unit()
             }
 
-            ExprKind::Continue { .. } | ExprKind::Break { .. } | ExprKind::Return { .. } => {
+            ExprKind::Continue { .. }
+            | ExprKind::Break { .. }
+            | ExprKind::Return { .. }
+            | ExprKind::Become { .. } => {
                 unpack!(block = this.stmt_expr(block, expr, None));
                 // No assign, as these have type `!`.
                 block.unit()
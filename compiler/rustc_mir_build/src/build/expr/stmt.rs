@@ -99,6 +99,9 @@ pub(crate) fn stmt_expr(
                 BreakableTarget::Return,
                 source_info,
             ),
+            ExprKind::Become { value } => {
+                this.break_for_tail_call(block, &this.thir[value], source_info)
+            }
             _ => {
                 assert!(
                     statement_scope.is_some(),
@@ -774,6 +774,11 @@ pub(super) fn visit_primary_bindings(
                 self.visit_primary_bindings(subpattern, pattern_user_ty.deref(), f);
             }
 
+            PatKind::DerefPattern { ref subpattern, .. } => {
+                // User type annotations can't be projected through an overloaded deref.
+                self.visit_primary_bindings(subpattern, UserTypeProjections::none(), f);
+            }
+
             PatKind::AscribeUserType {
                 ref subpattern,
                 ascription: thir::Ascription { ref annotation, variance: _ },
@@ -977,6 +982,10 @@ enum TestKind<'tcx> {
 
     /// Test that the length of the slice is equal to `len`.
     Len { len: u64, op: BinOp },
+
+    /// Call `Deref::deref` or `DerefMut::deref_mut` on the value and store the result in `temp`.
+    /// This is an infallible "test": it has a single target.
+    Deref { temp: Place<'tcx>, mutability: Mutability },
 }
 
 /// A test to perform to determine which [`Candidate`] matches a value.
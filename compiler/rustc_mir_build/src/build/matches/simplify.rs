@@ -304,7 +304,7 @@ fn simplify_match_pair<'pat>(
                 Ok(())
             }
 
-            PatKind::Or { .. } => Err(match_pair),
+            PatKind::DerefPattern { .. } | PatKind::Or { .. } => Err(match_pair),
         }
     }
 }
@@ -70,6 +70,18 @@ pub(super) fn test<'pat>(&mut self, match_pair: &MatchPair<'pat, 'tcx>) -> Test<
                 Test { span: match_pair.pattern.span, kind: TestKind::Len { len: len as u64, op } }
             }
 
+            PatKind::DerefPattern { ref subpattern, mutability } => {
+                // The result of the deref call is stored in a temporary that the subpattern is
+                // then matched against.
+                let tcx = self.tcx;
+                let ref_ty = tcx.mk_ref(
+                    tcx.lifetimes.re_erased,
+                    ty::TypeAndMut { ty: subpattern.ty, mutbl: mutability },
+                );
+                let temp = self.temp(ref_ty, match_pair.pattern.span);
+                Test { span: match_pair.pattern.span, kind: TestKind::Deref { temp, mutability } }
+            }
+
             PatKind::Or { .. } => bug!("or-patterns should have already been handled"),
 
             PatKind::AscribeUserType { .. }
@@ -113,7 +125,8 @@ pub(super) fn add_cases_to_switch<'pat>(
             | PatKind::Binding { .. }
             | PatKind::AscribeUserType { .. }
             | PatKind::Leaf { .. }
-            | PatKind::Deref { .. } => {
+            | PatKind::Deref { .. }
+            | PatKind::DerefPattern { .. } => {
                 // don't know how to add these patterns to a switch
                 false
             }
@@ -243,30 +256,17 @@ pub(super) fn perform_test(
                     if !tcx.features().string_deref_patterns {
                         bug!("matching on `String` went through without enabling string_deref_patterns");
                     }
-                    let re_erased = tcx.lifetimes.re_erased;
-                    let ref_string = self.temp(tcx.mk_imm_ref(re_erased, ty), test.span);
-                    let ref_str_ty = tcx.mk_imm_ref(re_erased, tcx.types.str_);
+                    let ref_str_ty = tcx.mk_imm_ref(tcx.lifetimes.re_erased, tcx.types.str_);
                     let ref_str = self.temp(ref_str_ty, test.span);
-                    let deref = tcx.require_lang_item(LangItem::Deref, None);
-                    let method = trait_method(tcx, deref, sym::deref, [ty]);
                     let eq_block = self.cfg.start_new_block();
-                    self.cfg.push_assign(block, source_info, ref_string, Rvalue::Ref(re_erased, BorrowKind::Shared, place));
-                    self.cfg.terminate(
+                    self.call_deref(
                         block,
-                        source_info,
-                        TerminatorKind::Call {
-                            func: Operand::Constant(Box::new(Constant {
-                                span: test.span,
-                                user_ty: None,
-                                literal: method,
-                            })),
-                            args: vec![Operand::Move(ref_string)],
-                            destination: ref_str,
-                            target: Some(eq_block),
-                            unwind: UnwindAction::Continue,
-                            from_hir_call: false,
-                            fn_span: source_info.span
-                        }
+                        eq_block,
+                        place,
+                        Mutability::Not,
+                        ty,
+                        ref_str,
+                        test.span,
                     );
                     self.non_scalar_compare(eq_block, make_target_blocks, source_info, value, ref_str, ref_str_ty);
                     return;
@@ -320,6 +320,13 @@ pub(super) fn perform_test(
                 self.compare(lower_bound_success, success, fail, source_info, op, val, hi);
             }
 
+            TestKind::Deref { temp, mutability } => {
+                let [target] = *make_target_blocks(self) else {
+                    bug!("`TestKind::Deref` should have one target block");
+                };
+                self.call_deref(block, target, place, mutability, place_ty.ty, temp, test.span);
+            }
+
             TestKind::Len { len, op } => {
                 let target_blocks = make_target_blocks(self);
 
@@ -380,6 +387,58 @@ fn compare(
         );
     }
 
+    /// Emit `temp = <ty as Deref>::deref(&place)` (or `DerefMut::deref_mut(&mut place)` if
+    /// `mutability` is `Mut`) at the end of `block`, continuing to `target_block`.
+    fn call_deref(
+        &mut self,
+        block: BasicBlock,
+        target_block: BasicBlock,
+        place: Place<'tcx>,
+        mutability: Mutability,
+        ty: Ty<'tcx>,
+        temp: Place<'tcx>,
+        span: Span,
+    ) {
+        let tcx = self.tcx;
+        let source_info = self.source_info(span);
+        let re_erased = tcx.lifetimes.re_erased;
+        let (trait_item, method, borrow_kind) = match mutability {
+            Mutability::Not => (LangItem::Deref, sym::deref, BorrowKind::Shared),
+            Mutability::Mut => (
+                LangItem::DerefMut,
+                sym::deref_mut,
+                BorrowKind::Mut { allow_two_phase_borrow: false },
+            ),
+        };
+        let ref_ty = tcx.mk_ref(re_erased, ty::TypeAndMut { ty, mutbl: mutability });
+        let ref_place = self.temp(ref_ty, span);
+        let trait_def_id = tcx.require_lang_item(trait_item, Some(span));
+        let method = trait_method(tcx, trait_def_id, method, [ty]);
+        self.cfg.push_assign(
+            block,
+            source_info,
+            ref_place,
+            Rvalue::Ref(re_erased, borrow_kind, place),
+        );
+        self.cfg.terminate(
+            block,
+            source_info,
+            TerminatorKind::Call {
+                func: Operand::Constant(Box::new(Constant {
+                    span,
+                    user_ty: None,
+                    literal: method,
+                })),
+                args: vec![Operand::Move(ref_place)],
+                destination: temp,
+                target: Some(target_block),
+                unwind: UnwindAction::Continue,
+                from_hir_call: false,
+                fn_span: span,
+            },
+        );
+    }
+
     /// Compare two `&T` values using `<T as std::compare::PartialEq>::eq`
     fn non_scalar_compare(
         &mut self,
@@ -527,6 +586,26 @@ pub(super) fn sort_candidate<'pat>(
             candidate.match_pairs.iter().enumerate().find(|&(_, mp)| mp.place == *test_place)?;
 
         match (&test.kind, &match_pair.pattern.kind) {
+            // A deref test informs deref patterns, which are then matched against the result of
+            // the deref call, but nothing else. Deref patterns needing the other kind of borrow
+            // get a deref test of their own, so that `ref mut` bindings are only ever made through
+            // `DerefMut::deref_mut`, and the patterns that don't need it keep borrowing the
+            // scrutinee immutably.
+            (
+                &TestKind::Deref { temp, mutability },
+                &PatKind::DerefPattern { ref subpattern, mutability: pat_mutability },
+            ) => {
+                if mutability != pat_mutability {
+                    return None;
+                }
+                candidate.match_pairs.remove(match_pair_index);
+                let place = PlaceBuilder::from(temp).deref();
+                candidate.match_pairs.push(MatchPair::new(place, subpattern, self));
+                Some(0)
+            }
+
+            (&TestKind::Deref { .. }, _) | (_, PatKind::DerefPattern { .. }) => None,
+
             // If we are performing a variant switch, then this
             // informs variant patterns, but nothing else.
             (
@@ -803,6 +882,7 @@ fn values_not_contained_in_range(
 impl Test<'_> {
     pub(super) fn targets(&self) -> usize {
         match self.kind {
+            TestKind::Deref { .. } => 1,
             TestKind::Eq { .. } | TestKind::Range(_) | TestKind::Len { .. } => 2,
             TestKind::Switch { adt_def, .. } => {
                 // While the switch that we generate doesn't test for all
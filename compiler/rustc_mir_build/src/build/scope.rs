@@ -89,7 +89,7 @@
 use rustc_index::{IndexSlice, IndexVec};
 use rustc_middle::middle::region;
 use rustc_middle::mir::*;
-use rustc_middle::thir::{Expr, LintLevel};
+use rustc_middle::thir::{Expr, ExprKind, LintLevel};
 
 use rustc_span::{DesugaringKind, Span, DUMMY_SP};
 
@@ -717,6 +717,86 @@ pub(crate) fn break_for_else(
         self.cfg.terminate(block, source_info, TerminatorKind::Resume);
     }
 
+    /// Builds `become value`, where `value` is a call, possibly wrapped in scopes.
+    ///
+    /// The frame of the function is gone once the callee starts executing, so the storage of the
+    /// locals of every enclosing scope is killed right before the tail call. Drops are never
+    /// scheduled here: drop elaboration rejects tail calls at which a local still needs one.
+    pub(crate) fn break_for_tail_call(
+        &mut self,
+        block: BasicBlock,
+        value: &Expr<'tcx>,
+        source_info: SourceInfo,
+    ) -> BlockAnd<()> {
+        let enclosing_scopes = self.scopes.scopes.len();
+        self.build_tail_call(block, value, enclosing_scopes, source_info)
+    }
+
+    fn build_tail_call(
+        &mut self,
+        mut block: BasicBlock,
+        expr: &Expr<'tcx>,
+        enclosing_scopes: usize,
+        source_info: SourceInfo,
+    ) -> BlockAnd<()> {
+        match expr.kind {
+            ExprKind::Scope { region_scope, lint_level, value } => {
+                self.in_scope((region_scope, source_info), lint_level, |this| {
+                    this.build_tail_call(block, &this.thir[value], enclosing_scopes, source_info)
+                })
+            }
+            ExprKind::Call { fun, ref args, fn_span, .. } => {
+                let func = unpack!(block = self.as_tail_call_operand(block, &self.thir[fun]));
+                let args: Vec<_> = args
+                    .iter()
+                    .map(|&arg| unpack!(block = self.as_tail_call_operand(block, &self.thir[arg])))
+                    .collect();
+                self.record_operands_moved(&args);
+
+                for scope in self.scopes.scopes[..enclosing_scopes].iter().rev() {
+                    for drop in scope.drops.iter().rev() {
+                        if drop.kind == DropKind::Storage {
+                            self.cfg.push(
+                                block,
+                                Statement {
+                                    source_info,
+                                    kind: StatementKind::StorageDead(drop.local),
+                                },
+                            );
+                        }
+                    }
+                }
+                self.cfg.terminate(
+                    block,
+                    source_info,
+                    TerminatorKind::TailCall { func, args, fn_span },
+                );
+                self.cfg.start_new_block().unit()
+            }
+            _ => span_bug!(expr.span, "`check_tail_calls` should have rejected this `become`"),
+        }
+    }
+
+    /// Evaluates an operand of a tail call into a temporary of the innermost scope, since the
+    /// locals of the enclosing scopes are dead by the time the callee starts executing.
+    fn as_tail_call_operand(
+        &mut self,
+        mut block: BasicBlock,
+        expr: &Expr<'tcx>,
+    ) -> BlockAnd<Operand<'tcx>> {
+        let operand = unpack!(block = self.as_local_call_operand(block, expr));
+        if let Operand::Constant(_) = operand {
+            return block.and(operand);
+        }
+        let source_info = self.source_info(expr.span);
+        let temp = self.temp(operand.ty(&self.local_decls, self.tcx), expr.span);
+        let local = temp.as_local().unwrap();
+        self.cfg.push(block, Statement { source_info, kind: StatementKind::StorageLive(local) });
+        self.cfg.push_assign(block, source_info, temp, Rvalue::Use(operand));
+        self.schedule_drop_storage_and_value(expr.span, self.local_scope(), local);
+        block.and(Operand::Move(temp))
+    }
+
     // Add a dummy `Assign` statement to the CFG, with the span for the source code's `continue`
     // statement.
     fn add_dummy_assignment(&mut self, span: Span, block: BasicBlock, source_info: SourceInfo) {
@@ -0,0 +1,116 @@
+//! Checks the operands of `become` expressions.
+//!
+//! A tail call replaces the frame of the caller with the frame of the callee, so the callee must
+//! take and return exactly what the caller does, with the same ABI. Locals with destructors which
+//! are still live at the tail call are rejected later, during drop elaboration.
+
+use crate::errors::*;
+
+use rustc_errors::ErrorGuaranteed;
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::thir::visit::{self, Visitor};
+use rustc_middle::thir::*;
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_span::def_id::{DefId, LocalDefId};
+use rustc_target::spec::abi::Abi;
+
+pub(crate) fn check_tail_calls(tcx: TyCtxt<'_>, def: LocalDefId) -> Result<(), ErrorGuaranteed> {
+    let (thir, expr) = tcx.thir_body(def)?;
+    let thir = &thir.borrow();
+    // If `thir` is empty, a type error occurred, skip this body.
+    if thir.exprs.is_empty() {
+        return Ok(());
+    }
+
+    let mut visitor = TailCallCkVisitor { tcx, thir, def, error: Ok(()) };
+    visitor.visit_expr(&thir[expr]);
+    visitor.error
+}
+
+struct TailCallCkVisitor<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    thir: &'a Thir<'tcx>,
+    /// The body containing the `become` expressions, that is the caller.
+    def: LocalDefId,
+    error: Result<(), ErrorGuaranteed>,
+}
+
+impl<'tcx> TailCallCkVisitor<'_, 'tcx> {
+    fn check_tail_call(&mut self, call: &Expr<'tcx>, expr: &Expr<'tcx>) {
+        let tcx = self.tcx;
+        let ExprKind::Call { ty, from_hir_call: true, fn_span, .. } = call.kind else {
+            self.error = Err(tcx.sess.emit_err(TailCallNotACall { span: call.span }));
+            return;
+        };
+
+        let unsupported = if tcx.is_closure(self.def.to_def_id()) {
+            Some("closure")
+        } else if tcx.generator_kind(self.def).is_some() {
+            Some("generator")
+        } else if self.is_track_caller(self.def.to_def_id())
+            || matches!(ty.kind(), ty::FnDef(did, _) if self.is_track_caller(*did))
+        {
+            Some("track_caller")
+        } else {
+            None
+        };
+        if let Some(kind) = unsupported {
+            self.error = Err(tcx.sess.emit_err(TailCallUnsupported { span: expr.span, kind }));
+            return;
+        }
+
+        let BodyTy::Fn(caller_sig) = self.thir.body_type else {
+            // `become` outside of a function body was already reported by typeck.
+            self.error = Err(tcx.sess.delay_span_bug(expr.span, "`become` outside of a fn body"));
+            return;
+        };
+        let param_env = tcx.param_env(self.def);
+        let caller_sig = tcx.normalize_erasing_regions(param_env, caller_sig);
+        let callee_sig = tcx.normalize_erasing_late_bound_regions(param_env, ty.fn_sig(tcx));
+
+        let unsupported = if caller_sig.c_variadic || callee_sig.c_variadic {
+            Some("variadic")
+        } else if caller_sig.abi == Abi::RustCall || callee_sig.abi == Abi::RustCall {
+            Some("rust_call")
+        } else {
+            None
+        };
+        if let Some(kind) = unsupported {
+            self.error = Err(tcx.sess.emit_err(TailCallUnsupported { span: expr.span, kind }));
+        } else if caller_sig.abi != callee_sig.abi {
+            self.error = Err(tcx.sess.emit_err(TailCallAbiMismatch {
+                span: expr.span,
+                caller_abi: caller_sig.abi.name(),
+                callee_abi: callee_sig.abi.name(),
+            }));
+        } else if caller_sig.inputs_and_output != callee_sig.inputs_and_output {
+            self.error = Err(tcx.sess.emit_err(TailCallSignatureMismatch {
+                span: expr.span,
+                fn_span,
+                caller_sig: caller_sig.to_string(),
+                callee_sig: callee_sig.to_string(),
+            }));
+        }
+    }
+
+    fn is_track_caller(&self, def_id: DefId) -> bool {
+        self.tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::TRACK_CALLER)
+    }
+}
+
+impl<'a, 'tcx> Visitor<'a, 'tcx> for TailCallCkVisitor<'a, 'tcx> {
+    fn thir(&self) -> &'a Thir<'tcx> {
+        self.thir
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'tcx>) {
+        if let ExprKind::Become { value } = expr.kind {
+            let mut call = &self.thir[value];
+            while let ExprKind::Scope { value, .. } = call.kind {
+                call = &self.thir[value];
+            }
+            self.check_tail_call(call, expr);
+        }
+        visit::walk_expr(self, expr);
+    }
+}
@@ -736,6 +736,18 @@ pub(crate) struct NonExhaustiveOmittedPattern<'tcx> {
     pub uncovered: Uncovered<'tcx>,
 }
 
+#[derive(Diagnostic)]
+#[diag(mir_build_mixed_deref_pattern_constructors)]
+pub(crate) struct MixedDerefPatternConstructors<'tcx> {
+    #[primary_span]
+    pub spans: Vec<Span>,
+    pub smart_pointer_ty: Ty<'tcx>,
+    #[label(mir_build_deref_pattern_label)]
+    pub deref_pattern_label: Span,
+    #[label(mir_build_normal_constructor_label)]
+    pub normal_constructor_label: Span,
+}
+
 #[derive(Subdiagnostic)]
 #[label(mir_build_uncovered)]
 pub(crate) struct Uncovered<'tcx> {
@@ -887,3 +899,41 @@ pub enum RustcBoxAttrReason {
     #[note(mir_build_missing_box)]
     MissingBox,
 }
+
+#[derive(Diagnostic)]
+#[diag(mir_build_tail_call_not_a_call)]
+pub struct TailCallNotACall {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_build_tail_call_unsupported)]
+pub struct TailCallUnsupported {
+    #[primary_span]
+    pub span: Span,
+    pub kind: &'static str,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_build_tail_call_abi_mismatch)]
+#[note]
+pub struct TailCallAbiMismatch {
+    #[primary_span]
+    pub span: Span,
+    pub caller_abi: &'static str,
+    pub callee_abi: &'static str,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_build_tail_call_signature_mismatch)]
+#[note]
+pub struct TailCallSignatureMismatch {
+    #[primary_span]
+    pub span: Span,
+    #[label]
+    pub fn_span: Span,
+    pub caller_sig: String,
+    pub callee_sig: String,
+}
@@ -17,6 +17,7 @@
 extern crate rustc_middle;
 
 mod build;
+mod check_tail_calls;
 mod check_unsafety;
 mod errors;
 mod lints;
@@ -31,6 +32,7 @@
 
 pub fn provide(providers: &mut Providers) {
     providers.check_match = thir::pattern::check_match;
+    providers.check_tail_calls = check_tail_calls::check_tail_calls;
     providers.lit_to_const = thir::constant::lit_to_const;
     providers.lit_to_mir_constant = build::lit_to_mir_constant;
     providers.mir_built = build::mir_built;
@@ -26,6 +26,8 @@
 use rustc_span::hygiene::DesugaringKind;
 use rustc_span::Span;
 
+use std::cell::Cell;
+
 pub(crate) fn check_match(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Result<(), ErrorGuaranteed> {
     let (thir, expr) = tcx.thir_body(def_id)?;
     let thir = thir.borrow();
@@ -210,6 +212,7 @@ fn new_cx(&self, hir_id: HirId, refutable: bool) -> MatchCheckCtxt<'p, 'tcx> {
             module: self.tcx.parent_module(hir_id).to_def_id(),
             pattern_arena: &self.pattern_arena,
             refutable,
+            mixed_deref_patterns: Cell::new(None),
         }
     }
 
@@ -255,6 +258,10 @@ fn check_match(
         let scrut = &self.thir[scrut];
         let scrut_ty = scrut.ty;
         let report = compute_match_usefulness(&cx, &tarms, self.lint_level, scrut_ty);
+        if let Some(guar) = cx.mixed_deref_patterns.get() {
+            self.error = Err(guar);
+            return;
+        }
 
         match source {
             // Don't report arm reachability of desugared `match $iter.into_iter() { iter => .. }`
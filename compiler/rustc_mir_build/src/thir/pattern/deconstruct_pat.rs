@@ -635,6 +635,11 @@ pub(super) enum Constructor<'tcx> {
     Str(mir::ConstantKind<'tcx>),
     /// Array and slice patterns.
     Slice(Slice),
+    /// Deref patterns (enabled by the `deref_patterns` feature) on a smart pointer. The carried
+    /// type is the type of the dereferenced value; the constructor has a single field of that type.
+    /// Deref patterns match any value, but they are not treated as wildcards so that they can't be
+    /// mixed with normal constructors of the smart pointer type.
+    DerefPattern(Ty<'tcx>),
     /// Constants that must not be matched structurally. They are treated as black
     /// boxes for the purposes of exhaustiveness: we must not inspect them, and they
     /// don't count towards making a match exhaustive.
@@ -732,6 +737,7 @@ pub(super) fn arity(&self, pcx: &PatCtxt<'_, '_, 'tcx>) -> usize {
                 _ => bug!("Unexpected type for `Single` constructor: {:?}", pcx.ty),
             },
             Slice(slice) => slice.arity(),
+            DerefPattern(_) => 1,
             Str(..)
             | FloatRange(..)
             | IntRange(..)
@@ -828,11 +834,14 @@ pub(super) fn is_covered_by<'p>(&self, pcx: &PatCtxt<'_, 'p, 'tcx>, other: &Self
                 self_val == other_val
             }
             (Slice(self_slice), Slice(other_slice)) => self_slice.is_covered_by(*other_slice),
+            (DerefPattern(_), DerefPattern(_)) => true,
 
             // We are trying to inspect an opaque constant. Thus we skip the row.
             (Opaque, _) | (_, Opaque) => false,
             // Only a wildcard pattern can match the special extra constructor.
             (NonExhaustive, _) => false,
+            // Deref patterns and normal constructors of the smart pointer don't cover each other.
+            (DerefPattern(_), _) | (_, DerefPattern(_)) => false,
 
             _ => span_bug!(
                 pcx.span,
@@ -868,6 +877,7 @@ fn is_covered_by_any<'p>(
                 .iter()
                 .filter_map(|c| c.as_slice())
                 .any(|other| slice.is_covered_by(other)),
+            DerefPattern(_) => used_ctors.iter().any(|c| matches!(c, DerefPattern(_))),
             // This constructor is never covered by anything else
             NonExhaustive => false,
             Str(..) | FloatRange(..) | Opaque | Missing { .. } | Wildcard | Or => {
@@ -1034,9 +1044,16 @@ pub(super) fn split<'a>(
     ) where
         'tcx: 'a,
     {
-        // Since `all_ctors` never contains wildcards, this won't recurse further.
-        self.all_ctors =
-            self.all_ctors.iter().flat_map(|ctor| ctor.split(pcx, ctors.clone())).collect();
+        if let Some(deref_ctor) = ctors.clone().find(|c| matches!(c, DerefPattern(_))) {
+            // A deref pattern matches every value of the smart pointer type, so it is the only
+            // constructor we need to consider. Mixing deref patterns with normal constructors is
+            // reported as an error in `is_useful`.
+            self.all_ctors = smallvec![deref_ctor.clone()];
+        } else {
+            // Since `all_ctors` never contains wildcards, this won't recurse further.
+            self.all_ctors =
+                self.all_ctors.iter().flat_map(|ctor| ctor.split(pcx, ctors.clone())).collect();
+        }
         self.matrix_ctors = ctors.filter(|c| !c.is_wildcard()).cloned().collect();
     }
 
@@ -1215,6 +1232,7 @@ pub(super) fn wildcards(pcx: &PatCtxt<'_, 'p, 'tcx>, constructor: &Constructor<'
                 }
                 _ => bug!("bad slice pattern {:?} {:?}", constructor, pcx),
             },
+            DerefPattern(inner_ty) => Fields::wildcards_from_tys(pcx.cx, once(*inner_ty), pcx.span),
             Str(..)
             | FloatRange(..)
             | IntRange(..)
@@ -1294,6 +1312,10 @@ pub(crate) fn from_pat(cx: &MatchCheckCtxt<'p, 'tcx>, pat: &Pat<'tcx>) -> Self {
                 ctor = Single;
                 fields = Fields::singleton(cx, mkpat(subpattern));
             }
+            PatKind::DerefPattern { subpattern, .. } => {
+                ctor = DerefPattern(subpattern.ty);
+                fields = Fields::singleton(cx, mkpat(subpattern));
+            }
             PatKind::Leaf { subpatterns } | PatKind::Variant { subpatterns, .. } => {
                 match pat.ty.kind() {
                     ty::Tuple(fs) => {
@@ -1503,6 +1525,10 @@ pub(crate) fn to_pat(&self, cx: &MatchCheckCtxt<'p, 'tcx>) -> Pat<'tcx> {
                     }
                 }
             }
+            DerefPattern(_) => PatKind::DerefPattern {
+                subpattern: subpatterns.next().unwrap(),
+                mutability: mir::Mutability::Not,
+            },
             &Str(value) => PatKind::Constant { value },
             &FloatRange(lo, hi, end) => PatKind::Range(Box::new(PatRange { lo, hi, end })),
             IntRange(range) => return range.to_pat(cx.tcx, self.ty),
@@ -1699,6 +1725,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 }
                 Ok(())
             }
+            DerefPattern(_) => write!(f, "{:?}", self.iter_fields().next().unwrap()),
             Str(value) => write!(f, "{}", value),
             Opaque => write!(f, "<constant pattern>"),
         }
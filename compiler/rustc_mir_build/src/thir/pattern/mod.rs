@@ -70,16 +70,28 @@ fn lower_pattern(&mut self, pat: &'tcx hir::Pat<'tcx>) -> Box<Pat<'tcx>> {
         // we wrap the unadjusted pattern in `PatKind::Deref` repeatedly, consuming the
         // adjustments in *reverse order* (last-in-first-out, so that the last `Deref` inserted
         // gets the least-dereferenced type).
+        //
+        // With `deref_patterns`, the adjustments may also contain smart pointer types such as
+        // `Rc<Option<i32>>`. Those are dereferenced through `Deref::deref` (or
+        // `DerefMut::deref_mut` if the pattern binds anything by mutable reference) using
+        // `PatKind::DerefPattern`. Boxes are dereferenced like references.
         let unadjusted_pat = self.lower_pattern_unadjusted(pat);
         self.typeck_results.pat_adjustments().get(pat.hir_id).unwrap_or(&vec![]).iter().rev().fold(
             unadjusted_pat,
-            |pat: Box<_>, ref_ty| {
-                debug!("{:?}: wrapping pattern with type {:?}", pat, ref_ty);
-                Box::new(Pat {
-                    span: pat.span,
-                    ty: *ref_ty,
-                    kind: PatKind::Deref { subpattern: pat },
-                })
+            |subpattern: Box<_>, ref_ty| {
+                debug!("{:?}: wrapping pattern with type {:?}", subpattern, ref_ty);
+                let kind = match ref_ty.kind() {
+                    ty::Adt(adt_def, _) if !adt_def.is_box() => {
+                        let mutability = if self.typeck_results.pat_has_ref_mut_binding(pat) {
+                            Mutability::Mut
+                        } else {
+                            Mutability::Not
+                        };
+                        PatKind::DerefPattern { subpattern, mutability }
+                    }
+                    _ => PatKind::Deref { subpattern },
+                };
+                Box::new(Pat { span: pat.span, ty: *ref_ty, kind })
             },
         )
     }
@@ -766,6 +778,9 @@ fn super_fold_with<F: PatternFolder<'tcx>>(&self, folder: &mut F) -> Self {
             PatKind::Deref { ref subpattern } => {
                 PatKind::Deref { subpattern: subpattern.fold_with(folder) }
             }
+            PatKind::DerefPattern { ref subpattern, mutability } => {
+                PatKind::DerefPattern { subpattern: subpattern.fold_with(folder), mutability }
+            }
             PatKind::Constant { value } => PatKind::Constant { value },
             PatKind::Range(ref range) => PatKind::Range(range.clone()),
             PatKind::Slice { ref prefix, ref slice, ref suffix } => PatKind::Slice {
@@ -815,6 +830,11 @@ pub(crate) fn compare_const_vals<'tcx>(
 
     use rustc_apfloat::Float;
     match *ty.kind() {
+        ty::Float(ty::FloatTy::F16) => {
+            let a = rustc_apfloat::ieee::Half::from_bits(a);
+            let b = rustc_apfloat::ieee::Half::from_bits(b);
+            a.partial_cmp(&b)
+        }
         ty::Float(ty::FloatTy::F32) => {
             let a = rustc_apfloat::ieee::Single::from_bits(a);
             let b = rustc_apfloat::ieee::Single::from_bits(b);
@@ -825,6 +845,11 @@ pub(crate) fn compare_const_vals<'tcx>(
             let b = rustc_apfloat::ieee::Double::from_bits(b);
             a.partial_cmp(&b)
         }
+        ty::Float(ty::FloatTy::F128) => {
+            let a = rustc_apfloat::ieee::Quad::from_bits(a);
+            let b = rustc_apfloat::ieee::Quad::from_bits(b);
+            a.partial_cmp(&b)
+        }
         ty::Int(ity) => {
             use rustc_middle::ty::layout::IntegerExt;
             let size = rustc_target::abi::Integer::from_int_ty(&tcx, ity).size();
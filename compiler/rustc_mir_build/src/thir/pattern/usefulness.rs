@@ -292,12 +292,13 @@
 use self::ArmType::*;
 use self::Usefulness::*;
 use super::deconstruct_pat::{Constructor, DeconstructedPat, Fields, SplitWildcard};
-use crate::errors::{NonExhaustiveOmittedPattern, Uncovered};
+use crate::errors::{MixedDerefPatternConstructors, NonExhaustiveOmittedPattern, Uncovered};
 
 use rustc_data_structures::captures::Captures;
 
 use rustc_arena::TypedArena;
 use rustc_data_structures::stack::ensure_sufficient_stack;
+use rustc_errors::ErrorGuaranteed;
 use rustc_hir::def_id::DefId;
 use rustc_hir::HirId;
 use rustc_middle::ty::{self, Ty, TyCtxt};
@@ -305,6 +306,7 @@
 use rustc_span::{Span, DUMMY_SP};
 
 use smallvec::{smallvec, SmallVec};
+use std::cell::Cell;
 use std::fmt;
 use std::iter::once;
 
@@ -320,6 +322,9 @@ pub(crate) struct MatchCheckCtxt<'p, 'tcx> {
     pub(crate) pattern_arena: &'p TypedArena<DeconstructedPat<'p, 'tcx>>,
     /// Only produce `NON_EXHAUSTIVE_OMITTED_PATTERNS` lint on refutable patterns.
     pub(crate) refutable: bool,
+    /// Set once we have reported a mix of deref patterns and normal constructors, so that we report
+    /// it only once and skip the now-meaningless reachability and exhaustiveness diagnostics.
+    pub(crate) mixed_deref_patterns: Cell<Option<ErrorGuaranteed>>,
 }
 
 impl<'a, 'tcx> MatchCheckCtxt<'a, 'tcx> {
@@ -840,6 +845,24 @@ fn is_useful<'p, 'tcx>(
                 lint_root,
             )
         }
+        // Deref patterns and normal constructors of the same smart pointer type can't be mixed: the
+        // former look at the pointee while the latter look at the pointer itself.
+        let mut heads = matrix.heads().chain(once(v.head()));
+        if cx.mixed_deref_patterns.get().is_none()
+            && let Some(deref_pat) =
+                heads.clone().find(|p| matches!(p.ctor(), Constructor::DerefPattern(_)))
+            && let Some(normal_pat) =
+                heads.find(|p| matches!(p.ctor(), Constructor::Single | Constructor::Variant(_)))
+        {
+            let guar = cx.tcx.sess.emit_err(MixedDerefPatternConstructors {
+                spans: vec![deref_pat.span(), normal_pat.span()],
+                smart_pointer_ty: ty,
+                deref_pattern_label: deref_pat.span(),
+                normal_constructor_label: normal_pat.span(),
+            });
+            cx.mixed_deref_patterns.set(Some(guar));
+        }
+
         // We split the head constructor of `v`.
         let split_ctors = v_ctor.split(pcx, matrix.heads().map(DeconstructedPat::ctor));
         let is_non_exhaustive_and_wild = is_non_exhaustive && v_ctor.is_wildcard();
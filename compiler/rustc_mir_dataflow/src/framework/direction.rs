@@ -474,7 +474,7 @@ fn join_state_into_successors_of<'tcx, A>(
     {
         use mir::TerminatorKind::*;
         match bb_data.terminator().kind {
-            Return | Resume | Terminate | GeneratorDrop | Unreachable => {}
+            Return | TailCall { .. } | Resume | Terminate | GeneratorDrop | Unreachable => {}
 
             Goto { target } => propagate(target, exit_state),
 
@@ -411,6 +411,12 @@ fn gather_terminator(&mut self, term: &Terminator<'tcx>) {
                     self.gather_init(destination.as_ref(), InitKind::NonPanicPathOnly);
                 }
             }
+            TerminatorKind::TailCall { ref func, ref args, fn_span: _ } => {
+                self.gather_operand(func);
+                for arg in args {
+                    self.gather_operand(arg);
+                }
+            }
             TerminatorKind::InlineAsm {
                 template: _,
                 ref operands,
@@ -263,6 +263,7 @@ fn super_terminator(&self, terminator: &Terminator<'tcx>, state: &mut State<Self
             | TerminatorKind::Resume
             | TerminatorKind::Terminate
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::Assert { .. }
             | TerminatorKind::GeneratorDrop
@@ -64,3 +64,7 @@ mir_transform_must_not_suspend = {$pre}`{$def_path}`{$post} held across a suspen
     .help = consider using a block (`{"{ ... }"}`) to shrink the value's scope, ending before the suspend point
 
 mir_transform_simd_shuffle_last_const = last argument of `simd_shuffle` is required to be a `const` item
+
+mir_transform_tail_call_live_drop = `become` while a value with a destructor is still live
+    .label = this value would never be dropped
+    .note = values with destructors must be moved or dropped before a tail call
@@ -66,7 +66,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                 // safe (at least as emitted during MIR construction)
             }
 
-            TerminatorKind::Call { ref func, .. } => {
+            TerminatorKind::Call { ref func, .. } | TerminatorKind::TailCall { ref func, .. } => {
                 let func_ty = func.ty(self.body, self.tcx);
                 let func_id =
                     if let ty::FnDef(func_id, _) = func_ty.kind() { Some(func_id) } else { None };
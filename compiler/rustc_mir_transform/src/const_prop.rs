@@ -922,6 +922,7 @@ fn visit_terminator(&mut self, terminator: &mut Terminator<'tcx>, location: Loca
             | TerminatorKind::Resume
             | TerminatorKind::Terminate
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::Drop { .. }
             | TerminatorKind::Yield { .. }
@@ -821,6 +821,7 @@ pub(super) fn term_type(kind: &TerminatorKind<'_>) -> &'static str {
         TerminatorKind::Unreachable => "Unreachable",
         TerminatorKind::Drop { .. } => "Drop",
         TerminatorKind::Call { .. } => "Call",
+        TerminatorKind::TailCall { .. } => "TailCall",
         TerminatorKind::Assert { .. } => "Assert",
         TerminatorKind::Yield { .. } => "Yield",
         TerminatorKind::GeneratorDrop => "GeneratorDrop",
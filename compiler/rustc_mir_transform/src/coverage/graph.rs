@@ -122,6 +122,7 @@ fn compute_basic_coverage_blocks(
 
             match term.kind {
                 TerminatorKind::Return { .. }
+                | TerminatorKind::TailCall { .. }
                 | TerminatorKind::Terminate
                 | TerminatorKind::Yield { .. }
                 | TerminatorKind::SwitchInt { .. } => {
@@ -871,6 +871,7 @@ pub(super) fn filtered_terminator_span(terminator: &Terminator<'_>) -> Option<Sp
         TerminatorKind::Resume
         | TerminatorKind::Terminate
         | TerminatorKind::Return
+        | TerminatorKind::TailCall { .. }
         | TerminatorKind::Yield { .. }
         | TerminatorKind::GeneratorDrop
         | TerminatorKind::FalseUnwind { .. }
@@ -1,4 +1,5 @@
 use crate::deref_separator::deref_finder;
+use crate::errors;
 use crate::MirPass;
 use rustc_index::bit_set::BitSet;
 use rustc_index::IndexVec;
@@ -323,6 +324,7 @@ fn drop_flag(&mut self, index: MovePathIndex) -> Option<Place<'tcx>> {
     /// create a patch that elaborates all drops in the input
     /// MIR.
     fn elaborate(mut self) -> MirPatch<'tcx> {
+        self.check_tail_calls();
         self.collect_drop_flags();
 
         self.elaborate_drops();
@@ -335,6 +337,35 @@ fn elaborate(mut self) -> MirPatch<'tcx> {
         self.patch
     }
 
+    /// Rejects tail calls while a local with a destructor may still be initialized. The frame of
+    /// the caller is gone once the callee runs, so nothing could drop it anymore.
+    fn check_tail_calls(&mut self) {
+        for (bb, data) in self.body.basic_blocks.iter_enumerated() {
+            if !self.reachable.contains(bb) {
+                continue;
+            }
+            let terminator = data.terminator();
+            let TerminatorKind::TailCall { .. } = terminator.kind else { continue };
+
+            // Seek past the tail call, so that the operands it moves are no longer initialized.
+            self.init_data.inits.seek_after_primary_effect(self.body.terminator_loc(bb));
+
+            for (local, decl) in self.body.local_decls.iter_enumerated().skip(1) {
+                let path = self.move_data().rev_lookup.find_local(local);
+                let mut maybe_live = false;
+                on_all_drop_children_bits(self.tcx, self.body, self.env, path, |child| {
+                    maybe_live |= self.init_data.inits.contains(child);
+                });
+                if maybe_live {
+                    self.tcx.sess.emit_err(errors::TailCallLiveDrop {
+                        span: terminator.source_info.span,
+                        local_span: decl.source_info.span,
+                    });
+                }
+            }
+        }
+    }
+
     fn collect_drop_flags(&mut self) {
         for (bb, data) in self.body.basic_blocks.iter_enumerated() {
             if !self.reachable.contains(bb) {
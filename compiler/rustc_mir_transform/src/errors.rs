@@ -243,3 +243,13 @@ pub(crate) struct SimdShuffleLastConst {
     #[primary_span]
     pub span: Span,
 }
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_tail_call_live_drop)]
+#[note]
+pub(crate) struct TailCallLiveDrop {
+    #[primary_span]
+    pub span: Span,
+    #[label]
+    pub local_span: Span,
+}
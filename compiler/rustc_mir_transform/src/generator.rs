@@ -221,7 +221,7 @@ struct SuspensionPoint<'tcx> {
 
 struct TransformVisitor<'tcx> {
     tcx: TyCtxt<'tcx>,
-    is_async_kind: bool,
+    generator_kind: GeneratorKind,
     state_adt_ref: AdtDef<'tcx>,
     state_substs: SubstsRef<'tcx>,
 
@@ -246,7 +246,7 @@ struct TransformVisitor<'tcx> {
 }
 
 impl<'tcx> TransformVisitor<'tcx> {
-    // Make a `GeneratorState` or `Poll` variant assignment.
+    // Make a `GeneratorState`, `Poll` or `Option` variant assignment.
     //
     // `core::ops::GeneratorState` only has single element tuple variants,
     // so we can just write to the downcasted first field and then set the
@@ -258,17 +258,22 @@ fn make_state(
         is_return: bool,
         statements: &mut Vec<Statement<'tcx>>,
     ) {
-        let idx = VariantIdx::new(match (is_return, self.is_async_kind) {
-            (true, false) => 1,  // GeneratorState::Complete
-            (false, false) => 0, // GeneratorState::Yielded
-            (true, true) => 0,   // Poll::Ready
-            (false, true) => 1,  // Poll::Pending
+        let idx = VariantIdx::new(match (is_return, self.generator_kind) {
+            (true, GeneratorKind::Gen) => 1,       // GeneratorState::Complete
+            (false, GeneratorKind::Gen) => 0,      // GeneratorState::Yielded
+            (true, GeneratorKind::Async(_)) => 0,  // Poll::Ready
+            (false, GeneratorKind::Async(_)) => 1, // Poll::Pending
+            (true, GeneratorKind::Iter(_)) => 0,   // Option::None
+            (false, GeneratorKind::Iter(_)) => 1,  // Option::Some
         });
 
         let kind = AggregateKind::Adt(self.state_adt_ref.did(), idx, self.state_substs, None, None);
 
-        // `Poll::Pending`
-        if self.is_async_kind && idx == VariantIdx::new(1) {
+        // `Poll::Pending` or `Option::None`
+        if matches!(
+            (is_return, self.generator_kind),
+            (false, GeneratorKind::Async(_)) | (true, GeneratorKind::Iter(_))
+        ) {
             assert_eq!(self.state_adt_ref.variant(idx).fields.len(), 0);
 
             // FIXME(swatinem): assert that `val` is indeed unit?
@@ -282,7 +287,8 @@ fn make_state(
             return;
         }
 
-        // else: `Poll::Ready(x)`, `GeneratorState::Yielded(x)` or `GeneratorState::Complete(x)`
+        // else: `Poll::Ready(x)`, `Option::Some(x)`, `GeneratorState::Yielded(x)` or
+        // `GeneratorState::Complete(x)`
         assert_eq!(self.state_adt_ref.variant(idx).fields.len(), 1);
 
         statements.push(Statement {
@@ -294,6 +300,20 @@ fn make_state(
         });
     }
 
+    // Create a block that returns `Option::None`, which is what a `gen` generator keeps
+    // returning once it has completed.
+    fn insert_none_ret_block(&self, body: &mut Body<'tcx>) -> BasicBlock {
+        let source_info = SourceInfo::outermost(body.span);
+        let mut statements = Vec::new();
+        let unit = Operand::Constant(unit_constant(self.tcx, body.span));
+        self.make_state(unit, source_info, true, &mut statements);
+        body.basic_blocks_mut().push(BasicBlockData {
+            statements,
+            terminator: Some(Terminator { source_info, kind: TerminatorKind::Return }),
+            is_cleanup: false,
+        })
+    }
+
     // Create a Place referencing a generator struct field
     fn make_field(&self, variant_index: VariantIdx, idx: usize, ty: Ty<'tcx>) -> Place<'tcx> {
         let self_place = Place::from(SELF_ARG);
@@ -1138,6 +1158,25 @@ fn insert_term_block<'tcx>(body: &mut Body<'tcx>, kind: TerminatorKind<'tcx>) ->
     })
 }
 
+fn unit_constant<'tcx>(tcx: TyCtxt<'tcx>, span: Span) -> Box<Constant<'tcx>> {
+    Box::new(Constant { span, user_ty: None, literal: ConstantKind::zero_sized(tcx.mk_unit()) })
+}
+
+/// The value the generator was resumed with. `gen` generators back `Iterator::next`, which has no
+/// resume argument, so they are always resumed with `()`.
+fn resume_arg_operand<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    generator_kind: GeneratorKind,
+    span: Span,
+) -> Operand<'tcx> {
+    match generator_kind {
+        GeneratorKind::Iter(_) => Operand::Constant(unit_constant(tcx, span)),
+        GeneratorKind::Async(_) | GeneratorKind::Gen => {
+            Operand::Move(Local::new(2).into()) // 0 = return, 1 = self
+        }
+    }
+}
+
 fn insert_panic_block<'tcx>(
     tcx: TyCtxt<'tcx>,
     body: &mut Body<'tcx>,
@@ -1213,6 +1252,7 @@ fn can_unwind<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> bool {
             // These may unwind.
             TerminatorKind::Drop { .. }
             | TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::InlineAsm { .. }
             | TerminatorKind::Assert { .. } => return true,
         }
@@ -1279,16 +1319,24 @@ fn create_generator_resume_function<'tcx>(
     }
 
     if can_return {
-        cases.insert(
-            1,
-            (RETURNED, insert_panic_block(tcx, body, ResumedAfterReturn(generator_kind))),
-        );
+        let block = match generator_kind {
+            GeneratorKind::Async(_) | GeneratorKind::Gen => {
+                insert_panic_block(tcx, body, ResumedAfterReturn(generator_kind))
+            }
+            // `gen` generators keep returning `None` once they are exhausted.
+            GeneratorKind::Iter(_) => transform.insert_none_ret_block(body),
+        };
+        cases.insert(1, (RETURNED, block));
     }
 
     insert_switch(body, cases, &transform, TerminatorKind::Unreachable);
 
     make_generator_state_argument_indirect(tcx, body);
-    make_generator_state_argument_pinned(tcx, body);
+    // `Iterator::next` takes `&mut self`, unlike the resume functions of all other
+    // generator kinds.
+    if !matches!(generator_kind, GeneratorKind::Iter(_)) {
+        make_generator_state_argument_pinned(tcx, body);
+    }
 
     // Make sure we remove dead blocks to remove
     // unrelated code from the drop part of the function
@@ -1367,12 +1415,13 @@ fn create_cases<'tcx>(
 
                 if operation == Operation::Resume {
                     // Move the resume argument to the destination place of the `Yield` terminator
-                    let resume_arg = Local::new(2); // 0 = return, 1 = self
+                    let resume_arg =
+                        resume_arg_operand(transform.tcx, transform.generator_kind, body.span);
                     statements.push(Statement {
                         source_info,
                         kind: StatementKind::Assign(Box::new((
                             point.resume_arg,
-                            Rvalue::Use(Operand::Move(resume_arg.into())),
+                            Rvalue::Use(resume_arg),
                         ))),
                     });
                 }
@@ -1457,19 +1506,30 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
             }
         };
 
-        let is_async_kind = matches!(body.generator_kind(), Some(GeneratorKind::Async(_)));
-        let (state_adt_ref, state_substs) = if is_async_kind {
-            // Compute Poll<return_ty>
-            let poll_did = tcx.require_lang_item(LangItem::Poll, None);
-            let poll_adt_ref = tcx.adt_def(poll_did);
-            let poll_substs = tcx.mk_substs(&[body.return_ty().into()]);
-            (poll_adt_ref, poll_substs)
-        } else {
-            // Compute GeneratorState<yield_ty, return_ty>
-            let state_did = tcx.require_lang_item(LangItem::GeneratorState, None);
-            let state_adt_ref = tcx.adt_def(state_did);
-            let state_substs = tcx.mk_substs(&[yield_ty.into(), body.return_ty().into()]);
-            (state_adt_ref, state_substs)
+        let generator_kind = body.generator_kind().unwrap();
+        let is_async_kind = matches!(generator_kind, GeneratorKind::Async(_));
+        let (state_adt_ref, state_substs) = match generator_kind {
+            GeneratorKind::Async(_) => {
+                // Compute Poll<return_ty>
+                let poll_did = tcx.require_lang_item(LangItem::Poll, None);
+                let poll_adt_ref = tcx.adt_def(poll_did);
+                let poll_substs = tcx.mk_substs(&[body.return_ty().into()]);
+                (poll_adt_ref, poll_substs)
+            }
+            GeneratorKind::Iter(_) => {
+                // Compute Option<yield_ty>
+                let option_did = tcx.require_lang_item(LangItem::Option, None);
+                let option_adt_ref = tcx.adt_def(option_did);
+                let option_substs = tcx.mk_substs(&[yield_ty.into()]);
+                (option_adt_ref, option_substs)
+            }
+            GeneratorKind::Gen => {
+                // Compute GeneratorState<yield_ty, return_ty>
+                let state_did = tcx.require_lang_item(LangItem::GeneratorState, None);
+                let state_adt_ref = tcx.adt_def(state_did);
+                let state_substs = tcx.mk_substs(&[yield_ty.into(), body.return_ty().into()]);
+                (state_adt_ref, state_substs)
+            }
         };
         let ret_ty = tcx.mk_adt(state_adt_ref, state_substs);
 
@@ -1493,6 +1553,7 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
 
         // When first entering the generator, move the resume argument into its new local.
         let source_info = SourceInfo::outermost(body.span);
+        let resume_arg = resume_arg_operand(tcx, generator_kind, body.span);
         let stmts = &mut body.basic_blocks_mut()[START_BLOCK].statements;
         stmts.insert(
             0,
@@ -1500,7 +1561,7 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                 source_info,
                 kind: StatementKind::Assign(Box::new((
                     new_resume_local.into(),
-                    Rvalue::Use(Operand::Move(resume_local.into())),
+                    Rvalue::Use(resume_arg),
                 ))),
             },
         );
@@ -1537,10 +1598,11 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         // accesses for locals in `remap`.
         // It also rewrites `return x` and `yield y` as writing a new generator state and returning
         // either GeneratorState::Complete(x) and GeneratorState::Yielded(y),
-        // or Poll::Ready(x) and Poll::Pending respectively depending on `is_async_kind`.
+        // Poll::Ready(x) and Poll::Pending, or Option::None and Option::Some(y) respectively
+        // depending on `generator_kind`.
         let mut transform = TransformVisitor {
             tcx,
-            is_async_kind,
+            generator_kind,
             state_adt_ref,
             state_substs,
             remap,
@@ -1553,7 +1615,12 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         transform.visit_body(body);
 
         // Update our MIR struct to reflect the changes we've made
-        body.arg_count = 2; // self, resume arg
+        body.arg_count = match generator_kind {
+            // self
+            GeneratorKind::Iter(_) => 1,
+            // self, resume arg
+            GeneratorKind::Async(_) | GeneratorKind::Gen => 2,
+        };
         body.spread_arg = None;
 
         // The original arguments to the function are no longer arguments, mark them as such.
@@ -1705,6 +1772,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
             TerminatorKind::InlineAsm { .. } => {}
 
             TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Goto { .. }
             | TerminatorKind::SwitchInt { .. }
             | TerminatorKind::Resume
@@ -389,6 +389,12 @@ fn check_codegen_attributes(
             }
         }
 
+        // Inlining a `#[target_clones]` function would bypass its runtime dispatch and
+        // always run the body compiled for the caller's target features.
+        if !callee_attrs.target_clones.is_empty() {
+            return Err("target clones");
+        }
+
         Ok(())
     }
 
@@ -459,6 +465,10 @@ fn check_mir_body(
                 // inline-asm is detected. LLVM will still possibly do an inline later on
                 // if the no-attribute function ends up with the same instruction set anyway.
                 return Err("Cannot move inline-asm across instruction sets");
+            } else if let TerminatorKind::TailCall { .. } = term.kind {
+                // FIXME(explicit_tail_calls): figure out how exactly functions containing tail
+                // calls can be inlined (and if they even should)
+                return Err("can't inline functions with tail calls");
             } else {
                 work_list.extend(term.successors())
             }
@@ -1093,6 +1103,10 @@ fn visit_terminator(&mut self, terminator: &mut Terminator<'tcx>, loc: Location)
                 *target = self.map_block(*target);
                 *unwind = self.map_unwind(*unwind);
             }
+            TerminatorKind::TailCall { .. } => {
+                // check_mir_body forbids tail calls
+                unreachable!()
+            }
             TerminatorKind::Return => {
                 terminator.kind = if let Some(tgt) = self.callsite.target {
                     TerminatorKind::Goto { target: tgt }
@@ -114,6 +114,7 @@ pub fn separate_const_switch(body: &mut Body<'_>) -> usize {
                         | TerminatorKind::Yield { .. }
                         | TerminatorKind::Terminate
                         | TerminatorKind::Return
+                        | TerminatorKind::TailCall { .. }
                         | TerminatorKind::Unreachable
                         | TerminatorKind::InlineAsm { .. }
                         | TerminatorKind::GeneratorDrop => {
@@ -166,6 +167,7 @@ pub fn separate_const_switch(body: &mut Body<'_>) -> usize {
             TerminatorKind::Resume
             | TerminatorKind::Terminate
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::GeneratorDrop
             | TerminatorKind::Assert { .. }
@@ -814,7 +814,8 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
 
         let tcx = self.tcx;
         match terminator.kind {
-            mir::TerminatorKind::Call { ref func, .. } => {
+            mir::TerminatorKind::Call { ref func, .. }
+            | mir::TerminatorKind::TailCall { ref func, .. } => {
                 let callee_ty = func.ty(self.body, tcx);
                 let callee_ty = self.monomorphize(callee_ty);
                 visit_fn_use(self.tcx, callee_ty, true, source, &mut self.output)
@@ -1449,6 +1450,19 @@ fn collect_neighbours<'tcx>(
 ) {
     let body = tcx.instance_mir(instance.def);
     MirNeighborCollector { tcx, body: &body, output, instance }.visit_body(&body);
+
+    // The dispatcher generated for a `#[target_clones]` function asks `std` which target
+    // features the running CPU supports.
+    if let ty::InstanceDef::Item(def_id) = instance.def {
+        if !tcx.codegen_fn_attrs(def_id).target_clones.is_empty() {
+            let detect_def_id =
+                tcx.require_lang_item(LangItem::TargetClonesFeaturesDetected, Some(body.span));
+            let detect = Instance::mono(tcx, detect_def_id);
+            if should_codegen_locally(tcx, &detect) {
+                output.push(create_fn_mono_item(tcx, detect, body.span));
+            }
+        }
+    }
 }
 
 #[instrument(skip(tcx, output), level = "debug")]
@@ -614,6 +614,10 @@ parse_fn_pointer_cannot_be_async = an `fn` pointer type cannot be `async`
     .label = `async` because of this
     .suggestion = remove the `async` qualifier
 
+parse_fn_pointer_cannot_be_gen = an `fn` pointer type cannot be `gen`
+    .label = `gen` because of this
+    .suggestion = remove the `gen` qualifier
+
 parse_nested_c_variadic_type = C-variadic type `...` may not be nested inside another type
 
 parse_invalid_dyn_keyword = invalid `dyn` keyword
@@ -801,9 +805,6 @@ parse_bad_item_kind = {$descr} is not supported in {$ctx}
 parse_single_colon_struct_type = found single colon in a struct field type path
     .suggestion = write a path separator here
 
-parse_equals_struct_default = default values on `struct` fields aren't supported
-    .suggestion = remove this unsupported default value
-
 parse_macro_rules_missing_bang = expected `!` after `macro_rules`
     .suggestion = add a `!`
 
@@ -2276,6 +2276,16 @@ pub(crate) struct FnPointerCannotBeAsync {
     pub qualifier: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(parse_fn_pointer_cannot_be_gen)]
+pub(crate) struct FnPointerCannotBeGen {
+    #[primary_span]
+    pub span: Span,
+    #[suggestion(code = "", applicability = "maybe-incorrect")]
+    #[label]
+    pub qualifier: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(parse_nested_c_variadic_type, code = "E0743")]
 pub(crate) struct NestedCVariadicType {
@@ -2489,14 +2499,6 @@ pub(crate) struct SingleColonStructType {
     pub span: Span,
 }
 
-#[derive(Diagnostic)]
-#[diag(parse_equals_struct_default)]
-pub(crate) struct EqualsStructDefault {
-    #[primary_span]
-    #[suggestion(code = "", applicability = "machine-applicable")]
-    pub span: Span,
-}
-
 #[derive(Diagnostic)]
 #[diag(parse_macro_rules_missing_bang)]
 pub(crate) struct MacroRulesMissingBang {
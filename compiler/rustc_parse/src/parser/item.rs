@@ -1547,6 +1547,7 @@ pub(super) fn parse_tuple_struct_body(&mut self) -> PResult<'a, ThinVec<FieldDef
                         ident: None,
                         id: DUMMY_NODE_ID,
                         ty,
+                        default: None,
                         attrs,
                         is_placeholder: false,
                     },
@@ -1722,18 +1723,22 @@ fn parse_name_and_ty(
         if self.token.kind == token::Colon && self.look_ahead(1, |tok| tok.kind != token::Colon) {
             self.sess.emit_err(errors::SingleColonStructType { span: self.token.span });
         }
-        if self.token.kind == token::Eq {
+        let default = if self.token.kind == token::Eq {
             self.bump();
             let const_expr = self.parse_expr_anon_const()?;
             let sp = ty.span.shrink_to_hi().to(const_expr.value.span);
-            self.sess.emit_err(errors::EqualsStructDefault { span: sp });
-        }
+            self.sess.gated_spans.gate(sym::default_field_values, sp);
+            Some(const_expr)
+        } else {
+            None
+        };
         Ok(FieldDef {
             span: lo.to(self.prev_token.span),
             ident: Some(name),
             vis,
             id: DUMMY_NODE_ID,
             ty,
+            default,
             attrs,
             is_placeholder: false,
         })
@@ -2065,12 +2070,42 @@ fn parse_fn(
                 }
             }
         };
+        let contract = self.parse_fn_contract()?; // `contract_requires(..) contract_ensures(..)`
         generics.where_clause = self.parse_where_clause()?; // `where T: Ord`
 
         let mut sig_hi = self.prev_token.span;
         let body = self.parse_fn_body(attrs, &ident, &mut sig_hi, fn_parse_mode.req_body)?; // `;` or `{ ... }`.
         let fn_sig_span = sig_lo.to(sig_hi);
-        Ok((ident, FnSig { header, decl, span: fn_sig_span }, generics, body))
+        Ok((ident, FnSig { header, decl, contract, span: fn_sig_span }, generics, body))
+    }
+
+    /// Parses the `contract_requires(<closure>, "<text>")` and `contract_ensures(<closure>,
+    /// "<text>")` clauses that the `#[requires]` and `#[ensures]` attribute macros insert into
+    /// a function. This syntax is internal and not meant to be written by hand.
+    fn parse_fn_contract(&mut self) -> PResult<'a, Option<P<FnContract>>> {
+        let mut contract = FnContract::default();
+        loop {
+            let clauses = if self.token.is_ident_named(sym::contract_requires) {
+                &mut contract.requires
+            } else if self.token.is_ident_named(sym::contract_ensures) {
+                &mut contract.ensures
+            } else {
+                break;
+            };
+            self.sess.gated_spans.gate(sym::contracts_internals, self.token.span);
+            self.bump();
+            self.expect(&token::OpenDelim(Delimiter::Parenthesis))?;
+            let closure = self.parse_expr()?;
+            self.expect(&token::Comma)?;
+            let Ok(text) = self.parse_str_lit() else { return self.unexpected() };
+            clauses.push(ContractClause { closure, text: text.symbol_unescaped });
+            self.expect(&token::CloseDelim(Delimiter::Parenthesis))?;
+        }
+        if contract.requires.is_empty() && contract.ensures.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(P(contract)))
+        }
     }
 
     /// Parse the "body" of a function.
@@ -2147,7 +2182,9 @@ pub(super) fn check_fn_front_matter(&mut self, check_pub: bool, case: Case) -> b
         };
         self.check_keyword_case(kw::Fn, case) // Definitely an `fn`.
             // `$qual fn` or `$qual $qual`:
-            || quals.iter().any(|&kw| self.check_keyword_case(kw, case))
+            || (quals.iter().any(|&kw| self.check_keyword_case(kw, case))
+                // `gen` is only a keyword starting with the 2024 edition.
+                || self.token.is_keyword(kw::Gen) && self.token.uninterpolated_span().rust_2024())
                 && self.look_ahead(1, |t| {
                     // `$qual fn`, e.g. `const fn` or `async fn`.
                     t.is_keyword_case(kw::Fn, case)
@@ -2155,8 +2192,8 @@ pub(super) fn check_fn_front_matter(&mut self, check_pub: bool, case: Case) -> b
                     || (
                         (
                             t.is_non_raw_ident_where(|i|
-                                quals.contains(&i.name)
-                                    // Rule out 2015 `const async: T = val`.
+                                (quals.contains(&i.name) || i.name == kw::Gen)
+                                    // Rule out 2015 `const async: T = val` and a 2021 `gen`.
                                     && i.is_reserved()
                             )
                             || case == Case::Insensitive
@@ -2176,7 +2213,7 @@ pub(super) fn check_fn_front_matter(&mut self, check_pub: bool, case: Case) -> b
     ///
     /// ```text
     /// Extern = "extern" StringLit? ;
-    /// FnQual = "const"? "async"? "unsafe"? Extern? ;
+    /// FnQual = "const"? "async"? "gen"? "unsafe"? Extern? ;
     /// FnFrontMatter = FnQual "fn" ;
     /// ```
     ///
@@ -2192,6 +2229,7 @@ pub(super) fn parse_fn_front_matter(
 
         let async_start_sp = self.token.span;
         let asyncness = self.parse_asyncness(case);
+        let genness = self.parse_genness(case);
 
         let unsafe_start_sp = self.token.span;
         let unsafety = self.parse_unsafety(case);
@@ -2312,7 +2350,7 @@ enum WrongKw {
             }
         }
 
-        Ok(FnHeader { constness, unsafety, asyncness, ext })
+        Ok(FnHeader { constness, unsafety, asyncness, genness, ext })
     }
 
     /// Parses the parameter list and result type of a function declaration.
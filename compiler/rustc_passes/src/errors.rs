@@ -24,6 +24,10 @@ pub struct IncorrectDoNotRecommendLocation {
     pub span: Span,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(passes_diagnostic_on_unimplemented_only_for_traits)]
+pub struct DiagnosticOnUnimplementedOnlyForTraits;
+
 #[derive(LintDiagnostic)]
 #[diag(passes_outer_crate_level_attr)]
 pub struct OuterCrateLevelAttr;
@@ -203,6 +203,7 @@ fn check_expr(&mut self, expr: &'tcx hir::Expr<'tcx>, span: Span) {
             | ExprKind::Break(..)
             | ExprKind::Continue(..)
             | ExprKind::Ret(..)
+            | ExprKind::Become(..)
             | ExprKind::OffsetOf(..)
             | ExprKind::Struct(..)
             | ExprKind::Repeat(..)
@@ -317,6 +317,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             | ty::Str
             | ty::Never
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(..)
             | ty::Tuple(..)
             | ty::RawPtr(..)
@@ -1110,17 +1111,22 @@ fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
             let res = self.typeck_results().qpath_res(qpath, expr.hir_id);
             let adt = self.typeck_results().expr_ty(expr).ty_adt_def().unwrap();
             let variant = adt.variant_of_res(res);
-            if let Some(base) = *base {
-                // If the expression uses FRU we need to make sure all the unmentioned fields
-                // are checked for privacy (RFC 736). Rather than computing the set of
-                // unmentioned fields, just check them all.
+            let base_span = match *base {
+                hir::StructTailExpr::Base(base) => Some(base.span),
+                hir::StructTailExpr::DefaultFields(span) => Some(span),
+                hir::StructTailExpr::None => None,
+            };
+            if let Some(base_span) = base_span {
+                // If the expression uses FRU or default field values we need to make sure all
+                // the unmentioned fields are checked for privacy (RFC 736). Rather than
+                // computing the set of unmentioned fields, just check them all.
                 for (vf_index, variant_field) in variant.fields.iter_enumerated() {
                     let field = fields
                         .iter()
                         .find(|f| self.typeck_results().field_index(f.hir_id) == vf_index);
                     let (use_ctxt, span) = match field {
                         Some(field) => (field.ident.span, field.span),
-                        None => (base.span, base.span),
+                        None => (base_span, base_span),
                     };
                     self.check_field(use_ctxt, span, adt, variant_field, true);
                 }
@@ -73,7 +73,11 @@ pub(crate) fn define<T>(&mut self, parent: Module<'a>, ident: Ident, ns: Namespa
     {
         let binding = def.to_name_binding(self.arenas);
         let key = self.new_key(ident, ns);
-        if let Err(old_binding) = self.try_define(parent, key, binding) {
+        if let Err(old_binding) = self.try_define(parent, key, binding)
+            // Rustdoc's `--cfg-set`s keep the items of several configurations, which may each
+            // define the same name. The first definition is the one used for resolution.
+            && self.tcx.sess.parse_sess.cfg_sets.is_empty()
+        {
             self.report_conflict(parent, ident, ns, old_binding, &binding);
         }
     }
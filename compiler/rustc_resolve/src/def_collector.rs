@@ -131,10 +131,12 @@ fn visit_item(&mut self, i: &'a Item) {
 
     fn visit_fn(&mut self, fn_kind: FnKind<'a>, span: Span, _: NodeId) {
         if let FnKind::Fn(_, _, sig, _, generics, body) = fn_kind {
-            if let Async::Yes { closure_id, .. } = sig.header.asyncness {
+            if let (Async::Yes { closure_id, .. }, _) | (Async::No, Gen::Yes { closure_id, .. }) =
+                (sig.header.asyncness, sig.header.genness)
+            {
                 self.visit_generics(generics);
 
-                // For async functions, we need to create their inner defs inside of a
+                // For async and gen functions, we need to create their inner defs inside of a
                 // closure to match their desugared representation. Besides that,
                 // we must mirror everything that `visit::walk_fn` below does.
                 self.visit_fn_header(&sig.header);
@@ -142,7 +144,12 @@ fn visit_fn(&mut self, fn_kind: FnKind<'a>, span: Span, _: NodeId) {
                     self.visit_param(param);
                 }
                 self.visit_fn_ret_ty(&sig.decl.output);
-                // If this async fn has no body (i.e. it's an async fn signature in a trait)
+                if let Some(contract) = &sig.contract {
+                    for clause in contract.requires.iter().chain(&contract.ensures) {
+                        self.visit_expr(&clause.closure);
+                    }
+                }
+                // If this function has no body (i.e. it's an async fn signature in a trait)
                 // then the closure_def will never be used, and we should avoid generating a
                 // def-id for it.
                 if let Some(body) = body {
@@ -260,7 +267,7 @@ fn visit_expr(&mut self, expr: &'a Expr) {
                     Async::No => closure_def,
                 }
             }
-            ExprKind::Async(_, _) => self.create_def(expr.id, DefPathData::ClosureExpr, expr.span),
+            ExprKind::Gen(_, _, _) => self.create_def(expr.id, DefPathData::ClosureExpr, expr.span),
             _ => self.parent_def,
         };
 
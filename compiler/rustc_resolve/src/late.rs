@@ -23,6 +23,7 @@
 use rustc_middle::{bug, span_bug};
 use rustc_session::config::{CrateType, ResolveDocLinks};
 use rustc_session::lint;
+use rustc_session::parse::feature_err;
 use rustc_span::source_map::{respan, Spanned};
 use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::{BytePos, Span, SyntaxContext};
@@ -71,6 +72,7 @@ enum IsRepeatExpr {
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum AnonConstKind {
     EnumDiscriminant,
+    FieldDefaultValue,
     InlineConst,
     ConstArg(IsRepeatExpr),
 }
@@ -900,7 +902,7 @@ fn visit_fn(&mut self, fn_kind: FnKind<'ast>, sp: Span, fn_id: NodeId) {
                         );
                     },
                 );
-                self.record_lifetime_params_for_async(fn_id, sig.header.asyncness.opt_return_id());
+                self.record_lifetime_params_for_async(fn_id, sig.header.opt_return_id());
                 return;
             }
             FnKind::Fn(..) => {
@@ -920,7 +922,7 @@ fn visit_fn(&mut self, fn_kind: FnKind<'ast>, sp: Span, fn_id: NodeId) {
                         this.visit_generics(generics);
 
                         let declaration = &sig.decl;
-                        let async_node_id = sig.header.asyncness.opt_return_id();
+                        let async_node_id = sig.header.opt_return_id();
 
                         this.with_lifetime_rib(
                             LifetimeRibKind::AnonymousCreateParameter {
@@ -951,7 +953,17 @@ fn visit_fn(&mut self, fn_kind: FnKind<'ast>, sp: Span, fn_id: NodeId) {
                             // Resolve the function body, potentially inside the body of an async closure
                             this.with_lifetime_rib(
                                 LifetimeRibKind::Elided(LifetimeRes::Infer),
-                                |this| this.visit_block(body),
+                                |this| {
+                                    // The contract sees the parameters, like the body does.
+                                    if let Some(contract) = &sig.contract {
+                                        let clauses =
+                                            contract.requires.iter().chain(&contract.ensures);
+                                        for clause in clauses {
+                                            this.visit_expr(&clause.closure);
+                                        }
+                                    }
+                                    this.visit_block(body)
+                                },
                             );
 
                             debug!("(resolving function) leaving function");
@@ -1231,7 +1243,13 @@ fn visit_variant_discr(&mut self, discr: &'ast AnonConst) {
 
     fn visit_field_def(&mut self, f: &'ast FieldDef) {
         self.resolve_doc_links(&f.attrs, MaybeExported::Ok(f.id));
-        visit::walk_field_def(self, f)
+        let FieldDef { attrs, id: _, span: _, vis, ident: _, ty, default, is_placeholder: _ } = f;
+        self.visit_vis(vis);
+        self.visit_ty(ty);
+        if let Some(default) = default {
+            self.resolve_anon_const(default, AnonConstKind::FieldDefaultValue);
+        }
+        walk_list!(self, visit_attribute, attrs);
     }
 }
 
@@ -3876,6 +3894,23 @@ fn resolve_qpath(
             PathResult::Indeterminate => bug!("indeterminate path result in resolve_qpath"),
         };
 
+        if let Res::PrimTy(PrimTy::Float(float_ty @ (FloatTy::F16 | FloatTy::F128))) =
+            result.base_res()
+        {
+            let feature = if float_ty == FloatTy::F16 { sym::f16 } else { sym::f128 };
+            if !self.r.tcx.features().enabled(feature)
+                && !finalize.path_span.allows_unstable(feature)
+            {
+                feature_err(
+                    &self.r.tcx.sess.parse_sess,
+                    feature,
+                    finalize.path_span,
+                    format!("the type `{}` is unstable", float_ty.name_str()),
+                )
+                .emit();
+            }
+        }
+
         if path.len() > 1
             && let Some(res) = result.full_res()
             && res != Res::Err
@@ -4014,7 +4049,9 @@ fn resolve_anon_const_manual(
             AnonConstKind::EnumDiscriminant => {
                 ConstantHasGenerics::No(NoConstantGenericsReason::IsEnumDiscriminant)
             }
-            AnonConstKind::InlineConst => ConstantHasGenerics::Yes,
+            AnonConstKind::InlineConst | AnonConstKind::FieldDefaultValue => {
+                ConstantHasGenerics::Yes
+            }
             AnonConstKind::ConstArg(_) => {
                 if self.r.tcx.features().generic_const_exprs || is_trivial_const_arg {
                     ConstantHasGenerics::Yes
@@ -4189,7 +4226,7 @@ fn resolve_expr(&mut self, expr: &'ast Expr, parent: Option<&'ast Expr>) {
                 );
             }
             ExprKind::Closure(..) => visit::walk_expr(self, expr),
-            ExprKind::Async(..) => {
+            ExprKind::Gen(..) => {
                 self.with_label_rib(RibKind::ClosureOrAsync, |this| visit::walk_expr(this, expr));
             }
             ExprKind::Repeat(ref elem, ref ct) => {
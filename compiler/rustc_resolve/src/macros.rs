@@ -22,6 +22,7 @@
 use rustc_middle::ty::RegisteredTools;
 use rustc_middle::ty::TyCtxt;
 use rustc_session::lint::builtin::{LEGACY_DERIVE_HELPERS, SOFT_UNSTABLE};
+use rustc_session::lint::builtin::UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES;
 use rustc_session::lint::builtin::{UNUSED_MACROS, UNUSED_MACRO_RULES};
 use rustc_session::lint::BuiltinLintDiagnostics;
 use rustc_session::parse::feature_err;
@@ -137,9 +138,9 @@ pub(crate) fn registered_tools(tcx: TyCtxt<'_>, (): ()) -> RegisteredTools {
             }
         }
     }
-    // We implicitly add `rustfmt` and `clippy` to known tools,
+    // We implicitly add `rustfmt`, `clippy` and `diagnostic` to known tools,
     // but it's not an error to register them explicitly.
-    let predefined_tools = [sym::clippy, sym::rustfmt];
+    let predefined_tools = [sym::clippy, sym::rustfmt, sym::diagnostic];
     registered_tools.extend(predefined_tools.iter().cloned().map(Ident::with_dummy_span));
     registered_tools
 }
@@ -591,6 +592,29 @@ fn smart_resolve_macro_path(
             }
         }
 
+        if res == Res::NonMacroAttr(NonMacroAttrKind::Tool)
+            && let [namespace, attribute, ..] = &*path.segments
+            && namespace.ident.name == sym::diagnostic
+        {
+            if !self.tcx.sess.features_untracked().diagnostic_namespace {
+                feature_err(
+                    &self.tcx.sess.parse_sess,
+                    sym::diagnostic_namespace,
+                    namespace.ident.span,
+                    "`#[diagnostic]` attribute name space is experimental",
+                )
+                .emit();
+            }
+            if path.segments.len() > 2 || attribute.ident.name != sym::on_unimplemented {
+                self.tcx.sess.parse_sess.buffer_lint(
+                    UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                    attribute.ident.span,
+                    node_id,
+                    "unknown diagnostic attribute",
+                );
+            }
+        }
+
         Ok((ext, res))
     }
 
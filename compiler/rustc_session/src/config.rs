@@ -11,7 +11,7 @@
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 
 use rustc_data_structures::stable_hasher::{StableOrd, ToStableHashKey};
-use rustc_target::abi::Align;
+use rustc_target::abi::{Align, TargetDataLayoutErrors};
 use rustc_target::spec::{PanicStrategy, SanitizerSet, SplitDebuginfo};
 use rustc_target::spec::{Target, TargetTriple, TargetWarnings, TARGETS};
 
@@ -965,27 +965,29 @@ pub const fn default_lib_output() -> CrateType {
     CrateType::Rlib
 }
 
-fn default_configuration(sess: &Session) -> CrateConfig {
+/// Returns the cfgs set when compiling for `target`, like `target_os` or `unix`.
+pub fn target_configuration(
+    target: &Target,
+    nightly: bool,
+) -> Result<CrateConfig, TargetDataLayoutErrors<'_>> {
     // NOTE: This should be kept in sync with `CrateCheckConfig::fill_well_known` below.
-    let end = &sess.target.endian;
-    let arch = &sess.target.arch;
-    let wordsz = sess.target.pointer_width.to_string();
-    let os = &sess.target.os;
-    let env = &sess.target.env;
-    let abi = &sess.target.abi;
-    let vendor = &sess.target.vendor;
-    let min_atomic_width = sess.target.min_atomic_width();
-    let max_atomic_width = sess.target.max_atomic_width();
-    let atomic_cas = sess.target.atomic_cas;
-    let layout = sess.target.parse_data_layout().unwrap_or_else(|err| {
-        sess.emit_fatal(err);
-    });
+    let end = &target.endian;
+    let arch = &target.arch;
+    let wordsz = target.pointer_width.to_string();
+    let os = &target.os;
+    let env = &target.env;
+    let abi = &target.abi;
+    let vendor = &target.vendor;
+    let min_atomic_width = target.min_atomic_width();
+    let max_atomic_width = target.max_atomic_width();
+    let atomic_cas = target.atomic_cas;
+    let layout = target.parse_data_layout()?;
 
     let mut ret = CrateConfig::default();
     ret.reserve(7); // the minimum number of insertions
     // Target bindings.
     ret.insert((sym::target_os, Some(Symbol::intern(os))));
-    for fam in sess.target.families.as_ref() {
+    for fam in target.families.as_ref() {
         ret.insert((sym::target_family, Some(Symbol::intern(fam))));
         if fam == "windows" {
             ret.insert((sym::windows, None));
@@ -999,7 +1001,7 @@ fn default_configuration(sess: &Session) -> CrateConfig {
     ret.insert((sym::target_env, Some(Symbol::intern(env))));
     ret.insert((sym::target_abi, Some(Symbol::intern(abi))));
     ret.insert((sym::target_vendor, Some(Symbol::intern(vendor))));
-    if sess.target.has_thread_local {
+    if target.has_thread_local {
         ret.insert((sym::target_thread_local, None));
     }
     let mut has_atomic = false;
@@ -1028,12 +1030,18 @@ fn default_configuration(sess: &Session) -> CrateConfig {
             }
         }
     }
-    if sess.is_nightly_build() && has_atomic {
+    if nightly && has_atomic {
         ret.insert((sym::target_has_atomic_load_store, None));
         if atomic_cas {
             ret.insert((sym::target_has_atomic, None));
         }
     }
+    Ok(ret)
+}
+
+fn default_configuration(sess: &Session) -> CrateConfig {
+    let mut ret = target_configuration(&sess.target, sess.is_nightly_build())
+        .unwrap_or_else(|err| sess.emit_fatal(err));
 
     let panic_strategy = sess.panic_strategy();
     ret.insert((sym::panic, Some(panic_strategy.desc_symbol())));
@@ -1377,6 +1377,8 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
+    contract_checks: bool = (false, parse_bool, [TRACKED],
+        "emit runtime checks for `#[requires]` and `#[ensures]` contracts (default: no)"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
         "inject the given attribute in the crate"),
     debug_info_for_profiling: bool = (false, parse_bool, [TRACKED],
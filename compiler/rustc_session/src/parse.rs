@@ -191,6 +191,9 @@ pub struct ParseSess {
     pub span_diagnostic: Handler,
     pub unstable_features: UnstableFeatures,
     pub config: CrateConfig,
+    /// The configurations rustdoc documents the crate for with `--cfg-set`. When there are some,
+    /// a cfg holds if it holds in any of them, and `config` isn't used.
+    pub cfg_sets: Vec<CrateConfig>,
     pub check_config: CrateCheckConfig,
     pub edition: Edition,
     /// Places where raw identifiers were used. This is used to avoid complaining about idents
@@ -244,6 +247,7 @@ pub fn with_span_handler(handler: Handler, source_map: Lrc<SourceMap>) -> Self {
             span_diagnostic: handler,
             unstable_features: UnstableFeatures::from_environment(None),
             config: FxIndexSet::default(),
+            cfg_sets: Vec::new(),
             check_config: CrateCheckConfig::default(),
             edition: ExpnId::root().expn_data().edition,
             raw_identifier_spans: Default::default(),
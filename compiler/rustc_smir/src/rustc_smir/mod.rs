@@ -7,7 +7,8 @@
 //!
 //! For now, we are developing everything inside `rustc`, thus, we keep this module private.
 
-use crate::stable_mir::{self, ty::TyKind, Context};
+use crate::stable_mir::ty::{Pattern, TyKind};
+use crate::stable_mir::{self, Context};
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use tracing::debug;
@@ -50,6 +51,14 @@ fn mir_body(&mut self, item: &stable_mir::CrateItem) -> stable_mir::mir::Body {
         }
     }
 
+    fn fn_contract(&mut self, item: &stable_mir::CrateItem) -> stable_mir::FnContract {
+        let contract = self.tcx.fn_contract(self.item_def_id(item));
+        stable_mir::FnContract {
+            requires: contract.requires.iter().map(|&def_id| self.crate_item(def_id)).collect(),
+            ensures: contract.ensures.iter().map(|&def_id| self.crate_item(def_id)).collect(),
+        }
+    }
+
     fn rustc_tables(&mut self, f: &mut dyn FnMut(&mut Tables<'_>)) {
         f(self)
     }
@@ -77,6 +86,9 @@ fn rustc_ty_to_ty(&mut self, ty: Ty<'tcx>) -> TyKind {
             ty::Foreign(_) => todo!(),
             ty::Str => todo!(),
             ty::Array(_, _) => todo!(),
+            ty::Pat(base, pat) => {
+                TyKind::Pat(self.intern_ty(*base), self.rustc_pat_to_pat(*base, *pat))
+            }
             ty::Slice(_) => todo!(),
             ty::RawPtr(_) => todo!(),
             ty::Ref(_, _, _) => todo!(),
@@ -100,6 +112,16 @@ fn rustc_ty_to_ty(&mut self, ty: Ty<'tcx>) -> TyKind {
         }
     }
 
+    fn rustc_pat_to_pat(&self, base: Ty<'tcx>, pat: ty::Pattern<'tcx>) -> Pattern {
+        match *pat {
+            ty::PatternKind::Range { start, end, include_end } => {
+                let param_env = ty::ParamEnv::reveal_all();
+                let bits = |ct: ty::Const<'tcx>| ct.eval_bits(self.tcx, param_env, base);
+                Pattern::Range { start: start.map(bits), end: end.map(bits), include_end }
+            }
+        }
+    }
+
     fn intern_ty(&mut self, ty: Ty<'tcx>) -> stable_mir::ty::Ty {
         if let Some(id) = self.types.iter().position(|&t| t == ty) {
             return stable_mir::ty::Ty(id);
@@ -273,7 +295,7 @@ fn rustc_un_op_to_un_op(unary_op: &rustc_middle::mir::UnOp) -> stable_mir::mir::
 fn rustc_generator_to_generator(
     generator: &rustc_hir::GeneratorKind,
 ) -> stable_mir::mir::GeneratorKind {
-    use rustc_hir::{AsyncGeneratorKind, GeneratorKind};
+    use rustc_hir::{AsyncGeneratorKind, GeneratorKind, IterGeneratorKind};
     match generator {
         GeneratorKind::Async(async_gen) => {
             let async_gen = match async_gen {
@@ -283,6 +305,13 @@ fn rustc_generator_to_generator(
             };
             stable_mir::mir::GeneratorKind::Async(async_gen)
         }
+        GeneratorKind::Iter(iter_gen) => {
+            let iter_gen = match iter_gen {
+                IterGeneratorKind::Block => stable_mir::mir::IterGeneratorKind::Block,
+                IterGeneratorKind::Fn => stable_mir::mir::IterGeneratorKind::Fn,
+            };
+            stable_mir::mir::GeneratorKind::Iter(iter_gen)
+        }
         GeneratorKind::Gen => stable_mir::mir::GeneratorKind::Gen,
     }
 }
@@ -330,6 +359,7 @@ fn rustc_terminator_to_terminator(
             target: target.as_usize(),
             unwind: rustc_unwind_to_unwind(unwind),
         },
+        TailCall { .. } => todo!(),
         Yield { .. } => todo!(),
         GeneratorDrop => Terminator::GeneratorDrop,
         FalseEdge { .. } => todo!(),
@@ -98,6 +98,7 @@ pub enum UnOp {
 #[derive(Clone, Debug)]
 pub enum GeneratorKind {
     Async(AsyncGeneratorKind),
+    Iter(IterGeneratorKind),
     Gen,
 }
 
@@ -108,6 +109,12 @@ pub enum AsyncGeneratorKind {
     Fn,
 }
 
+#[derive(Clone, Debug)]
+pub enum IterGeneratorKind {
+    Block,
+    Fn,
+}
+
 #[derive(Clone, Debug)]
 pub enum Statement {
     Assign(Place, Rvalue),
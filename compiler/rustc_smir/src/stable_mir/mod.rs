@@ -50,6 +50,21 @@ impl CrateItem {
     pub fn body(&self) -> mir::Body {
         with(|cx| cx.mir_body(self))
     }
+
+    /// The contract of this item, if it is a function annotated with `#[requires]` or
+    /// `#[ensures]`. The contract is exposed whether or not `-Z contract-checks` is enabled.
+    pub fn contract(&self) -> FnContract {
+        with(|cx| cx.fn_contract(self))
+    }
+}
+
+/// The contract of a function. Each clause is the closure evaluating the corresponding
+/// predicate: preconditions take no arguments, and postconditions take a reference to the
+/// returned value.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct FnContract {
+    pub requires: Vec<CrateItem>,
+    pub ensures: Vec<CrateItem>,
 }
 
 /// Return the function where execution starts if the current
@@ -84,6 +99,8 @@ pub trait Context {
     /// Retrieve all items of the local crate that have a MIR associated with them.
     fn all_local_items(&mut self) -> CrateItems;
     fn mir_body(&mut self, item: &CrateItem) -> mir::Body;
+    /// Retrieve the closures of the contract of a function.
+    fn fn_contract(&mut self, item: &CrateItem) -> FnContract;
     /// Get information about the local crate.
     fn local_crate(&self) -> Crate;
     /// Retrieve a list of all external crates.
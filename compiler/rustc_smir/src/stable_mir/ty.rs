@@ -11,5 +11,13 @@ pub fn kind(&self) -> TyKind {
 
 pub enum TyKind {
     Bool,
+    Pat(Ty, Pattern),
     Tuple(Vec<Ty>),
 }
+
+/// The pattern of a pattern type, restricting the values of its base type.
+pub enum Pattern {
+    /// The values in a range. The endpoints are the raw bits of values of the base type, and a
+    /// missing endpoint means the range is unbounded on that side.
+    Range { start: Option<u128>, end: Option<u128>, include_end: bool },
+}
@@ -88,6 +88,7 @@
 
         // Edition-specific keywords that are used in unstable Rust or reserved for future use.
         Try:                "try", // >= 2018 Edition only
+        Gen:                "gen", // >= 2024 Edition only
 
         // Special lifetime names
         UnderscoreLifetime: "'_",
@@ -215,6 +216,7 @@
         IpAddr,
         IrTyKind,
         Is,
+        Item,
         ItemContext,
         Iterator,
         IteratorItem,
@@ -528,6 +530,7 @@
         const_format_args,
         const_generics,
         const_generics_defaults,
+        const_heap,
         const_if_match,
         const_impl_trait,
         const_in_array_repeat_expressions,
@@ -549,6 +552,15 @@
         constant,
         constructor,
         context,
+        contract_build_check_ensures,
+        contract_check_ensures,
+        contract_check_requires,
+        contract_ensures,
+        contract_requires,
+        contracts,
+        contracts_ensures,
+        contracts_internals,
+        contracts_requires,
         copy,
         copy_closures,
         copy_nonoverlapping,
@@ -600,6 +612,7 @@
         declare_lint_pass,
         decode,
         default_alloc_error_handler,
+        default_field_values,
         default_lib_allocator,
         default_method_body_is_const,
         default_type_parameter_fallback,
@@ -612,6 +625,8 @@
         deref,
         deref_method,
         deref_mut,
+        deref_patterns,
+        deref_pure,
         deref_target,
         derive,
         derive_const,
@@ -619,6 +634,7 @@
         destruct,
         destructuring_assignment,
         diagnostic,
+        diagnostic_namespace,
         direct,
         discriminant_kind,
         discriminant_type,
@@ -686,6 +702,7 @@
         expf32,
         expf64,
         explicit_generic_args_with_impl_trait,
+        explicit_tail_calls,
         export_name,
         expr,
         extended_key_value_attributes,
@@ -698,6 +715,8 @@
         extern_types,
         external_doc,
         f,
+        f128,
+        f16,
         f16c_target_feature,
         f32,
         f64,
@@ -767,6 +786,7 @@
         future_trait,
         gdb_script_file,
         ge,
+        gen_blocks,
         gen_future,
         gen_kill,
         generator,
@@ -845,6 +865,7 @@
         intra_doc_pointers,
         intrinsics,
         irrefutable_let_patterns,
+        is,
         isa_attribute,
         isize,
         issue,
@@ -854,6 +875,7 @@
         item_like_imports,
         iter,
         iter_repeat,
+        iterator,
         iterator_collect_fn,
         kcfi,
         keyword,
@@ -1098,7 +1120,12 @@
         pat,
         pat_param,
         path,
+        pattern_base,
         pattern_parentheses,
+        pattern_type,
+        pattern_type_contains,
+        pattern_type_trait,
+        pattern_types,
         phantom_data,
         pin,
         platform_intrinsics,
@@ -1477,6 +1504,8 @@
         target,
         target_abi,
         target_arch,
+        target_clones,
+        target_clones_features_detected,
         target_endian,
         target_env,
         target_family,
@@ -2068,6 +2097,7 @@ fn is_unused_keyword_always(self) -> bool {
 
     fn is_unused_keyword_conditional(self, edition: impl FnOnce() -> Edition) -> bool {
         self == kw::Try && edition() >= Edition::Edition2018
+            || self == kw::Gen && edition() >= Edition::Edition2024
     }
 
     pub fn is_reserved(self, edition: impl Copy + FnOnce() -> Edition) -> bool {
@@ -453,8 +453,10 @@ fn encode_ty<'tcx>(
                 ty::Uint(UintTy::U64) => "u3u64",
                 ty::Uint(UintTy::U128) => "u4u128",
                 ty::Uint(UintTy::Usize) => "u5usize",
+                ty::Float(FloatTy::F16) => "u3f16",
                 ty::Float(FloatTy::F32) => "u3f32",
                 ty::Float(FloatTy::F64) => "u3f64",
+                ty::Float(FloatTy::F128) => "u4f128",
                 _ => "",
             });
             compress(dict, DictKey::Ty(ty, TyQ::None), &mut s);
@@ -509,6 +511,11 @@ fn encode_ty<'tcx>(
             typeid.push_str(&s);
         }
 
+        ty::Pat(ty0, _) => {
+            // Pattern types have the same ABI as their base type.
+            typeid.push_str(&encode_ty(tcx, *ty0, dict, options));
+        }
+
         ty::Slice(ty0) => {
             // u5sliceI<element-type>E as vendor extended type
             let mut s = String::from("u5sliceI");
@@ -755,6 +762,10 @@ fn transform_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, options: TransformTyOptio
             ty = tcx.mk_array(transform_ty(tcx, *ty0, options), len);
         }
 
+        ty::Pat(ty0, _) => {
+            ty = transform_ty(tcx, *ty0, options);
+        }
+
         ty::Slice(ty0) => {
             ty = tcx.mk_slice(transform_ty(tcx, *ty0, options));
         }
@@ -362,8 +362,10 @@ fn print_type(mut self, ty: Ty<'tcx>) -> Result<Self::Type, Self::Error> {
             ty::Uint(UintTy::U64) => "y",
             ty::Uint(UintTy::U128) => "o",
             ty::Uint(UintTy::Usize) => "j",
+            ty::Float(FloatTy::F16) => "k",
             ty::Float(FloatTy::F32) => "f",
             ty::Float(FloatTy::F64) => "d",
+            ty::Float(FloatTy::F128) => "g",
             ty::Never => "z",
 
             // Placeholders (should be demangled as `_`).
@@ -422,6 +424,24 @@ fn print_type(mut self, ty: Ty<'tcx>) -> Result<Self::Type, Self::Error> {
                 self = ty.print(self)?;
             }
 
+            ty::Pat(ty, pat) => match *pat {
+                ty::PatternKind::Range { start, end, include_end } => {
+                    // HACK(pattern_types): v0 has no syntax for pattern types yet, so they are
+                    // mangled as a tuple of the base type and of arrays whose lengths are the
+                    // bounds of the range and whether it includes its end.
+                    let tcx = self.tcx;
+                    let start = start.unwrap_or_else(|| ty.numeric_min_val(tcx).unwrap());
+                    let end = end.unwrap_or_else(|| ty.numeric_max_val(tcx).unwrap());
+                    let include_end = ty::Const::from_bool(tcx, include_end);
+                    self.push("T");
+                    self = ty.print(self)?;
+                    for ct in [start, end, include_end] {
+                        self = tcx.mk_array_with_const_len(tcx.types.unit, ct).print(self)?;
+                    }
+                    self.push("E");
+                }
+            },
+
             ty::Tuple(tys) => {
                 self.push("T");
                 for ty in tys.iter() {
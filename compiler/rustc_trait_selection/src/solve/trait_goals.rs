@@ -295,6 +295,30 @@ fn consider_builtin_future_candidate(
         ecx.evaluate_added_goals_and_make_canonical_response(Certainty::Yes)
     }
 
+    fn consider_builtin_iterator_candidate(
+        ecx: &mut EvalCtxt<'_, 'tcx>,
+        goal: Goal<'tcx, Self>,
+    ) -> QueryResult<'tcx> {
+        if goal.predicate.polarity != ty::ImplPolarity::Positive {
+            return Err(NoSolution);
+        }
+
+        let ty::Generator(def_id, _, _) = *goal.predicate.self_ty().kind() else {
+            return Err(NoSolution);
+        };
+
+        // Generators are not iterators unless they come from `gen` desugaring
+        let tcx = ecx.tcx();
+        if !tcx.generator_is_iter(def_id) {
+            return Err(NoSolution);
+        }
+
+        // `gen` generators unconditionally implement `Iterator`
+        // Technically, we need to check that the iterator item type is Sized,
+        // but that's already proven by the generator being WF.
+        ecx.evaluate_added_goals_and_make_canonical_response(Certainty::Yes)
+    }
+
     fn consider_builtin_generator_candidate(
         ecx: &mut EvalCtxt<'_, 'tcx>,
         goal: Goal<'tcx, Self>,
@@ -308,9 +332,9 @@ fn consider_builtin_generator_candidate(
             return Err(NoSolution);
         };
 
-        // `async`-desugared generators do not implement the generator trait
+        // `async`- and `gen`-desugared generators do not implement the generator trait
         let tcx = ecx.tcx();
-        if tcx.generator_is_async(def_id) {
+        if tcx.generator_is_async(def_id) || tcx.generator_is_iter(def_id) {
             return Err(NoSolution);
         }
 
@@ -537,6 +561,21 @@ fn consider_builtin_discriminant_kind_candidate(
         ecx.evaluate_added_goals_and_make_canonical_response(Certainty::Yes)
     }
 
+    fn consider_builtin_pattern_type_candidate(
+        ecx: &mut EvalCtxt<'_, 'tcx>,
+        goal: Goal<'tcx, Self>,
+    ) -> QueryResult<'tcx> {
+        if goal.predicate.polarity != ty::ImplPolarity::Positive {
+            return Err(NoSolution);
+        }
+
+        if let ty::Pat(..) = goal.predicate.self_ty().kind() {
+            ecx.evaluate_added_goals_and_make_canonical_response(Certainty::Yes)
+        } else {
+            Err(NoSolution)
+        }
+    }
+
     fn consider_builtin_destruct_candidate(
         ecx: &mut EvalCtxt<'_, 'tcx>,
         goal: Goal<'tcx, Self>,
@@ -648,6 +687,7 @@ fn disqualify_auto_trait_candidate_due_to_possible_impl(
             | ty::Float(_)
             | ty::Str
             | ty::Array(_, _)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(_, _, _)
@@ -671,6 +671,7 @@ fn visit_ty(&mut self, ty: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
             | ty::FnDef(..)
             | ty::FnPtr(_)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(..)
             | ty::RawPtr(..)
             | ty::Never
@@ -1708,6 +1708,7 @@ fn type_category(tcx: TyCtxt<'_>, t: Ty<'_>) -> Option<u32> {
                 ty::Foreign(..) => Some(18),
                 ty::GeneratorWitness(..) => Some(19),
                 ty::GeneratorWitnessMIR(..) => Some(20),
+                ty::Pat(..) => Some(21),
                 ty::Placeholder(..) | ty::Bound(..) | ty::Infer(..) | ty::Error(_) => None,
             }
         }
@@ -1760,6 +1761,8 @@ fn describe_generator(&self, body_id: hir::BodyId) -> Option<&'static str> {
             hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block) => "an async block",
             hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Fn) => "an async function",
             hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Closure) => "an async closure",
+            hir::GeneratorKind::Iter(hir::IterGeneratorKind::Block) => "a gen block",
+            hir::GeneratorKind::Iter(hir::IterGeneratorKind::Fn) => "a gen function",
         })
     }
 
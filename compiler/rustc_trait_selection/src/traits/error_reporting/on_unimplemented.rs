@@ -8,13 +8,16 @@
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::SubstsRef;
 use rustc_middle::ty::{self, GenericParamDefKind, TyCtxt};
+use rustc_session::lint::builtin::UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES;
 use rustc_parse_format::{ParseMode, Parser, Piece, Position};
 use rustc_span::symbol::{kw, sym, Symbol};
 use rustc_span::{Span, DUMMY_SP};
 use std::iter;
 
 use crate::errors::{
-    EmptyOnClauseInOnUnimplemented, InvalidOnClauseInOnUnimplemented, NoValueInOnUnimplemented,
+    EmptyOnClauseInOnUnimplemented, InvalidOnClauseInOnUnimplemented,
+    DisallowedPositionalArgument, MalformedOnUnimplementedAttrLint, NoValueInOnUnimplemented,
+    UnknownFormatParameterForOnUnimplementedAttr,
 };
 
 use super::InferCtxtPrivExt;
@@ -317,7 +320,13 @@ fn on_unimplemented_note(
 }
 
 #[derive(Clone, Debug)]
-pub struct OnUnimplementedFormatString(Symbol);
+pub struct OnUnimplementedFormatString {
+    symbol: Symbol,
+    /// Whether the string comes from `#[diagnostic::on_unimplemented]`, in which case its
+    /// invalid format parameters are linted against instead of being errors, and are printed
+    /// as they are written.
+    is_diagnostic_namespace_variant: bool,
+}
 
 #[derive(Debug)]
 pub struct OnUnimplementedDirective {
@@ -330,7 +339,7 @@ pub struct OnUnimplementedDirective {
     pub append_const_msg: Option<AppendConstMessage>,
 }
 
-/// For the `#[rustc_on_unimplemented]` attribute
+/// For the `#[rustc_on_unimplemented]` and `#[diagnostic::on_unimplemented]` attributes
 #[derive(Default)]
 pub struct OnUnimplementedNote {
     pub message: Option<String>,
@@ -361,12 +370,20 @@ fn parse(
         items: &[NestedMetaItem],
         span: Span,
         is_root: bool,
+        is_diagnostic_namespace_variant: bool,
     ) -> Result<Self, ErrorGuaranteed> {
         let mut errored = None;
         let mut item_iter = items.iter();
 
         let parse_value = |value_str| {
-            OnUnimplementedFormatString::try_parse(tcx, item_def_id, value_str, span).map(Some)
+            OnUnimplementedFormatString::try_parse(
+                tcx,
+                item_def_id,
+                value_str,
+                span,
+                is_diagnostic_namespace_variant,
+            )
+            .map(Some)
         };
 
         let condition = if is_root {
@@ -409,7 +426,10 @@ fn parse(
                     note = parse_value(note_)?;
                     continue;
                 }
-            } else if item.has_name(sym::parent_label) && parent_label.is_none() {
+            } else if item.has_name(sym::parent_label)
+                && parent_label.is_none()
+                && !is_diagnostic_namespace_variant
+            {
                 if let Some(parent_label_) = item.value_str() {
                     parent_label = parse_value(parent_label_)?;
                     continue;
@@ -419,15 +439,19 @@ fn parse(
                 && message.is_none()
                 && label.is_none()
                 && note.is_none()
+                && !is_diagnostic_namespace_variant
             {
                 if let Some(items) = item.meta_item_list() {
-                    match Self::parse(tcx, item_def_id, &items, item.span(), false) {
+                    match Self::parse(tcx, item_def_id, &items, item.span(), false, false) {
                         Ok(subcommand) => subcommands.push(subcommand),
                         Err(reported) => errored = Some(reported),
                     };
                     continue;
                 }
-            } else if item.has_name(sym::append_const_msg) && append_const_msg.is_none() {
+            } else if item.has_name(sym::append_const_msg)
+                && append_const_msg.is_none()
+                && !is_diagnostic_namespace_variant
+            {
                 if let Some(msg) = item.value_str() {
                     append_const_msg = Some(AppendConstMessage::Custom(msg));
                     continue;
@@ -438,7 +462,11 @@ fn parse(
             }
 
             // nothing found
-            tcx.sess.emit_err(NoValueInOnUnimplemented { span: item.span() });
+            if is_diagnostic_namespace_variant {
+                Self::lint_malformed(tcx, item_def_id, item.span());
+            } else {
+                tcx.sess.emit_err(NoValueInOnUnimplemented { span: item.span() });
+            }
         }
 
         if let Some(reported) = errored {
@@ -458,11 +486,11 @@ fn parse(
 
     pub fn of_item(tcx: TyCtxt<'tcx>, item_def_id: DefId) -> Result<Option<Self>, ErrorGuaranteed> {
         let Some(attr) = tcx.get_attr(item_def_id, sym::rustc_on_unimplemented) else {
-            return Ok(None);
+            return Self::of_diagnostic_namespace_attrs(tcx, item_def_id);
         };
 
         let result = if let Some(items) = attr.meta_item_list() {
-            Self::parse(tcx, item_def_id, &items, attr.span, true).map(Some)
+            Self::parse(tcx, item_def_id, &items, attr.span, true, false).map(Some)
         } else if let Some(value) = attr.value_str() {
             Ok(Some(OnUnimplementedDirective {
                 condition: None,
@@ -473,6 +501,7 @@ pub fn of_item(tcx: TyCtxt<'tcx>, item_def_id: DefId) -> Result<Option<Self>, Er
                     item_def_id,
                     value,
                     attr.span,
+                    false,
                 )?),
                 note: None,
                 parent_label: None,
@@ -487,6 +516,47 @@ pub fn of_item(tcx: TyCtxt<'tcx>, item_def_id: DefId) -> Result<Option<Self>, Er
         result
     }
 
+    /// Parses all `#[diagnostic::on_unimplemented]` attributes of the item as one. Only
+    /// `message`, `label` and `note` are supported, and anything else is linted against
+    /// instead of being an error.
+    fn of_diagnostic_namespace_attrs(
+        tcx: TyCtxt<'tcx>,
+        item_def_id: DefId,
+    ) -> Result<Option<Self>, ErrorGuaranteed> {
+        // The attribute is linted against on anything but a trait, and ignored there.
+        if !tcx.is_trait(item_def_id) {
+            return Ok(None);
+        }
+        let mut items = vec![];
+        let mut span = None;
+        for attr in tcx.get_attrs_by_path(item_def_id, &[sym::diagnostic, sym::on_unimplemented]) {
+            span.get_or_insert(attr.span);
+            match attr.meta_item_list() {
+                Some(list) => items.extend(list),
+                None => Self::lint_malformed(tcx, item_def_id, attr.span),
+            }
+        }
+        let Some(span) = span else {
+            return Ok(None);
+        };
+
+        let result = Self::parse(tcx, item_def_id, &items, span, true, true).map(Some);
+        debug!("of_diagnostic_namespace_attrs({:?}) = {:?}", item_def_id, result);
+        result
+    }
+
+    fn lint_malformed(tcx: TyCtxt<'tcx>, item_def_id: DefId, span: Span) {
+        // Attributes of foreign items were already linted against in their own crate.
+        if let Some(item_def_id) = item_def_id.as_local() {
+            tcx.emit_spanned_lint(
+                UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                tcx.hir().local_def_id_to_hir_id(item_def_id),
+                span,
+                MalformedOnUnimplementedAttrLint { span },
+            );
+        }
+    }
+
     pub fn evaluate(
         &self,
         tcx: TyCtxt<'tcx>,
@@ -510,7 +580,11 @@ pub fn evaluate(
                 Some(tcx.features()),
                 &mut |cfg| {
                     let value = cfg.value.map(|v| {
-                        OnUnimplementedFormatString(v).format(tcx, trait_ref, &options_map)
+                        OnUnimplementedFormatString {
+                            symbol: v,
+                            is_diagnostic_namespace_variant: false,
+                        }
+                        .format(tcx, trait_ref, &options_map)
                     });
 
                     options.contains(&(cfg.name, value))
@@ -555,8 +629,9 @@ fn try_parse(
         item_def_id: DefId,
         from: Symbol,
         err_sp: Span,
+        is_diagnostic_namespace_variant: bool,
     ) -> Result<Self, ErrorGuaranteed> {
-        let result = OnUnimplementedFormatString(from);
+        let result = OnUnimplementedFormatString { symbol: from, is_diagnostic_namespace_variant };
         result.verify(tcx, item_def_id, err_sp)?;
         Ok(result)
     }
@@ -575,7 +650,7 @@ fn verify(
         };
         let trait_name = tcx.item_name(trait_def_id);
         let generics = tcx.generics_of(item_def_id);
-        let s = self.0.as_str();
+        let s = self.symbol.as_str();
         let parser = Parser::new(s, None, None, false, ParseMode::Format);
         let mut result = Ok(());
         for token in parser {
@@ -589,6 +664,19 @@ fn verify(
                             s if ALLOWED_FORMAT_SYMBOLS.contains(&s) => (),
                             // So is `{A}` if A is a type parameter
                             s if generics.params.iter().any(|param| param.name == s) => (),
+                            s if self.is_diagnostic_namespace_variant => {
+                                if let Some(item_def_id) = item_def_id.as_local() {
+                                    tcx.emit_spanned_lint(
+                                        UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                                        tcx.hir().local_def_id_to_hir_id(item_def_id),
+                                        span,
+                                        UnknownFormatParameterForOnUnimplementedAttr {
+                                            argument_name: s,
+                                            trait_name,
+                                        },
+                                    );
+                                }
+                            }
                             s => {
                                 result = Err(struct_span_err!(
                                     tcx.sess,
@@ -607,6 +695,18 @@ fn verify(
                         }
                     }
                     // `{:1}` and `{}` are not to be used
+                    Position::ArgumentIs(..) | Position::ArgumentImplicitlyIs(_)
+                        if self.is_diagnostic_namespace_variant =>
+                    {
+                        if let Some(item_def_id) = item_def_id.as_local() {
+                            tcx.emit_spanned_lint(
+                                UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                                tcx.hir().local_def_id_to_hir_id(item_def_id),
+                                span,
+                                DisallowedPositionalArgument,
+                            );
+                        }
+                    }
                     Position::ArgumentIs(..) | Position::ArgumentImplicitlyIs(_) => {
                         let reported = struct_span_err!(
                             tcx.sess,
@@ -649,47 +749,60 @@ pub fn format(
             .collect::<FxHashMap<Symbol, String>>();
         let empty_string = String::new();
 
-        let s = self.0.as_str();
+        let s = self.symbol.as_str();
         let parser = Parser::new(s, None, None, false, ParseMode::Format);
         let item_context = (options.get(&sym::ItemContext)).unwrap_or(&empty_string);
-        parser
-            .map(|p| match p {
-                Piece::String(s) => s,
-                Piece::NextArgument(a) => match a.position {
-                    Position::ArgumentNamed(s) => {
-                        let s = Symbol::intern(s);
-                        match generic_map.get(&s) {
-                            Some(val) => val,
-                            None if s == name => &trait_str,
-                            None => {
-                                if let Some(val) = options.get(&s) {
-                                    val
-                                } else if s == sym::from_desugaring || s == sym::from_method {
-                                    // don't break messages using these two arguments incorrectly
-                                    &empty_string
-                                } else if s == sym::ItemContext {
-                                    &item_context
-                                } else if s == sym::integral {
-                                    "{integral}"
-                                } else if s == sym::integer_ {
-                                    "{integer}"
-                                } else if s == sym::float {
-                                    "{float}"
-                                } else {
-                                    bug!(
-                                        "broken on_unimplemented {:?} for {:?}: \
-                                      no argument matching {:?}",
-                                        self.0,
-                                        trait_ref,
-                                        s
-                                    )
-                                }
+        let mut result = String::new();
+        for piece in parser {
+            let a = match piece {
+                Piece::String(s) => {
+                    result.push_str(s);
+                    continue;
+                }
+                Piece::NextArgument(a) => a,
+            };
+            let value: &str = match a.position {
+                Position::ArgumentNamed(s) => {
+                    let s = Symbol::intern(s);
+                    match generic_map.get(&s) {
+                        Some(val) => val,
+                        None if s == name => &trait_str,
+                        None => {
+                            if let Some(val) = options.get(&s) {
+                                val
+                            } else if s == sym::from_desugaring || s == sym::from_method {
+                                // don't break messages using these two arguments incorrectly
+                                &empty_string
+                            } else if s == sym::ItemContext {
+                                &item_context
+                            } else if s == sym::integral {
+                                "{integral}"
+                            } else if s == sym::integer_ {
+                                "{integer}"
+                            } else if s == sym::float {
+                                "{float}"
+                            } else if self.is_diagnostic_namespace_variant {
+                                // This was linted against when parsing the attribute.
+                                result.push_str(&format!("{{{s}}}"));
+                                continue;
+                            } else {
+                                bug!(
+                                    "broken on_unimplemented {:?} for {:?}: \
+                                  no argument matching {:?}",
+                                    self.symbol,
+                                    trait_ref,
+                                    s
+                                )
                             }
                         }
                     }
-                    _ => bug!("broken on_unimplemented {:?} - bad format arg", self.0),
-                },
-            })
-            .collect()
+                }
+                // This was linted against when parsing the attribute.
+                _ if self.is_diagnostic_namespace_variant => "{}",
+                _ => bug!("broken on_unimplemented {:?} - bad format arg", self.symbol),
+            };
+            result.push_str(value);
+        }
+        result
     }
 }
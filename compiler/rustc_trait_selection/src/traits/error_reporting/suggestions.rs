@@ -21,7 +21,7 @@
 use rustc_hir::intravisit::Visitor;
 use rustc_hir::is_range_literal;
 use rustc_hir::lang_items::LangItem;
-use rustc_hir::{AsyncGeneratorKind, GeneratorKind, Node};
+use rustc_hir::{AsyncGeneratorKind, GeneratorKind, IterGeneratorKind, Node};
 use rustc_hir::{Expr, HirId};
 use rustc_infer::infer::error_reporting::TypeErrCtxt;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
@@ -2560,6 +2560,18 @@ fn note_obligation_cause_for_async_await(
                         GeneratorKind::Async(AsyncGeneratorKind::Closure) => {
                             format!("future created by async closure is not {}", trait_name)
                         }
+                        GeneratorKind::Iter(IterGeneratorKind::Fn) => self
+                            .tcx
+                            .parent(generator_did)
+                            .as_local()
+                            .map(|parent_did| hir.local_def_id_to_hir_id(parent_did))
+                            .and_then(|parent_hir_id| hir.opt_name(parent_hir_id))
+                            .map(|name| {
+                                format!("iterator returned by `{}` is not {}", name, trait_name)
+                            })?,
+                        GeneratorKind::Iter(IterGeneratorKind::Block) => {
+                            format!("iterator created by gen block is not {}", trait_name)
+                        }
                     })
                 })
                 .unwrap_or_else(|| format!("{} is not {}", future_or_generator, trait_name));
@@ -101,6 +101,8 @@ pub(super) fn assemble_candidates<'o>(
                 self.assemble_candidate_for_pointer_like(obligation, &mut candidates);
             } else if lang_items.fn_ptr_trait() == Some(def_id) {
                 self.assemble_candidates_for_fn_ptr_trait(obligation, &mut candidates);
+            } else if lang_items.pattern_type_trait() == Some(def_id) {
+                self.assemble_candidates_for_pattern_type_trait(obligation, &mut candidates);
             } else {
                 if lang_items.clone_trait() == Some(def_id) {
                     // Same builtin conditions as `Copy`, i.e., every type which has builtin support
@@ -114,6 +116,8 @@ pub(super) fn assemble_candidates<'o>(
                     self.assemble_generator_candidates(obligation, &mut candidates);
                 } else if lang_items.future_trait() == Some(def_id) {
                     self.assemble_future_candidates(obligation, &mut candidates);
+                } else if lang_items.iterator_trait() == Some(def_id) {
+                    self.assemble_iterator_candidates(obligation, &mut candidates);
                 }
 
                 self.assemble_closure_candidates(obligation, &mut candidates);
@@ -213,9 +217,11 @@ fn assemble_generator_candidates(
         // type/region parameters.
         let self_ty = obligation.self_ty().skip_binder();
         match self_ty.kind() {
-            // async constructs get lowered to a special kind of generator that
+            // async and `gen` constructs get lowered to a special kind of generator that
             // should *not* `impl Generator`.
-            ty::Generator(did, ..) if !self.tcx().generator_is_async(*did) => {
+            ty::Generator(did, ..)
+                if !self.tcx().generator_is_async(*did) && !self.tcx().generator_is_iter(*did) =>
+            {
                 debug!(?self_ty, ?obligation, "assemble_generator_candidates",);
 
                 candidates.vec.push(GeneratorCandidate);
@@ -245,6 +251,23 @@ fn assemble_future_candidates(
         }
     }
 
+    fn assemble_iterator_candidates(
+        &mut self,
+        obligation: &TraitObligation<'tcx>,
+        candidates: &mut SelectionCandidateSet<'tcx>,
+    ) {
+        let self_ty = obligation.self_ty().skip_binder();
+        if let ty::Generator(did, ..) = self_ty.kind() {
+            // `gen` constructs get lowered to a special kind of generator that
+            // should directly `impl Iterator`.
+            if self.tcx().generator_is_iter(*did) {
+                debug!(?self_ty, ?obligation, "assemble_iterator_candidates",);
+
+                candidates.vec.push(IteratorCandidate);
+            }
+        }
+    }
+
     /// Checks for the artificial impl that the compiler will create for an obligation like `X :
     /// FnMut<..>` where `X` is a closure type.
     ///
@@ -436,6 +459,7 @@ fn reject_fn_ptr_impls(
                 | ty::Foreign(_)
                 | ty::Str
                 | ty::Array(_, _)
+                | ty::Pat(..)
                 | ty::Slice(_)
                 | ty::RawPtr(_)
                 | ty::Ref(_, _, _)
@@ -869,6 +893,7 @@ fn assemble_const_destruct_candidates(
             | ty::Never
             | ty::Foreign(_)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::Closure(..)
             | ty::Generator(..)
@@ -939,6 +964,7 @@ fn assemble_candidate_for_tuple(
             | ty::Foreign(_)
             | ty::Str
             | ty::Array(_, _)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(_, _, _)
@@ -999,6 +1025,7 @@ fn assemble_candidates_for_fn_ptr_trait(
             | ty::Foreign(..)
             | ty::Str
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Slice(_)
             | ty::RawPtr(_)
             | ty::Ref(..)
@@ -1026,4 +1053,20 @@ fn assemble_candidates_for_fn_ptr_trait(
             }
         }
     }
+
+    /// `PatternType` is automatically implemented for every pattern type, and only for them.
+    fn assemble_candidates_for_pattern_type_trait(
+        &mut self,
+        obligation: &TraitObligation<'tcx>,
+        candidates: &mut SelectionCandidateSet<'tcx>,
+    ) {
+        let self_ty = self.infcx.shallow_resolve(obligation.self_ty());
+        match self_ty.skip_binder().kind() {
+            ty::Pat(..) => candidates.vec.push(BuiltinCandidate { has_nested: false }),
+            ty::Infer(ty::InferTy::TyVar(_) | ty::InferTy::FreshTy(_)) => {
+                candidates.ambiguous = true;
+            }
+            _ => {}
+        }
+    }
 }
@@ -1423,6 +1423,7 @@ fn filter_impls(
                     // or ignore error with const_async_blocks feature
                     | GeneratorCandidate
                     | FutureCandidate
+                    | IteratorCandidate
                     // FnDef where the function is const
                     | FnPointerCandidate { is_const: true }
                     | ConstDestructCandidate(_)
@@ -1910,6 +1911,7 @@ fn candidate_should_be_dropped_in_favor_of(
                 | ClosureCandidate { .. }
                 | GeneratorCandidate
                 | FutureCandidate
+                | IteratorCandidate
                 | FnPointerCandidate { .. }
                 | BuiltinObjectCandidate
                 | BuiltinUnsizeCandidate
@@ -1937,6 +1939,7 @@ fn candidate_should_be_dropped_in_favor_of(
                 | ClosureCandidate { .. }
                 | GeneratorCandidate
                 | FutureCandidate
+                | IteratorCandidate
                 | FnPointerCandidate { .. }
                 | BuiltinObjectCandidate
                 | BuiltinUnsizeCandidate
@@ -1970,6 +1973,7 @@ fn candidate_should_be_dropped_in_favor_of(
                 | ClosureCandidate { .. }
                 | GeneratorCandidate
                 | FutureCandidate
+                | IteratorCandidate
                 | FnPointerCandidate { .. }
                 | BuiltinObjectCandidate
                 | BuiltinUnsizeCandidate
@@ -1983,6 +1987,7 @@ fn candidate_should_be_dropped_in_favor_of(
                 | ClosureCandidate { .. }
                 | GeneratorCandidate
                 | FutureCandidate
+                | IteratorCandidate
                 | FnPointerCandidate { .. }
                 | BuiltinObjectCandidate
                 | BuiltinUnsizeCandidate
@@ -2076,6 +2081,7 @@ fn candidate_should_be_dropped_in_favor_of(
                 | ClosureCandidate { .. }
                 | GeneratorCandidate
                 | FutureCandidate
+                | IteratorCandidate
                 | FnPointerCandidate { .. }
                 | BuiltinObjectCandidate
                 | BuiltinUnsizeCandidate
@@ -2086,6 +2092,7 @@ fn candidate_should_be_dropped_in_favor_of(
                 | ClosureCandidate { .. }
                 | GeneratorCandidate
                 | FutureCandidate
+                | IteratorCandidate
                 | FnPointerCandidate { .. }
                 | BuiltinObjectCandidate
                 | BuiltinUnsizeCandidate
@@ -2122,6 +2129,7 @@ fn sized_conditions(
             | ty::GeneratorWitness(..)
             | ty::GeneratorWitnessMIR(..)
             | ty::Array(..)
+            | ty::Pat(..)
             | ty::Closure(..)
             | ty::Never
             | ty::Dynamic(_, _, ty::DynStar)
@@ -2201,6 +2209,11 @@ fn copy_clone_conditions(
                 Where(obligation.predicate.rebind(tys.iter().collect()))
             }
 
+            ty::Pat(ty, _) => {
+                // Pattern types are `Copy` and `Clone` like their base integer type.
+                Where(obligation.predicate.rebind(vec![*ty]))
+            }
+
             ty::Generator(_, substs, hir::Movability::Movable) => {
                 if self.tcx().features().generator_clone {
                     let resolved_upvars =
@@ -2325,7 +2338,9 @@ fn constituent_types_for_ty(
                 t.rebind(vec![element_ty])
             }
 
-            ty::Array(element_ty, _) | ty::Slice(element_ty) => t.rebind(vec![element_ty]),
+            ty::Array(element_ty, _) | ty::Pat(element_ty, _) | ty::Slice(element_ty) => {
+                t.rebind(vec![element_ty])
+            }
 
             ty::Tuple(ref tys) => {
                 // (T1, ..., Tn) -- meets any bound that all of T1...Tn meet
@@ -98,7 +98,7 @@ fn visit_ty(&mut self, ty: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
             ty::Alias(..) => {
                 return ControlFlow::Break(ty);
             }
-            ty::Closure(..) => {
+            ty::Closure(..) | ty::Pat(..) => {
                 return ControlFlow::Break(ty);
             }
             ty::Generator(..) | ty::GeneratorWitness(..) | ty::GeneratorWitnessMIR(..) => {
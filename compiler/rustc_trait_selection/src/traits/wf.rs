@@ -613,6 +613,11 @@ fn compute(&mut self, arg: GenericArg<'tcx>) {
                     // Note that we handle the len is implicitly checked while walking `arg`.
                 }
 
+                ty::Pat(..) => {
+                    // The base type and the range were checked when lowering the pattern type,
+                    // and the constants in the range are walked as part of `arg`.
+                }
+
                 ty::Tuple(ref tys) => {
                     if let Some((_last, rest)) = tys.split_last() {
                         for &elem in rest {
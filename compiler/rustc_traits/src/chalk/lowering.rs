@@ -312,6 +312,11 @@ fn lower_into(self, interner: RustInterner<'tcx>) -> chalk_ir::Ty<RustInterner<'
                 ty::UintTy::U128 => uint(chalk_ir::UintTy::U128),
             },
             ty::Float(ty) => match ty {
+                // Chalk has no scalar types for half and quad precision floats.
+                ty::FloatTy::F16 | ty::FloatTy::F128 => interner.tcx.sess.fatal(format!(
+                    "`{}` is not supported by the chalk trait solver",
+                    ty.name_str()
+                )),
                 ty::FloatTy::F32 => float(chalk_ir::FloatTy::F32),
                 ty::FloatTy::F64 => float(chalk_ir::FloatTy::F64),
             },
@@ -364,6 +369,7 @@ fn lower_into(self, interner: RustInterner<'tcx>) -> chalk_ir::Ty<RustInterner<'
             ),
             ty::GeneratorWitness(_) => unimplemented!(),
             ty::GeneratorWitnessMIR(..) => unimplemented!(),
+            ty::Pat(..) => unimplemented!(),
             ty::Never => chalk_ir::TyKind::Never,
             ty::Tuple(types) => chalk_ir::TyKind::Tuple(types.len(), types.lower_into(interner)),
             ty::Alias(ty::Projection, ty::AliasTy { def_id, substs, .. }) => {
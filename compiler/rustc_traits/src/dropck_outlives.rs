@@ -164,6 +164,7 @@ fn dtorck_constraint_for_ty<'tcx>(
         | ty::Ref(..)
         | ty::FnDef(..)
         | ty::FnPtr(_)
+        | ty::Pat(..)
         | ty::GeneratorWitness(..)
         | ty::GeneratorWitnessMIR(..) => {
             // these types never have a destructor
@@ -107,16 +107,24 @@ fn fn_sig_for_fn_abi<'tcx>(
             };
             let env_ty = tcx.mk_mut_ref(tcx.mk_re_late_bound(ty::INNERMOST, br), ty);
 
-            let pin_did = tcx.require_lang_item(LangItem::Pin, None);
-            let pin_adt_ref = tcx.adt_def(pin_did);
-            let pin_substs = tcx.mk_substs(&[env_ty.into()]);
-            let env_ty = tcx.mk_adt(pin_adt_ref, pin_substs);
+            // `Iterator::next` doesn't take a pinned `self`, unlike the resume functions of all
+            // other generator kinds.
+            let env_ty = if tcx.generator_is_iter(did) {
+                env_ty
+            } else {
+                let pin_did = tcx.require_lang_item(LangItem::Pin, None);
+                let pin_adt_ref = tcx.adt_def(pin_did);
+                let pin_substs = tcx.mk_substs(&[env_ty.into()]);
+                tcx.mk_adt(pin_adt_ref, pin_substs)
+            };
 
             let sig = sig.skip_binder();
             // The `FnSig` and the `ret_ty` here is for a generators main
             // `Generator::resume(...) -> GeneratorState` function in case we
-            // have an ordinary generator, or the `Future::poll(...) -> Poll`
-            // function in case this is a special generator backing an async construct.
+            // have an ordinary generator, the `Future::poll(...) -> Poll`
+            // function in case this is a special generator backing an async construct,
+            // or the `Iterator::next(...) -> Option` function in case this is a special
+            // generator backing a `gen` construct.
             let (resume_ty, ret_ty) = if tcx.generator_is_async(did) {
                 // The signature should be `Future::poll(_, &mut Context<'_>) -> Poll<Output>`
                 let poll_did = tcx.require_lang_item(LangItem::Poll, None);
@@ -138,7 +146,18 @@ fn fn_sig_for_fn_abi<'tcx>(
                 }
                 let context_mut_ref = tcx.mk_task_context();
 
-                (context_mut_ref, ret_ty)
+                (Some(context_mut_ref), ret_ty)
+            } else if tcx.generator_is_iter(did) {
+                // The signature should be `Iterator::next(_) -> Option<Yield>`
+                let option_did = tcx.require_lang_item(LangItem::Option, None);
+                let option_adt_ref = tcx.adt_def(option_did);
+                let option_substs = tcx.mk_substs(&[sig.yield_ty.into()]);
+                let ret_ty = tcx.mk_adt(option_adt_ref, option_substs);
+
+                assert_eq!(sig.return_ty, tcx.mk_unit());
+                assert_eq!(sig.resume_ty, tcx.mk_unit());
+
+                (None, ret_ty)
             } else {
                 // The signature should be `Generator::resume(_, Resume) -> GeneratorState<Yield, Return>`
                 let state_did = tcx.require_lang_item(LangItem::GeneratorState, None);
@@ -146,12 +165,14 @@ fn fn_sig_for_fn_abi<'tcx>(
                 let state_substs = tcx.mk_substs(&[sig.yield_ty.into(), sig.return_ty.into()]);
                 let ret_ty = tcx.mk_adt(state_adt_ref, state_substs);
 
-                (sig.resume_ty, ret_ty)
+                (Some(sig.resume_ty), ret_ty)
             };
 
+            // `Iterator::next` doesn't have a resume argument.
+            let inputs = iter::once(env_ty).chain(resume_ty);
             ty::Binder::bind_with_vars(
                 tcx.mk_fn_sig(
-                    [env_ty, resume_ty],
+                    inputs,
                     ret_ty,
                     false,
                     hir::Unsafety::Normal,
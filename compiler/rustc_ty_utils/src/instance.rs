@@ -207,6 +207,20 @@ fn resolve_associated_item<'tcx>(
                 Some(Instance::new(trait_item_id, rcvr_substs))
             }
         }
+        traits::ImplSource::Iterator(iterator_data) => {
+            if Some(trait_item_id) == tcx.lang_items().next_fn() {
+                // `Iterator::next` is generated by the compiler.
+                Some(Instance {
+                    def: ty::InstanceDef::Item(iterator_data.generator_def_id),
+                    substs: iterator_data.substs,
+                })
+            } else {
+                // All other methods are default methods of the `Iterator` trait.
+                // (this assumes that `ImplSource::Iterator` is only used for methods on `Iterator`)
+                debug_assert!(tcx.impl_defaultness(trait_item_id).has_value());
+                Some(Instance::new(trait_item_id, rcvr_substs))
+            }
+        }
         traits::ImplSource::Closure(closure_data) => {
             if cfg!(debug_assertions)
                 && ![sym::call, sym::call_mut, sym::call_once]
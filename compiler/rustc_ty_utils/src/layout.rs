@@ -120,9 +120,38 @@ fn layout_of_uncached<'tcx>(
         ty::Int(ity) => scalar(Int(Integer::from_int_ty(dl, ity), true)),
         ty::Uint(ity) => scalar(Int(Integer::from_uint_ty(dl, ity), false)),
         ty::Float(fty) => scalar(match fty {
+            ty::FloatTy::F16 => F16,
             ty::FloatTy::F32 => F32,
             ty::FloatTy::F64 => F64,
+            ty::FloatTy::F128 => F128,
         }),
+        // Pattern types restrict the valid range of their base integer, which gives them a niche
+        // just like `#[rustc_layout_scalar_valid_range_start]` does for `NonZeroU32`.
+        ty::Pat(base, pat) => {
+            let Abi::Scalar(mut scalar) = cx.layout_of(base)?.abi else {
+                bug!("pattern type with non-scalar base type `{}`", base)
+            };
+            let (size, signed) = base.int_size_and_signed(tcx);
+            match *pat {
+                ty::PatternKind::Range { start, end, include_end } => {
+                    let start = match start {
+                        Some(start) => start.eval_bits(tcx, param_env, base),
+                        None if signed => size.truncate(size.signed_int_min() as u128),
+                        None => 0,
+                    };
+                    let end = match end {
+                        Some(end) => {
+                            let end = end.eval_bits(tcx, param_env, base);
+                            if include_end { end } else { size.truncate(end.wrapping_sub(1)) }
+                        }
+                        None if signed => size.signed_int_max() as u128,
+                        None => size.unsigned_int_max(),
+                    };
+                    *scalar.valid_range_mut() = WrappingRange { start, end };
+                }
+            }
+            tcx.mk_layout(LayoutS::scalar(cx, scalar))
+        }
         ty::FnPtr(_) => {
             let mut ptr = scalar_unit(Pointer(dl.instruction_address_space));
             ptr.valid_range_mut().start = 1;
@@ -81,6 +81,12 @@ pub enum TyKind<I: Interner> {
     /// An array with the given length. Written as `[T; N]`.
     Array(I::Ty, I::Const),
 
+    /// A pattern type, the values of a type that match a pattern.
+    /// Written as `pattern_type!(u32 is 1..=100)`.
+    ///
+    /// Only integer types restricted by a range pattern are supported for now.
+    Pat(I::Ty, I::Pat),
+
     /// The pointee of an array slice. Written as `[T]`.
     Slice(I::Ty),
 
@@ -277,6 +283,7 @@ const fn tykind_discriminant<I: Interner>(value: &TyKind<I>) -> usize {
         Infer(_) => 24,
         Error(_) => 25,
         GeneratorWitnessMIR(_, _) => 26,
+        Pat(_, _) => 27,
     }
 }
 
@@ -293,6 +300,7 @@ fn clone(&self) -> Self {
             Foreign(d) => Foreign(d.clone()),
             Str => Str,
             Array(t, c) => Array(t.clone(), c.clone()),
+            Pat(t, p) => Pat(t.clone(), p.clone()),
             Slice(t) => Slice(t.clone()),
             RawPtr(t) => RawPtr(t.clone()),
             Ref(r, t, m) => Ref(r.clone(), t.clone(), m.clone()),
@@ -332,6 +340,7 @@ fn eq(&self, other: &TyKind<I>) -> bool {
             (Adt(a_d, a_s), Adt(b_d, b_s)) => a_d == b_d && a_s == b_s,
             (Foreign(a_d), Foreign(b_d)) => a_d == b_d,
             (Array(a_t, a_c), Array(b_t, b_c)) => a_t == b_t && a_c == b_c,
+            (Pat(a_t, a_p), Pat(b_t, b_p)) => a_t == b_t && a_p == b_p,
             (Slice(a_t), Slice(b_t)) => a_t == b_t,
             (RawPtr(a_t), RawPtr(b_t)) => a_t == b_t,
             (Ref(a_r, a_t, a_m), Ref(b_r, b_t, b_m)) => a_r == b_r && a_t == b_t && a_m == b_m,
@@ -390,6 +399,7 @@ fn cmp(&self, other: &TyKind<I>) -> Ordering {
                 (Adt(a_d, a_s), Adt(b_d, b_s)) => a_d.cmp(b_d).then_with(|| a_s.cmp(b_s)),
                 (Foreign(a_d), Foreign(b_d)) => a_d.cmp(b_d),
                 (Array(a_t, a_c), Array(b_t, b_c)) => a_t.cmp(b_t).then_with(|| a_c.cmp(b_c)),
+                (Pat(a_t, a_p), Pat(b_t, b_p)) => a_t.cmp(b_t).then_with(|| a_p.cmp(b_p)),
                 (Slice(a_t), Slice(b_t)) => a_t.cmp(b_t),
                 (RawPtr(a_t), RawPtr(b_t)) => a_t.cmp(b_t),
                 (Ref(a_r, a_t, a_m), Ref(b_r, b_t, b_m)) => {
@@ -446,6 +456,10 @@ fn hash<__H: hash::Hasher>(&self, state: &mut __H) -> () {
                 t.hash(state);
                 c.hash(state)
             }
+            Pat(t, p) => {
+                t.hash(state);
+                p.hash(state)
+            }
             Slice(t) => t.hash(state),
             RawPtr(t) => t.hash(state),
             Ref(r, t, m) => {
@@ -508,6 +522,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Foreign(d) => f.debug_tuple_field1_finish("Foreign", d),
             Str => f.write_str("Str"),
             Array(t, c) => f.debug_tuple_field2_finish("Array", t, c),
+            Pat(t, p) => f.debug_tuple_field2_finish("Pat", t, p),
             Slice(t) => f.debug_tuple_field1_finish("Slice", t),
             RawPtr(t) => f.debug_tuple_field1_finish("RawPtr", t),
             Ref(r, t, m) => f.debug_tuple_field3_finish("Ref", r, t, m),
@@ -539,6 +554,7 @@ impl<I: Interner, E: TyEncoder> Encodable<E> for TyKind<I>
     I::DefId: Encodable<E>,
     I::Ty: Encodable<E>,
     I::Const: Encodable<E>,
+    I::Pat: Encodable<E>,
     I::Region: Encodable<E>,
     I::TypeAndMut: Encodable<E>,
     I::Mutability: Encodable<E>,
@@ -581,6 +597,10 @@ fn encode(&self, e: &mut E) {
                 t.encode(e);
                 c.encode(e);
             }),
+            Pat(t, p) => e.emit_enum_variant(disc, |e| {
+                t.encode(e);
+                p.encode(e);
+            }),
             Slice(t) => e.emit_enum_variant(disc, |e| {
                 t.encode(e);
             }),
@@ -657,6 +677,7 @@ impl<I: Interner, D: TyDecoder<I = I>> Decodable<D> for TyKind<I>
     I::DefId: Decodable<D>,
     I::Ty: Decodable<D>,
     I::Const: Decodable<D>,
+    I::Pat: Decodable<D>,
     I::Region: Decodable<D>,
     I::TypeAndMut: Decodable<D>,
     I::Mutability: Decodable<D>,
@@ -703,11 +724,12 @@ fn decode(d: &mut D) -> Self {
             24 => Infer(Decodable::decode(d)),
             25 => Error(Decodable::decode(d)),
             26 => GeneratorWitnessMIR(Decodable::decode(d), Decodable::decode(d)),
+            27 => Pat(Decodable::decode(d), Decodable::decode(d)),
             _ => panic!(
                 "{}",
                 format!(
                     "invalid enum variant tag while decoding `{}`, expected 0..{}",
-                    "TyKind", 27,
+                    "TyKind", 28,
                 )
             ),
         }
@@ -723,6 +745,7 @@ impl<CTX: HashStableContext, I: Interner> HashStable<CTX> for TyKind<I>
     I::SubstsRef: HashStable<CTX>,
     I::Ty: HashStable<CTX>,
     I::Const: HashStable<CTX>,
+    I::Pat: HashStable<CTX>,
     I::TypeAndMut: HashStable<CTX>,
     I::PolyFnSig: HashStable<CTX>,
     I::ListBinderExistentialPredicate: HashStable<CTX>,
@@ -769,6 +792,10 @@ fn hash_stable(
                 t.hash_stable(__hcx, __hasher);
                 c.hash_stable(__hcx, __hasher);
             }
+            Pat(t, p) => {
+                t.hash_stable(__hcx, __hasher);
+                p.hash_stable(__hcx, __hasher);
+            }
             Slice(t) => {
                 t.hash_stable(__hcx, __hasher);
             }
@@ -319,12 +319,29 @@ unsafe fn shrink(
 #[cfg(all(not(no_global_oom_handling), not(test)))]
 #[lang = "exchange_malloc"]
 #[inline]
-unsafe fn exchange_malloc(size: usize, align: usize) -> *mut u8 {
-    let layout = unsafe { Layout::from_size_align_unchecked(size, align) };
-    match Global.allocate(layout) {
-        Ok(ptr) => ptr.as_mut_ptr(),
-        Err(_) => handle_alloc_error(layout),
+const unsafe fn exchange_malloc(size: usize, align: usize) -> *mut u8 {
+    // At compile time, the memory comes from the interpreter's heap. A `Box` can't be dropped
+    // during const evaluation, so that memory is never handed to `Global` at runtime.
+    const fn ct_exchange_malloc(size: usize, align: usize) -> *mut u8 {
+        if size == 0 {
+            // Like `Global`, don't allocate for zero-sized types.
+            ptr::invalid_mut(align)
+        } else {
+            unsafe { intrinsics::const_allocate(size, align) }
+        }
     }
+
+    #[inline]
+    fn rt_exchange_malloc(size: usize, align: usize) -> *mut u8 {
+        let layout = unsafe { Layout::from_size_align_unchecked(size, align) };
+        match Global.allocate(layout) {
+            Ok(ptr) => ptr.as_mut_ptr(),
+            Err(_) => handle_alloc_error(layout),
+        }
+    }
+
+    // SAFETY: both functions return a fresh allocation for `size` bytes aligned to `align`.
+    unsafe { intrinsics::const_eval_select((size, align), ct_exchange_malloc, rt_exchange_malloc) }
 }
 
 #[cfg_attr(not(test), lang = "box_free")]
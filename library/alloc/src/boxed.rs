@@ -159,7 +159,8 @@
 use core::marker::Unsize;
 use core::mem;
 use core::ops::{
-    CoerceUnsized, Deref, DerefMut, DispatchFromDyn, Generator, GeneratorState, Receiver,
+    CoerceUnsized, Deref, DerefMut, DerefPure, DispatchFromDyn, Generator, GeneratorState,
+    Receiver,
 };
 use core::pin::Pin;
 use core::ptr::{self, Unique};
@@ -210,9 +211,10 @@ impl<T> Box<T> {
     #[cfg(all(not(no_global_oom_handling)))]
     #[inline(always)]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
     #[must_use]
     #[rustc_diagnostic_item = "box_new"]
-    pub fn new(x: T) -> Self {
+    pub const fn new(x: T) -> Self {
         #[rustc_box]
         Box::new(x)
     }
@@ -1158,12 +1160,15 @@ pub const fn allocator(b: &Self) -> &A {
     /// assert_eq!(*static_ref, [4, 2, 3]);
     /// ```
     #[stable(feature = "box_leak", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
     #[inline]
-    pub fn leak<'a>(b: Self) -> &'a mut T
+    pub const fn leak<'a>(b: Self) -> &'a mut T
     where
         A: 'a,
     {
-        unsafe { &mut *mem::ManuallyDrop::new(b).0.as_ptr() }
+        let ptr = b.0.as_ptr();
+        mem::forget(b);
+        unsafe { &mut *ptr }
     }
 
     /// Converts a `Box<T>` into a `Pin<Box<T>>`. If `T` does not implement [`Unpin`], then
@@ -1909,6 +1914,9 @@ fn deref_mut(&mut self) -> &mut T {
     }
 }
 
+#[unstable(feature = "deref_pure_trait", issue = "87121")]
+unsafe impl<T: ?Sized, A: Allocator> DerefPure for Box<T, A> {}
+
 #[unstable(feature = "receiver_trait", issue = "none")]
 impl<T: ?Sized, A: Allocator> Receiver for Box<T, A> {}
 
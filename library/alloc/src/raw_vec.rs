@@ -126,8 +126,16 @@ pub const fn new_in(alloc: A) -> Self {
     /// allocator for the returned `RawVec`.
     #[cfg(not(no_global_oom_handling))]
     #[inline]
-    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
-        Self::allocate_in(capacity, AllocInit::Uninitialized, alloc)
+    pub const fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        // SAFETY: both functions allocate a buffer for `capacity` elements, see
+        // `const_allocate_in` for where the memory comes from at compile time.
+        unsafe {
+            intrinsics::const_eval_select(
+                (capacity, AllocInit::Uninitialized, alloc),
+                Self::const_allocate_in,
+                Self::allocate_in,
+            )
+        }
     }
 
     /// Like `with_capacity_zeroed`, but parameterized over the choice
@@ -200,6 +208,31 @@ fn allocate_in(capacity: usize, init: AllocInit, alloc: A) -> Self {
         }
     }
 
+    /// The compile-time counterpart of `allocate_in`.
+    ///
+    /// During const evaluation there is no allocator to call, so the memory always comes from
+    /// the interpreter's heap via `const_allocate`, whatever `A` is. This memory can never be
+    /// handed back to `alloc` at runtime: const-checking rejects dropping a `RawVec` at compile
+    /// time, and interning rejects final values that still own such an allocation, so the only
+    /// way for it to outlive the evaluation is to be leaked.
+    #[cfg(not(no_global_oom_handling))]
+    const fn const_allocate_in(capacity: usize, init: AllocInit, alloc: A) -> Self {
+        if T::IS_ZST || capacity == 0 {
+            return Self::new_in(alloc);
+        }
+        let size = match mem::size_of::<T>().checked_mul(capacity) {
+            Some(size) if size <= isize::MAX as usize => size,
+            _ => capacity_overflow(),
+        };
+        unsafe {
+            let ptr = intrinsics::const_allocate(size, mem::align_of::<T>());
+            if let AllocInit::Zeroed = init {
+                ptr::write_bytes(ptr, 0, size);
+            }
+            Self { ptr: Unique::new_unchecked(ptr.cast()), cap: capacity, alloc }
+        }
+    }
+
     /// Reconstitutes a `RawVec` from a pointer, capacity, and allocator.
     ///
     /// # Safety
@@ -219,7 +252,7 @@ pub unsafe fn from_raw_parts_in(ptr: *mut T, capacity: usize, alloc: A) -> Self
     /// `Unique::dangling()` if `capacity == 0` or `T` is zero-sized. In the former case, you must
     /// be careful.
     #[inline]
-    pub fn ptr(&self) -> *mut T {
+    pub const fn ptr(&self) -> *mut T {
         self.ptr.as_ptr()
     }
 
@@ -227,7 +260,7 @@ pub fn ptr(&self) -> *mut T {
     ///
     /// This will always be `usize::MAX` if `T` is zero-sized.
     #[inline(always)]
-    pub fn capacity(&self) -> usize {
+    pub const fn capacity(&self) -> usize {
         if T::IS_ZST { usize::MAX } else { self.cap }
     }
 
@@ -275,7 +308,7 @@ fn current_memory(&self) -> Option<(NonNull<u8>, Layout)> {
     /// Aborts on OOM.
     #[cfg(not(no_global_oom_handling))]
     #[inline]
-    pub fn reserve(&mut self, len: usize, additional: usize) {
+    pub const fn reserve(&mut self, len: usize, additional: usize) {
         // Callers expect this function to be very cheap when there is already sufficient capacity.
         // Therefore, we move all the resizing and error-handling logic from grow_amortized and
         // handle_reserve behind a call, while making sure that this function is likely to be
@@ -290,7 +323,15 @@ fn do_reserve_and_handle<T, A: Allocator>(
         }
 
         if self.needs_to_grow(len, additional) {
-            do_reserve_and_handle(self, len, additional);
+            // SAFETY: both functions grow the buffer to hold at least `len + additional`
+            // elements, see `const_allocate_in` for where the memory comes from at compile time.
+            unsafe {
+                intrinsics::const_eval_select(
+                    (self, len, additional),
+                    Self::const_grow_amortized,
+                    do_reserve_and_handle,
+                )
+            }
         }
     }
 
@@ -298,8 +339,19 @@ fn do_reserve_and_handle<T, A: Allocator>(
     /// oft-instantiated `Vec::push()`, which does its own capacity check.
     #[cfg(not(no_global_oom_handling))]
     #[inline(never)]
-    pub fn reserve_for_push(&mut self, len: usize) {
-        handle_reserve(self.grow_amortized(len, 1));
+    pub const fn reserve_for_push(&mut self, len: usize) {
+        const fn ct_reserve_for_push<T, A: Allocator>(slf: &mut RawVec<T, A>, len: usize) {
+            slf.const_grow_amortized(len, 1);
+        }
+
+        fn rt_reserve_for_push<T, A: Allocator>(slf: &mut RawVec<T, A>, len: usize) {
+            handle_reserve(slf.grow_amortized(len, 1));
+        }
+
+        // SAFETY: see `reserve`.
+        unsafe {
+            intrinsics::const_eval_select((self, len), ct_reserve_for_push, rt_reserve_for_push)
+        }
     }
 
     /// The same as `reserve`, but returns on errors instead of panicking or aborting.
@@ -361,7 +413,7 @@ pub fn shrink_to_fit(&mut self, cap: usize) {
 impl<T, A: Allocator> RawVec<T, A> {
     /// Returns if the buffer needs to grow to fulfill the needed extra capacity.
     /// Mainly used to make inlining reserve-calls possible without inlining `grow`.
-    fn needs_to_grow(&self, len: usize, additional: usize) -> bool {
+    const fn needs_to_grow(&self, len: usize, additional: usize) -> bool {
         additional > self.capacity().wrapping_sub(len)
     }
 
@@ -406,6 +458,40 @@ fn grow_amortized(&mut self, len: usize, additional: usize) -> Result<(), TryRes
         Ok(())
     }
 
+    /// The compile-time counterpart of `grow_amortized`, which panics instead of returning an
+    /// error. See `const_allocate_in` for where the memory comes from.
+    #[cfg(not(no_global_oom_handling))]
+    const fn const_grow_amortized(&mut self, len: usize, additional: usize) {
+        if T::IS_ZST {
+            capacity_overflow();
+        }
+
+        let required_cap = match len.checked_add(additional) {
+            Some(cap) => cap,
+            None => capacity_overflow(),
+        };
+        // `cmp::max` is not callable at compile time.
+        let cap = if self.cap * 2 > required_cap { self.cap * 2 } else { required_cap };
+        let cap = if Self::MIN_NON_ZERO_CAP > cap { Self::MIN_NON_ZERO_CAP } else { cap };
+        let size = match mem::size_of::<T>().checked_mul(cap) {
+            Some(size) if size <= isize::MAX as usize => size,
+            _ => capacity_overflow(),
+        };
+
+        let align = mem::align_of::<T>();
+        unsafe {
+            let ptr = intrinsics::const_allocate(size, align);
+            if self.cap != 0 {
+                let old_ptr = self.ptr.as_ptr().cast::<u8>();
+                let old_size = mem::size_of::<T>() * self.cap;
+                ptr::copy_nonoverlapping(old_ptr, ptr, old_size);
+                intrinsics::const_deallocate(old_ptr, old_size, align);
+            }
+            self.ptr = Unique::new_unchecked(ptr.cast());
+        }
+        self.cap = cap;
+    }
+
     // The constraints on this method are much the same as those on
     // `grow_amortized`, but this method is usually instantiated less often so
     // it's less critical.
@@ -520,6 +606,6 @@ fn alloc_guard(alloc_size: usize) -> Result<(), TryReserveError> {
 // ensure that the code generation related to these panics is minimal as there's
 // only one location which panics rather than a bunch throughout the module.
 #[cfg(not(no_global_oom_handling))]
-fn capacity_overflow() -> ! {
+const fn capacity_overflow() -> ! {
     panic!("capacity overflow");
 }
@@ -574,3 +574,92 @@ struct TwoRefs {
     assert_eq!(Rc::strong_count(&two_refs), 3);
     assert_eq!(Rc::weak_count(&two_refs), 2);
 }
+
+/// An allocator that forwards to `Global` and tracks the number of live allocations.
+#[derive(Clone)]
+struct CountingAlloc<'a>(&'a Cell<usize>);
+
+unsafe impl Allocator for CountingAlloc<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.set(self.0.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.set(self.0.get() - 1);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn test_rc_new_in() {
+    let live = Cell::new(0);
+    let x = Rc::new_in(5, CountingAlloc(&live));
+    assert_eq!(live.get(), 1);
+
+    let y = Rc::clone(&x);
+    let weak = Rc::downgrade(&x);
+    assert_eq!(Rc::strong_count(&x), 2);
+    assert_eq!(Rc::weak_count(&x), 1);
+    assert!(Rc::ptr_eq(&x, &y));
+
+    drop(x);
+    assert_eq!(Rc::try_unwrap(y), Ok(5));
+    assert!(weak.upgrade().is_none());
+    assert_eq!(live.get(), 1);
+
+    drop(weak);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn test_rc_uninit_in() {
+    let live = Cell::new(0);
+    let mut five = Rc::<u32, _>::new_uninit_in(CountingAlloc(&live));
+    Rc::get_mut(&mut five).unwrap().write(5);
+    let five = unsafe { five.assume_init() };
+    assert_eq!(*five, 5);
+
+    let zeros = Rc::<[u32], _>::new_zeroed_slice_in(3, CountingAlloc(&live));
+    let zeros = unsafe { zeros.assume_init() };
+    assert_eq!(*zeros, [0, 0, 0]);
+    assert_eq!(live.get(), 2);
+
+    drop((five, zeros));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn test_rc_from_box_and_vec_in() {
+    let live = Cell::new(0);
+    let b = Box::new_in(String::from("boxed"), CountingAlloc(&live));
+    let rc: Rc<String, _> = Rc::from(b);
+    assert_eq!(*rc, "boxed");
+    assert_eq!(live.get(), 1);
+
+    let mut v = Vec::with_capacity_in(4, CountingAlloc(&live));
+    v.extend([1, 2, 3]);
+    let rc_slice: Rc<[i32], _> = Rc::from(v);
+    assert_eq!(*rc_slice, [1, 2, 3]);
+    assert_eq!(live.get(), 2);
+
+    drop((rc, rc_slice));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn test_rc_raw_in() {
+    let live = Cell::new(0);
+    let x = Rc::new_in(String::from("raw"), CountingAlloc(&live));
+    let ptr = Rc::into_raw(x);
+    let x = unsafe { Rc::from_raw_in(ptr, CountingAlloc(&live)) };
+    assert_eq!(*x, "raw");
+
+    let weak = Rc::downgrade(&x);
+    let weak_ptr = weak.into_raw();
+    let weak = unsafe { Weak::from_raw_in(weak_ptr, CountingAlloc(&live)) };
+    assert!(Rc::ptr_eq(&weak.upgrade().unwrap(), &x));
+
+    drop((x, weak));
+    assert_eq!(live.get(), 0);
+}
@@ -669,3 +669,61 @@ fn arc_drop_dereferenceable_race() {
         thread.join().unwrap();
     }
 }
+
+/// An allocator that forwards to `Global` and tracks the number of live allocations.
+#[derive(Clone)]
+struct CountingAlloc<'a>(&'a atomic::AtomicUsize);
+
+unsafe impl Allocator for CountingAlloc<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.fetch_add(1, SeqCst);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.fetch_sub(1, SeqCst);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn arc_new_in() {
+    let live = atomic::AtomicUsize::new(0);
+    let x = Arc::new_in(5, CountingAlloc(&live));
+    assert_eq!(live.load(SeqCst), 1);
+
+    let y = Arc::clone(&x);
+    let weak = Arc::downgrade(&x);
+    assert_eq!(Arc::strong_count(&x), 2);
+    assert_eq!(Arc::weak_count(&x), 1);
+
+    drop(x);
+    assert_eq!(Arc::try_unwrap(y), Ok(5));
+    assert!(weak.upgrade().is_none());
+    assert_eq!(live.load(SeqCst), 1);
+
+    drop(weak);
+    assert_eq!(live.load(SeqCst), 0);
+}
+
+#[test]
+fn arc_uninit_and_from_vec_in() {
+    let live = atomic::AtomicUsize::new(0);
+    let mut five = Arc::<u32, _>::new_uninit_in(CountingAlloc(&live));
+    Arc::get_mut(&mut five).unwrap().write(5);
+    let five = unsafe { five.assume_init() };
+    assert_eq!(*five, 5);
+
+    let mut v = Vec::with_capacity_in(4, CountingAlloc(&live));
+    v.extend([1, 2, 3]);
+    let slice: Arc<[i32], _> = Arc::from(v);
+    assert_eq!(*slice, [1, 2, 3]);
+    assert_eq!(live.load(SeqCst), 2);
+
+    let ptr = Arc::into_raw(slice);
+    let slice = unsafe { Arc::from_raw_in(ptr, CountingAlloc(&live)) };
+    assert_eq!(*slice, [1, 2, 3]);
+
+    drop((five, slice));
+    assert_eq!(live.load(SeqCst), 0);
+}
@@ -474,8 +474,9 @@ pub const fn new() -> Self {
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
     #[must_use]
-    pub fn with_capacity(capacity: usize) -> Self {
+    pub const fn with_capacity(capacity: usize) -> Self {
         Self::with_capacity_in(capacity, Global)
     }
 
@@ -668,7 +669,8 @@ pub const fn new_in(alloc: A) -> Self {
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[unstable(feature = "allocator_api", issue = "32838")]
-    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
+    pub const fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         Vec { buf: RawVec::with_capacity_in(capacity, alloc), len: 0 }
     }
 
@@ -904,7 +906,8 @@ pub fn capacity(&self) -> usize {
     /// ```
     #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn reserve(&mut self, additional: usize) {
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
+    pub const fn reserve(&mut self, additional: usize) {
         self.buf.reserve(self.len, additional);
     }
 
@@ -1265,8 +1268,9 @@ pub fn as_ptr(&self) -> *const T {
     /// assert_eq!(&*x, &[0, 1, 2, 3]);
     /// ```
     #[stable(feature = "vec_as_ptr", since = "1.37.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
     #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut T {
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
         // We shadow the slice method of the same name to avoid going through
         // `deref_mut`, which creates an intermediate reference.
         self.buf.ptr()
@@ -1821,7 +1825,8 @@ fn drop(&mut self) {
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn push(&mut self, value: T) {
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
+    pub const fn push(&mut self, value: T) {
         // This will panic or abort if we would allocate > isize::MAX bytes
         // or if the length increment would overflow for zero-sized types.
         if self.len == self.buf.capacity() {
@@ -1935,10 +1940,10 @@ pub fn append(&mut self, other: &mut Self) {
     /// Appends elements to `self` from other buffer.
     #[cfg(not(no_global_oom_handling))]
     #[inline]
-    unsafe fn append_elements(&mut self, other: *const [T]) {
+    pub(crate) const unsafe fn append_elements(&mut self, other: *const [T]) {
         let count = unsafe { (*other).len() };
         self.reserve(count);
-        let len = self.len();
+        let len = self.len;
         unsafe { ptr::copy_nonoverlapping(other as *const T, self.as_mut_ptr().add(len), count) };
         self.len += count;
     }
@@ -2187,13 +2192,16 @@ pub fn resize_with<F>(&mut self, new_len: usize, f: F)
     /// assert_eq!(static_ref, &[2, 2, 3]);
     /// ```
     #[stable(feature = "vec_leak", since = "1.47.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
     #[inline]
-    pub fn leak<'a>(self) -> &'a mut [T]
+    pub const fn leak<'a>(mut self) -> &'a mut [T]
     where
         A: 'a,
     {
-        let mut me = ManuallyDrop::new(self);
-        unsafe { slice::from_raw_parts_mut(me.as_mut_ptr(), me.len) }
+        let ptr = self.as_mut_ptr();
+        let len = self.len;
+        mem::forget(self);
+        unsafe { slice::from_raw_parts_mut(ptr, len) }
     }
 
     /// Returns the remaining spare capacity of the vector as a slice of
@@ -2634,6 +2642,9 @@ fn deref_mut(&mut self) -> &mut [T] {
     }
 }
 
+#[unstable(feature = "deref_pure_trait", issue = "87121")]
+unsafe impl<T, A: Allocator> ops::DerefPure for Vec<T, A> {}
+
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: Clone, A: Allocator + Clone> Clone for Vec<T, A> {
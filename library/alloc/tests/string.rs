@@ -824,3 +824,24 @@ fn test_str_concat() {
     let s: String = format!("{a}{b}");
     assert_eq!(s.as_bytes()[9], 'd' as u8);
 }
+
+#[test]
+fn test_string_new_in() {
+    use std::alloc::System;
+
+    let mut s = String::new_in(System);
+    assert!(s.is_empty());
+    s.push_str("hello");
+    s.push(' ');
+    s.extend(["wor", "ld"]);
+    assert_eq!(s, "hello world");
+
+    let tail = s.split_off(5);
+    assert_eq!(tail, " world");
+    assert_eq!(s, "hello");
+    assert_eq!(s.clone(), s);
+
+    let mut t = String::with_capacity_in(8, System);
+    t.push_str("hello");
+    assert_eq!(s, t);
+}
@@ -0,0 +1,80 @@
+//! Function contracts: preconditions and postconditions attached to functions.
+//!
+//! A function can state what it expects from its caller with [`requires`] and what it
+//! guarantees about its return value with [`ensures`]:
+//!
+//! ```
+//! #![feature(contracts)]
+//! # #![allow(incomplete_features)]
+//! use std::contracts::{ensures, requires};
+//!
+//! #[requires(divisor != 0)]
+//! #[ensures(|ret| *ret <= dividend)]
+//! fn divide(dividend: u32, divisor: u32) -> u32 {
+//!     dividend / divisor
+//! }
+//! # assert_eq!(divide(7, 2), 3);
+//! ```
+//!
+//! A precondition is a `bool` expression over the parameters of the function. A
+//! postcondition is a closure that receives a reference to the returned value and returns
+//! a `bool`. Several of each can be attached to the same function.
+//!
+//! When the crate defining the function is compiled with `-Z contract-checks`, the
+//! preconditions are evaluated on entry and the postconditions on every return, and a
+//! failing contract panics with its predicate and the location of the contract attribute.
+//! Whether checks are enabled is decided when the function is compiled, so instances of a
+//! generic function follow the flag of the crate that defines it, not the one of the crate
+//! that instantiates it. Without the flag the contracts are not evaluated, but they remain
+//! part of the MIR of the function, as calls to the lang items below, so that verification
+//! tools can find them.
+
+pub use crate::macros::builtin::{contracts_ensures as ensures, contracts_requires as requires};
+
+/// Checks a precondition, if `checks` is true.
+///
+/// The lowering of `#[requires]` calls this at the start of the function body, with the
+/// source text of the precondition and whether the crate defining the function is compiled
+/// with `-Z contract-checks`.
+#[unstable(feature = "contracts_internals", issue = "128044")]
+#[lang = "contract_check_requires"]
+#[track_caller]
+#[inline]
+pub fn check_requires<C: FnOnce() -> bool>(cond: C, text: &'static str, checks: bool) {
+    if checks && !cond() {
+        panic!("failed requires check: {text}");
+    }
+}
+
+/// Returns the closure checking a postcondition.
+///
+/// This only exists to constrain the argument type of the closure, so that
+/// `#[ensures(|ret| ...)]` works without a type annotation on `ret`.
+#[unstable(feature = "contracts_internals", issue = "128044")]
+#[lang = "contract_build_check_ensures"]
+#[inline]
+pub fn build_check_ensures<Ret, C: Fn(&Ret) -> bool>(cond: C) -> C {
+    cond
+}
+
+/// Checks a postcondition against the value `ret` about to be returned, if `checks` is true,
+/// and passes it through.
+///
+/// The lowering of `#[ensures]` calls this on the tail expression of the function body and
+/// on every returned value, with the source text of the postcondition and whether the crate
+/// defining the function is compiled with `-Z contract-checks`.
+#[unstable(feature = "contracts_internals", issue = "128044")]
+#[lang = "contract_check_ensures"]
+#[track_caller]
+#[inline]
+pub fn check_ensures<Ret, C: Fn(&Ret) -> bool>(
+    ret: Ret,
+    cond: &C,
+    text: &'static str,
+    checks: bool,
+) -> Ret {
+    if checks && !cond(&ret) {
+        panic!("failed ensures check: {text}");
+    }
+    ret
+}
@@ -22,6 +22,56 @@ fn already_rounded_value_should_use_exponential(&self) -> bool {
 }
 
 impl_general_format! { f32 f64 }
+#[cfg(not(bootstrap))]
+impl_general_format! { f128 }
+
+// `1e+16` is out of range for `f16`, so only small magnitudes switch to exponential notation.
+#[cfg(not(bootstrap))]
+impl GeneralFormat for f16 {
+    fn already_rounded_value_should_use_exponential(&self) -> bool {
+        let abs = f16::abs_private(*self);
+        abs != 0.0 && abs < 1e-4
+    }
+}
+
+#[doc(hidden)]
+trait FormatBuffers {
+    /// Scratch buffer for the shortest mode.
+    type Shortest: AsMut<[MaybeUninit<u8>]>;
+    /// Scratch buffer for the exact and fixed modes.
+    type Exact: AsMut<[MaybeUninit<u8>]>;
+
+    fn shortest_buf() -> Self::Shortest;
+    fn exact_buf() -> Self::Exact;
+}
+
+macro_rules! impl_format_buffers {
+    ($($t:ident: shortest = $shortest:expr, exact = $exact:expr;)*) => {
+        $(impl FormatBuffers for $t {
+            type Shortest = [MaybeUninit<u8>; $shortest];
+            type Exact = [MaybeUninit<u8>; $exact];
+
+            fn shortest_buf() -> Self::Shortest {
+                MaybeUninit::uninit_array()
+            }
+
+            fn exact_buf() -> Self::Exact {
+                MaybeUninit::uninit_array()
+            }
+        })*
+    }
+}
+
+impl_format_buffers! {
+    f32: shortest = flt2dec::MAX_SIG_DIGITS, exact = 1024;
+    f64: shortest = flt2dec::MAX_SIG_DIGITS, exact = 1024;
+}
+#[cfg(not(bootstrap))]
+impl_format_buffers! {
+    f16: shortest = flt2dec::MAX_SIG_DIGITS, exact = 1024;
+    // the exact modes may need up to 12,412 digits for the smallest subnormals.
+    f128: shortest = flt2dec::MAX_SIG_DIGITS_F128, exact = 12416;
+}
 
 // Don't inline this so callers don't use the stack space this function
 // requires unless they have to.
@@ -33,16 +83,16 @@ fn float_to_decimal_common_exact<T>(
     precision: usize,
 ) -> Result
 where
-    T: flt2dec::DecodableFloat,
+    T: flt2dec::DecodableFloat + FormatBuffers,
 {
-    let mut buf: [MaybeUninit<u8>; 1024] = MaybeUninit::uninit_array(); // enough for f32 and f64
+    let mut buf = T::exact_buf();
     let mut parts: [MaybeUninit<numfmt::Part<'_>>; 4] = MaybeUninit::uninit_array();
     let formatted = flt2dec::to_exact_fixed_str(
         flt2dec::strategy::grisu::format_exact,
         *num,
         sign,
         precision,
-        &mut buf,
+        buf.as_mut(),
         &mut parts,
     );
     fmt.pad_formatted_parts(&formatted)
@@ -58,17 +108,16 @@ fn float_to_decimal_common_shortest<T>(
     precision: usize,
 ) -> Result
 where
-    T: flt2dec::DecodableFloat,
+    T: flt2dec::DecodableFloat + FormatBuffers,
 {
-    // enough for f32 and f64
-    let mut buf: [MaybeUninit<u8>; flt2dec::MAX_SIG_DIGITS] = MaybeUninit::uninit_array();
+    let mut buf = T::shortest_buf();
     let mut parts: [MaybeUninit<numfmt::Part<'_>>; 4] = MaybeUninit::uninit_array();
     let formatted = flt2dec::to_shortest_str(
         flt2dec::strategy::grisu::format_shortest,
         *num,
         sign,
         precision,
-        &mut buf,
+        buf.as_mut(),
         &mut parts,
     );
     fmt.pad_formatted_parts(&formatted)
@@ -76,7 +125,7 @@ fn float_to_decimal_common_shortest<T>(
 
 fn float_to_decimal_display<T>(fmt: &mut Formatter<'_>, num: &T) -> Result
 where
-    T: flt2dec::DecodableFloat,
+    T: flt2dec::DecodableFloat + FormatBuffers,
 {
     let force_sign = fmt.sign_plus();
     let sign = match force_sign {
@@ -103,9 +152,9 @@ fn float_to_exponential_common_exact<T>(
     upper: bool,
 ) -> Result
 where
-    T: flt2dec::DecodableFloat,
+    T: flt2dec::DecodableFloat + FormatBuffers,
 {
-    let mut buf: [MaybeUninit<u8>; 1024] = MaybeUninit::uninit_array(); // enough for f32 and f64
+    let mut buf = T::exact_buf();
     let mut parts: [MaybeUninit<numfmt::Part<'_>>; 6] = MaybeUninit::uninit_array();
     let formatted = flt2dec::to_exact_exp_str(
         flt2dec::strategy::grisu::format_exact,
@@ -113,7 +162,7 @@ fn float_to_exponential_common_exact<T>(
         sign,
         precision,
         upper,
-        &mut buf,
+        buf.as_mut(),
         &mut parts,
     );
     fmt.pad_formatted_parts(&formatted)
@@ -129,10 +178,9 @@ fn float_to_exponential_common_shortest<T>(
     upper: bool,
 ) -> Result
 where
-    T: flt2dec::DecodableFloat,
+    T: flt2dec::DecodableFloat + FormatBuffers,
 {
-    // enough for f32 and f64
-    let mut buf: [MaybeUninit<u8>; flt2dec::MAX_SIG_DIGITS] = MaybeUninit::uninit_array();
+    let mut buf = T::shortest_buf();
     let mut parts: [MaybeUninit<numfmt::Part<'_>>; 6] = MaybeUninit::uninit_array();
     let formatted = flt2dec::to_shortest_exp_str(
         flt2dec::strategy::grisu::format_shortest,
@@ -140,7 +188,7 @@ fn float_to_exponential_common_shortest<T>(
         sign,
         (0, 0),
         upper,
-        &mut buf,
+        buf.as_mut(),
         &mut parts,
     );
     fmt.pad_formatted_parts(&formatted)
@@ -149,7 +197,7 @@ fn float_to_exponential_common_shortest<T>(
 // Common code of floating point LowerExp and UpperExp.
 fn float_to_exponential_common<T>(fmt: &mut Formatter<'_>, num: &T, upper: bool) -> Result
 where
-    T: flt2dec::DecodableFloat,
+    T: flt2dec::DecodableFloat + FormatBuffers,
 {
     let force_sign = fmt.sign_plus();
     let sign = match force_sign {
@@ -167,7 +215,7 @@ fn float_to_exponential_common<T>(fmt: &mut Formatter<'_>, num: &T, upper: bool)
 
 fn float_to_general_debug<T>(fmt: &mut Formatter<'_>, num: &T) -> Result
 where
-    T: flt2dec::DecodableFloat + GeneralFormat,
+    T: flt2dec::DecodableFloat + FormatBuffers + GeneralFormat,
 {
     let force_sign = fmt.sign_plus();
     let sign = match force_sign {
@@ -224,3 +272,7 @@ fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
 
 floating! { f32 }
 floating! { f64 }
+#[cfg(not(bootstrap))]
+floating! { f16 }
+#[cfg(not(bootstrap))]
+floating! { f128 }
@@ -2289,6 +2289,21 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[rustc_nounwind]
     pub fn discriminant_value<T>(v: &T) -> <T as DiscriminantKind>::Discriminant;
 
+    /// Returns whether `value` matches the pattern of the pattern type `P`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// The safe wrapper of this intrinsic is [`core::pat::try_from_base`].
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    #[rustc_nounwind]
+    pub fn pattern_type_contains<P: crate::pat::PatternType>(
+        value: <P as crate::pat::PatternType>::Base,
+    ) -> bool;
+
     /// Returns the number of variants of the type `T` cast to a `usize`;
     /// if `T` has no variants, returns `0`. Uninhabited variants will be counted.
     ///
@@ -69,6 +69,7 @@ fn _assert_is_object_safe(_: &dyn Iterator<Item = ()>) {}
 )]
 #[doc(notable_trait)]
 #[rustc_diagnostic_item = "Iterator"]
+#[cfg_attr(not(bootstrap), lang = "iterator")]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub trait Iterator {
     /// The type of the elements being iterated over.
@@ -208,6 +208,8 @@
 #![feature(doc_notable_trait)]
 #![feature(exhaustive_patterns)]
 #![feature(extern_types)]
+#![cfg_attr(not(bootstrap), feature(f128))]
+#![cfg_attr(not(bootstrap), feature(f16))]
 #![feature(fundamental)]
 #![feature(generic_arg_infer)]
 #![feature(if_let_guard)]
@@ -310,6 +312,12 @@ pub mod assert_matches {
 #[path = "num/shells/usize.rs"]
 pub mod usize;
 
+#[cfg(not(bootstrap))]
+#[path = "num/f128.rs"]
+pub mod f128;
+#[cfg(not(bootstrap))]
+#[path = "num/f16.rs"]
+pub mod f16;
 #[path = "num/f32.rs"]
 pub mod f32;
 #[path = "num/f64.rs"]
@@ -350,12 +358,18 @@ pub mod assert_matches {
 pub mod async_iter;
 pub mod cell;
 pub mod char;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "contracts", issue = "128044")]
+pub mod contracts;
 pub mod ffi;
 pub mod iter;
 pub mod net;
 pub mod option;
 pub mod panic;
 pub mod panicking;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "pattern_types", issue = "123646")]
+pub mod pat;
 pub mod pin;
 pub mod result;
 pub mod sync;
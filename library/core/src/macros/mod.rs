@@ -1562,6 +1562,39 @@ macro_rules! trace_macros {
         /* compiler built-in */
     }
 
+    /// Attribute macro stating a precondition of the function it is applied to.
+    ///
+    /// See the [`contracts`](crate::contracts) module for details.
+    #[cfg(not(bootstrap))]
+    #[unstable(feature = "contracts", issue = "128044")]
+    #[allow_internal_unstable(contracts_internals)]
+    #[rustc_builtin_macro]
+    pub macro contracts_requires($item:item) {
+        /* compiler built-in */
+    }
+
+    /// Attribute macro stating a postcondition of the function it is applied to.
+    ///
+    /// See the [`contracts`](crate::contracts) module for details.
+    #[cfg(not(bootstrap))]
+    #[unstable(feature = "contracts", issue = "128044")]
+    #[allow_internal_unstable(contracts_internals)]
+    #[rustc_builtin_macro]
+    pub macro contracts_ensures($item:item) {
+        /* compiler built-in */
+    }
+
+    /// Creates a pattern type, which restricts the values of an integer type to those
+    /// matching a pattern.
+    ///
+    /// See the [`pat`](crate::pat) module for details.
+    #[cfg(not(bootstrap))]
+    #[unstable(feature = "pattern_types", issue = "123646")]
+    #[rustc_builtin_macro]
+    pub macro pattern_type($($arg:tt)*) {
+        /* compiler built-in */
+    }
+
     /// Unstable placeholder for type ascription.
     #[rustc_builtin_macro]
     #[unstable(
@@ -486,6 +486,12 @@ pub trait Copy: Clone {
 
 }
 
+#[cfg(not(bootstrap))]
+marker_impls! {
+    #[stable(feature = "rust1", since = "1.0.0")]
+    Copy for f16, f128,
+}
+
 #[unstable(feature = "never_type", issue = "35121")]
 impl Copy for ! {}
 
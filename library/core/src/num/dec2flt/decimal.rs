@@ -11,8 +11,43 @@
 
 use crate::num::dec2flt::common::{is_8digits, ByteSlice};
 
+/// The maximum number of digits required to unambiguously round a float.
+///
+/// For a double-precision IEEE 754 float, this required 767 digits,
+/// so we store the max digits + 1.
+///
+/// We can exactly represent a float in radix `b` from radix 2 if
+/// `b` is divisible by 2. This function calculates the exact number of
+/// digits required to exactly represent that float.
+///
+/// According to the "Handbook of Floating Point Arithmetic",
+/// for IEEE754, with emin being the min exponent, p2 being the
+/// precision, and b being the radix, the number of digits follows as:
+///
+/// `−emin + p2 + ⌊(emin + 1) log(2, b) − log(1 − 2^(−p2), b)⌋`
+///
+/// For f32, this follows as:
+///     emin = -126
+///     p2 = 24
+///
+/// For f64, this follows as:
+///     emin = -1022
+///     p2 = 53
+///
+/// In Python:
+///     `-emin + p2 + math.floor((emin+ 1)*math.log(2, b)-math.log(1-2**(-p2), b))`
+pub const MAX_DIGITS: usize = 768;
+
+/// The same as `MAX_DIGITS` for a quadruple-precision IEEE 754 float.
+///
+/// With emin = -16382 and p2 = 113, this requires 11563 digits.
+#[cfg(not(bootstrap))]
+pub const MAX_DIGITS_F128: usize = 11564;
+
+/// A big decimal holding up to `N` significant digits, which is either `MAX_DIGITS` or
+/// `MAX_DIGITS_F128`.
 #[derive(Clone)]
-pub struct Decimal {
+pub struct Decimal<const N: usize> {
     /// The number of significant digits in the decimal.
     pub num_digits: usize,
     /// The offset of the decimal point in the significant digits.
@@ -20,42 +55,18 @@ pub struct Decimal {
     /// If the number of significant digits stored in the decimal is truncated.
     pub truncated: bool,
     /// Buffer of the raw digits, in the range [0, 9].
-    pub digits: [u8; Self::MAX_DIGITS],
+    pub digits: [u8; N],
 }
 
-impl Default for Decimal {
+impl<const N: usize> Default for Decimal<N> {
     fn default() -> Self {
-        Self { num_digits: 0, decimal_point: 0, truncated: false, digits: [0; Self::MAX_DIGITS] }
+        Self { num_digits: 0, decimal_point: 0, truncated: false, digits: [0; N] }
     }
 }
 
-impl Decimal {
-    /// The maximum number of digits required to unambiguously round a float.
-    ///
-    /// For a double-precision IEEE 754 float, this required 767 digits,
-    /// so we store the max digits + 1.
-    ///
-    /// We can exactly represent a float in radix `b` from radix 2 if
-    /// `b` is divisible by 2. This function calculates the exact number of
-    /// digits required to exactly represent that float.
-    ///
-    /// According to the "Handbook of Floating Point Arithmetic",
-    /// for IEEE754, with emin being the min exponent, p2 being the
-    /// precision, and b being the radix, the number of digits follows as:
-    ///
-    /// `−emin + p2 + ⌊(emin + 1) log(2, b) − log(1 − 2^(−p2), b)⌋`
-    ///
-    /// For f32, this follows as:
-    ///     emin = -126
-    ///     p2 = 24
-    ///
-    /// For f64, this follows as:
-    ///     emin = -1022
-    ///     p2 = 53
-    ///
-    /// In Python:
-    ///     `-emin + p2 + math.floor((emin+ 1)*math.log(2, b)-math.log(1-2**(-p2), b))`
-    pub const MAX_DIGITS: usize = 768;
+impl<const N: usize> Decimal<N> {
+    /// The maximum number of digits stored in the decimal.
+    pub const MAX_DIGITS: usize = N;
     /// The max digits that can be exactly represented in a 64-bit integer.
     pub const MAX_DIGITS_WITHOUT_OVERFLOW: usize = 19;
     pub const DECIMAL_POINT_RANGE: i32 = 2047;
@@ -83,18 +94,18 @@ pub fn trim(&mut self) {
         }
     }
 
-    pub fn round(&self) -> u64 {
+    pub fn round(&self) -> u128 {
         if self.num_digits == 0 || self.decimal_point < 0 {
             return 0;
-        } else if self.decimal_point > 18 {
-            return 0xFFFF_FFFF_FFFF_FFFF_u64;
+        } else if self.decimal_point > 38 {
+            return u128::MAX;
         }
         let dp = self.decimal_point as usize;
-        let mut n = 0_u64;
+        let mut n = 0_u128;
         for i in 0..dp {
             n *= 10;
             if i < self.num_digits {
-                n += self.digits[i] as u64;
+                n += self.digits[i] as u128;
             }
         }
         let mut round_up = false;
@@ -202,8 +213,8 @@ pub fn right_shift(&mut self, shift: usize) {
 }
 
 /// Parse a big integer representation of the float as a decimal.
-pub fn parse_decimal(mut s: &[u8]) -> Decimal {
-    let mut d = Decimal::default();
+pub fn parse_decimal<const N: usize>(mut s: &[u8]) -> Decimal<N> {
+    let mut d = Decimal::<N>::default();
     let start = s;
 
     while let Some((&b'0', s_next)) = s.split_first() {
@@ -221,7 +232,7 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
                 s = s_next;
             }
         }
-        while s.len() >= 8 && d.num_digits + 8 < Decimal::MAX_DIGITS {
+        while s.len() >= 8 && d.num_digits + 8 < N {
             let v = s.read_u64();
             if !is_8digits(v) {
                 break;
@@ -246,9 +257,9 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
         d.decimal_point += n_trailing_zeros as i32;
         d.num_digits -= n_trailing_zeros;
         d.decimal_point += d.num_digits as i32;
-        if d.num_digits > Decimal::MAX_DIGITS {
+        if d.num_digits > N {
             d.truncated = true;
-            d.num_digits = Decimal::MAX_DIGITS;
+            d.num_digits = N;
         }
     }
     if let Some((&ch, s_next)) = s.split_first() {
@@ -272,13 +283,13 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
             d.decimal_point += if neg_exp { -exp_num } else { exp_num };
         }
     }
-    for i in d.num_digits..Decimal::MAX_DIGITS_WITHOUT_OVERFLOW {
+    for i in d.num_digits..Decimal::<N>::MAX_DIGITS_WITHOUT_OVERFLOW {
         d.digits[i] = 0;
     }
     d
 }
 
-fn number_of_digits_decimal_left_shift(d: &Decimal, mut shift: usize) -> usize {
+fn number_of_digits_decimal_left_shift<const N: usize>(d: &Decimal<N>, mut shift: usize) -> usize {
     #[rustfmt::skip]
     const TABLE: [u16; 65] = [
         0x0000, 0x0800, 0x0801, 0x0803, 0x1006, 0x1009, 0x100D, 0x1812, 0x1817, 0x181D, 0x2024,
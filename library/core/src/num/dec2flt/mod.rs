@@ -83,7 +83,11 @@
 use self::float::RawFloat;
 use self::lemire::compute_float;
 use self::parse::{parse_inf_nan, parse_number};
+#[cfg(not(bootstrap))]
+use self::parse::{parse_special, Special};
 use self::slow::parse_long_mantissa;
+#[cfg(not(bootstrap))]
+use self::slow::parse_long_mantissa_f128;
 
 mod common;
 mod decimal;
@@ -98,6 +102,9 @@
 
 macro_rules! from_str_float_impl {
     ($t:ty) => {
+        from_str_float_impl!($t, dec2flt);
+    };
+    ($t:ty, $dec2flt:ident) => {
         #[stable(feature = "rust1", since = "1.0.0")]
         impl FromStr for $t {
             type Err = ParseFloatError;
@@ -155,13 +162,17 @@ impl FromStr for $t {
             // possible instances can ever exist. Adding #[inline(never)] avoids this.
             #[inline(never)]
             fn from_str(src: &str) -> Result<Self, ParseFloatError> {
-                dec2flt(src)
+                $dec2flt(src)
             }
         }
     };
 }
 from_str_float_impl!(f32);
 from_str_float_impl!(f64);
+#[cfg(not(bootstrap))]
+from_str_float_impl!(f16);
+#[cfg(not(bootstrap))]
+from_str_float_impl!(f128, dec2flt_f128);
 
 /// An error which can be returned when parsing a float.
 ///
@@ -274,3 +285,42 @@ pub fn dec2flt<F: RawFloat>(s: &str) -> Result<F, ParseFloatError> {
     }
     Ok(float)
 }
+
+/// Converts a decimal string into an `f128`.
+///
+/// The fast paths above rely on native arithmetic and on 64-bit mantissas, neither of
+/// which are available for `f128`, so finite values always take the slow path.
+#[cfg(not(bootstrap))]
+fn dec2flt_f128(s: &str) -> Result<f128, ParseFloatError> {
+    const SIGN_MASK: u128 = 1 << 127;
+
+    let mut s = s.as_bytes();
+    let c = if let Some(&c) = s.first() {
+        c
+    } else {
+        return Err(pfe_empty());
+    };
+    let negative = c == b'-';
+    if c == b'-' || c == b'+' {
+        s = &s[1..];
+    }
+    if s.is_empty() {
+        return Err(pfe_invalid());
+    }
+
+    // Only the syntax is checked here, the digits are parsed again by the slow path.
+    let mut bits = if parse_number(s).is_some() {
+        let (mantissa, power2) = parse_long_mantissa_f128(s);
+        mantissa | ((power2 as u128) << 112)
+    } else {
+        match parse_special(s) {
+            Some(Special::Infinity) => f128::INFINITY.to_bits(),
+            Some(Special::Nan) => f128::NAN.to_bits(),
+            None => return Err(pfe_invalid()),
+        }
+    };
+    if negative {
+        bits |= SIGN_MASK;
+    }
+    Ok(f128::from_bits(bits))
+}
@@ -196,8 +196,24 @@ pub fn parse_number(s: &[u8]) -> Option<Number> {
     None
 }
 
+/// A special, non-finite float.
+pub(crate) enum Special {
+    Infinity,
+    Nan,
+}
+
 /// Try to parse a special, non-finite float.
 pub(crate) fn parse_inf_nan<F: RawFloat>(s: &[u8], negative: bool) -> Option<F> {
+    let float = match parse_special(s)? {
+        Special::Infinity => F::INFINITY,
+        Special::Nan => F::NAN,
+    };
+
+    if negative { Some(-float) } else { Some(float) }
+}
+
+/// Try to parse a special, non-finite float, independent of its type.
+pub(crate) fn parse_special(s: &[u8]) -> Option<Special> {
     // Since a valid string has at most the length 8, we can load
     // all relevant characters into a u64 and work from there.
     // This also generates much better code.
@@ -232,12 +248,10 @@ pub(crate) fn parse_inf_nan<F: RawFloat>(s: &[u8], negative: bool) -> Option<F>
     // Match register value to constant to parse string.
     // Also match on the string length to catch edge cases
     // like "inf\0\0\0\0\0".
-    let float = match (register, len) {
-        (INF_3, 3) => F::INFINITY,
-        (INF_8, 8) => F::INFINITY,
-        (NAN, 3) => F::NAN,
-        _ => return None,
-    };
-
-    if negative { Some(-float) } else { Some(float) }
+    match (register, len) {
+        (INF_3, 3) => Some(Special::Infinity),
+        (INF_8, 8) => Some(Special::Infinity),
+        (NAN, 3) => Some(Special::Nan),
+        _ => None,
+    }
 }
@@ -1,7 +1,9 @@
 //! Slow, fallback algorithm for cases the Eisel-Lemire algorithm cannot round.
 
 use crate::num::dec2flt::common::BiasedFp;
-use crate::num::dec2flt::decimal::{parse_decimal, Decimal};
+#[cfg(not(bootstrap))]
+use crate::num::dec2flt::decimal::MAX_DIGITS_F128;
+use crate::num::dec2flt::decimal::{parse_decimal, Decimal, MAX_DIGITS};
 use crate::num::dec2flt::float::RawFloat;
 
 /// Parse the significant digits and biased, binary exponent of a float.
@@ -24,6 +26,49 @@
 /// The algorithms described here are based on "Processing Long Numbers Quickly",
 /// available here: <https://arxiv.org/pdf/2101.11408.pdf#section.11>.
 pub(crate) fn parse_long_mantissa<F: RawFloat>(s: &[u8]) -> BiasedFp {
+    let mut d = parse_decimal::<MAX_DIGITS>(s);
+
+    // Short-circuit if the value can only be a literal 0 or infinity.
+    if d.num_digits == 0 || d.decimal_point < -324 {
+        return BiasedFp::zero_pow2(0);
+    } else if d.decimal_point >= 310 {
+        return BiasedFp::zero_pow2(F::INFINITE_POWER);
+    }
+    let (mantissa, power2) =
+        shift_and_round(&mut d, F::MANTISSA_EXPLICIT_BITS, F::MINIMUM_EXPONENT, F::INFINITE_POWER);
+    BiasedFp { f: mantissa as u64, e: power2 }
+}
+
+/// Parse the significant digits and biased, binary exponent of an `f128`.
+///
+/// There is no faster algorithm for `f128`, so this is always used. It is otherwise
+/// the same as `parse_long_mantissa`, but keeps enough digits for the 113-bit mantissa.
+#[cfg(not(bootstrap))]
+pub(crate) fn parse_long_mantissa_f128(s: &[u8]) -> (u128, i32) {
+    const MANTISSA_EXPLICIT_BITS: usize = 112;
+    const MINIMUM_EXPONENT: i32 = -16383;
+    const INFINITE_POWER: i32 = 0x7FFF;
+
+    let mut d = parse_decimal::<MAX_DIGITS_F128>(s);
+
+    // Short-circuit if the value can only be a literal 0 or infinity.
+    // The smallest subnormal is about `6.5e-4966` and the largest value about `1.2e4932`.
+    if d.num_digits == 0 || d.decimal_point < -4966 {
+        return (0, 0);
+    } else if d.decimal_point >= 4934 {
+        return (0, INFINITE_POWER);
+    }
+    shift_and_round(&mut d, MANTISSA_EXPLICIT_BITS, MINIMUM_EXPONENT, INFINITE_POWER)
+}
+
+/// Shift the decimal into the range of a float with the given parameters and round it,
+/// returning the explicit mantissa bits and the biased exponent.
+fn shift_and_round<const N: usize>(
+    d: &mut Decimal<N>,
+    mantissa_explicit_bits: usize,
+    minimum_exponent: i32,
+    infinite_power: i32,
+) -> (u128, i32) {
     const MAX_SHIFT: usize = 60;
     const NUM_POWERS: usize = 19;
     const POWERS: [u8; 19] =
@@ -33,24 +78,16 @@ pub(crate) fn parse_long_mantissa<F: RawFloat>(s: &[u8]) -> BiasedFp {
         if n < NUM_POWERS { POWERS[n] as usize } else { MAX_SHIFT }
     };
 
-    let fp_zero = BiasedFp::zero_pow2(0);
-    let fp_inf = BiasedFp::zero_pow2(F::INFINITE_POWER);
+    let fp_zero = (0, 0);
+    let fp_inf = (0, infinite_power);
 
-    let mut d = parse_decimal(s);
-
-    // Short-circuit if the value can only be a literal 0 or infinity.
-    if d.num_digits == 0 || d.decimal_point < -324 {
-        return fp_zero;
-    } else if d.decimal_point >= 310 {
-        return fp_inf;
-    }
     let mut exp2 = 0_i32;
     // Shift right toward (1/2 ... 1].
     while d.decimal_point > 0 {
         let n = d.decimal_point as usize;
         let shift = get_shift(n);
         d.right_shift(shift);
-        if d.decimal_point < -Decimal::DECIMAL_POINT_RANGE {
+        if d.decimal_point < -Decimal::<N>::DECIMAL_POINT_RANGE {
             return fp_zero;
         }
         exp2 += shift as i32;
@@ -67,43 +104,49 @@ pub(crate) fn parse_long_mantissa<F: RawFloat>(s: &[u8]) -> BiasedFp {
             get_shift((-d.decimal_point) as _)
         };
         d.left_shift(shift);
-        if d.decimal_point > Decimal::DECIMAL_POINT_RANGE {
+        if d.decimal_point > Decimal::<N>::DECIMAL_POINT_RANGE {
             return fp_inf;
         }
         exp2 -= shift as i32;
     }
     // We are now in the range [1/2 ... 1] but the binary format uses [1 ... 2].
     exp2 -= 1;
-    while (F::MINIMUM_EXPONENT + 1) > exp2 {
-        let mut n = ((F::MINIMUM_EXPONENT + 1) - exp2) as usize;
+    while (minimum_exponent + 1) > exp2 {
+        let mut n = ((minimum_exponent + 1) - exp2) as usize;
         if n > MAX_SHIFT {
             n = MAX_SHIFT;
         }
         d.right_shift(n);
         exp2 += n as i32;
     }
-    if (exp2 - F::MINIMUM_EXPONENT) >= F::INFINITE_POWER {
+    if (exp2 - minimum_exponent) >= infinite_power {
         return fp_inf;
     }
     // Shift the decimal to the hidden bit, and then round the value
     // to get the high mantissa+1 bits.
-    d.left_shift(F::MANTISSA_EXPLICIT_BITS + 1);
+    // `left_shift` can only shift by up to `MAX_SHIFT` bits at once.
+    let mut shift = mantissa_explicit_bits + 1;
+    while shift > 0 {
+        let n = if shift > MAX_SHIFT { MAX_SHIFT } else { shift };
+        d.left_shift(n);
+        shift -= n;
+    }
     let mut mantissa = d.round();
-    if mantissa >= (1_u64 << (F::MANTISSA_EXPLICIT_BITS + 1)) {
+    if mantissa >= (1_u128 << (mantissa_explicit_bits + 1)) {
         // Rounding up overflowed to the carry bit, need to
         // shift back to the hidden bit.
         d.right_shift(1);
         exp2 += 1;
         mantissa = d.round();
-        if (exp2 - F::MINIMUM_EXPONENT) >= F::INFINITE_POWER {
+        if (exp2 - minimum_exponent) >= infinite_power {
             return fp_inf;
         }
     }
-    let mut power2 = exp2 - F::MINIMUM_EXPONENT;
-    if mantissa < (1_u64 << F::MANTISSA_EXPLICIT_BITS) {
+    let mut power2 = exp2 - minimum_exponent;
+    if mantissa < (1_u128 << mantissa_explicit_bits) {
         power2 -= 1;
     }
     // Zero out all the bits above the explicit mantissa bits.
-    mantissa &= (1_u64 << F::MANTISSA_EXPLICIT_BITS) - 1;
-    BiasedFp { f: mantissa, e: power2 }
+    mantissa &= (1_u128 << mantissa_explicit_bits) - 1;
+    (mantissa, power2)
 }
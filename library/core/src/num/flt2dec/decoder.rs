@@ -13,11 +13,11 @@
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Decoded {
     /// The scaled mantissa.
-    pub mant: u64,
+    pub mant: u128,
     /// The lower error range.
-    pub minus: u64,
+    pub minus: u128,
     /// The upper error range.
-    pub plus: u64,
+    pub plus: u128,
     /// The shared exponent in base 2.
     pub exp: i16,
     /// True when the error range is inclusive.
@@ -40,20 +40,64 @@ pub enum FullDecoded {
 }
 
 /// A floating point type which can be `decode`d.
-pub trait DecodableFloat: RawFloat + Copy {
+pub trait DecodableFloat: Copy {
     /// The minimum positive normalized value.
     fn min_pos_norm_value() -> Self;
+
+    /// Returns the category that this number falls into.
+    fn classify(self) -> FpCategory;
+
+    /// Returns the mantissa, exponent and sign as integers.
+    fn integer_decode(self) -> (u128, i16, i8);
 }
 
-impl DecodableFloat for f32 {
-    fn min_pos_norm_value() -> Self {
-        f32::MIN_POSITIVE
+macro_rules! impl_decodable_float {
+    ($($t:ident)*) => {
+        $(impl DecodableFloat for $t {
+            fn min_pos_norm_value() -> Self {
+                $t::MIN_POSITIVE
+            }
+
+            fn classify(self) -> FpCategory {
+                self.classify()
+            }
+
+            fn integer_decode(self) -> (u128, i16, i8) {
+                let (mant, exp, sign) = RawFloat::integer_decode(self);
+                (mant as u128, exp, sign)
+            }
+        })*
     }
 }
 
-impl DecodableFloat for f64 {
+impl_decodable_float! { f32 f64 }
+#[cfg(not(bootstrap))]
+impl_decodable_float! { f16 }
+
+// `f128` does not fit the `u64` mantissas of `RawFloat`, so it is decoded here directly.
+#[cfg(not(bootstrap))]
+impl DecodableFloat for f128 {
     fn min_pos_norm_value() -> Self {
-        f64::MIN_POSITIVE
+        f128::MIN_POSITIVE
+    }
+
+    fn classify(self) -> FpCategory {
+        self.classify()
+    }
+
+    fn integer_decode(self) -> (u128, i16, i8) {
+        const MANTISSA_MASK: u128 = (1 << 112) - 1;
+        let bits = self.to_bits();
+        let sign: i8 = if bits >> 127 == 0 { 1 } else { -1 };
+        let mut exponent: i16 = ((bits >> 112) & 0x7fff) as i16;
+        let mantissa = if exponent == 0 {
+            (bits & MANTISSA_MASK) << 1
+        } else {
+            (bits & MANTISSA_MASK) | (1 << 112)
+        };
+        // Exponent bias + mantissa shift
+        exponent -= 16383 + 112;
+        (mantissa, exponent, sign)
     }
 }
 
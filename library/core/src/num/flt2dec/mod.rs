@@ -76,14 +76,16 @@
 Both implementations expose two public functions:
 
 - `format_shortest(decoded, buf)`, which always needs at least
-  `MAX_SIG_DIGITS` digits of buffer. Implements the shortest mode.
+  `MAX_SIG_DIGITS` digits of buffer (`MAX_SIG_DIGITS_F128` for `f128`).
+  Implements the shortest mode.
 
 - `format_exact(decoded, buf, limit)`, which accepts as small as
   one digit of buffer. Implements exact and fixed modes.
 
 They try to fill the `u8` buffer with digits and returns the number of digits
-written and the exponent `k`. They are total for all finite `f32` and `f64`
-inputs (Grisu internally falls back to Dragon if necessary).
+written and the exponent `k`. They are total for all finite `f16`, `f32`, `f64`
+and `f128` inputs (Grisu internally falls back to Dragon if necessary, which is
+always the case for `f128`).
 
 The rendered digits are formatted into the actual string form with
 four functions:
@@ -143,6 +145,11 @@ pub mod strategy {
 /// The exact formula is `ceil(# bits in mantissa * log_10 2 + 1)`.
 pub const MAX_SIG_DIGITS: usize = 17;
 
+/// The minimum size of buffer necessary for the shortest mode of `f128`.
+///
+/// This is `MAX_SIG_DIGITS` for the 113-bit mantissa of `f128`.
+pub const MAX_SIG_DIGITS_F128: usize = 36;
+
 /// When `d` contains decimal digits, increase the last digit and propagate carry.
 /// Returns a next digit when it causes the length to change.
 #[doc(hidden)]
@@ -343,7 +350,8 @@ fn determine_sign(sign: Sign, decoded: &FullDecoded, negative: bool) -> &'static
 /// additional zeroes after rendered digits. Thus `frac_digits` of 0 means that
 /// it will only print given digits and nothing else.
 ///
-/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long,
+/// or `MAX_SIG_DIGITS_F128` bytes for `f128`.
 /// There should be at least 4 parts available, due to the worst case like
 /// `[+][0.][0000][2][0000]` with `frac_digits = 10`.
 pub fn to_shortest_str<'a, T, F>(
@@ -417,7 +425,8 @@ pub fn to_shortest_str<'a, T, F>(
 /// instead of the actual `v`! Thus any printed exponent in the exponential form
 /// cannot be in this range, avoiding any confusion.
 ///
-/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long,
+/// or `MAX_SIG_DIGITS_F128` bytes for `f128`.
 /// There should be at least 6 parts available, due to the worst case like
 /// `[+][1][.][2345][e][-][6]`.
 pub fn to_shortest_exp_str<'a, T, F>(
@@ -483,7 +492,8 @@ pub fn to_shortest_exp_str<'a, T, F>(
 /// `ceil(log_10 (x^exp * (2^64 - 1)))` is less than `ceil(log_10 (2^64 - 1)) +
 /// ceil(exp * log_10 x)`, which is in turn less than `20 + (1 + exp * log_10 x)`.
 /// We use the facts that `log_10 2 < 5/16` and `log_10 5 < 12/16`, which is
-/// enough for our purposes.
+/// enough for our purposes. Mantissas wider than 64 bits (from `f128`) use
+/// `ceil(log_10 (2^128 - 1)) = 39` instead of `20`.
 ///
 /// Why do we need this? `format_exact` functions will fill the entire buffer
 /// unless limited by the last digit restriction, but it is possible that
@@ -492,8 +502,10 @@ pub fn to_shortest_exp_str<'a, T, F>(
 /// allocate all the buffer beforehand. Consequently, for any given arguments,
 /// 826 bytes of buffer should be sufficient for `f64`. Compare this with
 /// the actual number for the worst case: 770 bytes (when `exp = -1074`).
-fn estimate_max_buf_len(exp: i16) -> usize {
-    21 + ((if exp < 0 { -12 } else { 5 } * exp as i32) as usize >> 4)
+/// For `f128` the same estimate gives 12,412 bytes.
+fn estimate_max_buf_len(mant: u128, exp: i16) -> usize {
+    let mant_len = if mant < (1 << 64) { 21 } else { 40 };
+    mant_len + ((if exp < 0 { -12 } else { 5 } * exp as i32) as usize >> 4)
 }
 
 /// Formats given floating point number into the exponential form with
@@ -562,7 +574,7 @@ pub fn to_exact_exp_str<'a, T, F>(
             }
         }
         FullDecoded::Finite(ref decoded) => {
-            let maxlen = estimate_max_buf_len(decoded.exp);
+            let maxlen = estimate_max_buf_len(decoded.mant, decoded.exp);
             assert!(buf.len() >= ndigits || buf.len() >= maxlen);
 
             let trunc = if ndigits < maxlen { ndigits } else { maxlen };
@@ -635,7 +647,7 @@ pub fn to_exact_fixed_str<'a, T, F>(
             }
         }
         FullDecoded::Finite(ref decoded) => {
-            let maxlen = estimate_max_buf_len(decoded.exp);
+            let maxlen = estimate_max_buf_len(decoded.mant, decoded.exp);
             assert!(buf.len() >= maxlen);
 
             // it *is* possible that `frac_digits` is ridiculously large.
@@ -7,10 +7,10 @@
 use crate::cmp::Ordering;
 use crate::mem::MaybeUninit;
 
-use crate::num::bignum::Big32x40 as Big;
 use crate::num::bignum::Digit32 as Digit;
+use crate::num::bignum::{Big32x40, Big32x520};
 use crate::num::flt2dec::estimator::estimate_scaling_factor;
-use crate::num::flt2dec::{round_up, Decoded, MAX_SIG_DIGITS};
+use crate::num::flt2dec::{round_up, Decoded, MAX_SIG_DIGITS, MAX_SIG_DIGITS_F128};
 
 static POW10: [Digit; 10] =
     [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000];
@@ -31,9 +31,73 @@
     0x55bc28f2, 0x80dcc7f7, 0xf46eeddc, 0x5fdcefce, 0x553f7,
 ];
 
+/// The bignum operations needed by Dragon.
+///
+/// `f32` and `f64` only ever need `Big32x40`, while `f128` needs a much larger bignum
+/// that we don't want to put on the stack for every other float.
 #[doc(hidden)]
-pub fn mul_pow10(x: &mut Big, n: usize) -> &mut Big {
-    debug_assert!(n < 512);
+pub trait Bignum: Clone + Ord {
+    fn from_small(v: Digit) -> Self;
+    fn from_u128(v: u128) -> Self;
+    fn is_zero(&self) -> bool;
+    fn add(&mut self, other: &Self) -> &mut Self;
+    fn sub(&mut self, other: &Self) -> &mut Self;
+    fn mul_small(&mut self, other: Digit) -> &mut Self;
+    fn mul_pow2(&mut self, bits: usize) -> &mut Self;
+    fn mul_digits(&mut self, other: &[Digit]) -> &mut Self;
+    fn div_rem_small(&mut self, other: Digit) -> (&mut Self, Digit);
+}
+
+macro_rules! impl_bignum {
+    ($($name:ident)*) => {
+        $(impl Bignum for $name {
+            fn from_small(v: Digit) -> Self {
+                $name::from_small(v)
+            }
+            fn from_u128(v: u128) -> Self {
+                $name::from_u128(v)
+            }
+            fn is_zero(&self) -> bool {
+                $name::is_zero(self)
+            }
+            fn add(&mut self, other: &Self) -> &mut Self {
+                $name::add(self, other)
+            }
+            fn sub(&mut self, other: &Self) -> &mut Self {
+                $name::sub(self, other)
+            }
+            fn mul_small(&mut self, other: Digit) -> &mut Self {
+                $name::mul_small(self, other)
+            }
+            fn mul_pow2(&mut self, bits: usize) -> &mut Self {
+                $name::mul_pow2(self, bits)
+            }
+            fn mul_digits(&mut self, other: &[Digit]) -> &mut Self {
+                $name::mul_digits(self, other)
+            }
+            fn div_rem_small(&mut self, other: Digit) -> (&mut Self, Digit) {
+                $name::div_rem_small(self, other)
+            }
+        })*
+    }
+}
+
+impl_bignum! { Big32x40 Big32x520 }
+
+/// Returns `true` when `mant * 2^exp` comes from `f64` or a narrower type,
+/// so that all intermediate values fit in `Big32x40`.
+fn fits_big32x40(d: &Decoded) -> bool {
+    d.mant < (1 << 64) && (-1076..=970).contains(&d.exp)
+}
+
+#[doc(hidden)]
+pub fn mul_pow10<Big: Bignum>(x: &mut Big, mut n: usize) -> &mut Big {
+    // `f128` needs powers up to `10^4966`, which are built from repeated `10^256`.
+    while n >= 512 {
+        x.mul_digits(&POW10TO256);
+        x.mul_digits(&POW10TO256);
+        n -= 512;
+    }
     if n & 7 != 0 {
         x.mul_small(POW10[n & 7]);
     }
@@ -58,7 +122,7 @@ pub fn mul_pow10(x: &mut Big, n: usize) -> &mut Big {
     x
 }
 
-fn div_2pow10(x: &mut Big, mut n: usize) -> &mut Big {
+fn div_2pow10<Big: Bignum>(x: &mut Big, mut n: usize) -> &mut Big {
     let largest = POW10.len() - 1;
     while n > largest {
         x.div_rem_small(POW10[largest]);
@@ -69,7 +133,7 @@ fn div_2pow10(x: &mut Big, mut n: usize) -> &mut Big {
 }
 
 // only usable when `x < 16 * scale`; `scaleN` should be `scale.mul_small(N)`
-fn div_rem_upto_16<'a>(
+fn div_rem_upto_16<'a, Big: Bignum>(
     x: &'a mut Big,
     scale: &Big,
     scale2: &Big,
@@ -101,6 +165,18 @@ fn div_rem_upto_16<'a>(
 pub fn format_shortest<'a>(
     d: &Decoded,
     buf: &'a mut [MaybeUninit<u8>],
+) -> (/*digits*/ &'a [u8], /*exp*/ i16) {
+    if fits_big32x40(d) {
+        format_shortest_with::<Big32x40>(d, buf)
+    } else {
+        assert!(buf.len() >= MAX_SIG_DIGITS_F128);
+        format_shortest_with::<Big32x520>(d, buf)
+    }
+}
+
+fn format_shortest_with<'a, Big: Bignum>(
+    d: &Decoded,
+    buf: &'a mut [MaybeUninit<u8>],
 ) -> (/*digits*/ &'a [u8], /*exp*/ i16) {
     // the number `v` to format is known to be:
     // - equal to `mant * 2^exp`;
@@ -132,9 +208,9 @@ pub fn format_shortest<'a>(
     // - `v = mant / scale`
     // - `low = (mant - minus) / scale`
     // - `high = (mant + plus) / scale`
-    let mut mant = Big::from_u64(d.mant);
-    let mut minus = Big::from_u64(d.minus);
-    let mut plus = Big::from_u64(d.plus);
+    let mut mant = Big::from_u128(d.mant);
+    let mut minus = Big::from_u128(d.minus);
+    let mut plus = Big::from_u128(d.plus);
     let mut scale = Big::from_small(1);
     if d.exp < 0 {
         scale.mul_pow2(-d.exp as usize);
@@ -262,6 +338,18 @@ pub fn format_exact<'a>(
     d: &Decoded,
     buf: &'a mut [MaybeUninit<u8>],
     limit: i16,
+) -> (/*digits*/ &'a [u8], /*exp*/ i16) {
+    if fits_big32x40(d) {
+        format_exact_with::<Big32x40>(d, buf, limit)
+    } else {
+        format_exact_with::<Big32x520>(d, buf, limit)
+    }
+}
+
+fn format_exact_with<'a, Big: Bignum>(
+    d: &Decoded,
+    buf: &'a mut [MaybeUninit<u8>],
+    limit: i16,
 ) -> (/*digits*/ &'a [u8], /*exp*/ i16) {
     assert!(d.mant > 0);
     assert!(d.minus > 0);
@@ -273,7 +361,7 @@ pub fn format_exact<'a>(
     let mut k = estimate_scaling_factor(d.mant, d.exp);
 
     // `v = mant / scale`.
-    let mut mant = Big::from_u64(d.mant);
+    let mut mant = Big::from_u128(d.mant);
     let mut scale = Big::from_small(1);
     if d.exp < 0 {
         scale.mul_pow2(-d.exp as usize);
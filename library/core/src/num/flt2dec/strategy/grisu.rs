@@ -159,6 +159,14 @@ pub fn max_pow10_no_more_than(x: u32) -> (u8, u32) {
     }
 }
 
+/// Returns `true` when `mant * 2^exp` can be handled by Grisu at all.
+///
+/// We need at least three bits of additional precision in a 64-bit word, and the cached powers
+/// only cover the exponents of `f64`. Anything wider (i.e. `f128`) is left to the fallback.
+fn fits_f64_range(mant: u128, exp: i16) -> bool {
+    mant < (1 << 61) && (-1076..=970).contains(&exp)
+}
+
 /// The shortest mode implementation for Grisu.
 ///
 /// It returns `None` when it would return an inexact representation otherwise.
@@ -172,12 +180,15 @@ pub fn format_shortest_opt<'a>(
     assert!(d.mant.checked_add(d.plus).is_some());
     assert!(d.mant.checked_sub(d.minus).is_some());
     assert!(buf.len() >= MAX_SIG_DIGITS);
-    assert!(d.mant + d.plus < (1 << 61)); // we need at least three bits of additional precision
+
+    if !fits_f64_range(d.mant + d.plus, d.exp) {
+        return None;
+    }
 
     // start with the normalized values with the shared exponent
-    let plus = Fp { f: d.mant + d.plus, e: d.exp }.normalize();
-    let minus = Fp { f: d.mant - d.minus, e: d.exp }.normalize_to(plus.e);
-    let v = Fp { f: d.mant, e: d.exp }.normalize_to(plus.e);
+    let plus = Fp { f: (d.mant + d.plus) as u64, e: d.exp }.normalize();
+    let minus = Fp { f: (d.mant - d.minus) as u64, e: d.exp }.normalize_to(plus.e);
+    let v = Fp { f: d.mant as u64, e: d.exp }.normalize_to(plus.e);
 
     // find any `cached = 10^minusk` such that `ALPHA <= minusk + plus.e + 64 <= GAMMA`.
     // since `plus` is normalized, this means `2^(62 + ALPHA) <= plus * cached < 2^(64 + GAMMA)`;
@@ -474,11 +485,14 @@ pub fn format_exact_opt<'a>(
     limit: i16,
 ) -> Option<(/*digits*/ &'a [u8], /*exp*/ i16)> {
     assert!(d.mant > 0);
-    assert!(d.mant < (1 << 61)); // we need at least three bits of additional precision
     assert!(!buf.is_empty());
 
+    if !fits_f64_range(d.mant, d.exp) {
+        return None;
+    }
+
     // normalize and scale `v`.
-    let v = Fp { f: d.mant, e: d.exp }.normalize();
+    let v = Fp { f: d.mant as u64, e: d.exp }.normalize();
     let (minusk, cached) = cached_power(ALPHA - v.e - 64, GAMMA - v.e - 64);
     let v = v.mul(&cached);
 
@@ -0,0 +1,92 @@
+//! Pattern types: integer types restricted to the values matching a pattern.
+//!
+//! The type `pattern_type!(u32 is 1..)` has the same size and representation as `u32`, but
+//! its values are restricted to the range `1..`. The values outside the range are a niche
+//! that enums can use, just like for the `NonZero*` types:
+//!
+//! ```
+//! #![feature(pattern_types)]
+//! # #![allow(incomplete_features)]
+//! use std::mem::size_of;
+//! use std::pat::{pattern_type, to_base, try_from_base};
+//!
+//! type Positive = pattern_type!(u32 is 1..);
+//!
+//! let x: Positive = 42;
+//! assert_eq!(to_base(x), 42);
+//! assert_eq!(try_from_base::<Positive>(7).map(to_base), Some(7));
+//! assert_eq!(try_from_base::<Positive>(0), None);
+//! assert_eq!(size_of::<Option<Positive>>(), size_of::<u32>());
+//! ```
+//!
+//! Only range patterns with integer literals as endpoints are supported for now. An
+//! integer literal is only accepted as a value of a pattern type if it lies within the
+//! range. Other values of the base type are converted with [`try_from_base`], which checks
+//! the range at runtime, and every value can be turned back into its base type with
+//! [`to_base`].
+
+use crate::intrinsics;
+
+pub use crate::macros::builtin::pattern_type;
+
+/// Implemented by the compiler for every pattern type, and only for them.
+///
+/// This trait cannot be implemented manually.
+#[unstable(feature = "pattern_types", issue = "123646")]
+#[lang = "pattern_type_trait"]
+#[rustc_deny_explicit_impl]
+pub trait PatternType: Copy {
+    /// The type whose values the pattern restricts, e.g. `u32` for
+    /// `pattern_type!(u32 is 1..)`.
+    #[lang = "pattern_base"]
+    type Base: Copy;
+}
+
+/// Converts `value` into the pattern type `P`, or returns `None` if it does not match the
+/// pattern of `P`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pattern_types)]
+/// # #![allow(incomplete_features)]
+/// use std::pat::{pattern_type, try_from_base};
+///
+/// type Percent = pattern_type!(u8 is 0..=100);
+///
+/// assert!(try_from_base::<Percent>(100).is_some());
+/// assert!(try_from_base::<Percent>(101).is_none());
+/// ```
+#[unstable(feature = "pattern_types", issue = "123646")]
+#[inline]
+pub fn try_from_base<P: PatternType>(value: P::Base) -> Option<P> {
+    if intrinsics::pattern_type_contains::<P>(value) {
+        // SAFETY: a pattern type has the same layout as its base type, and `value` was just
+        // checked to match the pattern.
+        Some(unsafe { intrinsics::transmute_unchecked(value) })
+    } else {
+        None
+    }
+}
+
+/// Returns the value of the pattern type `P` as a value of its base type.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pattern_types)]
+/// # #![allow(incomplete_features)]
+/// use std::pat::{pattern_type, to_base};
+///
+/// type Small = pattern_type!(i8 is -5..5);
+///
+/// let small: Small = -5;
+/// assert_eq!(to_base(small), -5i8);
+/// ```
+#[unstable(feature = "pattern_types", issue = "123646")]
+#[inline]
+pub fn to_base<P: PatternType>(value: P) -> P::Base {
+    // SAFETY: a pattern type has the same layout as its base type, and every value of the
+    // pattern type is a value of the base type.
+    unsafe { intrinsics::transmute_unchecked(value) }
+}
@@ -35,6 +35,12 @@
 pub use bool;
 #[stable(feature = "core_primitive", since = "1.43.0")]
 pub use char;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "f128", issue = "116909")]
+pub use f128;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "f16", issue = "116909")]
+pub use f16;
 #[stable(feature = "core_primitive", since = "1.43.0")]
 pub use f32;
 #[stable(feature = "core_primitive", since = "1.43.0")]
@@ -1181,6 +1181,43 @@ mod prim_f32 {}
 #[stable(feature = "rust1", since = "1.0.0")]
 mod prim_f64 {}
 
+#[cfg(not(bootstrap))]
+#[rustc_doc_primitive = "f16"]
+/// A 16-bit floating point type (specifically, the "binary16" type defined in IEEE 754-2008).
+///
+/// This type is very similar to [`f32`] but has decreased precision and range by using half
+/// as many bits. Please see [the documentation for `f32`][`f32`] or [Wikipedia on half
+/// precision values][wikipedia] for more information.
+///
+/// Not every target has hardware support for this type; where it is missing, arithmetic is
+/// typically carried out by promoting to `f32` and rounding the result back.
+///
+/// *[See also the `std::f16::consts` module](crate::f16::consts).*
+///
+/// [`f32`]: prim@f32
+/// [wikipedia]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
+#[unstable(feature = "f16", issue = "116909")]
+mod prim_f16 {}
+
+#[cfg(not(bootstrap))]
+#[rustc_doc_primitive = "f128"]
+/// A 128-bit floating point type (specifically, the "binary128" type defined in IEEE 754-2008).
+///
+/// This type is very similar to [`f32`] and [`f64`], but has increased precision by using
+/// twice as many bits as `f64`. Please see [the documentation for `f32`][`f32`] or
+/// [Wikipedia on quad precision values][wikipedia] for more information.
+///
+/// Library support for this type is still incomplete: it implements `Debug` (printing the
+/// raw bit pattern), but not yet `Display` or `FromStr`.
+///
+/// *[See also the `std::f128::consts` module](crate::f128::consts).*
+///
+/// [`f32`]: prim@f32
+/// [`f64`]: prim@f64
+/// [wikipedia]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
+#[unstable(feature = "f128", issue = "116909")]
+mod prim_f128 {}
+
 #[rustc_doc_primitive = "i8"]
 //
 /// The 8-bit signed integer type.
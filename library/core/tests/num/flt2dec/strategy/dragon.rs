@@ -1,5 +1,6 @@
 use super::super::*;
 use core::num::bignum::Big32x40 as Big;
+use core::num::bignum::Big32x520;
 use core::num::flt2dec::strategy::dragon::*;
 
 #[test]
@@ -13,6 +14,21 @@ fn test_mul_pow10() {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_mul_pow10_f128() {
+    // `f128` needs powers of ten far beyond what `f64` uses
+    let mut expected = Big32x520::from_small(1);
+    for i in 0..4967 {
+        if i % 97 == 0 {
+            let mut actual = Big32x520::from_small(1);
+            mul_pow10(&mut actual, i);
+            assert_eq!(actual, expected);
+        }
+        expected.mul_small(10);
+    }
+}
+
 #[test]
 fn shortest_sanity_test() {
     f64_shortest_sanity_test(format_shortest);
@@ -93,9 +93,12 @@
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
+use crate::path::PathBuf;
+use crate::ptr;
+use crate::string::String;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use crate::sync::Once;
-use crate::sys_common::backtrace::{lock, output_filename};
+use crate::sys_common::backtrace::{filename_to_path, lock, output_filename};
 use crate::vec::Vec;
 
 /// A captured OS thread stack backtrace.
@@ -157,15 +160,23 @@ pub struct BacktraceFrame {
 #[derive(Debug)]
 enum RawFrame {
     Actual(backtrace_rs::Frame),
+    Address(usize),
     #[cfg(test)]
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol resolved for a single frame of a backtrace.
+///
+/// One frame may resolve to several symbols when functions have been inlined
+/// into it. In that case the inlined functions come first, innermost first,
+/// and the function the frame actually belongs to comes last.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
     colno: Option<u32>,
+    inlined: bool,
 }
 
 enum BytesOrWide {
@@ -209,6 +220,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "79676")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // FIXME: improve formatting: https://github.com/rust-lang/rust/issues/65280
@@ -239,10 +251,7 @@ impl fmt::Debug for BytesOrWide {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         output_filename(
             fmt,
-            match self {
-                BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-            },
+            self.as_raw(),
             backtrace_rs::PrintFmt::Short,
             crate::env::current_dir().as_ref().ok(),
         )
@@ -415,10 +424,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
                     f.frame().print_raw_with_column(
                         frame.frame.ip(),
                         symbol.name.as_ref().map(|b| backtrace_rs::SymbolName::new(b)),
-                        symbol.filename.as_ref().map(|b| match b {
-                            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-                        }),
+                        symbol.filename.as_ref().map(BytesOrWide::as_raw),
                         symbol.lineno,
                         symbol.colno,
                     )?;
@@ -470,26 +476,155 @@ fn resolve(&mut self) {
         // everything.
         let _lock = lock();
         for frame in self.frames.iter_mut() {
-            let symbols = &mut frame.symbols;
-            let frame = match &frame.frame {
-                RawFrame::Actual(frame) => frame,
+            frame.resolve_unsynchronized();
+        }
+    }
+}
+
+impl BacktraceFrame {
+    /// Resolves the symbols of an instruction pointer captured elsewhere.
+    ///
+    /// This can be used to symbolize addresses that were not captured through
+    /// [`Backtrace`], for example ones recorded by a signal handler or read
+    /// back from a crash dump of the current process. The address must belong
+    /// to code loaded in the current process; the returned frame has no
+    /// module base address.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn resolve(ip: *mut c_void) -> BacktraceFrame {
+        let _lock = lock();
+        let mut frame =
+            BacktraceFrame { frame: RawFrame::Address(ip.addr()), symbols: Vec::new() };
+        frame.resolve_unsynchronized();
+        frame
+    }
+
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is normally the next instruction to execute in the frame, although
+    /// not all platforms report it with full precision.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function this frame belongs to.
+    ///
+    /// On platforms where this can't be determined the instruction pointer is
+    /// returned instead.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the base address of the module (executable or shared library)
+    /// containing this frame, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn module_base_address(&self) -> Option<*mut c_void> {
+        self.frame.module_base_address()
+    }
+
+    /// Returns the symbols this frame resolved to.
+    ///
+    /// The slice is empty if no symbol information was found for the frame.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+
+    // Resolves the symbols of this frame. The caller must hold the global
+    // backtrace lock.
+    fn resolve_unsynchronized(&mut self) {
+        let symbols = &mut self.symbols;
+        let push = |symbol: &backtrace_rs::Symbol| {
+            symbols.push(BacktraceSymbol {
+                name: symbol.name().map(|m| m.as_bytes().to_vec()),
+                filename: symbol.filename_raw().map(|b| match b {
+                    BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
+                    BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
+                }),
+                lineno: symbol.lineno(),
+                colno: symbol.colno(),
+                inlined: false,
+            });
+        };
+        unsafe {
+            match &self.frame {
+                RawFrame::Actual(frame) => backtrace_rs::resolve_frame_unsynchronized(frame, push),
+                RawFrame::Address(ip) => {
+                    backtrace_rs::resolve_unsynchronized(ptr::invalid_mut(*ip), push)
+                }
                 #[cfg(test)]
                 RawFrame::Fake => unimplemented!(),
-            };
-            unsafe {
-                backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
-                    symbols.push(BacktraceSymbol {
-                        name: symbol.name().map(|m| m.as_bytes().to_vec()),
-                        filename: symbol.filename_raw().map(|b| match b {
-                            BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
-                            BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
-                        }),
-                        lineno: symbol.lineno(),
-                        colno: symbol.colno(),
-                    });
-                });
             }
         }
+
+        // Every symbol but the last one was inlined into the frame's function.
+        if let Some((_, inlined)) = self.symbols.split_last_mut() {
+            for symbol in inlined {
+                symbol.inlined = true;
+            }
+        }
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, if known.
+    ///
+    /// Trailing hashes of Rust symbols are stripped.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the raw, possibly mangled, name of this symbol, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn raw_name(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the source file this symbol was defined in, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().map(|b| filename_to_path(b.as_raw()).into_owned())
+    }
+
+    /// Returns the line number for this symbol, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number for this symbol, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+
+    /// Returns `true` if this symbol is a function that was inlined into the
+    /// frame it was resolved for.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[must_use]
+    pub fn is_inlined(&self) -> bool {
+        self.inlined
+    }
+}
+
+impl BytesOrWide {
+    fn as_raw(&self) -> BytesOrWideString<'_> {
+        match self {
+            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
+            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
+        }
     }
 }
 
@@ -497,8 +632,23 @@ impl RawFrame {
     fn ip(&self) -> *mut c_void {
         match self {
             RawFrame::Actual(frame) => frame.ip(),
+            RawFrame::Address(ip) => ptr::invalid_mut(*ip),
             #[cfg(test)]
             RawFrame::Fake => crate::ptr::invalid_mut(1),
         }
     }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            _ => self.ip(),
+        }
+    }
+
+    fn module_base_address(&self) -> Option<*mut c_void> {
+        match self {
+            RawFrame::Actual(frame) => frame.module_base_address(),
+            _ => None,
+        }
+    }
 }
@@ -9,6 +9,7 @@ fn generate_fake_frames() -> Vec<BacktraceFrame> {
                 filename: Some(BytesOrWide::Bytes(b"rust/backtrace.rs".to_vec())),
                 lineno: Some(100),
                 colno: None,
+                inlined: false,
             }],
         },
         BacktraceFrame {
@@ -18,6 +19,7 @@ fn generate_fake_frames() -> Vec<BacktraceFrame> {
                 filename: None,
                 lineno: None,
                 colno: None,
+                inlined: false,
             }],
         },
         BacktraceFrame {
@@ -28,12 +30,14 @@ fn generate_fake_frames() -> Vec<BacktraceFrame> {
                     filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
                     lineno: Some(300),
                     colno: Some(5),
+                    inlined: true,
                 },
                 BacktraceSymbol {
                     name: Some(b"std::rt::lang_start".to_vec()),
                     filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
                     lineno: Some(400),
                     colno: None,
+                    inlined: false,
                 },
             ],
         },
@@ -93,3 +97,26 @@ fn test_frames() {
 
     assert!(iter.all(|(f, e)| format!("{f:#?}") == *e));
 }
+
+#[test]
+fn test_symbol_accessors() {
+    let frames = generate_fake_frames();
+
+    assert_eq!(frames[0].ip(), crate::ptr::invalid_mut(1));
+    assert_eq!(frames[0].module_base_address(), None);
+
+    let symbols = frames[2].symbols();
+    assert_eq!(symbols.len(), 2);
+
+    assert_eq!(symbols[0].name().as_deref(), Some("std::rt::lang_start_internal"));
+    assert_eq!(symbols[0].raw_name(), Some(&b"std::rt::lang_start_internal"[..]));
+    assert_eq!(symbols[0].filename(), Some(PathBuf::from("rust/rt.rs")));
+    assert_eq!(symbols[0].lineno(), Some(300));
+    assert_eq!(symbols[0].colno(), Some(5));
+    assert!(symbols[0].is_inlined());
+
+    assert_eq!(symbols[1].colno(), None);
+    assert!(!symbols[1].is_inlined());
+
+    assert!(frames[1].symbols()[0].filename().is_none());
+}
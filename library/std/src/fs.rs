@@ -145,6 +145,57 @@ pub struct File {
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct DirEntry(fs_imp::DirEntry);
 
+/// Recursive iterator over the entries in a directory tree.
+///
+/// This iterator is returned from the [`walk_dir`] function of this module and
+/// will yield instances of <code>[io::Result]<[DirEntry]></code> for every
+/// entry below the root directory, in depth-first order. A directory is always
+/// yielded before its contents, which gives the caller a chance to skip it
+/// with [`WalkDir::prune`].
+///
+/// The traversal can be configured with [`min_depth`], [`max_depth`],
+/// [`follow_links`] and [`sort_by_file_name`] before iteration starts.
+///
+/// # Errors
+///
+/// This [`io::Result`] will be an [`Err`] if a directory cannot be opened or
+/// read, or if a symbolic link loop is detected while following links. The
+/// iterator keeps going after an error, so the caller may choose to skip the
+/// offending subtree and continue.
+///
+/// [`min_depth`]: WalkDir::min_depth
+/// [`max_depth`]: WalkDir::max_depth
+/// [`follow_links`]: WalkDir::follow_links
+/// [`sort_by_file_name`]: WalkDir::sort_by_file_name
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+#[derive(Debug)]
+pub struct WalkDir {
+    root: Option<PathBuf>,
+    stack: Vec<WalkDirLevel>,
+    // Directory most recently yielded, to be descended into on the next call
+    // to `next` unless `prune` is called first.
+    pending: Option<PathBuf>,
+    depth: usize,
+    min_depth: usize,
+    max_depth: usize,
+    follow_links: bool,
+    sort: bool,
+}
+
+#[derive(Debug)]
+struct WalkDirLevel {
+    entries: WalkDirEntries,
+    // Canonical path of this directory, only tracked while following symbolic
+    // links so that loops can be detected.
+    canonical: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+enum WalkDirEntries {
+    Unsorted(ReadDir),
+    Sorted(crate::vec::IntoIter<io::Result<DirEntry>>),
+}
+
 /// Options and flags which can be used to configure how a file is opened.
 ///
 /// This builder exposes the ability to configure how a [`File`] is opened and
@@ -1782,6 +1833,250 @@ fn as_inner(&self) -> &fs_imp::DirEntry {
     }
 }
 
+impl WalkDir {
+    fn new(root: PathBuf) -> WalkDir {
+        WalkDir {
+            root: Some(root),
+            stack: Vec::new(),
+            pending: None,
+            depth: 0,
+            min_depth: 1,
+            max_depth: usize::MAX,
+            follow_links: false,
+            sort: false,
+        }
+    }
+
+    /// Only yields entries at least `depth` levels below the root.
+    ///
+    /// The immediate children of the root directory are at depth 1, which is
+    /// also the default. Entries above the minimum depth are still traversed,
+    /// they are just not yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     // Skip the top-level entries of `src`.
+    ///     for entry in fs::walk_dir("src").min_depth(2) {
+    ///         println!("{}", entry?.path().display());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn min_depth(mut self, depth: usize) -> WalkDir {
+        self.min_depth = depth;
+        self
+    }
+
+    /// Does not descend more than `depth` levels below the root.
+    ///
+    /// A maximum depth of 1 yields the same entries as [`read_dir`]. By
+    /// default there is no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     for entry in fs::walk_dir(".").max_depth(2) {
+    ///         println!("{}", entry?.path().display());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn max_depth(mut self, depth: usize) -> WalkDir {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets whether symbolic links to directories are followed.
+    ///
+    /// By default symbolic links are yielded like any other entry but never
+    /// descended into. When following links, a link pointing back at one of
+    /// its own ancestors yields an error of kind
+    /// [`FilesystemLoop`](io::ErrorKind::FilesystemLoop) instead of recursing
+    /// forever.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn follow_links(mut self, follow: bool) -> WalkDir {
+        self.follow_links = follow;
+        self
+    }
+
+    /// Yields the entries of each directory sorted by their file name.
+    ///
+    /// By default entries are yielded in the order the platform returns them,
+    /// which is platform and filesystem dependent. Sorting requires reading
+    /// every directory in full before yielding its first entry.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let paths = fs::walk_dir("assets")
+    ///         .sort_by_file_name()
+    ///         .map(|entry| entry.map(|e| e.path()))
+    ///         .collect::<Result<Vec<_>, _>>()?;
+    ///     println!("{paths:?}");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn sort_by_file_name(mut self) -> WalkDir {
+        self.sort = true;
+        self
+    }
+
+    /// Skips the contents of the directory most recently yielded.
+    ///
+    /// If the last entry returned by [`next`](Iterator::next) was a directory,
+    /// the iterator will not descend into it. Otherwise this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut walk = fs::walk_dir(".");
+    ///     while let Some(entry) = walk.next() {
+    ///         let entry = entry?;
+    ///         if entry.file_name() == ".git" {
+    ///             walk.prune();
+    ///             continue;
+    ///         }
+    ///         println!("{}", entry.path().display());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn prune(&mut self) {
+        self.pending = None;
+    }
+
+    /// Returns the depth of the entry most recently yielded.
+    ///
+    /// The immediate children of the root directory are at depth 1.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn push(&mut self, dir: PathBuf) -> io::Result<()> {
+        let canonical = if self.follow_links {
+            let canonical = canonicalize(&dir)?;
+            if self.stack.iter().any(|level| level.canonical.as_ref() == Some(&canonical)) {
+                return Err(io::const_io_error!(
+                    io::ErrorKind::FilesystemLoop,
+                    "symbolic link loop detected while walking directory",
+                ));
+            }
+            Some(canonical)
+        } else {
+            None
+        };
+        let read_dir = read_dir(&dir)?;
+        let entries = if self.sort {
+            let mut entries: Vec<_> = read_dir.collect();
+            // Errors sort first so that they are not lost behind the entries.
+            entries.sort_by_cached_key(|entry| entry.as_ref().ok().map(|e| e.file_name()));
+            WalkDirEntries::Sorted(entries.into_iter())
+        } else {
+            WalkDirEntries::Unsorted(read_dir)
+        };
+        self.stack.push(WalkDirLevel { entries, canonical });
+        Ok(())
+    }
+
+    fn is_dir(&self, entry: &DirEntry) -> io::Result<bool> {
+        let file_type = entry.file_type()?;
+        if self.follow_links && file_type.is_symlink() {
+            match metadata(entry.path()) {
+                Ok(meta) => Ok(meta.is_dir()),
+                // Dangling links are yielded, but there is nothing to descend into.
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(file_type.is_dir())
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Iterator for WalkDir {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        if let Some(root) = self.root.take() {
+            if self.max_depth == 0 {
+                return None;
+            }
+            if let Err(e) = self.push(root) {
+                return Some(Err(e));
+            }
+        }
+        if let Some(dir) = self.pending.take() {
+            if let Err(e) = self.push(dir) {
+                return Some(Err(e));
+            }
+        }
+        loop {
+            let level = self.stack.last_mut()?;
+            let entry = match &mut level.entries {
+                WalkDirEntries::Unsorted(read_dir) => read_dir.next(),
+                WalkDirEntries::Sorted(entries) => entries.next(),
+            };
+            let entry = match entry {
+                Some(Ok(entry)) => entry,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            let depth = self.stack.len();
+            if depth < self.max_depth {
+                match self.is_dir(&entry) {
+                    Ok(true) => {
+                        if depth < self.min_depth {
+                            // Not yielded, so it cannot be pruned: descend right away.
+                            if let Err(e) = self.push(entry.path()) {
+                                return Some(Err(e));
+                            }
+                            continue;
+                        }
+                        self.pending = Some(entry.path());
+                    }
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            if depth >= self.min_depth {
+                self.depth = depth;
+                return Some(Ok(entry));
+            }
+        }
+    }
+}
+
 /// Removes a file from the filesystem.
 ///
 /// Note that there is no
@@ -2314,6 +2609,57 @@ pub fn remove_dir_all<P: AsRef<Path>>(path: P) -> io::Result<()> {
     fs_imp::remove_dir_all(path.as_ref())
 }
 
+/// Recursively copies a directory and all of its contents to a new location.
+///
+/// `to` is created as a new directory and must not exist yet. Regular files
+/// are copied with their contents and permissions, directories are recreated
+/// with the permissions of their source, and symbolic links are copied as
+/// symbolic links pointing at the same target, without being followed. If
+/// `from` itself is a symbolic link to a directory, it is followed.
+///
+/// On failure the partially copied tree is left in place at `to`.
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to `openat`, `fdopendir`, `mkdirat`,
+/// `readlinkat` and `symlinkat` on Unix (except for macOS on x86 and
+/// platforms where [`remove_dir_all`] falls back to a path-based
+/// implementation), so the source tree cannot be redirected through symbolic
+/// links swapped in during the copy. On Linux and Android the file contents
+/// are copied as by [`fs::copy`], using `copy_file_range(2)` where possible.
+/// Note that, this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+/// [`fs::copy`]: copy
+///
+/// # Errors
+///
+/// This function will return an error in the following situations, but is not
+/// limited to just these cases:
+///
+/// * `from` is not a directory or doesn't exist.
+/// * `to` already exists.
+/// * The tree contains an entry that is neither a regular file, a directory
+///   nor a symbolic link, such as a FIFO or a device node.
+/// * The current process lacks the permissions to read `from` or to write to
+///   the parent of `to`.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_copy_dir_all)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     fs::copy_dir_all("template", "project")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_copy_dir_all", issue = "none")]
+pub fn copy_dir_all<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<()> {
+    fs_imp::copy_dir_all(from.as_ref(), to.as_ref())
+}
+
 /// Returns an iterator over the entries within a directory.
 ///
 /// The iterator will yield instances of <code>[io::Result]<[DirEntry]></code>.
@@ -2389,6 +2735,46 @@ pub fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
     fs_imp::readdir(path.as_ref()).map(ReadDir)
 }
 
+/// Returns an iterator over all entries below a directory, recursively.
+///
+/// The iterator will yield instances of <code>[io::Result]<[DirEntry]></code>
+/// in depth-first order, each directory before its contents. The root
+/// directory itself is not yielded, and entries for the current and parent
+/// directories (typically `.` and `..`) are skipped.
+///
+/// The root is only opened once iteration starts, so errors opening it are
+/// reported by the first call to [`next`](Iterator::next). See [`WalkDir`] for
+/// limiting the depth, following symbolic links, sorting and pruning.
+///
+/// # Platform-specific behavior
+///
+/// This function is currently built on [`read_dir`], so it uses `opendir` and
+/// `readdir` on Unix and `FindFirstFile` and `FindNextFile` on Windows.
+/// Note that, this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in fs::walk_dir("src") {
+///         let entry = entry?;
+///         if entry.file_type()?.is_file() {
+///             println!("{}", entry.path().display());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> WalkDir {
+    WalkDir::new(path.as_ref().to_path_buf())
+}
+
 /// Changes the permissions found on a file or a directory.
 ///
 /// # Platform-specific behavior
@@ -709,6 +709,99 @@ fn recursive_rmdir_toctou() {
     }
 }
 
+#[test]
+fn walk_dir_depth_first() {
+    let tmpdir = tmpdir();
+    let root = tmpdir.join("root");
+    check!(fs::create_dir_all(root.join("a/b")));
+    check!(fs::create_dir_all(root.join("c")));
+    check!(File::create(root.join("a/b/file")));
+    check!(File::create(root.join("c/file")));
+
+    let paths: Vec<_> = fs::walk_dir(&root)
+        .sort_by_file_name()
+        .map(|e| check!(e).path().strip_prefix(&root).unwrap().to_path_buf())
+        .collect();
+    let expected = ["a", "a/b", "a/b/file", "c", "c/file"];
+    assert_eq!(paths, expected.iter().map(Path::new).collect::<Vec<_>>());
+
+    let shallow = fs::walk_dir(&root).max_depth(1).map(|e| check!(e)).count();
+    assert_eq!(shallow, 2);
+    let deep = fs::walk_dir(&root).min_depth(3).map(|e| check!(e).file_name()).collect::<Vec<_>>();
+    assert_eq!(deep, ["file"]);
+}
+
+#[test]
+fn walk_dir_prune() {
+    let tmpdir = tmpdir();
+    let root = tmpdir.join("root");
+    check!(fs::create_dir_all(root.join("skip/inner")));
+    check!(fs::create_dir_all(root.join("keep/inner")));
+
+    let mut walk = fs::walk_dir(&root);
+    let mut seen = Vec::new();
+    while let Some(entry) = walk.next() {
+        let entry = check!(entry);
+        if entry.file_name() == "skip" {
+            walk.prune();
+        }
+        seen.push(entry.path());
+    }
+    seen.sort();
+    assert_eq!(seen, [root.join("keep"), root.join("keep/inner"), root.join("skip")]);
+}
+
+#[test]
+fn walk_dir_symlink_loop() {
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+    let root = tmpdir.join("root");
+    check!(fs::create_dir_all(root.join("d")));
+    check!(symlink_dir(&root, root.join("d/up")));
+
+    // Links are not followed by default.
+    assert_eq!(fs::walk_dir(&root).map(|e| check!(e)).count(), 2);
+
+    let errors = fs::walk_dir(&root).follow_links(true).filter_map(Result::err);
+    let kinds: Vec<_> = errors.map(|e| e.kind()).collect();
+    assert_eq!(kinds, [ErrorKind::FilesystemLoop]);
+}
+
+#[test]
+fn recursive_copy_dir() {
+    let tmpdir = tmpdir();
+    let src = tmpdir.join("src");
+    let dst = tmpdir.join("dst");
+    check!(fs::create_dir_all(src.join("a/b")));
+    check!(fs::write(src.join("a/b/file"), b"hello"));
+    check!(fs::write(src.join("top"), b"world"));
+    if got_symlink_permission(&tmpdir) {
+        check!(symlink_file("top", src.join("link")));
+    }
+    check!(fs::copy_dir_all(&src, &dst));
+
+    assert_eq!(check!(fs::read(dst.join("a/b/file"))), b"hello");
+    assert_eq!(check!(fs::read(dst.join("top"))), b"world");
+    if got_symlink_permission(&tmpdir) {
+        assert!(check!(fs::symlink_metadata(dst.join("link"))).file_type().is_symlink());
+        assert_eq!(check!(fs::read_link(dst.join("link"))), Path::new("top"));
+    }
+
+    // The destination must not exist yet.
+    assert!(fs::copy_dir_all(&src, &dst).is_err());
+}
+
+#[test]
+fn recursive_copy_dir_of_file_fails() {
+    let tmpdir = tmpdir();
+    let file = tmpdir.join("file");
+    check!(File::create(&file));
+    assert!(fs::copy_dir_all(&file, tmpdir.join("dst")).is_err());
+    assert!(!tmpdir.join("dst").exists());
+}
+
 #[test]
 fn unicode_path_is_dir() {
     assert!(Path::new(".").is_dir());
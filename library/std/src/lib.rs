@@ -253,6 +253,8 @@
 #![feature(doc_notable_trait)]
 #![feature(dropck_eyepatch)]
 #![feature(exhaustive_patterns)]
+#![cfg_attr(not(bootstrap), feature(f128))]
+#![cfg_attr(not(bootstrap), feature(f16))]
 #![feature(if_let_guard)]
 #![feature(intra_doc_pointers)]
 #![feature(lang_items)]
@@ -451,10 +453,19 @@
 pub use core::clone;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::cmp;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "contracts", issue = "128044")]
+pub use core::contracts;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::convert;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::default;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "f128", issue = "116909")]
+pub use core::f128;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "f16", issue = "116909")]
+pub use core::f16;
 #[stable(feature = "futures_api", since = "1.36.0")]
 pub use core::future;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -491,6 +502,9 @@
 pub use core::ops;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::option;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "pattern_types", issue = "123646")]
+pub use core::pat;
 #[stable(feature = "pin", since = "1.33.0")]
 pub use core::pin;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -603,6 +617,8 @@ pub mod arch {
 // Private support modules
 mod panicking;
 mod personality;
+#[cfg(not(bootstrap))]
+mod target_clones;
 
 #[path = "../../backtrace/src/lib.rs"]
 #[allow(dead_code, unused_attributes, fuzzy_provenance_casts)]
@@ -29,10 +29,14 @@
 pub use self::socket_addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
 #[unstable(feature = "tcplistener_into_incoming", issue = "88339")]
 pub use self::tcp::IntoIncoming;
+#[unstable(feature = "net_socket_builder", issue = "none")]
+pub use self::tcp::TcpSocket;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::tcp::{Incoming, TcpListener, TcpStream};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::udp::UdpSocket;
+#[unstable(feature = "net_socket_builder", issue = "none")]
+pub use self::udp::UdpSocketBuilder;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::net::AddrParseError;
 
@@ -8,7 +8,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::iter::FusedIterator;
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -1058,3 +1058,297 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A TCP socket that has not yet been turned into a [`TcpStream`] or a [`TcpListener`].
+///
+/// [`TcpStream::connect`] and [`TcpListener::bind`] create, configure and connect or bind a
+/// socket in one step, picking the socket options and the listen backlog themselves. A
+/// `TcpSocket` splits these steps apart: options such as `SO_REUSEPORT` or `IPV6_V6ONLY`
+/// can be set on it before it is [bound], and it is then turned into a listener with
+/// [`listen`] or into a stream with [`connect`].
+///
+/// The socket will be closed when the value is dropped.
+///
+/// [bound]: TcpSocket::bind
+/// [`connect`]: TcpSocket::connect
+/// [`listen`]: TcpSocket::listen
+///
+/// # Examples
+///
+/// Running two listeners on the same port:
+///
+/// ```no_run
+/// #![feature(net_socket_builder)]
+/// use std::net::TcpSocket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let addr = "127.0.0.1:8080".parse().unwrap();
+///     let mut listeners = Vec::new();
+///     for _ in 0..2 {
+///         let socket = TcpSocket::new_v4()?;
+///         socket.set_reuseport(true)?;
+///         socket.bind(addr)?;
+///         listeners.push(socket.listen(1024)?);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "net_socket_builder", issue = "none")]
+pub struct TcpSocket(net_imp::TcpSocket);
+
+impl TcpSocket {
+    /// Creates a new IPv4 TCP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v4() -> io::Result<TcpSocket> {
+        let family = SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0));
+        net_imp::TcpSocket::new(&family).map(TcpSocket)
+    }
+
+    /// Creates a new IPv6 TCP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v6().expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v6() -> io::Result<TcpSocket> {
+        let family = SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0));
+        net_imp::TcpSocket::new(&family).map(TcpSocket)
+    }
+
+    /// Binds this socket to the specified address.
+    ///
+    /// Binding with a port number of 0 will request that the OS assigns a port to this socket.
+    /// The port allocated can be queried via the [`TcpSocket::local_addr`] method.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn bind(&self, addr: SocketAddr) -> io::Result<()> {
+        self.0.bind(&addr)
+    }
+
+    /// Returns the local socket address of this socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.socket_addr()
+    }
+
+    /// Starts listening for connections on this socket, turning it into a [`TcpListener`].
+    ///
+    /// `backlog` is the maximum number of pending connections the OS will queue before
+    /// refusing new ones; the OS may silently lower it. If the socket has not been bound, the
+    /// OS binds it to an unspecified address and a random port.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = TcpSocket::new_v4()?;
+    ///     socket.set_reuseaddr(true)?;
+    ///     socket.bind("127.0.0.1:8080".parse().unwrap())?;
+    ///     let listener = socket.listen(1024)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        self.0.listen(backlog).map(TcpListener)
+    }
+
+    /// Opens a connection to a remote host from this socket, turning it into a [`TcpStream`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocket;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = TcpSocket::new_v4()?;
+    ///     socket.set_keepalive(true)?;
+    ///     socket.set_keepalive_time(Duration::from_secs(60))?;
+    ///     let stream = socket.connect("127.0.0.1:8080".parse().unwrap())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn connect(self, addr: SocketAddr) -> io::Result<TcpStream> {
+        self.0.connect(&addr).map(TcpStream)
+    }
+
+    /// Opens a connection to a remote host from this socket, with a timeout.
+    ///
+    /// See [`TcpStream::connect_timeout`] for how the timeout is handled.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn connect_timeout(self, addr: SocketAddr, timeout: Duration) -> io::Result<TcpStream> {
+        self.0.connect_timeout(&addr, timeout).map(TcpStream)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// On Unix, this allows binding to an address that still has connections in the
+    /// `TIME_WAIT` state; [`TcpListener::bind`] sets it by default. On Windows, it allows
+    /// binding to an address that is actively in use by another socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseaddr`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// This allows several sockets to bind to the same address and port, as long as all of
+    /// them set this option. On Linux, incoming connections are then spread across the
+    /// listeners.
+    ///
+    /// Returns an error of kind [`Unsupported`] on platforms without `SO_REUSEPORT`, such as
+    /// Windows.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseport`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Sets the value for the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// If this is set to `true` then the socket is restricted to sending and receiving IPv6
+    /// packets only. If it is set to `false`, an IPv6 socket can also communicate with IPv4
+    /// peers using IPv4-mapped addresses. This has no effect on IPv4 sockets.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        self.0.set_only_v6(only_v6)
+    }
+
+    /// Gets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_only_v6`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn only_v6(&self) -> io::Result<bool> {
+        self.0.only_v6()
+    }
+
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_nodelay`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    /// Gets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_nodelay`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.0.nodelay()
+    }
+
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// When enabled, the OS periodically probes an idle connection and closes it if the peer
+    /// stops responding. The probes can be tuned with [`TcpSocket::set_keepalive_time`],
+    /// [`TcpSocket::set_keepalive_interval`] and [`TcpSocket::set_keepalive_retries`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_keepalive`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets how long a connection must be idle before the first keepalive probe is sent.
+    ///
+    /// This is the `TCP_KEEPIDLE` option, or `TCP_KEEPALIVE` on Apple platforms and Windows.
+    /// The duration is truncated to whole seconds.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive_time(&self, time: Duration) -> io::Result<()> {
+        self.0.set_keepalive_time(time)
+    }
+
+    /// Sets the time between two keepalive probes (`TCP_KEEPINTVL`).
+    ///
+    /// The duration is truncated to whole seconds.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.0.set_keepalive_interval(interval)
+    }
+
+    /// Sets the number of unanswered keepalive probes after which the connection is dropped
+    /// (`TCP_KEEPCNT`).
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        self.0.set_keepalive_retries(retries)
+    }
+
+    /// Enables TCP Fast Open on a socket that is going to [`listen`].
+    ///
+    /// `queue_len` is the maximum number of pending fast open requests; zero disables fast
+    /// open. On Windows only zero and non-zero are distinguished.
+    ///
+    /// Returns an error of kind [`Unsupported`] on platforms without `TCP_FASTOPEN`.
+    ///
+    /// [`listen`]: TcpSocket::listen
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_fastopen(&self, queue_len: u32) -> io::Result<()> {
+        self.0.set_fastopen(queue_len)
+    }
+
+    /// Binds this socket to a network interface, such as `b"eth0"`, or removes the binding
+    /// if `interface` is `None`.
+    ///
+    /// Only packets received on that interface are processed by the socket, and outgoing
+    /// packets are sent through it. This is the `SO_BINDTODEVICE` option, and usually
+    /// requires elevated privileges.
+    ///
+    /// Returns an error of kind [`Unsupported`] on platforms other than Linux and Android.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn bind_device(&self, interface: Option<&[u8]>) -> io::Result<()> {
+        self.0.bind_device(interface)
+    }
+}
+
+#[unstable(feature = "net_socket_builder", issue = "none")]
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
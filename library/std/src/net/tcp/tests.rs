@@ -903,3 +903,51 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+fn tcp_socket_for(addr: SocketAddr) -> TcpSocket {
+    t!(if addr.is_ipv4() { TcpSocket::new_v4() } else { TcpSocket::new_v6() })
+}
+
+#[test]
+fn tcp_socket_listen_connect() {
+    each_ip(&mut |addr| {
+        let socket = tcp_socket_for(addr);
+        t!(socket.set_reuseaddr(true));
+        assert!(t!(socket.reuseaddr()));
+        t!(socket.bind(addr));
+        assert_eq!(t!(socket.local_addr()), addr);
+        let acceptor = t!(socket.listen(16));
+
+        let _t = thread::spawn(move || {
+            let socket = tcp_socket_for(addr);
+            t!(socket.set_nodelay(true));
+            t!(socket.set_keepalive(true));
+            assert!(t!(socket.keepalive()));
+            let mut stream = t!(socket.connect(addr));
+            assert!(t!(stream.nodelay()));
+            t!(stream.write(&[144]));
+        });
+
+        let mut stream = t!(acceptor.accept()).0;
+        let mut buf = [0];
+        t!(stream.read(&mut buf));
+        assert!(buf[0] == 144);
+    })
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "freebsd"))]
+fn tcp_socket_reuseport() {
+    let addr = next_test_ip4();
+    let mut listeners = Vec::new();
+    for _ in 0..2 {
+        let socket = t!(TcpSocket::new_v4());
+        t!(socket.set_reuseport(true));
+        assert!(t!(socket.reuseport()));
+        t!(socket.bind(addr));
+        listeners.push(t!(socket.listen(16)));
+    }
+
+    let socket = t!(TcpSocket::new_v4());
+    assert!(socket.bind(addr).is_err());
+}
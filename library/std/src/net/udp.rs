@@ -812,3 +812,134 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A builder for a [`UdpSocket`] that sets socket options before the socket is bound.
+///
+/// [`UdpSocket::bind`] creates and binds a socket in one step, so options that only take
+/// effect on binding, such as `SO_REUSEPORT` or `IPV6_V6ONLY`, cannot be changed through it.
+/// A `UdpSocketBuilder` owns an unbound socket; options are set on it directly, and
+/// [`bind`] then turns it into a [`UdpSocket`].
+///
+/// [`bind`]: UdpSocketBuilder::bind
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(net_socket_builder)]
+/// use std::net::UdpSocketBuilder;
+///
+/// fn main() -> std::io::Result<()> {
+///     let builder = UdpSocketBuilder::new_v6()?;
+///     builder.set_only_v6(false)?;
+///     builder.set_reuseport(true)?;
+///     let socket = builder.bind("[::]:5353".parse().unwrap())?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "net_socket_builder", issue = "none")]
+pub struct UdpSocketBuilder(net_imp::UdpSocketBuilder);
+
+impl UdpSocketBuilder {
+    /// Creates a builder for an IPv4 UDP socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v4() -> io::Result<UdpSocketBuilder> {
+        let family = SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0));
+        net_imp::UdpSocketBuilder::new(&family).map(UdpSocketBuilder)
+    }
+
+    /// Creates a builder for an IPv6 UDP socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v6() -> io::Result<UdpSocketBuilder> {
+        let family = SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0));
+        net_imp::UdpSocketBuilder::new(&family).map(UdpSocketBuilder)
+    }
+
+    /// Binds the socket to the specified address, turning it into a [`UdpSocket`].
+    ///
+    /// Binding with a port number of 0 will request that the OS assigns a port to this socket.
+    /// The port allocated can be queried via the [`UdpSocket::local_addr`] method.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn bind(self, addr: SocketAddr) -> io::Result<UdpSocket> {
+        self.0.bind(&addr).map(UdpSocket)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseaddr`].
+    ///
+    /// [`TcpSocket::set_reuseaddr`]: crate::net::TcpSocket::set_reuseaddr
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseport`].
+    ///
+    /// [`TcpSocket::set_reuseport`]: crate::net::TcpSocket::set_reuseport
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Sets the value for the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// If this is set to `true` then the socket is restricted to sending and receiving IPv6
+    /// packets only. If it is set to `false`, an IPv6 socket can also communicate with IPv4
+    /// peers using IPv4-mapped addresses. This has no effect on IPv4 sockets.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        self.0.set_only_v6(only_v6)
+    }
+
+    /// Gets the value of the `IPV6_V6ONLY` option on this socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn only_v6(&self) -> io::Result<bool> {
+        self.0.only_v6()
+    }
+
+    /// Sets the value of the `SO_BROADCAST` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_broadcast`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_broadcast(&self, broadcast: bool) -> io::Result<()> {
+        self.0.set_broadcast(broadcast)
+    }
+
+    /// Gets the value of the `SO_BROADCAST` option on this socket.
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn broadcast(&self) -> io::Result<bool> {
+        self.0.broadcast()
+    }
+
+    /// Binds this socket to a network interface, or removes the binding if `interface` is
+    /// `None`.
+    ///
+    /// For more information about this option, see [`TcpSocket::bind_device`].
+    ///
+    /// [`TcpSocket::bind_device`]: crate::net::TcpSocket::bind_device
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn bind_device(&self, interface: Option<&[u8]>) -> io::Result<()> {
+        self.0.bind_device(interface)
+    }
+}
+
+#[unstable(feature = "net_socket_builder", issue = "none")]
+impl fmt::Debug for UdpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
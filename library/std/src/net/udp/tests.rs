@@ -366,3 +366,30 @@ fn set_nonblocking() {
         }
     })
 }
+
+#[test]
+fn udp_socket_builder() {
+    each_ip(&mut |server_ip, client_ip| {
+        let builder = t!(if server_ip.is_ipv4() {
+            UdpSocketBuilder::new_v4()
+        } else {
+            UdpSocketBuilder::new_v6()
+        });
+        t!(builder.set_reuseaddr(true));
+        assert!(t!(builder.reuseaddr()));
+        t!(builder.set_broadcast(true));
+        assert!(t!(builder.broadcast()));
+        let server = t!(builder.bind(server_ip));
+        assert_eq!(t!(server.local_addr()), server_ip);
+        assert!(t!(server.broadcast()));
+
+        let client = t!(UdpSocket::bind(&client_ip));
+        t!(client.send_to(&[99], &server_ip));
+
+        let mut buf = [0];
+        let (nread, src) = t!(server.recv_from(&mut buf));
+        assert_eq!(nread, 1);
+        assert_eq!(buf[0], 99);
+        assert_eq!(src, client_ip);
+    })
+}
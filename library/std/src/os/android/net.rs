@@ -7,3 +7,6 @@
 
 #[unstable(feature = "tcp_quickack", issue = "96256")]
 pub use crate::os::net::linux_ext::tcp::TcpStreamExt;
+
+#[unstable(feature = "udp_mmsg", issue = "none")]
+pub use crate::os::net::linux_ext::udp::{RecvMeta, UdpSocketExt};
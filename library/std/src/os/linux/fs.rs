@@ -5,6 +5,7 @@
 #![stable(feature = "metadata_ext", since = "1.1.0")]
 
 use crate::fs::Metadata;
+use crate::sealed::Sealed;
 use crate::sys_common::AsInner;
 
 #[allow(deprecated)]
@@ -395,3 +396,138 @@ fn st_blocks(&self) -> u64 {
         self.as_inner().as_inner().st_blocks as u64
     }
 }
+
+/// Linux-specific extensions to [`fs::Metadata`] for fields that are only
+/// reported by `statx(2)`.
+///
+/// Each method returns `None` when the information is unavailable, either
+/// because `statx` is not supported by the kernel or C library, or because the
+/// filesystem did not report the field. Metadata obtained from
+/// [`DirEntry::metadata`] is also queried with `statx` where possible.
+///
+/// [`fs::Metadata`]: crate::fs::Metadata
+/// [`DirEntry::metadata`]: crate::fs::DirEntry::metadata
+#[unstable(feature = "linux_statx_metadata", issue = "none")]
+pub trait StatxMetadataExt: Sealed {
+    /// Returns the ID of the mount containing the file, as found in the first
+    /// field of `/proc/self/mountinfo`.
+    ///
+    /// Unlike [`MetadataExt::st_dev`], this distinguishes bind mounts of the
+    /// same filesystem. It requires Linux 5.8 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_statx_metadata)]
+    /// use std::fs;
+    /// use std::io;
+    /// use std::os::linux::fs::StatxMetadataExt;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let meta = fs::metadata("some_file")?;
+    ///     if let Some(id) = meta.mount_id() {
+    ///         println!("mounted at mount {id}");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn mount_id(&self) -> Option<u64>;
+
+    /// Returns the raw `STATX_ATTR_*` attribute flags of the file, limited to
+    /// the flags the filesystem supports.
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn attributes(&self) -> Option<u64>;
+
+    /// Returns the mask of `STATX_ATTR_*` flags the filesystem supports for
+    /// this file.
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn attributes_mask(&self) -> Option<u64>;
+
+    /// Returns whether the file is immutable (`chattr +i`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_statx_metadata)]
+    /// use std::fs;
+    /// use std::io;
+    /// use std::os::linux::fs::StatxMetadataExt;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let meta = fs::metadata("some_file")?;
+    ///     if meta.is_immutable() == Some(true) {
+    ///         println!("skipping immutable file");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn is_immutable(&self) -> Option<bool>;
+
+    /// Returns whether the file can only be opened for appending (`chattr +a`).
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn is_append_only(&self) -> Option<bool>;
+
+    /// Returns whether the file is in the DAX (CPU direct access) state.
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn is_dax(&self) -> Option<bool>;
+
+    /// Returns the alignment, in bytes, required for user memory buffers used
+    /// with direct I/O (`O_DIRECT`) on this file.
+    ///
+    /// This requires Linux 6.1 or later, and is only reported for regular
+    /// files and block devices on filesystems that support direct I/O.
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn dio_mem_align(&self) -> Option<u32>;
+
+    /// Returns the alignment, in bytes, required for file offsets and I/O
+    /// lengths used with direct I/O (`O_DIRECT`) on this file.
+    ///
+    /// See [`dio_mem_align`](StatxMetadataExt::dio_mem_align) for availability.
+    #[unstable(feature = "linux_statx_metadata", issue = "none")]
+    fn dio_offset_align(&self) -> Option<u32>;
+}
+
+#[unstable(feature = "linux_statx_metadata", issue = "none")]
+impl Sealed for Metadata {}
+
+#[unstable(feature = "linux_statx_metadata", issue = "none")]
+impl StatxMetadataExt for Metadata {
+    fn mount_id(&self) -> Option<u64> {
+        self.as_inner().stx_mnt_id()
+    }
+    fn attributes(&self) -> Option<u64> {
+        self.as_inner().stx_attributes().map(|(attributes, _)| attributes)
+    }
+    fn attributes_mask(&self) -> Option<u64> {
+        self.as_inner().stx_attributes().map(|(_, mask)| mask)
+    }
+    fn is_immutable(&self) -> Option<bool> {
+        statx_attribute(self, STATX_ATTR_IMMUTABLE)
+    }
+    fn is_append_only(&self) -> Option<bool> {
+        statx_attribute(self, STATX_ATTR_APPEND)
+    }
+    fn is_dax(&self) -> Option<bool> {
+        statx_attribute(self, STATX_ATTR_DAX)
+    }
+    fn dio_mem_align(&self) -> Option<u32> {
+        self.as_inner().stx_dio_align().map(|(mem, _)| mem)
+    }
+    fn dio_offset_align(&self) -> Option<u32> {
+        self.as_inner().stx_dio_align().map(|(_, offset)| offset)
+    }
+}
+
+// The `STATX_ATTR_*` flags from `<linux/stat.h>`. The `libc` crate only
+// defines them for glibc, but they're the same on every Linux target.
+pub(crate) const STATX_ATTR_IMMUTABLE: u64 = 0x10;
+pub(crate) const STATX_ATTR_APPEND: u64 = 0x20;
+pub(crate) const STATX_ATTR_DAX: u64 = 0x200000;
+
+// Returns `None` if the filesystem doesn't support the attribute at all.
+fn statx_attribute(meta: &Metadata, attr: u64) -> Option<bool> {
+    let (attributes, mask) = meta.as_inner().stx_attributes()?;
+    if mask & attr != 0 { Some(attributes & attr != 0) } else { None }
+}
@@ -26,3 +26,51 @@ macro_rules! t {
     t!(stream.set_quickack(false));
     assert_eq!(false, t!(stream.quickack()));
 }
+
+#[test]
+fn udp_mmsg() {
+    use crate::{
+        io::{IoSlice, IoSliceMut},
+        net::{test::next_test_ip4, UdpSocket},
+        os::net::linux_ext::udp::{RecvMeta, UdpSocketExt},
+    };
+
+    macro_rules! t {
+        ($e:expr) => {
+            match $e {
+                Ok(t) => t,
+                Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+            }
+        };
+    }
+
+    let addr1 = next_test_ip4();
+    let addr2 = next_test_ip4();
+    let sender = t!(UdpSocket::bind(&addr1));
+    let receiver = t!(UdpSocket::bind(&addr2));
+
+    let bufs = [IoSlice::new(b"one"), IoSlice::new(b"two"), IoSlice::new(b"three")];
+    assert_eq!(t!(sender.send_mmsg(&bufs, &[addr2; 3])), 3);
+    assert_eq!(
+        sender.send_mmsg(&bufs, &[addr2; 2]).unwrap_err().kind(),
+        crate::io::ErrorKind::InvalidInput
+    );
+
+    let mut buf1 = [0; 8];
+    let mut buf2 = [0; 8];
+    let mut buf3 = [0; 4];
+    let mut bufs =
+        [IoSliceMut::new(&mut buf1), IoSliceMut::new(&mut buf2), IoSliceMut::new(&mut buf3)];
+    let mut meta = [RecvMeta::new(); 3];
+    let mut received = 0;
+    while received < 3 {
+        received += t!(receiver.recv_mmsg(&mut bufs[received..], &mut meta[received..]));
+    }
+
+    assert_eq!(&bufs[0][..meta[0].len()], b"one");
+    assert_eq!(&bufs[1][..meta[1].len()], b"two");
+    assert_eq!(&bufs[2][..meta[2].len()], b"thre");
+    assert!(meta.iter().all(|meta| meta.addr() == addr1 && meta.segment_size().is_none()));
+    assert!(!meta[0].is_truncated());
+    assert!(meta[2].is_truncated());
+}
@@ -0,0 +1,430 @@
+//! Linux and Android-specific udp extensions to primitives in the [`std::net`] module.
+//!
+//! [`std::net`]: crate::net
+
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::mem::{size_of, zeroed};
+use crate::net::{self, Ipv4Addr, SocketAddr, SocketAddrV4};
+#[cfg(target_os = "linux")]
+use crate::os::unix::net::AncillaryData;
+use crate::os::unix::net::SocketAncillary;
+use crate::sealed::Sealed;
+use crate::sys_common::net::sockaddr_to_addr;
+use crate::sys_common::{AsInner, IntoInner};
+
+/// Metadata about a single datagram received by [`UdpSocketExt::recv_mmsg`].
+///
+/// A `RecvMeta` starts out empty and is overwritten by every batch it takes part in.
+#[unstable(feature = "udp_mmsg", issue = "none")]
+#[derive(Clone, Copy)]
+pub struct RecvMeta {
+    len: usize,
+    addr: SocketAddr,
+    truncated: bool,
+    segment_size: Option<u16>,
+}
+
+impl RecvMeta {
+    /// Creates empty metadata, ready to be passed to [`UdpSocketExt::recv_mmsg`].
+    #[must_use]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    pub const fn new() -> RecvMeta {
+        RecvMeta {
+            len: 0,
+            addr: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)),
+            truncated: false,
+            segment_size: None,
+        }
+    }
+
+    /// Returns the number of bytes written into the buffer of this datagram.
+    #[must_use]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes were written into the buffer of this datagram.
+    #[must_use]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the address the datagram was sent from.
+    #[must_use]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns `true` if the datagram was longer than its buffer and the excess was discarded.
+    #[must_use]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the segment size of a coalesced datagram.
+    ///
+    /// With [`UdpSocketExt::set_gro`] enabled, the kernel may merge several datagrams from the
+    /// same sender into one buffer. In that case this returns the size of each of them; only
+    /// the last one may be shorter. Returns `None` for datagrams that were not coalesced.
+    #[must_use]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    pub fn segment_size(&self) -> Option<u16> {
+        self.segment_size
+    }
+}
+
+#[unstable(feature = "udp_mmsg", issue = "none")]
+impl Default for RecvMeta {
+    fn default() -> RecvMeta {
+        RecvMeta::new()
+    }
+}
+
+#[unstable(feature = "udp_mmsg", issue = "none")]
+impl fmt::Debug for RecvMeta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecvMeta")
+            .field("len", &self.len)
+            .field("addr", &self.addr)
+            .field("truncated", &self.truncated)
+            .field("segment_size", &self.segment_size)
+            .finish()
+    }
+}
+
+/// Os-specific extensions for [`UdpSocket`]
+///
+/// [`UdpSocket`]: net::UdpSocket
+#[unstable(feature = "udp_mmsg", issue = "none")]
+pub trait UdpSocketExt: Sealed {
+    /// Receives a batch of datagrams with a single system call.
+    ///
+    /// Each datagram is received into the buffer at the same index in `bufs`, and its length,
+    /// source address and flags are stored into the same index in `meta`. At most
+    /// `min(bufs.len(), meta.len())` datagrams are received. On success, returns the number of
+    /// datagrams received, which is at least one unless `bufs` or `meta` is empty.
+    ///
+    /// This blocks until at least one datagram is available, unless the socket is in
+    /// nonblocking mode. It does not wait for the batch to fill up.
+    ///
+    /// See [`man 2 recvmmsg`](https://man7.org/linux/man-pages/man2/recvmmsg.2.html) for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_mmsg)]
+    /// use std::io::IoSliceMut;
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::{RecvMeta, UdpSocketExt};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:34254")?;
+    ///
+    ///     let mut buf1 = [0; 1500];
+    ///     let mut buf2 = [0; 1500];
+    ///     let mut bufs = [IoSliceMut::new(&mut buf1), IoSliceMut::new(&mut buf2)];
+    ///     let mut meta = [RecvMeta::new(); 2];
+    ///
+    ///     let count = socket.recv_mmsg(&mut bufs, &mut meta)?;
+    ///     for (buf, meta) in bufs.iter().zip(&meta).take(count) {
+    ///         println!("{} bytes from {}: {:?}", meta.len(), meta.addr(), &buf[..meta.len()]);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn recv_mmsg(&self, bufs: &mut [IoSliceMut<'_>], meta: &mut [RecvMeta]) -> io::Result<usize>;
+
+    /// Sends a batch of datagrams with a single system call.
+    ///
+    /// Each buffer in `bufs` is sent as its own datagram. If `addrs` is empty, the datagrams go
+    /// to the address the socket is [connected] to; otherwise it must have the same length as
+    /// `bufs`, and each datagram is sent to the address at the same index.
+    ///
+    /// On success, returns the number of datagrams sent, which may be less than `bufs.len()`.
+    ///
+    /// See [`man 2 sendmmsg`](https://man7.org/linux/man-pages/man2/sendmmsg.2.html) for more
+    /// information.
+    ///
+    /// [connected]: net::UdpSocket::connect
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`] if `addrs` is neither empty nor as long as
+    /// `bufs`.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_mmsg)]
+    /// use std::io::IoSlice;
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::UdpSocketExt;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:34254")?;
+    ///     let addrs = ["127.0.0.1:4242".parse().unwrap(), "127.0.0.1:4243".parse().unwrap()];
+    ///     let bufs = [IoSlice::new(b"hello"), IoSlice::new(b"world")];
+    ///
+    ///     let sent = socket.send_mmsg(&bufs, &addrs)?;
+    ///     assert_eq!(sent, 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn send_mmsg(&self, bufs: &[IoSlice<'_>], addrs: &[SocketAddr]) -> io::Result<usize>;
+
+    /// Sends a batch of datagrams using generic segmentation offload (GSO).
+    ///
+    /// This works like [`UdpSocketExt::send_mmsg`], except that the kernel splits every buffer
+    /// in `bufs` into datagrams of `segment_size` bytes each; only the last one may be shorter.
+    /// The segment size is attached to each message as a `UDP_SEGMENT` control message, which
+    /// overrides [`UdpSocketExt::set_segment_size`].
+    ///
+    /// On success, returns the number of buffers sent, not the number of datagrams.
+    ///
+    /// See [`man 7 udp`](https://man7.org/linux/man-pages/man7/udp.7.html) for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_mmsg)]
+    /// use std::io::IoSlice;
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::UdpSocketExt;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:34254")?;
+    ///     socket.connect("127.0.0.1:4242")?;
+    ///
+    ///     // Sent as four datagrams of 1200 bytes each.
+    ///     let payload = [0; 4800];
+    ///     socket.send_mmsg_with_segment_size(&[IoSlice::new(&payload)], &[], 1200)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(target_os = "linux")]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn send_mmsg_with_segment_size(
+        &self,
+        bufs: &[IoSlice<'_>],
+        addrs: &[SocketAddr],
+        segment_size: u16,
+    ) -> io::Result<usize>;
+
+    /// Sets the value of the `UDP_SEGMENT` option on this socket.
+    ///
+    /// When non-zero, every payload sent on this socket is split by the kernel into datagrams
+    /// of `segment_size` bytes each. Setting it to zero disables segmentation offload.
+    ///
+    /// See [`man 7 udp`](https://man7.org/linux/man-pages/man7/udp.7.html) for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_mmsg)]
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::UdpSocketExt;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_segment_size(1200).expect("set_segment_size call failed");
+    /// ```
+    #[cfg(target_os = "linux")]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn set_segment_size(&self, segment_size: u16) -> io::Result<()>;
+
+    /// Gets the value of the `UDP_SEGMENT` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocketExt::set_segment_size`].
+    #[cfg(target_os = "linux")]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn segment_size(&self) -> io::Result<u16>;
+
+    /// Enable or disable `UDP_GRO`.
+    ///
+    /// With generic receive offload enabled, the kernel may coalesce consecutive datagrams from
+    /// the same sender into a single buffer. The size of the original datagrams is then reported
+    /// by [`RecvMeta::segment_size`], or as an [`AncillaryData::UdpGroSegments`] control
+    /// message.
+    ///
+    /// See [`man 7 udp`](https://man7.org/linux/man-pages/man7/udp.7.html) for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_mmsg)]
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::UdpSocketExt;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_gro(true).expect("set_gro call failed");
+    /// ```
+    #[cfg(target_os = "linux")]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn set_gro(&self, gro: bool) -> io::Result<()>;
+
+    /// Gets the value of the `UDP_GRO` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocketExt::set_gro`].
+    #[cfg(target_os = "linux")]
+    #[unstable(feature = "udp_mmsg", issue = "none")]
+    fn gro(&self) -> io::Result<bool>;
+}
+
+#[unstable(feature = "udp_mmsg", issue = "none")]
+impl Sealed for net::UdpSocket {}
+
+/// Control message space reserved for each received datagram, large enough for a `UDP_GRO`
+/// message.
+#[repr(C, align(8))]
+struct ControlBuf([u8; 64]);
+
+#[unstable(feature = "udp_mmsg", issue = "none")]
+impl UdpSocketExt for net::UdpSocket {
+    fn recv_mmsg(&self, bufs: &mut [IoSliceMut<'_>], meta: &mut [RecvMeta]) -> io::Result<usize> {
+        let count = bufs.len().min(meta.len());
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let mut names: Vec<libc::sockaddr_storage> = Vec::with_capacity(count);
+        let mut controls: Vec<ControlBuf> = Vec::with_capacity(count);
+        for _ in 0..count {
+            names.push(unsafe { zeroed() });
+            controls.push(ControlBuf([0; 64]));
+        }
+        let mut ancillaries: Vec<SocketAncillary<'_>> =
+            controls.iter_mut().map(|control| SocketAncillary::new(&mut control.0)).collect();
+
+        let mut msgs: Vec<libc::mmsghdr> = Vec::with_capacity(count);
+        for ((buf, name), ancillary) in bufs.iter_mut().zip(&mut names).zip(&mut ancillaries) {
+            let mut msg: libc::mmsghdr = unsafe { zeroed() };
+            msg.msg_hdr.msg_name = name as *mut _ as *mut _;
+            msg.msg_hdr.msg_namelen = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+            msg.msg_hdr.msg_iov = buf as *mut IoSliceMut<'_> as *mut libc::iovec;
+            msg.msg_hdr.msg_iovlen = 1;
+            ancillary.prepare_recv(&mut msg.msg_hdr);
+            msgs.push(msg);
+        }
+
+        let received = self.as_inner().socket().recv_mmsg(&mut msgs)?;
+
+        for (i, msg) in msgs.iter().enumerate().take(received) {
+            let ancillary = &mut ancillaries[i];
+            ancillary.finish_recv(&msg.msg_hdr);
+
+            #[cfg(target_os = "linux")]
+            let segment_size = ancillary.messages().find_map(|data| match data {
+                Ok(AncillaryData::UdpGroSegments(size)) => Some(size),
+                _ => None,
+            });
+            #[cfg(not(target_os = "linux"))]
+            let segment_size = None;
+
+            meta[i] = RecvMeta {
+                len: msg.msg_len as usize,
+                addr: sockaddr_to_addr(&names[i], msg.msg_hdr.msg_namelen as usize)?,
+                truncated: msg.msg_hdr.msg_flags & libc::MSG_TRUNC == libc::MSG_TRUNC,
+                segment_size,
+            };
+        }
+        Ok(received)
+    }
+
+    fn send_mmsg(&self, bufs: &[IoSlice<'_>], addrs: &[SocketAddr]) -> io::Result<usize> {
+        send_mmsg(self, bufs, addrs, None)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn send_mmsg_with_segment_size(
+        &self,
+        bufs: &[IoSlice<'_>],
+        addrs: &[SocketAddr],
+        segment_size: u16,
+    ) -> io::Result<usize> {
+        send_mmsg(self, bufs, addrs, Some(segment_size))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_segment_size(&self, segment_size: u16) -> io::Result<()> {
+        self.as_inner().socket().set_udp_segment(segment_size)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn segment_size(&self) -> io::Result<u16> {
+        self.as_inner().socket().udp_segment()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_gro(&self, gro: bool) -> io::Result<()> {
+        self.as_inner().socket().set_udp_gro(gro)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn gro(&self) -> io::Result<bool> {
+        self.as_inner().socket().udp_gro()
+    }
+}
+
+fn send_mmsg(
+    socket: &net::UdpSocket,
+    bufs: &[IoSlice<'_>],
+    addrs: &[SocketAddr],
+    segment_size: Option<u16>,
+) -> io::Result<usize> {
+    if !addrs.is_empty() && addrs.len() != bufs.len() {
+        return Err(io::const_io_error!(
+            io::ErrorKind::InvalidInput,
+            "number of addresses does not match number of buffers"
+        ));
+    }
+    if bufs.is_empty() {
+        return Ok(0);
+    }
+
+    let mut names = Vec::with_capacity(addrs.len());
+    names.extend(addrs.iter().map(|addr| addr.into_inner()));
+
+    let mut controls: Vec<ControlBuf> = Vec::with_capacity(bufs.len());
+    if segment_size.is_some() {
+        for _ in 0..bufs.len() {
+            controls.push(ControlBuf([0; 64]));
+        }
+    }
+    let mut ancillaries: Vec<SocketAncillary<'_>> =
+        controls.iter_mut().map(|control| SocketAncillary::new(&mut control.0)).collect();
+    #[cfg(target_os = "linux")]
+    if let Some(segment_size) = segment_size {
+        for ancillary in &mut ancillaries {
+            ancillary.add_udp_segment(segment_size);
+        }
+    }
+
+    let mut msgs: Vec<libc::mmsghdr> = Vec::with_capacity(bufs.len());
+    for (i, buf) in bufs.iter().enumerate() {
+        let mut msg: libc::mmsghdr = unsafe { zeroed() };
+        if let Some((name, len)) = names.get(i) {
+            msg.msg_hdr.msg_name = name.as_ptr() as *mut _;
+            msg.msg_hdr.msg_namelen = *len;
+        }
+        msg.msg_hdr.msg_iov = buf as *const IoSlice<'_> as *mut libc::iovec;
+        msg.msg_hdr.msg_iovlen = 1;
+        if let Some(ancillary) = ancillaries.get_mut(i) {
+            ancillary.prepare_send(&mut msg.msg_hdr);
+        }
+        msgs.push(msg);
+    }
+
+    socket.as_inner().socket().send_mmsg(&mut msgs)
+}
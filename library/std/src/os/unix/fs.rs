@@ -1050,3 +1050,234 @@ pub fn lchown<P: AsRef<Path>>(dir: P, uid: Option<u32>, gid: Option<u32>) -> io:
 pub fn chroot<P: AsRef<Path>>(dir: P) -> io::Result<()> {
     sys::fs::chroot(dir.as_ref())
 }
+
+/// Get the value of an extended attribute of the specified path.
+///
+/// Returns `Ok(None)` if the file exists but has no attribute with this name.
+/// Symbolic links are followed. Attribute names usually carry a namespace
+/// prefix, such as `user.` on Linux.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     if let Some(value) = fs::getxattr("/file", "user.checksum")? {
+///         println!("checksum: {}", String::from_utf8_lossy(&value));
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn getxattr<P: AsRef<Path>, N: AsRef<OsStr>>(path: P, name: N) -> io::Result<Option<Vec<u8>>> {
+    sys::fs::getxattr(path.as_ref(), name.as_ref())
+}
+
+/// Get the value of an extended attribute of the file referenced by the specified open file
+/// descriptor.
+///
+/// For semantics, see [`getxattr`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     let f = std::fs::File::open("/file")?;
+///     let value = fs::fgetxattr(&f, "user.checksum")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn fgetxattr<F: AsFd, N: AsRef<OsStr>>(fd: F, name: N) -> io::Result<Option<Vec<u8>>> {
+    sys::fs::fgetxattr(fd.as_fd().as_raw_fd(), name.as_ref())
+}
+
+/// Set the value of an extended attribute of the specified path.
+///
+/// The attribute is created if it doesn't exist and replaced otherwise.
+/// Symbolic links are followed.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     fs::setxattr("/file", "user.checksum", b"d41d8cd9")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn setxattr<P: AsRef<Path>, N: AsRef<OsStr>>(path: P, name: N, value: &[u8]) -> io::Result<()> {
+    sys::fs::setxattr(path.as_ref(), name.as_ref(), value)
+}
+
+/// Set the value of an extended attribute of the file referenced by the specified open file
+/// descriptor.
+///
+/// For semantics, see [`setxattr`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     let f = std::fs::File::open("/file")?;
+///     fs::fsetxattr(&f, "user.checksum", b"d41d8cd9")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn fsetxattr<F: AsFd, N: AsRef<OsStr>>(fd: F, name: N, value: &[u8]) -> io::Result<()> {
+    sys::fs::fsetxattr(fd.as_fd().as_raw_fd(), name.as_ref(), value)
+}
+
+/// List the names of the extended attributes of the specified path.
+///
+/// Symbolic links are followed. Which names are visible may depend on the privileges of the
+/// current process.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     for name in fs::listxattr("/file")? {
+///         println!("{}", name.to_string_lossy());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn listxattr<P: AsRef<Path>>(path: P) -> io::Result<Vec<crate::ffi::OsString>> {
+    sys::fs::listxattr(path.as_ref())
+}
+
+/// List the names of the extended attributes of the file referenced by the specified open file
+/// descriptor.
+///
+/// For semantics, see [`listxattr`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     let f = std::fs::File::open("/file")?;
+///     let names = fs::flistxattr(&f)?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn flistxattr<F: AsFd>(fd: F) -> io::Result<Vec<crate::ffi::OsString>> {
+    sys::fs::flistxattr(fd.as_fd().as_raw_fd())
+}
+
+/// Remove an extended attribute from the specified path.
+///
+/// Symbolic links are followed. Removing an attribute that doesn't exist is an error.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     fs::removexattr("/file", "user.checksum")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn removexattr<P: AsRef<Path>, N: AsRef<OsStr>>(path: P, name: N) -> io::Result<()> {
+    sys::fs::removexattr(path.as_ref(), name.as_ref())
+}
+
+/// Remove an extended attribute from the file referenced by the specified open file descriptor.
+///
+/// For semantics, see [`removexattr`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_xattr)]
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     let f = std::fs::File::open("/file")?;
+///     fs::fremovexattr(&f, "user.checksum")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_xattr", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub fn fremovexattr<F: AsFd, N: AsRef<OsStr>>(fd: F, name: N) -> io::Result<()> {
+    sys::fs::fremovexattr(fd.as_fd().as_raw_fd(), name.as_ref())
+}
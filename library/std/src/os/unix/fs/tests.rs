@@ -55,3 +55,58 @@ fn write_vectored_at() {
     let content = fs::read(&filename).unwrap();
     assert_eq!(&content, expected);
 }
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn xattr_roundtrip() {
+    let dir = crate::sys_common::io::test::tmpdir();
+    let filename = dir.join("xattr.txt");
+    let file = fs::File::create(&filename).unwrap();
+
+    match setxattr(&filename, "user.rust_test", b"value") {
+        // Not every filesystem used for temporary files supports user attributes.
+        Err(e) if e.raw_os_error() == Some(libc::ENOTSUP) => return,
+        result => result.unwrap(),
+    }
+    assert_eq!(getxattr(&filename, "user.rust_test").unwrap().as_deref(), Some(&b"value"[..]));
+    assert!(listxattr(&filename).unwrap().iter().any(|name| name == "user.rust_test"));
+
+    fsetxattr(&file, "user.rust_test", b"").unwrap();
+    assert_eq!(fgetxattr(&file, "user.rust_test").unwrap(), Some(vec![]));
+
+    fremovexattr(&file, "user.rust_test").unwrap();
+    assert_eq!(getxattr(&filename, "user.rust_test").unwrap(), None);
+    assert!(!flistxattr(&file).unwrap().iter().any(|name| name == "user.rust_test"));
+    assert!(removexattr(&filename, "user.rust_test").is_err());
+}
+
+#[test]
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn statx_attributes() {
+    use crate::os::linux::fs::{
+        StatxMetadataExt, STATX_ATTR_APPEND, STATX_ATTR_DAX, STATX_ATTR_IMMUTABLE,
+    };
+
+    let dir = crate::sys_common::io::test::tmpdir();
+    let filename = dir.join("statx.txt");
+    fs::File::create(&filename).unwrap();
+    let meta = fs::metadata(&filename).unwrap();
+
+    // `statx` is missing on kernels before 4.11 and may be blocked by sandboxes.
+    let Some(mask) = meta.attributes_mask() else { return };
+    let attributes = meta.attributes().unwrap();
+    assert_eq!(attributes & !mask, 0);
+
+    // A fresh file is neither immutable nor append-only, and the accessors only return `None`
+    // for the attributes the filesystem doesn't support.
+    for (flag, value) in [
+        (STATX_ATTR_IMMUTABLE, meta.is_immutable()),
+        (STATX_ATTR_APPEND, meta.is_append_only()),
+        (STATX_ATTR_DAX, meta.is_dax()),
+    ] {
+        assert_eq!(value.is_some(), mask & flag != 0);
+        assert_eq!(value, value.map(|_| attributes & flag != 0));
+    }
+    assert_ne!(meta.is_immutable(), Some(true));
+    assert_ne!(meta.is_append_only(), Some(true));
+}
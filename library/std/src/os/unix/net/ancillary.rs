@@ -34,16 +34,11 @@ pub(super) fn recv_vectored_with_ancillary_from(
         msg.msg_namelen = size_of::<libc::sockaddr_un>() as libc::socklen_t;
         msg.msg_iov = bufs.as_mut_ptr().cast();
         msg.msg_iovlen = bufs.len() as _;
-        msg.msg_controllen = ancillary.buffer.len() as _;
-        // macos requires that the control pointer is null when the len is 0.
-        if msg.msg_controllen > 0 {
-            msg.msg_control = ancillary.buffer.as_mut_ptr().cast();
-        }
+        ancillary.prepare_recv(&mut msg);
 
         let count = socket.recv_msg(&mut msg)?;
 
-        ancillary.length = msg.msg_controllen as usize;
-        ancillary.truncated = msg.msg_flags & libc::MSG_CTRUNC == libc::MSG_CTRUNC;
+        ancillary.finish_recv(&msg);
 
         let truncated = msg.msg_flags & libc::MSG_TRUNC == libc::MSG_TRUNC;
         let addr = SocketAddr::from_parts(msg_name, msg.msg_namelen);
@@ -67,13 +62,7 @@ pub(super) fn send_vectored_with_ancillary_to(
         msg.msg_namelen = msg_namelen;
         msg.msg_iov = bufs.as_ptr() as *mut _;
         msg.msg_iovlen = bufs.len() as _;
-        msg.msg_controllen = ancillary.length as _;
-        // macos requires that the control pointer is null when the len is 0.
-        if msg.msg_controllen > 0 {
-            msg.msg_control = ancillary.buffer.as_mut_ptr().cast();
-        }
-
-        ancillary.truncated = false;
+        ancillary.prepare_send(&mut msg);
 
         socket.send_msg(&mut msg)
     }
@@ -456,6 +445,8 @@ pub enum AncillaryData<'a> {
         target_os = "freebsd"
     ))]
     ScmCredentials(ScmCredentials<'a>),
+    #[cfg(target_os = "linux")]
+    UdpGroSegments(u16),
 }
 
 impl<'a> AncillaryData<'a> {
@@ -490,6 +481,18 @@ unsafe fn as_credentials(data: &'a [u8]) -> Self {
         AncillaryData::ScmCredentials(scm_credentials)
     }
 
+    /// Create an `AncillaryData::UdpGroSegments` variant.
+    ///
+    /// # Safety
+    ///
+    /// `data` must contain a valid control message and the control message must be type of
+    /// `SOL_UDP` and level of `UDP_GRO`.
+    #[cfg(target_os = "linux")]
+    unsafe fn as_udp_gro_segments(data: &'a [u8]) -> Self {
+        let segment_size = read_unaligned(data.as_ptr().cast::<libc::c_int>());
+        AncillaryData::UdpGroSegments(segment_size as u16)
+    }
+
     fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
         unsafe {
             let cmsg_len_zero = libc::CMSG_LEN(0) as usize;
@@ -510,6 +513,13 @@ fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
                         Err(AncillaryError::Unknown { cmsg_level: libc::SOL_SOCKET, cmsg_type })
                     }
                 },
+                #[cfg(target_os = "linux")]
+                libc::SOL_UDP => match (*cmsg).cmsg_type {
+                    libc::UDP_GRO => Ok(AncillaryData::as_udp_gro_segments(data)),
+                    cmsg_type => {
+                        Err(AncillaryError::Unknown { cmsg_level: libc::SOL_UDP, cmsg_type })
+                    }
+                },
                 cmsg_level => {
                     Err(AncillaryError::Unknown { cmsg_level, cmsg_type: (*cmsg).cmsg_type })
                 }
@@ -741,6 +751,28 @@ pub fn add_creds(&mut self, creds: &[SocketCred]) -> bool {
         )
     }
 
+    /// Add a UDP generic segmentation offload (GSO) segment size to the ancillary data.
+    ///
+    /// When sent along with a datagram socket message, the kernel splits the payload into
+    /// datagrams of `segment_size` bytes each; only the last one may be shorter.
+    ///
+    /// The function returns `true` if there is enough space in the buffer.
+    /// If there is not enough space then the segment size will not be appended.
+    /// Technically, that means this operation adds a control message with the level `SOL_UDP`
+    /// and type `UDP_SEGMENT`.
+    #[cfg(target_os = "linux")]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn add_udp_segment(&mut self, segment_size: u16) -> bool {
+        self.truncated = false;
+        add_to_ancillary_data(
+            &mut self.buffer,
+            &mut self.length,
+            &[segment_size],
+            libc::SOL_UDP,
+            libc::UDP_SEGMENT,
+        )
+    }
+
     /// Clears the ancillary data, removing all values.
     ///
     /// # Example
@@ -788,4 +820,32 @@ pub fn clear(&mut self) {
         self.length = 0;
         self.truncated = false;
     }
+
+    /// Points the control fields of `msg` at the unused capacity of this buffer, so that the
+    /// kernel can fill it in during a receive.
+    pub(crate) fn prepare_recv(&mut self, msg: &mut libc::msghdr) {
+        msg.msg_controllen = self.buffer.len() as _;
+        // macos requires that the control pointer is null when the len is 0.
+        if msg.msg_controllen > 0 {
+            msg.msg_control = self.buffer.as_mut_ptr().cast();
+        }
+    }
+
+    /// Records the control data the kernel stored into `msg` during a receive.
+    pub(crate) fn finish_recv(&mut self, msg: &libc::msghdr) {
+        self.length = msg.msg_controllen as usize;
+        self.truncated = msg.msg_flags & libc::MSG_CTRUNC == libc::MSG_CTRUNC;
+    }
+
+    /// Points the control fields of `msg` at the messages added to this buffer, so that they
+    /// are sent along with the data.
+    pub(crate) fn prepare_send(&mut self, msg: &mut libc::msghdr) {
+        msg.msg_controllen = self.length as _;
+        // macos requires that the control pointer is null when the len is 0.
+        if msg.msg_controllen > 0 {
+            msg.msg_control = self.buffer.as_mut_ptr().cast();
+        }
+
+        self.truncated = false;
+    }
 }
@@ -0,0 +1,708 @@
+#[cfg(any(doc, target_os = "android", target_os = "linux"))]
+use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
+use super::{sockaddr_un, SocketAddr};
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::Shutdown;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::os::unix::ucred;
+use crate::path::Path;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+use crate::sys_common::{AsInner, FromInner};
+use crate::time::Duration;
+
+#[cfg(target_os = "linux")]
+const BACKLOG: libc::c_int = -1;
+#[cfg(not(target_os = "linux"))]
+const BACKLOG: libc::c_int = 128;
+
+/// A Unix sequenced-packet socket server, listening for connections.
+///
+/// Sequenced-packet sockets (`SOCK_SEQPACKET`) are connection-oriented like
+/// [`UnixStream`], but preserve message boundaries like [`UnixDatagram`]:
+/// every call to [`send`] on one end is received by exactly one call to
+/// [`recv`] on the other.
+///
+/// [`UnixStream`]: super::UnixStream
+/// [`UnixDatagram`]: super::UnixDatagram
+/// [`send`]: UnixSeqpacketConn::send
+/// [`recv`]: UnixSeqpacketConn::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacketListener;
+/// use std::thread;
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+///
+///     for conn in listener.incoming() {
+///         let conn = conn?;
+///         thread::spawn(move || {
+///             let mut buf = [0; 1024];
+///             while let Ok(len) = conn.recv(&mut buf) {
+///                 if len == 0 {
+///                     break;
+///                 }
+///                 let _ = conn.send(&buf[..len]);
+///             }
+///         });
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacketListener(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketListener {
+    /// Creates a new `UnixSeqpacketListener` bound to the specified socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// let listener = match UnixSeqpacketListener::bind("/path/to/the/socket") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't bind: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::bind(inner.as_raw_fd(), &addr as *const _ as *const _, len as _))?;
+            cvt(libc::listen(inner.as_raw_fd(), BACKLOG))?;
+
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Creates a new `UnixSeqpacketListener` bound to the specified
+    /// [`socket address`], which may be in the abstract namespace on Linux.
+    ///
+    /// [`socket address`]: crate::os::unix::net::SocketAddr
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::linux::net::SocketAddrExt;
+    /// use std::os::unix::net::{SocketAddr, UnixSeqpacketListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = SocketAddr::from_abstract_name(b"hidden")?;
+    ///     let listener = UnixSeqpacketListener::bind_addr(&addr)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::bind(
+                inner.as_raw_fd(),
+                &socket_addr.addr as *const _ as *const _,
+                socket_addr.len as _,
+            ))?;
+            cvt(libc::listen(inner.as_raw_fd(), BACKLOG))?;
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new connection is
+    /// established. When established, the corresponding [`UnixSeqpacketConn`]
+    /// and the remote peer's address will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///
+    ///     match listener.accept() {
+    ///         Ok((conn, addr)) => println!("Got a client: {addr:?}"),
+    ///         Err(e) => println!("accept function failed: {e:?}"),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixSeqpacketConn, SocketAddr)> {
+        let mut storage: libc::sockaddr_un = unsafe { crate::mem::zeroed() };
+        let mut len = crate::mem::size_of_val(&storage) as libc::socklen_t;
+        let sock = self.0.accept(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixSeqpacketConn(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacketListener` is a reference to the same socket
+    /// that this object references. Both handles can be used to accept
+    /// incoming connections and options set on one listener will affect the
+    /// other.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketListener> {
+        self.0.duplicate().map(UnixSeqpacketListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// This will result in the `accept` operation becoming nonblocking,
+    /// i.e., immediately returning from their calls. If the IO operation is
+    /// successful, `Ok` is returned and no further action is required. If the
+    /// IO operation could not be completed and needs to be retried, an error
+    /// with kind [`io::ErrorKind::WouldBlock`] is returned.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Returns an iterator over incoming connections.
+    ///
+    /// The iterator will never return [`None`] and will also not yield the
+    /// peer's [`SocketAddr`] structure.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn incoming(&self) -> SeqpacketIncoming<'_> {
+        SeqpacketIncoming { listener: self }
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_inner().as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketListener {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_inner().as_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacketListener {
+    #[inline]
+    fn from(fd: OwnedFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(fd)))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<UnixSeqpacketListener> for OwnedFd {
+    #[inline]
+    fn from(listener: UnixSeqpacketListener) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(listener.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl<'a> IntoIterator for &'a UnixSeqpacketListener {
+    type Item = io::Result<UnixSeqpacketConn>;
+    type IntoIter = SeqpacketIncoming<'a>;
+
+    fn into_iter(self) -> SeqpacketIncoming<'a> {
+        self.incoming()
+    }
+}
+
+/// An iterator over incoming connections to a [`UnixSeqpacketListener`].
+///
+/// It will never return [`None`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct SeqpacketIncoming<'a> {
+    listener: &'a UnixSeqpacketListener,
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl<'a> Iterator for SeqpacketIncoming<'a> {
+    type Item = io::Result<UnixSeqpacketConn>;
+
+    fn next(&mut self) -> Option<io::Result<UnixSeqpacketConn>> {
+        Some(self.listener.accept().map(|s| s.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// A connected Unix sequenced-packet socket.
+///
+/// Messages are delivered reliably and in order, and each [`send`] is
+/// received by exactly one [`recv`]. If the receive buffer is too small for a
+/// message, the remainder of that message is discarded.
+///
+/// [`send`]: UnixSeqpacketConn::send
+/// [`recv`]: UnixSeqpacketConn::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacketConn;
+///
+/// fn main() -> std::io::Result<()> {
+///     let conn = UnixSeqpacketConn::connect("/path/to/my/socket")?;
+///     conn.send(b"hello world")?;
+///     let mut buf = [0; 1024];
+///     let len = conn.recv(&mut buf)?;
+///     println!("{:?}", &buf[..len]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacketConn(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketConn {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketConn");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketConn {
+    /// Connects to the socket named by `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// let conn = match UnixSeqpacketConn::connect("/tmp/sock") {
+    ///     Ok(conn) => conn,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketConn> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::connect(inner.as_raw_fd(), &addr as *const _ as *const _, len))?;
+            Ok(UnixSeqpacketConn(inner))
+        }
+    }
+
+    /// Connects to the socket specified by [`address`].
+    ///
+    /// [`address`]: crate::os::unix::net::SocketAddr
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacketConn> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::connect(
+                inner.as_raw_fd(),
+                &socket_addr.addr as *const _ as *const _,
+                socket_addr.len,
+            ))?;
+            Ok(UnixSeqpacketConn(inner))
+        }
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// Returns two `UnixSeqpacketConn`s which are connected to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// let (conn1, conn2) = match UnixSeqpacketConn::pair() {
+    ///     Ok((conn1, conn2)) => (conn1, conn2),
+    ///     Err(e) => {
+    ///         println!("Couldn't create a pair of sockets: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn pair() -> io::Result<(UnixSeqpacketConn, UnixSeqpacketConn)> {
+        let (i1, i2) = Socket::new_pair(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        Ok((UnixSeqpacketConn(i1), UnixSeqpacketConn(i2)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacketConn` is a reference to the same socket that
+    /// this object references. Both handles will send and receive on the same
+    /// connection, and options set on one will affect the other.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketConn> {
+        self.0.duplicate().map(UnixSeqpacketConn)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Gets the peer credentials for this Unix domain socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket, peer_credentials_unix_socket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let conn = UnixSeqpacketConn::connect("/tmp/sock")?;
+    ///     let peer_cred = conn.peer_cred().expect("Couldn't get peer credentials");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peer_cred(&self) -> io::Result<ucred::UCred> {
+        ucred::peer_cred(self)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`recv`]: UnixSeqpacketConn::recv
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`send`]: UnixSeqpacketConn::send
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of [`Shutdown`]).
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+
+    /// Sends a single message on the socket.
+    ///
+    /// The whole buffer is sent as one message, or not at all. On success,
+    /// returns the number of bytes written, which is the length of `buf`.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Sends a single message gathered from several buffers.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send_vectored(&self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    /// Receives a single message from the socket.
+    ///
+    /// On success, returns the number of bytes read. If `buf` is too small to
+    /// hold the message, the excess bytes are discarded. A return value of 0
+    /// means the peer has shut down the connection, unless a zero-length
+    /// message was sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let (a, b) = UnixSeqpacketConn::pair()?;
+    ///     a.send(b"first")?;
+    ///     a.send(b"second")?;
+    ///     let mut buf = [0; 16];
+    ///     assert_eq!(&buf[..b.recv(&mut buf)?], b"first");
+    ///     assert_eq!(&buf[..b.recv(&mut buf)?], b"second");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Receives a single message from the socket, scattered into several
+    /// buffers.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    /// Receives the next message without removing it from the queue.
+    ///
+    /// Successive calls return the same data. This is accomplished by passing
+    /// `MSG_PEEK` as a flag to the underlying `recv` system call.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Moves the socket to pass unix credentials as control message in
+    /// [`SocketAncillary`].
+    ///
+    /// Set the socket option `SO_PASSCRED`.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        self.0.set_passcred(passcred)
+    }
+
+    /// Get the current value of the socket for passing unix credentials in
+    /// [`SocketAncillary`].
+    ///
+    /// This value can be change by [`set_passcred`].
+    ///
+    /// Get the socket option `SO_PASSCRED`.
+    ///
+    /// [`set_passcred`]: UnixSeqpacketConn::set_passcred
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn passcred(&self) -> io::Result<bool> {
+        self.0.passcred()
+    }
+
+    /// Receives a single message and its ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read and whether the message
+    /// was truncated because the buffers were too small.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{UnixSeqpacketConn, SocketAncillary, AncillaryData};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let conn = UnixSeqpacketConn::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 1024];
+    ///     let bufs = &mut [IoSliceMut::new(&mut buf)][..];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let (size, truncated) = conn.recv_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///     println!("received {size}, truncated: {truncated}");
+    ///     for ancillary_result in ancillary.messages() {
+    ///         if let AncillaryData::ScmRights(scm_rights) = ancillary_result.unwrap() {
+    ///             for fd in scm_rights {
+    ///                 println!("receive file descriptor: {fd}");
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<(usize, bool)> {
+        let (count, truncated, _) = recv_vectored_with_ancillary_from(&self.0, bufs, ancillary)?;
+
+        Ok((count, truncated))
+    }
+
+    /// Sends a single message and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{UnixSeqpacketConn, SocketAncillary};
+    /// use std::io::IoSlice;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let conn = UnixSeqpacketConn::connect("/tmp/sock")?;
+    ///     let buf = [1; 8];
+    ///     let fds = [0, 1, 2];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&fds[..]);
+    ///     conn.send_vectored_with_ancillary(&[IoSlice::new(&buf)], &mut ancillary)
+    ///         .expect("send_vectored_with_ancillary function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &[IoSlice<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketConn {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketConn {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketConn {
+        UnixSeqpacketConn(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketConn {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacketConn {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<UnixSeqpacketConn> for OwnedFd {
+    #[inline]
+    fn from(conn: UnixSeqpacketConn) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(conn.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacketConn {
+    #[inline]
+    fn from(owned: OwnedFd) -> Self {
+        unsafe { Self::from_raw_fd(owned.into_raw_fd()) }
+    }
+}
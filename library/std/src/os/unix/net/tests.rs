@@ -758,3 +758,85 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_preserves_boundaries() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let conn = or_panic!(listener.accept()).0;
+        or_panic!(conn.send(b"hello"));
+        or_panic!(conn.send(b"world!"));
+    });
+
+    let conn = or_panic!(UnixSeqpacketConn::connect(&socket_path));
+    assert_eq!(Some(&*socket_path), conn.peer_addr().unwrap().as_pathname());
+    thread.join().unwrap();
+
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(conn.recv(&mut buf)), 5);
+    assert_eq!(&buf[..5], b"hello");
+    // A short buffer truncates the message instead of leaving the rest queued.
+    let mut short = [0; 3];
+    assert_eq!(or_panic!(conn.recv(&mut short)), 3);
+    assert_eq!(&short, b"wor");
+    // The peer is gone, so the next receive reports end of file.
+    assert_eq!(or_panic!(conn.recv(&mut buf)), 0);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_abstract_and_cred() {
+    let addr = or_panic!(SocketAddr::from_abstract_name(b"seqpacket"));
+    let listener = or_panic!(UnixSeqpacketListener::bind_addr(&addr));
+
+    let client = or_panic!(UnixSeqpacketConn::connect_addr(&addr));
+    let server = or_panic!(listener.incoming().next().unwrap());
+
+    let cred = or_panic!(server.peer_cred());
+    assert_eq!(cred.uid, unsafe { libc::getuid() });
+    assert_eq!(cred.pid, Some(crate::process::id() as libc::pid_t));
+    assert_eq!(or_panic!(client.peer_addr()).as_abstract_name().unwrap(), b"seqpacket");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_send_vectored_fds_unix_seqpacket() {
+    let (s1, s2) = or_panic!(UnixSeqpacketConn::pair());
+
+    let buf1 = [1; 8];
+    let bufs_send = &[IoSlice::new(&buf1[..])][..];
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_fds(&[s1.as_raw_fd()][..]));
+
+    let usize = or_panic!(s1.send_vectored_with_ancillary(&bufs_send, &mut ancillary1));
+    assert_eq!(usize, 8);
+
+    let mut buf2 = [0; 4];
+    let mut bufs_recv = &mut [IoSliceMut::new(&mut buf2[..])][..];
+
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+
+    let (usize, truncated) =
+        or_panic!(s2.recv_vectored_with_ancillary(&mut bufs_recv, &mut ancillary2));
+    assert_eq!(usize, 4);
+    assert!(truncated);
+
+    let mut ancillary_data_vec = Vec::from_iter(ancillary2.messages());
+    assert_eq!(ancillary_data_vec.len(), 1);
+    if let AncillaryData::ScmRights(scm_rights) = ancillary_data_vec.pop().unwrap().unwrap() {
+        let fd_vec = Vec::from_iter(scm_rights);
+        assert_eq!(fd_vec.len(), 1);
+        unsafe {
+            libc::close(fd_vec[0]);
+        }
+    } else {
+        unreachable!("must be ScmRights");
+    }
+}
@@ -442,6 +442,10 @@ pub fn remove_dir_all(_path: &Path) -> io::Result<()> {
     Ok(())
 }
 
+pub fn copy_dir_all(_from: &Path, _to: &Path) -> io::Result<()> {
+    unsupported()
+}
+
 pub fn readlink(_p: &Path) -> io::Result<PathBuf> {
     unsupported()
 }
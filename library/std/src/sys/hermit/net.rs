@@ -6,6 +6,7 @@
 use crate::net::{Shutdown, SocketAddr};
 use crate::os::hermit::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, RawFd};
 use crate::sys::hermit::fd::FileDesc;
+use crate::sys::unsupported;
 use crate::sys::time::Instant;
 use crate::sys_common::net::{getsockopt, setsockopt, sockaddr_to_addr};
 use crate::sys_common::{AsInner, FromInner, IntoInner};
@@ -317,6 +318,42 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, _keepalive: bool) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_time(&self, _time: Duration) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_interval(&self, _interval: Duration) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_retries(&self, _retries: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn set_fastopen(&self, _queue_len: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn bind_device(&self, _interface: Option<&[u8]>) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
         let mut nonblocking: i32 = if nonblocking { 1 } else { 0 };
         cvt(unsafe {
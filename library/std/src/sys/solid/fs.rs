@@ -12,7 +12,7 @@
     sys::unsupported,
 };
 
-pub use crate::sys_common::fs::try_exists;
+pub use crate::sys_common::fs::{copy_dir_all, try_exists};
 
 /// A file descriptor.
 #[derive(Clone, Copy)]
@@ -132,6 +132,11 @@ struct StatxExtraFields {
         // This is needed to check if btime is supported by the filesystem.
         stx_mask: u32,
         stx_btime: libc::statx_timestamp,
+        stx_attributes: u64,
+        stx_attributes_mask: u64,
+        stx_mnt_id: u64,
+        stx_dio_mem_align: u32,
+        stx_dio_offset_align: u32,
         // With statx, we can overcome 32-bit `time_t` too.
         #[cfg(target_pointer_width = "32")]
         stx_atime: libc::statx_timestamp,
@@ -142,6 +147,10 @@ struct StatxExtraFields {
 
     }
 
+    // Everything `FileAttr` can report. Kernels that don't know about a field
+    // leave it out of `stx_mask` rather than failing the call.
+    const STATX_MASK: u32 = libc::STATX_ALL | libc::STATX_MNT_ID | libc::STATX_DIOALIGN;
+
     // We prefer `statx` on Linux if available, which contains file creation time,
     // as well as 64-bit timestamps of all kinds.
     // Default `stat64` contains no creation time and may have 32-bit `time_t`.
@@ -234,6 +243,11 @@ fn statx(
         let extra = StatxExtraFields {
             stx_mask: buf.stx_mask,
             stx_btime: buf.stx_btime,
+            stx_attributes: buf.stx_attributes,
+            stx_attributes_mask: buf.stx_attributes_mask,
+            stx_mnt_id: buf.stx_mnt_id,
+            stx_dio_mem_align: buf.stx_dio_mem_align,
+            stx_dio_offset_align: buf.stx_dio_offset_align,
             // Store full times to avoid 32-bit `time_t` truncation.
             #[cfg(target_pointer_width = "32")]
             stx_atime: buf.stx_atime,
@@ -408,6 +422,28 @@ pub fn stx_ctime(&self) -> Option<&libc::statx_timestamp> {
             }
             None
         }
+
+        pub fn stx_mnt_id(&self) -> Option<u64> {
+            let ext = self.statx_extra_fields.as_ref()?;
+            if (ext.stx_mask & libc::STATX_MNT_ID) != 0 { Some(ext.stx_mnt_id) } else { None }
+        }
+
+        /// Returns the attribute flags along with the mask of flags the
+        /// filesystem supports.
+        pub fn stx_attributes(&self) -> Option<(u64, u64)> {
+            let ext = self.statx_extra_fields.as_ref()?;
+            Some((ext.stx_attributes & ext.stx_attributes_mask, ext.stx_attributes_mask))
+        }
+
+        /// Returns the memory and file offset alignment required for direct I/O.
+        pub fn stx_dio_align(&self) -> Option<(u32, u32)> {
+            let ext = self.statx_extra_fields.as_ref()?;
+            if (ext.stx_mask & libc::STATX_DIOALIGN) != 0 {
+                Some((ext.stx_dio_mem_align, ext.stx_dio_offset_align))
+            } else {
+                None
+            }
+        }
     }
 } else {
     impl FileAttr {
@@ -415,6 +451,22 @@ fn from_stat64(stat: stat64) -> Self {
             Self { stat }
         }
     }
+
+    // Without `statx` none of the extra fields can be queried.
+    #[cfg(target_os = "linux")]
+    impl FileAttr {
+        pub fn stx_mnt_id(&self) -> Option<u64> {
+            None
+        }
+
+        pub fn stx_attributes(&self) -> Option<(u64, u64)> {
+            None
+        }
+
+        pub fn stx_dio_align(&self) -> Option<(u32, u32)> {
+            None
+        }
+    }
 }}
 
 impl FileAttr {
@@ -810,7 +862,7 @@ pub fn metadata(&self) -> io::Result<FileAttr> {
                 fd,
                 name,
                 libc::AT_SYMLINK_NOFOLLOW | libc::AT_STATX_SYNC_AS_STAT,
-                libc::STATX_ALL,
+                STATX_MASK,
             ) } {
                 return ret;
             }
@@ -1058,7 +1110,7 @@ pub fn file_attr(&self) -> io::Result<FileAttr> {
                 fd,
                 b"\0" as *const _ as *const c_char,
                 libc::AT_EMPTY_PATH | libc::AT_STATX_SYNC_AS_STAT,
-                libc::STATX_ALL,
+                STATX_MASK,
             ) } {
                 return ret;
             }
@@ -1559,7 +1611,7 @@ pub fn stat(p: &Path) -> io::Result<FileAttr> {
                 libc::AT_FDCWD,
                 p.as_ptr(),
                 libc::AT_STATX_SYNC_AS_STAT,
-                libc::STATX_ALL,
+                STATX_MASK,
             ) } {
                 return ret;
             }
@@ -1578,7 +1630,7 @@ pub fn lstat(p: &Path) -> io::Result<FileAttr> {
                 libc::AT_FDCWD,
                 p.as_ptr(),
                 libc::AT_SYMLINK_NOFOLLOW | libc::AT_STATX_SYNC_AS_STAT,
-                libc::STATX_ALL,
+                STATX_MASK,
             ) } {
                 return ret;
             }
@@ -1820,6 +1872,233 @@ pub fn chroot(dir: &Path) -> io::Result<()> {
     run_path_with_cstr(dir, |dir| cvt(unsafe { libc::chroot(dir.as_ptr()) }).map(|_| ()))
 }
 
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+pub use xattr::{
+    fgetxattr, flistxattr, fremovexattr, fsetxattr, getxattr, listxattr, removexattr, setxattr,
+};
+
+// Extended attributes. Apple platforms take an extra position and options
+// argument, which we always pass as zero to match the Linux behavior of
+// following symlinks.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+))]
+mod xattr {
+    use crate::ffi::{CStr, OsStr, OsString};
+    use crate::io;
+    use crate::os::unix::ffi::{OsStrExt, OsStringExt};
+    use crate::path::Path;
+    use crate::ptr;
+    use crate::sys::common::small_c_string::{run_path_with_cstr, run_with_cstr};
+    use crate::sys::cvt;
+    use libc::{c_char, c_int, c_void, size_t, ssize_t};
+
+    cfg_if::cfg_if! {
+        if #[cfg(any(target_os = "linux", target_os = "android"))] {
+            const ENOATTR: c_int = libc::ENODATA;
+
+            unsafe fn sys_getxattr(
+                p: *const c_char,
+                n: *const c_char,
+                v: *mut c_void,
+                len: size_t,
+            ) -> ssize_t {
+                libc::getxattr(p, n, v, len)
+            }
+            unsafe fn sys_fgetxattr(
+                fd: c_int,
+                n: *const c_char,
+                v: *mut c_void,
+                len: size_t,
+            ) -> ssize_t {
+                libc::fgetxattr(fd, n, v, len)
+            }
+            unsafe fn sys_setxattr(
+                p: *const c_char,
+                n: *const c_char,
+                v: *const c_void,
+                len: size_t,
+            ) -> c_int {
+                libc::setxattr(p, n, v, len, 0)
+            }
+            unsafe fn sys_fsetxattr(
+                fd: c_int,
+                n: *const c_char,
+                v: *const c_void,
+                len: size_t,
+            ) -> c_int {
+                libc::fsetxattr(fd, n, v, len, 0)
+            }
+            unsafe fn sys_listxattr(p: *const c_char, list: *mut c_char, len: size_t) -> ssize_t {
+                libc::listxattr(p, list, len)
+            }
+            unsafe fn sys_flistxattr(fd: c_int, list: *mut c_char, len: size_t) -> ssize_t {
+                libc::flistxattr(fd, list, len)
+            }
+            unsafe fn sys_removexattr(p: *const c_char, n: *const c_char) -> c_int {
+                libc::removexattr(p, n)
+            }
+            unsafe fn sys_fremovexattr(fd: c_int, n: *const c_char) -> c_int {
+                libc::fremovexattr(fd, n)
+            }
+        } else {
+            const ENOATTR: c_int = libc::ENOATTR;
+
+            unsafe fn sys_getxattr(
+                p: *const c_char,
+                n: *const c_char,
+                v: *mut c_void,
+                len: size_t,
+            ) -> ssize_t {
+                libc::getxattr(p, n, v, len, 0, 0)
+            }
+            unsafe fn sys_fgetxattr(
+                fd: c_int,
+                n: *const c_char,
+                v: *mut c_void,
+                len: size_t,
+            ) -> ssize_t {
+                libc::fgetxattr(fd, n, v, len, 0, 0)
+            }
+            unsafe fn sys_setxattr(
+                p: *const c_char,
+                n: *const c_char,
+                v: *const c_void,
+                len: size_t,
+            ) -> c_int {
+                libc::setxattr(p, n, v, len, 0, 0)
+            }
+            unsafe fn sys_fsetxattr(
+                fd: c_int,
+                n: *const c_char,
+                v: *const c_void,
+                len: size_t,
+            ) -> c_int {
+                libc::fsetxattr(fd, n, v, len, 0, 0)
+            }
+            unsafe fn sys_listxattr(p: *const c_char, list: *mut c_char, len: size_t) -> ssize_t {
+                libc::listxattr(p, list, len, 0)
+            }
+            unsafe fn sys_flistxattr(fd: c_int, list: *mut c_char, len: size_t) -> ssize_t {
+                libc::flistxattr(fd, list, len, 0)
+            }
+            unsafe fn sys_removexattr(p: *const c_char, n: *const c_char) -> c_int {
+                libc::removexattr(p, n, 0)
+            }
+            unsafe fn sys_fremovexattr(fd: c_int, n: *const c_char) -> c_int {
+                libc::fremovexattr(fd, n, 0)
+            }
+        }
+    }
+
+    // Queries the size first, then reads into a buffer of that size. The value
+    // may grow in between, in which case `ERANGE` is returned and we start over.
+    fn read_sized(mut read: impl FnMut(*mut c_void, size_t) -> ssize_t) -> io::Result<Vec<u8>> {
+        loop {
+            let len = cvt(read(ptr::null_mut(), 0))? as usize;
+            let mut buf = Vec::with_capacity(len);
+            match cvt(read(buf.as_mut_ptr() as *mut c_void, buf.capacity())) {
+                Ok(read) => {
+                    unsafe { buf.set_len(read as usize) };
+                    return Ok(buf);
+                }
+                Err(e) if e.raw_os_error() == Some(libc::ERANGE) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn value_or_none(value: io::Result<Vec<u8>>) -> io::Result<Option<Vec<u8>>> {
+        match value {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.raw_os_error() == Some(ENOATTR) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // The list is a sequence of nul-terminated names.
+    fn split_names(list: Vec<u8>) -> Vec<OsString> {
+        list.split(|&b| b == 0)
+            .filter(|name| !name.is_empty())
+            .map(|name| OsString::from_vec(name.to_vec()))
+            .collect()
+    }
+
+    fn run_with_name<T>(name: &OsStr, f: impl FnOnce(&CStr) -> io::Result<T>) -> io::Result<T> {
+        run_with_cstr(name.as_bytes(), f)
+    }
+
+    pub fn getxattr(p: &Path, name: &OsStr) -> io::Result<Option<Vec<u8>>> {
+        run_path_with_cstr(p, |p| {
+            run_with_name(name, |name| {
+                value_or_none(read_sized(|buf, len| unsafe {
+                    sys_getxattr(p.as_ptr(), name.as_ptr(), buf, len)
+                }))
+            })
+        })
+    }
+
+    pub fn fgetxattr(fd: c_int, name: &OsStr) -> io::Result<Option<Vec<u8>>> {
+        run_with_name(name, |name| {
+            value_or_none(read_sized(|buf, len| unsafe {
+                sys_fgetxattr(fd, name.as_ptr(), buf, len)
+            }))
+        })
+    }
+
+    pub fn setxattr(p: &Path, name: &OsStr, value: &[u8]) -> io::Result<()> {
+        run_path_with_cstr(p, |p| {
+            run_with_name(name, |name| {
+                cvt(unsafe {
+                    sys_setxattr(p.as_ptr(), name.as_ptr(), value.as_ptr().cast(), value.len())
+                })
+                .map(drop)
+            })
+        })
+    }
+
+    pub fn fsetxattr(fd: c_int, name: &OsStr, value: &[u8]) -> io::Result<()> {
+        run_with_name(name, |name| {
+            cvt(unsafe { sys_fsetxattr(fd, name.as_ptr(), value.as_ptr().cast(), value.len()) })
+                .map(drop)
+        })
+    }
+
+    pub fn listxattr(p: &Path) -> io::Result<Vec<OsString>> {
+        run_path_with_cstr(p, |p| {
+            read_sized(|buf, len| unsafe { sys_listxattr(p.as_ptr(), buf.cast(), len) })
+                .map(split_names)
+        })
+    }
+
+    pub fn flistxattr(fd: c_int) -> io::Result<Vec<OsString>> {
+        read_sized(|buf, len| unsafe { sys_flistxattr(fd, buf.cast(), len) }).map(split_names)
+    }
+
+    pub fn removexattr(p: &Path, name: &OsStr) -> io::Result<()> {
+        run_path_with_cstr(p, |p| {
+            run_with_name(name, |name| {
+                cvt(unsafe { sys_removexattr(p.as_ptr(), name.as_ptr()) }).map(drop)
+            })
+        })
+    }
+
+    pub fn fremovexattr(fd: c_int, name: &OsStr) -> io::Result<()> {
+        run_with_name(name, |name| cvt(unsafe { sys_fremovexattr(fd, name.as_ptr()) }).map(drop))
+    }
+}
+
+pub use copy_dir_impl::copy_dir_all;
 pub use remove_dir_impl::remove_dir_all;
 
 // Fallback for REDOX, ESP-ID, Horizon, and Miri
@@ -1914,7 +2193,7 @@ pub fn openat_nofollow_dironly(parent_fd: Option<RawFd>, p: &CStr) -> io::Result
         Ok(unsafe { OwnedFd::from_raw_fd(fd) })
     }
 
-    fn fdreaddir(dir_fd: OwnedFd) -> io::Result<(ReadDir, RawFd)> {
+    pub fn fdreaddir(dir_fd: OwnedFd) -> io::Result<(ReadDir, RawFd)> {
         let ptr = unsafe { fdopendir(dir_fd.as_raw_fd()) };
         if ptr.is_null() {
             return Err(io::Error::last_os_error());
@@ -2028,3 +2307,169 @@ pub fn remove_dir_all(p: &Path) -> io::Result<()> {
         }
     }
 }
+
+// Fallback for REDOX, ESP-ID, Horizon, Miri, and macOS on x86 where the *at()
+// functions are only weakly available
+#[cfg(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "nto",
+    all(target_os = "macos", not(target_arch = "aarch64")),
+    miri
+))]
+mod copy_dir_impl {
+    pub use crate::sys_common::fs::copy_dir_all;
+}
+
+// Modern implementation using openat(), fdopendir(), mkdirat() and symlinkat(), walking the source
+// through directory file descriptors the same way remove_dir_all() does.
+#[cfg(not(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "nto",
+    all(target_os = "macos", not(target_arch = "aarch64")),
+    miri
+)))]
+mod copy_dir_impl {
+    use super::remove_dir_impl::{fdreaddir, openat_nofollow_dironly};
+    use crate::ffi::{CStr, OsString};
+    use crate::fs::File;
+    use crate::io;
+    use crate::mem::MaybeUninit;
+    use crate::os::unix::ffi::OsStringExt;
+    use crate::os::unix::io::{AsRawFd, FromRawFd};
+    use crate::os::unix::prelude::{OwnedFd, RawFd};
+    use crate::path::Path;
+    use crate::sys::common::small_c_string::run_path_with_cstr;
+    use crate::sys::{cvt, cvt_r};
+    use crate::sys_common::fs::NOT_COPYABLE_ERROR;
+
+    #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+    use libc::{fstat, fstatat, openat, stat};
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    use libc::{fstat64 as fstat, fstatat64 as fstatat, openat64 as openat, stat64 as stat};
+
+    fn fstatat_nofollow(dir_fd: RawFd, name: &CStr) -> io::Result<stat> {
+        let mut st = MaybeUninit::<stat>::uninit();
+        cvt(unsafe { fstatat(dir_fd, name.as_ptr(), st.as_mut_ptr(), libc::AT_SYMLINK_NOFOLLOW) })?;
+        Ok(unsafe { st.assume_init() })
+    }
+
+    fn openat_file(dir_fd: RawFd, name: &CStr, flags: libc::c_int, mode: u32) -> io::Result<File> {
+        let fd = cvt_r(|| unsafe {
+            openat(dir_fd, name.as_ptr(), flags | libc::O_CLOEXEC, mode as libc::c_int)
+        })?;
+        Ok(File::from(unsafe { OwnedFd::from_raw_fd(fd) }))
+    }
+
+    fn readlinkat(dir_fd: RawFd, name: &CStr) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(256);
+        loop {
+            let buf_read = cvt(unsafe {
+                libc::readlinkat(dir_fd, name.as_ptr(), buf.as_mut_ptr() as *mut _, buf.capacity())
+            })? as usize;
+            unsafe {
+                buf.set_len(buf_read);
+            }
+            if buf_read != buf.capacity() {
+                return Ok(buf);
+            }
+            // Trigger the internal buffer resizing logic of `Vec` by requiring
+            // more space than the current capacity.
+            buf.reserve(1);
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn copy_file_contents(reader: &mut File, writer: &mut File) -> io::Result<u64> {
+        use crate::sys::kernel_copy::{copy_regular_files, CopyResult};
+
+        match copy_regular_files(reader.as_raw_fd(), writer.as_raw_fd(), u64::MAX) {
+            CopyResult::Ended(bytes) => Ok(bytes),
+            CopyResult::Error(e, _) => Err(e),
+            CopyResult::Fallback(written) => {
+                io::copy::generic_copy(reader, writer).map(|bytes| bytes + written)
+            }
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn copy_file_contents(reader: &mut File, writer: &mut File) -> io::Result<u64> {
+        io::copy(reader, writer)
+    }
+
+    fn copy_file(src_fd: RawFd, dst_fd: RawFd, name: &CStr) -> io::Result<()> {
+        // O_NOFOLLOW makes sure the entry was not swapped for a symlink since it was inspected.
+        let mut reader = openat_file(src_fd, name, libc::O_RDONLY | libc::O_NOFOLLOW, 0)?;
+        let mut st = MaybeUninit::<stat>::uninit();
+        cvt(unsafe { fstat(reader.as_raw_fd(), st.as_mut_ptr()) })?;
+        let st = unsafe { st.assume_init() };
+        if st.st_mode & libc::S_IFMT != libc::S_IFREG {
+            return Err(NOT_COPYABLE_ERROR);
+        }
+        let mode = (st.st_mode & 0o7777) as u32;
+        let mut writer =
+            openat_file(dst_fd, name, libc::O_WRONLY | libc::O_CREAT | libc::O_EXCL, mode)?;
+        copy_file_contents(&mut reader, &mut writer)?;
+        // The mode passed to openat() is subject to the umask, so set it explicitly.
+        cvt(unsafe { libc::fchmod(writer.as_raw_fd(), mode as libc::mode_t) })?;
+        Ok(())
+    }
+
+    fn copy_dir_all_recursive(src: OwnedFd, dst_fd: RawFd) -> io::Result<()> {
+        // open the directory passing ownership of the fd
+        let (dir, src_fd) = fdreaddir(src)?;
+        for child in dir {
+            let child = child?;
+            let name = child.name_cstr();
+            let st = fstatat_nofollow(src_fd, name)?;
+            match st.st_mode & libc::S_IFMT {
+                libc::S_IFDIR => {
+                    // Keep the new directory private until its contents are in place.
+                    cvt(unsafe { libc::mkdirat(dst_fd, name.as_ptr(), 0o700) })?;
+                    let child_src = openat_nofollow_dironly(Some(src_fd), name)?;
+                    let child_dst = openat_nofollow_dironly(Some(dst_fd), name)?;
+                    copy_dir_all_recursive(child_src, child_dst.as_raw_fd())?;
+                    cvt(unsafe { libc::fchmod(child_dst.as_raw_fd(), st.st_mode & 0o7777) })?;
+                }
+                libc::S_IFLNK => {
+                    let target = readlinkat(src_fd, name)?;
+                    run_path_with_cstr(Path::new(&OsString::from_vec(target)), |target| {
+                        cvt(unsafe { libc::symlinkat(target.as_ptr(), dst_fd, name.as_ptr()) })
+                            .map(drop)
+                    })?;
+                }
+                libc::S_IFREG => copy_file(src_fd, dst_fd, name)?,
+                _ => return Err(NOT_COPYABLE_ERROR),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn copy_dir_all(from: &Path, to: &Path) -> io::Result<()> {
+        // The root is allowed to be a symlink to a directory, so it is opened without O_NOFOLLOW.
+        let src = run_path_with_cstr(from, |from| {
+            let fd = cvt_r(|| unsafe {
+                openat(
+                    libc::AT_FDCWD,
+                    from.as_ptr(),
+                    libc::O_CLOEXEC | libc::O_RDONLY | libc::O_DIRECTORY,
+                )
+            })?;
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        })?;
+        let mut st = MaybeUninit::<stat>::uninit();
+        cvt(unsafe { fstat(src.as_raw_fd(), st.as_mut_ptr()) })?;
+        let mode = unsafe { st.assume_init() }.st_mode & 0o7777;
+
+        run_path_with_cstr(to, |to| {
+            cvt(unsafe { libc::mkdir(to.as_ptr(), 0o700) })?;
+            let dst = openat_nofollow_dironly(None, to)?;
+            copy_dir_all_recursive(src, dst.as_raw_fd())?;
+            cvt(unsafe { libc::fchmod(dst.as_raw_fd(), mode) })?;
+            Ok(())
+        })
+    }
+}
@@ -336,6 +336,28 @@ pub fn send_msg(&self, msg: &mut libc::msghdr) -> io::Result<usize> {
         Ok(n as usize)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn recv_mmsg(&self, msgs: &mut [libc::mmsghdr]) -> io::Result<usize> {
+        let len = cmp::min(msgs.len(), libc::c_uint::MAX as usize) as libc::c_uint;
+        let n = cvt(unsafe {
+            libc::recvmmsg(
+                self.as_raw_fd(),
+                msgs.as_mut_ptr(),
+                len,
+                libc::MSG_CMSG_CLOEXEC as _,
+                crate::ptr::null_mut(),
+            )
+        })?;
+        Ok(n as usize)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn send_mmsg(&self, msgs: &mut [libc::mmsghdr]) -> io::Result<usize> {
+        let len = cmp::min(msgs.len(), libc::c_uint::MAX as usize) as libc::c_uint;
+        let n = cvt(unsafe { libc::sendmmsg(self.as_raw_fd(), msgs.as_mut_ptr(), len, 0) })?;
+        Ok(n as usize)
+    }
+
     pub fn set_timeout(&self, dur: Option<Duration>, kind: libc::c_int) -> io::Result<()> {
         let timeout = match dur {
             Some(dur) => {
@@ -421,6 +443,213 @@ pub fn quickack(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, keepalive as c_int)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_keepalive_time(&self, time: Duration) -> io::Result<()> {
+        let secs = cmp::min(time.as_secs(), c_int::MAX as u64) as c_int;
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "illumos",
+                target_os = "solaris",
+            ))] {
+                setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPIDLE, secs)
+            } else if #[cfg(any(
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "tvos",
+                target_os = "watchos",
+            ))] {
+                setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPALIVE, secs)
+            } else {
+                let _ = secs;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "setting the keepalive time is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let secs = cmp::min(interval.as_secs(), c_int::MAX as u64) as c_int;
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "illumos",
+                target_os = "solaris",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "tvos",
+                target_os = "watchos",
+            ))] {
+                setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL, secs)
+            } else {
+                let _ = secs;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "setting the keepalive interval is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "illumos",
+                target_os = "solaris",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "tvos",
+                target_os = "watchos",
+            ))] {
+                setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPCNT, retries)
+            } else {
+                let _ = retries;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "setting the keepalive retry count is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "tvos",
+                target_os = "watchos",
+            ))] {
+                setsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT, reuseport as c_int)
+            } else {
+                let _ = reuseport;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "SO_REUSEPORT is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "tvos",
+                target_os = "watchos",
+            ))] {
+                let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT)?;
+                Ok(raw != 0)
+            } else {
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "SO_REUSEPORT is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn set_fastopen(&self, queue_len: u32) -> io::Result<()> {
+        let queue_len = cmp::min(queue_len, c_int::MAX as u32) as c_int;
+        cfg_if::cfg_if! {
+            if #[cfg(any(target_os = "android", target_os = "linux", target_os = "freebsd"))] {
+                setsockopt(self, libc::IPPROTO_TCP, libc::TCP_FASTOPEN, queue_len)
+            } else {
+                let _ = queue_len;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "TCP_FASTOPEN is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn bind_device(&self, interface: Option<&[u8]>) -> io::Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(target_os = "android", target_os = "linux"))] {
+                let (ptr, len) = match interface {
+                    Some(interface) => (interface.as_ptr(), interface.len()),
+                    None => (crate::ptr::null(), 0),
+                };
+                cvt(unsafe {
+                    libc::setsockopt(
+                        self.as_raw_fd(),
+                        libc::SOL_SOCKET,
+                        libc::SO_BINDTODEVICE,
+                        ptr.cast(),
+                        len as socklen_t,
+                    )
+                })?;
+                Ok(())
+            } else {
+                let _ = interface;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "binding to a device is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn set_udp_segment(&self, segment_size: u16) -> io::Result<()> {
+        setsockopt(self, libc::SOL_UDP, libc::UDP_SEGMENT, segment_size as c_int)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn udp_segment(&self) -> io::Result<u16> {
+        let raw: c_int = getsockopt(self, libc::SOL_UDP, libc::UDP_SEGMENT)?;
+        Ok(raw as u16)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn set_udp_gro(&self, gro: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_UDP, libc::UDP_GRO, gro as c_int)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn udp_gro(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, libc::SOL_UDP, libc::UDP_GRO)?;
+        Ok(raw != 0)
+    }
+
     #[cfg(any(target_os = "android", target_os = "linux",))]
     pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
         setsockopt(self, libc::SOL_SOCKET, libc::SO_PASSCRED, passcred as libc::c_int)
@@ -291,6 +291,10 @@ pub fn remove_dir_all(_path: &Path) -> io::Result<()> {
     unsupported()
 }
 
+pub fn copy_dir_all(_from: &Path, _to: &Path) -> io::Result<()> {
+    unsupported()
+}
+
 pub fn try_exists(_path: &Path) -> io::Result<bool> {
     unsupported()
 }
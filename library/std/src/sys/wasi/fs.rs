@@ -17,7 +17,7 @@
 use crate::sys::unsupported;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
-pub use crate::sys_common::fs::try_exists;
+pub use crate::sys_common::fs::{copy_dir_all, try_exists};
 
 pub struct File {
     fd: WasiFd,
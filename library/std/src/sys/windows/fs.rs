@@ -1198,6 +1198,34 @@ fn copy_handle(f: &File) -> mem::ManuallyDrop<File> {
     Ok(())
 }
 
+pub fn copy_dir_all(from: &Path, to: &Path) -> io::Result<()> {
+    let metadata = crate::fs::metadata(from)?;
+    if !metadata.is_dir() {
+        return Err(io::Error::from_raw_os_error(c::ERROR_DIRECTORY as _));
+    }
+    copy_dir_all_recursive(from, to, metadata.permissions())
+}
+
+fn copy_dir_all_recursive(from: &Path, to: &Path, perm: crate::fs::Permissions) -> io::Result<()> {
+    crate::fs::create_dir(to)?;
+    for child in crate::fs::read_dir(from)? {
+        let child = child?;
+        let file_type = child.file_type()?;
+        let target = to.join(child.file_name());
+        if file_type.as_inner().is_symlink() {
+            // Unlike on Unix, the kind of link has to match what it points at.
+            let original = readlink(&child.path())?;
+            symlink_inner(&original, &target, file_type.as_inner().is_symlink_dir())?;
+        } else if file_type.is_dir() {
+            copy_dir_all_recursive(&child.path(), &target, child.metadata()?.permissions())?;
+        } else {
+            copy(&child.path(), &target)?;
+        }
+    }
+    // Permissions are applied last so that read-only directories can be filled.
+    crate::fs::set_permissions(to, perm)
+}
+
 pub fn readlink(path: &Path) -> io::Result<PathBuf> {
     // Open the link with no access mode, instead of generic read.
     // By default FILE_LIST_DIRECTORY is denied for the junction "C:\Documents and Settings", so
@@ -427,6 +427,56 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE, keepalive as c::BOOL)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c::BOOL = net::getsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_keepalive_time(&self, time: Duration) -> io::Result<()> {
+        let secs = cmp::min(time.as_secs(), u32::MAX as u64) as u32;
+        net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPALIVE, secs)
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let secs = cmp::min(interval.as_secs(), u32::MAX as u64) as u32;
+        net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPINTVL, secs)
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPCNT, retries)
+    }
+
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "SO_REUSEPORT is not supported on Windows",
+        ))
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "SO_REUSEPORT is not supported on Windows",
+        ))
+    }
+
+    pub fn set_fastopen(&self, queue_len: u32) -> io::Result<()> {
+        // Windows only supports turning fast open on or off; the queue length is chosen by the
+        // system.
+        net::setsockopt(self, c::IPPROTO_TCP, c::TCP_FASTOPEN, (queue_len != 0) as c::BOOL)
+    }
+
+    pub fn bind_device(&self, _interface: Option<&[u8]>) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "binding to a device is not supported on Windows",
+        ))
+    }
+
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         let raw: c_int = net::getsockopt(self, c::SOL_SOCKET, c::SO_ERROR)?;
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
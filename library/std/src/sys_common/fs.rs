@@ -42,6 +42,42 @@ fn remove_dir_all_recursive(path: &Path) -> io::Result<()> {
     fs::remove_dir(path)
 }
 
+pub(crate) const NOT_COPYABLE_ERROR: Error = io::const_io_error!(
+    ErrorKind::InvalidInput,
+    "the directory contains an entry that is neither a regular file, a directory nor a symlink",
+);
+
+pub fn copy_dir_all(from: &Path, to: &Path) -> io::Result<()> {
+    let metadata = fs::metadata(from)?;
+    if !metadata.is_dir() {
+        return Err(io::const_io_error!(
+            ErrorKind::InvalidInput,
+            "the source path is not a directory",
+        ));
+    }
+    copy_dir_all_recursive(from, to, metadata.permissions())
+}
+
+fn copy_dir_all_recursive(from: &Path, to: &Path, perm: fs::Permissions) -> io::Result<()> {
+    fs::create_dir(to)?;
+    for child in fs::read_dir(from)? {
+        let child = child?;
+        let filetype = child.file_type()?;
+        let target = to.join(child.file_name());
+        if filetype.is_dir() {
+            copy_dir_all_recursive(&child.path(), &target, child.metadata()?.permissions())?;
+        } else if filetype.is_symlink() {
+            crate::sys::fs::symlink(&fs::read_link(&child.path())?, &target)?;
+        } else if filetype.is_file() {
+            fs::copy(&child.path(), &target)?;
+        } else {
+            return Err(NOT_COPYABLE_ERROR);
+        }
+    }
+    // Permissions are applied last so that read-only directories can be filled.
+    fs::set_permissions(to, perm)
+}
+
 pub fn try_exists(path: &Path) -> io::Result<bool> {
     match fs::metadata(path) {
         Ok(_) => Ok(true),
@@ -0,0 +1,51 @@
+//! Runtime support for `#[target_clones]` functions.
+//!
+//! The compiler turns a function annotated with `#[target_clones("avx2", "default")]` into one
+//! copy per feature set plus a dispatcher. The first time the dispatcher is called it asks
+//! [`features_detected`] about every feature set in turn and remembers the first copy the CPU
+//! can run, falling back to the `"default"` one.
+
+/// Returns whether the running CPU supports all the target features in the comma-separated
+/// list `features`.
+///
+/// Features that cannot be detected at runtime on the current target are reported as
+/// unsupported, so a copy of a function that requires them is never picked.
+#[lang = "target_clones_features_detected"]
+fn features_detected(features: &str) -> bool {
+    features.split(',').all(feature_detected)
+}
+
+macro_rules! detect {
+    ($feature:ident, $detected:ident, $($name:tt)*) => {
+        match $feature {
+            $($name => crate::arch::$detected!($name),)*
+            _ => false,
+        }
+    };
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn feature_detected(feature: &str) -> bool {
+    detect! {
+        feature, is_x86_feature_detected,
+        "adx" "aes" "avx" "avx2" "avx512bw" "avx512cd" "avx512dq" "avx512f" "avx512vl"
+        "bmi1" "bmi2" "cmpxchg16b" "f16c" "fma" "fxsr" "lzcnt" "pclmulqdq" "popcnt"
+        "rdrand" "rdseed" "sha" "sse" "sse2" "sse3" "sse4.1" "sse4.2" "ssse3" "xsave"
+        "xsavec" "xsaveopt" "xsaves"
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn feature_detected(feature: &str) -> bool {
+    detect! {
+        feature, is_aarch64_feature_detected,
+        "aes" "bf16" "crc" "dit" "dotprod" "fcma" "fhm" "fp16" "frintts" "i8mm"
+        "jsconv" "lse" "lse2" "neon" "rcpc" "rcpc2" "rdm" "sha2" "sha3" "sm4" "sve"
+        "sve2"
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn feature_detected(_feature: &str) -> bool {
+    false
+}
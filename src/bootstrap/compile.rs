@@ -343,6 +343,13 @@ pub fn std_cargo(builder: &Builder<'_>, target: TargetSelection, stage: u32, car
         features += " compiler-builtins-no-asm";
     }
 
+    // The `f16` and `f128` soft-float routines of `compiler-builtins` can only be compiled by a
+    // compiler that knows these types, which the bootstrap compiler does not, and by a codegen
+    // backend that supports them, which only LLVM does.
+    if stage == 0 || builder.config.default_codegen_backend().map_or(false, |b| b != "llvm") {
+        features += " compiler-builtins-no-f16-f128";
+    }
+
     if builder.no_std(target) == Some(true) {
         features += " compiler-builtins-mem";
         if !target.starts_with("bpf") {
@@ -67,6 +67,8 @@ impl Step for CrateBootstrap {
 
     fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
         run.path("src/tools/jsondoclint")
+            .path("src/tools/rustdoc-api-diff")
+            .path("src/tools/rustdoc-search")
             .path("src/tools/suggest-tests")
             .path("src/tools/replace-version-placeholder")
             .alias("tidyselftest")
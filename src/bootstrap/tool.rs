@@ -295,6 +295,8 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
     LintDocs, "src/tools/lint-docs", "lint-docs";
     JsonDocCk, "src/tools/jsondocck", "jsondocck";
     JsonDocLint, "src/tools/jsondoclint", "jsondoclint";
+    RustdocApiDiff, "src/tools/rustdoc-api-diff", "rustdoc-api-diff";
+    RustdocSearch, "src/tools/rustdoc-search", "rustdoc-search";
     HtmlChecker, "src/tools/html-checker", "html-checker";
     BumpStage0, "src/tools/bump-stage0", "bump-stage0";
     ReplaceVersionPlaceholder, "src/tools/replace-version-placeholder", "replace-version-placeholder";
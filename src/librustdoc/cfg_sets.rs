@@ -0,0 +1,162 @@
+//! Documents a crate for several sets of cfgs and targets at once, with `--cfg-set`.
+//!
+//! The crate is compiled once, for the target of the first set, and keeps the code enabled in any
+//! of the sets: a cfg-pattern holds if it holds in the configuration of one of them. The
+//! [`tag-cfg-sets`](crate::passes::TAG_CFG_SETS) pass then tags the items which don't exist in
+//! every set with the cfgs of the sets they exist in, and the crate is rendered once.
+
+use std::path::Path;
+
+use rustc_interface::interface;
+use rustc_session::config;
+use rustc_session::filesearch;
+use rustc_session::parse::{CrateConfig, ParseSess};
+use rustc_span::symbol::{sym, Symbol};
+use rustc_target::spec::{Target, TargetTriple};
+
+use crate::clean::cfg::Cfg;
+
+/// A set of cfgs, and optionally a target, given with `--cfg-set`.
+#[derive(Clone, Debug)]
+pub(crate) struct CfgSet {
+    /// The cfgs, in the syntax of `--cfg`.
+    cfgs: Vec<String>,
+    target: Option<TargetTriple>,
+}
+
+impl CfgSet {
+    /// Parses a whitespace-separated list of cfgs, like `unix feature="std"`. `target=<triple>`
+    /// sets the target to document the crate for.
+    pub(crate) fn parse(spec: &str) -> Result<CfgSet, String> {
+        let mut set = CfgSet { cfgs: Vec::new(), target: None };
+        for part in spec.split_whitespace() {
+            let Some(target) = part.strip_prefix("target=") else {
+                set.cfgs.push(part.to_owned());
+                continue;
+            };
+            if set.target.is_some() {
+                return Err("the target is given more than once".to_owned());
+            }
+            let target = target.trim_matches('"');
+            set.target = Some(if target.ends_with(".json") {
+                TargetTriple::from_path(Path::new(target))
+                    .map_err(|_| format!("target file {target:?} does not exist"))?
+            } else {
+                TargetTriple::from_triple(target)
+            });
+        }
+        if set.cfgs.is_empty() && set.target.is_none() {
+            return Err("no cfg or target given".to_owned());
+        }
+        Ok(set)
+    }
+
+    /// Returns the target of the set, or `default` if it doesn't give one.
+    pub(crate) fn target<'a>(&'a self, default: &'a TargetTriple) -> &'a TargetTriple {
+        self.target.as_ref().unwrap_or(default)
+    }
+}
+
+fn target_configuration(
+    parse_sess: &ParseSess,
+    triple: &TargetTriple,
+    sysroot: &Path,
+) -> CrateConfig {
+    let target = match Target::search(triple, sysroot) {
+        Ok((target, _)) => target,
+        Err(err) => parse_sess
+            .span_diagnostic
+            .fatal(format!("error loading target specification of a `--cfg-set`: {err}"))
+            .raise(),
+    };
+    config::target_configuration(&target, parse_sess.unstable_features.is_nightly_build())
+        .unwrap_or_else(|err| parse_sess.emit_fatal(err))
+}
+
+/// Fills `parse_sess.cfg_sets` with the configuration of each set.
+///
+/// `parse_sess.config` is the configuration of the compilation, for `target`. The sets for
+/// another target get the cfgs of their target instead of the ones of `target`.
+pub(crate) fn configure(
+    parse_sess: &mut ParseSess,
+    sets: &[CfgSet],
+    target: &TargetTriple,
+    sysroot: Option<&Path>,
+) {
+    let sysroot = match sysroot {
+        Some(sysroot) => sysroot.to_owned(),
+        None => filesearch::get_or_default_sysroot().expect("failed finding sysroot"),
+    };
+    let mut compilation_target_cfgs = None;
+    let mut configs = Vec::with_capacity(sets.len());
+    for set in sets {
+        let mut config = parse_sess.config.clone();
+        let set_target = set.target(target);
+        if set_target != target {
+            let compilation_target_cfgs = compilation_target_cfgs
+                .get_or_insert_with(|| target_configuration(parse_sess, target, &sysroot));
+            // The target features are only known for the target of the compilation.
+            config.retain(|cfg| {
+                !compilation_target_cfgs.contains(cfg) && cfg.0 != sym::target_feature
+            });
+            config.extend(target_configuration(parse_sess, set_target, &sysroot));
+        }
+        config.extend(config::to_crate_config(interface::parse_cfgspecs(set.cfgs.clone())));
+        configs.push(config);
+    }
+    parse_sess.cfg_sets = configs;
+}
+
+/// Returns the cfgs which tell each set apart from the others, shown in the badges of the items
+/// which only exist in some of them.
+///
+/// These are the cfgs given to the set, and when the sets have different targets, the
+/// `target_os` of its target, with its `target_arch` if another set is for the same OS. The cfgs
+/// shared by every set are left out.
+pub(crate) fn distinguishing_cfgs(sets: &[CfgSet], configs: &[CrateConfig]) -> Vec<Cfg> {
+    let target_cfg = |config: &CrateConfig, name| config.iter().find(|cfg| cfg.0 == name).copied();
+    let has_target = sets.iter().any(|set| set.target.is_some());
+    let mut set_cfgs = sets
+        .iter()
+        .zip(configs)
+        .map(|(set, config)| {
+            let mut cfgs = set.cfgs.iter().map(|cfg| split_cfg(cfg)).collect::<Vec<_>>();
+            if has_target {
+                let os = target_cfg(config, sym::target_os);
+                let arch = target_cfg(config, sym::target_arch);
+                cfgs.extend(os);
+                if configs.iter().any(|other| {
+                    target_cfg(other, sym::target_os) == os
+                        && target_cfg(other, sym::target_arch) != arch
+                }) {
+                    cfgs.extend(arch);
+                }
+            }
+            cfgs
+        })
+        .collect::<Vec<_>>();
+    let common = set_cfgs[0]
+        .iter()
+        .filter(|cfg| set_cfgs.iter().all(|cfgs| cfgs.contains(cfg)))
+        .copied()
+        .collect::<Vec<_>>();
+    for cfgs in &mut set_cfgs {
+        cfgs.retain(|cfg| !common.contains(cfg));
+    }
+    set_cfgs
+        .into_iter()
+        .map(|cfgs| {
+            cfgs.into_iter().fold(Cfg::True, |cfg, (name, value)| cfg & Cfg::Cfg(name, value))
+        })
+        .collect()
+}
+
+/// Splits a cfg given in the syntax of `--cfg`, like `feature="std"`, into its name and value.
+fn split_cfg(cfg: &str) -> (Symbol, Option<Symbol>) {
+    match cfg.split_once('=') {
+        Some((name, value)) => {
+            (Symbol::intern(name), Some(Symbol::intern(value.trim_matches('"'))))
+        }
+        None => (Symbol::intern(cfg), None),
+    }
+}
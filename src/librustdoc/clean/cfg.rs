@@ -10,7 +10,7 @@
 use rustc_ast::{LitKind, MetaItem, MetaItemKind, NestedMetaItem};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_feature::Features;
-use rustc_session::parse::ParseSess;
+use rustc_session::parse::{CrateConfig, ParseSess};
 use rustc_span::symbol::{sym, Symbol};
 
 use rustc_span::Span;
@@ -130,18 +130,23 @@ pub(crate) fn parse(cfg: &MetaItem) -> Result<Cfg, InvalidCfgError> {
     ///
     /// Equivalent to `attr::cfg_matches`.
     // FIXME: Actually make use of `features`.
-    pub(crate) fn matches(&self, parse_sess: &ParseSess, features: Option<&Features>) -> bool {
+    pub(crate) fn matches(&self, parse_sess: &ParseSess, _features: Option<&Features>) -> bool {
+        if parse_sess.cfg_sets.is_empty() {
+            self.holds_in(&parse_sess.config)
+        } else {
+            parse_sess.cfg_sets.iter().any(|config| self.holds_in(config))
+        }
+    }
+
+    /// Checks whether the given configuration holds in `config`.
+    pub(crate) fn holds_in(&self, config: &CrateConfig) -> bool {
         match *self {
             Cfg::False => false,
             Cfg::True => true,
-            Cfg::Not(ref child) => !child.matches(parse_sess, features),
-            Cfg::All(ref sub_cfgs) => {
-                sub_cfgs.iter().all(|sub_cfg| sub_cfg.matches(parse_sess, features))
-            }
-            Cfg::Any(ref sub_cfgs) => {
-                sub_cfgs.iter().any(|sub_cfg| sub_cfg.matches(parse_sess, features))
-            }
-            Cfg::Cfg(name, value) => parse_sess.config.contains(&(name, value)),
+            Cfg::Not(ref child) => !child.holds_in(config),
+            Cfg::All(ref sub_cfgs) => sub_cfgs.iter().all(|sub_cfg| sub_cfg.holds_in(config)),
+            Cfg::Any(ref sub_cfgs) => sub_cfgs.iter().any(|sub_cfg| sub_cfg.holds_in(config)),
+            Cfg::Cfg(name, value) => config.contains(&(name, value)),
         }
     }
 
@@ -1687,6 +1687,11 @@ pub(crate) fn clean_ty<'tcx>(ty: &hir::Ty<'tcx>, cx: &mut DocContext<'tcx>) -> T
 
             Array(Box::new(clean_ty(ty, cx)), length.into())
         }
+        TyKind::Pat(ty, pat) => {
+            let pat =
+                rustc_hir_pretty::to_string(rustc_hir_pretty::NO_ANN, |s| s.print_ty_pat(pat));
+            Type::Pat(Box::new(clean_ty(ty, cx)), pat.into())
+        }
         TyKind::Tup(tys) => Tuple(tys.iter().map(|ty| clean_ty(ty, cx)).collect()),
         TyKind::OpaqueDef(item_id, _, _) => {
             let item = cx.tcx.hir().item(item_id);
@@ -1762,6 +1767,10 @@ pub(crate) fn clean_middle_ty<'tcx>(
             let n = print_const(cx, n);
             Array(Box::new(clean_middle_ty(bound_ty.rebind(ty), cx, None)), n.into())
         }
+        ty::Pat(ty, pat) => Type::Pat(
+            Box::new(clean_middle_ty(bound_ty.rebind(ty), cx, None)),
+            format!("{pat:?}").into(),
+        ),
         ty::RawPtr(mt) => {
             RawPointer(mt.mutbl, Box::new(clean_middle_ty(bound_ty.rebind(mt.ty), cx, None)))
         }
@@ -46,8 +46,8 @@
 pub(crate) use self::ItemKind::*;
 pub(crate) use self::SelfTy::*;
 pub(crate) use self::Type::{
-    Array, BareFunction, BorrowedRef, DynTrait, Generic, ImplTrait, Infer, Primitive, QPath,
-    RawPointer, Slice, Tuple,
+    Array, BareFunction, BorrowedRef, DynTrait, Generic, ImplTrait, Infer, Pat, Primitive,
+    QPath, RawPointer, Slice, Tuple,
 };
 
 #[cfg(test)]
@@ -1514,6 +1514,10 @@ pub(crate) enum Type {
     ///
     /// The `String` field is a stringified version of the array's length parameter.
     Array(Box<Type>, Box<str>),
+    /// A pattern type: `u32 is 1..`
+    ///
+    /// The `String` field is a stringified version of the pattern.
+    Pat(Box<Type>, Box<str>),
     /// A raw pointer type: `*const i32`, `*mut i32`
     RawPointer(Mutability, Box<Type>),
     /// A reference type: `&i32`, `&'a mut Foo`
@@ -1578,6 +1582,7 @@ pub(crate) fn is_doc_subtype_of(&self, other: &Self, cache: &Cache) -> bool {
             }
             (Type::Slice(a), Type::Slice(b)) => a.is_doc_subtype_of(b, cache),
             (Type::Array(a, al), Type::Array(b, bl)) => al == bl && a.is_doc_subtype_of(b, cache),
+            (Type::Pat(a, ap), Type::Pat(b, bp)) => ap == bp && a.is_doc_subtype_of(b, cache),
             (Type::RawPointer(mutability, type_), Type::RawPointer(b_mutability, b_type_)) => {
                 mutability == b_mutability && type_.is_doc_subtype_of(b_type_, cache)
             }
@@ -1685,7 +1690,7 @@ fn inner_def_id(&self, cache: Option<&Cache>) -> Option<DefId> {
             Array(..) => PrimitiveType::Array,
             RawPointer(..) => PrimitiveType::RawPointer,
             QPath(box QPathData { ref self_type, .. }) => return self_type.inner_def_id(cache),
-            Generic(_) | Infer | ImplTrait(_) => return None,
+            Generic(_) | Infer | ImplTrait(_) | Pat(..) => return None,
         };
         cache.and_then(|c| Primitive(t).def_id(c))
     }
@@ -1727,8 +1732,10 @@ pub(crate) enum PrimitiveType {
     U32,
     U64,
     U128,
+    F16,
     F32,
     F64,
+    F128,
     Char,
     Bool,
     Str,
@@ -1759,8 +1766,10 @@ pub(crate) fn from_hir(prim: hir::PrimTy) -> PrimitiveType {
             hir::PrimTy::Uint(UintTy::U32) => PrimitiveType::U32,
             hir::PrimTy::Uint(UintTy::U64) => PrimitiveType::U64,
             hir::PrimTy::Uint(UintTy::U128) => PrimitiveType::U128,
+            hir::PrimTy::Float(FloatTy::F16) => PrimitiveType::F16,
             hir::PrimTy::Float(FloatTy::F32) => PrimitiveType::F32,
             hir::PrimTy::Float(FloatTy::F64) => PrimitiveType::F64,
+            hir::PrimTy::Float(FloatTy::F128) => PrimitiveType::F128,
             hir::PrimTy::Str => PrimitiveType::Str,
             hir::PrimTy::Bool => PrimitiveType::Bool,
             hir::PrimTy::Char => PrimitiveType::Char,
@@ -1784,8 +1793,10 @@ pub(crate) fn from_symbol(s: Symbol) -> Option<PrimitiveType> {
             sym::bool => Some(PrimitiveType::Bool),
             sym::char => Some(PrimitiveType::Char),
             sym::str => Some(PrimitiveType::Str),
+            sym::f16 => Some(PrimitiveType::F16),
             sym::f32 => Some(PrimitiveType::F32),
             sym::f64 => Some(PrimitiveType::F64),
+            sym::f128 => Some(PrimitiveType::F128),
             sym::array => Some(PrimitiveType::Array),
             sym::slice => Some(PrimitiveType::Slice),
             sym::tuple => Some(PrimitiveType::Tuple),
@@ -1819,8 +1830,10 @@ pub(crate) fn simplified_types() -> &'static SimplifiedTypes {
                 U32 => single(UintSimplifiedType(UintTy::U32)),
                 U64 => single(UintSimplifiedType(UintTy::U64)),
                 U128 => single(UintSimplifiedType(UintTy::U128)),
+                F16 => single(FloatSimplifiedType(FloatTy::F16)),
                 F32 => single(FloatSimplifiedType(FloatTy::F32)),
                 F64 => single(FloatSimplifiedType(FloatTy::F64)),
+                F128 => single(FloatSimplifiedType(FloatTy::F128)),
                 Str => single(StrSimplifiedType),
                 Bool => single(BoolSimplifiedType),
                 Char => single(CharSimplifiedType),
@@ -1875,8 +1888,10 @@ pub(crate) fn as_sym(&self) -> Symbol {
             U32 => sym::u32,
             U64 => sym::u64,
             U128 => sym::u128,
+            F16 => sym::f16,
             F32 => sym::f32,
             F64 => sym::f64,
+            F128 => sym::f128,
             Str => sym::str,
             Bool => sym::bool,
             Char => sym::char,
@@ -1958,8 +1973,10 @@ fn from(uint_ty: ast::UintTy) -> PrimitiveType {
 impl From<ast::FloatTy> for PrimitiveType {
     fn from(float_ty: ast::FloatTy) -> PrimitiveType {
         match float_ty {
+            ast::FloatTy::F16 => PrimitiveType::F16,
             ast::FloatTy::F32 => PrimitiveType::F32,
             ast::FloatTy::F64 => PrimitiveType::F64,
+            ast::FloatTy::F128 => PrimitiveType::F128,
         }
     }
 }
@@ -1993,8 +2010,10 @@ fn from(uint_ty: ty::UintTy) -> PrimitiveType {
 impl From<ty::FloatTy> for PrimitiveType {
     fn from(float_ty: ty::FloatTy) -> PrimitiveType {
         match float_ty {
+            ty::FloatTy::F16 => PrimitiveType::F16,
             ty::FloatTy::F32 => PrimitiveType::F32,
             ty::FloatTy::F64 => PrimitiveType::F64,
+            ty::FloatTy::F128 => PrimitiveType::F128,
         }
     }
 }
@@ -18,6 +18,7 @@
 use rustc_span::edition::Edition;
 use rustc_target::spec::TargetTriple;
 
+use crate::cfg_sets::CfgSet;
 use crate::core::new_handler;
 use crate::externalfiles::ExternalHtml;
 use crate::html;
@@ -34,6 +35,8 @@ pub(crate) enum OutputFormat {
     Json,
     #[default]
     Html,
+    Markdown,
+    Man,
 }
 
 impl OutputFormat {
@@ -49,6 +52,8 @@ fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
             "json" => Ok(OutputFormat::Json),
             "html" => Ok(OutputFormat::Html),
+            "markdown" => Ok(OutputFormat::Markdown),
+            "man" => Ok(OutputFormat::Man),
             _ => Err(format!("unknown output format `{}`", value)),
         }
     }
@@ -133,6 +138,8 @@ pub(crate) struct Options {
     /// Whether to run the `calculate-doc-coverage` pass, which counts the number of public items
     /// with and without documentation.
     pub(crate) show_coverage: bool,
+    /// Options for the report of the `calculate-doc-coverage` pass.
+    pub(crate) coverage_options: CoverageOptions,
 
     // Options that alter generated documentation pages
     /// Crate version to note on the sidebar of generated docs.
@@ -148,6 +155,8 @@ pub(crate) struct Options {
     pub(crate) json_unused_externs: JsonUnusedExterns,
     /// Whether to skip capturing stdout and stderr of tests.
     pub(crate) nocapture: bool,
+    /// Whether to compile compatible doctests together into a single binary.
+    pub(crate) merge_doctests: bool,
 
     /// Configuration for scraping examples from the current crate. If this option is Some(..) then
     /// the compiler will scrape examples and not generate documentation.
@@ -191,6 +200,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .field("test_run_directory", &self.test_run_directory)
             .field("persist_doctests", &self.persist_doctests)
             .field("show_coverage", &self.show_coverage)
+            .field("coverage_options", &self.coverage_options)
             .field("crate_version", &self.crate_version)
             .field("runtool", &self.runtool)
             .field("runtool_args", &self.runtool_args)
@@ -198,12 +208,22 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .field("run_check", &self.run_check)
             .field("no_run", &self.no_run)
             .field("nocapture", &self.nocapture)
+            .field("merge_doctests", &self.merge_doctests)
             .field("scrape_examples_options", &self.scrape_examples_options)
             .field("unstable_features", &self.unstable_features)
             .finish()
     }
 }
 
+/// Options for the report printed with `--show-coverage`.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct CoverageOptions {
+    /// Whether to list the undocumented items and the items without examples in the JSON output.
+    pub(crate) list_items: bool,
+    /// The percentage of documented items under which rustdoc fails.
+    pub(crate) fail_under: Option<f64>,
+}
+
 /// Configuration options for the HTML page-creation process.
 #[derive(Clone, Debug)]
 pub(crate) struct RenderOptions {
@@ -272,6 +292,10 @@ pub(crate) struct RenderOptions {
     pub(crate) call_locations: AllCallLocations,
     /// If `true`, Context::init will not emit shared files.
     pub(crate) no_emit_shared: bool,
+    /// The sets of cfgs and targets to document the crate for with `--cfg-set`. The crate is
+    /// compiled with the code of every set, and the items which don't exist in all of them are
+    /// tagged with the sets they exist in.
+    pub(crate) cfg_sets: Vec<CfgSet>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -640,9 +664,9 @@ fn println_condition(condition: Condition) {
             Some(s) => match OutputFormat::try_from(s.as_str()) {
                 Ok(out_fmt) => {
                     if !out_fmt.is_json() && show_coverage {
-                        diag.struct_err(
-                            "html output format isn't supported for the --show-coverage option",
-                        )
+                        diag.struct_err(format!(
+                            "{s} output format isn't supported for the --show-coverage option"
+                        ))
                         .emit();
                         return Err(1);
                     }
@@ -688,10 +712,41 @@ fn println_condition(condition: Condition) {
         let generate_redirect_map = matches.opt_present("generate-redirect-map");
         let show_type_layout = matches.opt_present("show-type-layout");
         let nocapture = matches.opt_present("nocapture");
+        let merge_doctests = matches.opt_present("merge-doctests");
         let generate_link_to_definition = matches.opt_present("generate-link-to-definition");
         let extern_html_root_takes_precedence =
             matches.opt_present("extern-html-root-takes-precedence");
 
+        let coverage_options = CoverageOptions {
+            list_items: matches.opt_present("coverage-items"),
+            fail_under: match matches.opt_str("coverage-fail-under") {
+                Some(percent) => match percent.parse::<f64>() {
+                    Ok(percent) if (0.0..=100.0).contains(&percent) => Some(percent),
+                    _ => {
+                        diag.struct_err(format!(
+                            "--coverage-fail-under expects a percentage between 0 and 100, \
+                             found `{percent}`"
+                        ))
+                        .emit();
+                        return Err(1);
+                    }
+                },
+                None => None,
+            },
+        };
+        if (coverage_options.list_items || coverage_options.fail_under.is_some()) && !show_coverage
+        {
+            diag.struct_err(
+                "--coverage-items and --coverage-fail-under can only be used with --show-coverage",
+            )
+            .emit();
+            return Err(1);
+        }
+        if coverage_options.list_items && !output_format.is_json() {
+            diag.struct_err("--coverage-items can only be used with JSON output format").emit();
+            return Err(1);
+        }
+
         if generate_link_to_definition && (show_coverage || output_format != OutputFormat::Html) {
             diag.struct_err(
                 "--generate-link-to-definition option can only be used with HTML output format",
@@ -700,7 +755,32 @@ fn println_condition(condition: Condition) {
             return Err(1);
         }
 
+        let mut cfg_sets = Vec::new();
+        for spec in matches.opt_strs("cfg-set") {
+            match CfgSet::parse(&spec) {
+                Ok(set) => cfg_sets.push(set),
+                Err(err) => {
+                    diag.struct_err(format!("invalid `--cfg-set` `{spec}`: {err}")).emit();
+                    return Err(1);
+                }
+            }
+        }
+        if !cfg_sets.is_empty() {
+            if cfg_sets.len() < 2 {
+                diag.struct_err("--cfg-set must be given at least twice").emit();
+                return Err(1);
+            }
+            if show_coverage || run_check || output_format != OutputFormat::Html {
+                diag.struct_err("--cfg-set option can only be used with HTML output format").emit();
+                return Err(1);
+            }
+        }
+
         let scrape_examples_options = ScrapeExamplesOptions::new(matches, &diag)?;
+        if scrape_examples_options.is_some() && !cfg_sets.is_empty() {
+            diag.struct_err("--cfg-set cannot be used while scraping examples").emit();
+            return Err(1);
+        }
         let with_examples = matches.opt_strs("with-examples");
         let call_locations = crate::scrape_examples::load_call_locations(with_examples, &diag)?;
 
@@ -731,6 +811,7 @@ fn println_condition(condition: Condition) {
             should_test,
             test_args,
             show_coverage,
+            coverage_options,
             crate_version,
             test_run_directory,
             persist_doctests,
@@ -741,6 +822,7 @@ fn println_condition(condition: Condition) {
             run_check,
             no_run,
             nocapture,
+            merge_doctests,
             crate_name,
             output_format,
             json_unused_externs,
@@ -774,6 +856,7 @@ fn println_condition(condition: Condition) {
             generate_link_to_definition,
             call_locations,
             no_emit_shared: false,
+            cfg_sets,
         };
         Ok((options, render_options))
     }
@@ -14,6 +14,7 @@
 use rustc_middle::ty::{ParamEnv, Ty, TyCtxt};
 use rustc_session::config::{self, CrateType, ErrorOutputType, ResolveDocLinks};
 use rustc_session::lint;
+use rustc_session::parse::ParseSess;
 use rustc_session::Session;
 use rustc_span::symbol::sym;
 use rustc_span::{source_map, Span};
@@ -23,9 +24,10 @@
 use std::rc::Rc;
 use std::sync::LazyLock;
 
+use crate::cfg_sets;
 use crate::clean::inline::build_external_trait;
 use crate::clean::{self, ItemId};
-use crate::config::{Options as RustdocOptions, OutputFormat, RenderOptions};
+use crate::config::{CoverageOptions, Options as RustdocOptions, OutputFormat, RenderOptions};
 use crate::formats::cache::Cache;
 use crate::passes::{self, Condition::*};
 
@@ -63,6 +65,8 @@ pub(crate) struct DocContext<'tcx> {
     pub(crate) output_format: OutputFormat,
     /// Used by `strip_private`.
     pub(crate) show_coverage: bool,
+    /// Used by `calculate_doc_coverage`.
+    pub(crate) coverage_options: CoverageOptions,
 }
 
 impl<'tcx> DocContext<'tcx> {
@@ -202,11 +206,25 @@ pub(crate) fn create_config(
         scrape_examples_options,
         ..
     }: RustdocOptions,
-    RenderOptions { document_private, .. }: &RenderOptions,
+    RenderOptions { document_private, cfg_sets, .. }: &RenderOptions,
 ) -> rustc_interface::Config {
     // Add the doc cfg into the doc build.
     cfgs.push("doc".to_string());
 
+    // With `--cfg-set`, the crate is compiled for the target of the first set.
+    let target = match cfg_sets.first() {
+        Some(set) => set.target(&target).clone(),
+        None => target,
+    };
+    let parse_sess_created = (!cfg_sets.is_empty()).then(|| {
+        let cfg_sets = cfg_sets.clone();
+        let target = target.clone();
+        let sysroot = maybe_sysroot.clone();
+        Box::new(move |parse_sess: &mut ParseSess| {
+            cfg_sets::configure(parse_sess, &cfg_sets, &target, sysroot.as_deref())
+        }) as Box<dyn FnOnce(&mut ParseSess) + Send>
+    });
+
     let input = Input::File(input);
 
     // By default, rustdoc ignores all lints.
@@ -266,7 +284,7 @@ pub(crate) fn create_config(
         file_loader: None,
         locale_resources: rustc_driver::DEFAULT_LOCALE_RESOURCES,
         lint_caps,
-        parse_sess_created: None,
+        parse_sess_created,
         register_lints: Some(Box::new(crate::lint::register_lints)),
         override_queries: Some(|_sess, providers, _external_providers| {
             // Most lints will require typechecking, so just don't run them.
@@ -301,6 +319,7 @@ pub(crate) fn create_config(
 pub(crate) fn run_global_ctxt(
     tcx: TyCtxt<'_>,
     show_coverage: bool,
+    coverage_options: CoverageOptions,
     render_options: RenderOptions,
     output_format: OutputFormat,
 ) -> (clean::Crate, RenderOptions, Cache) {
@@ -349,6 +368,7 @@ pub(crate) fn run_global_ctxt(
         output_format,
         render_options,
         show_coverage,
+        coverage_options,
     };
 
     for cnum in tcx.crates(()) {
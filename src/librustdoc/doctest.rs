@@ -152,7 +152,16 @@ pub(crate) fn run(options: RustdocOptions) -> Result<(), ErrorGuaranteed> {
 
                 let unused_extern_reports = collector.unused_extern_reports.clone();
                 let compiling_test_count = collector.compiling_test_count.load(Ordering::SeqCst);
-                Ok((collector.tests, unused_extern_reports, compiling_test_count))
+                let mut tests = collector.tests;
+                merged::merge_doctests(
+                    compiler.session(),
+                    &mut tests,
+                    collector.mergeable,
+                    &collector.crate_name,
+                    &collector.opts,
+                    &collector.rustdoc_options,
+                );
+                Ok((tests, unused_extern_reports, compiling_test_count))
             })
         })?;
 
@@ -297,6 +306,46 @@ fn add_exe_suffix(input: String, target: &TargetTriple) -> String {
     input + &exe_suffix
 }
 
+/// Adds the arguments shared by every rustc invocation that builds doctests: cfgs, search
+/// paths, externs, codegen and unstable options, and the target.
+fn add_common_rustc_args(
+    compiler: &mut Command,
+    rustdoc_options: &RustdocOptions,
+    target: &TargetTriple,
+) {
+    for cfg in &rustdoc_options.cfgs {
+        compiler.arg("--cfg").arg(&cfg);
+    }
+    if !rustdoc_options.check_cfgs.is_empty() {
+        compiler.arg("-Z").arg("unstable-options");
+        for check_cfg in &rustdoc_options.check_cfgs {
+            compiler.arg("--check-cfg").arg(&check_cfg);
+        }
+    }
+    if let Some(sysroot) = &rustdoc_options.maybe_sysroot {
+        compiler.arg("--sysroot").arg(sysroot);
+    }
+    for lib_str in &rustdoc_options.lib_strs {
+        compiler.arg("-L").arg(&lib_str);
+    }
+    for extern_str in &rustdoc_options.extern_strs {
+        compiler.arg("--extern").arg(&extern_str);
+    }
+    compiler.arg("-Ccodegen-units=1");
+    for codegen_options_str in &rustdoc_options.codegen_options_strs {
+        compiler.arg("-C").arg(&codegen_options_str);
+    }
+    for unstable_option_str in &rustdoc_options.unstable_opts_strs {
+        compiler.arg("-Z").arg(&unstable_option_str);
+    }
+    compiler.arg("--target").arg(match target {
+        TargetTriple::TargetTriple(s) => s.as_str(),
+        TargetTriple::TargetJson { path_for_rustdoc, .. } => {
+            path_for_rustdoc.to_str().expect("target path must be valid unicode")
+        }
+    });
+}
+
 fn run_test(
     test: &str,
     crate_name: &str,
@@ -327,18 +376,7 @@ fn run_test(
         .unwrap_or_else(|| rustc_interface::util::rustc_path().expect("found rustc"));
     let mut compiler = Command::new(&rustc_binary);
     compiler.arg("--crate-type").arg("bin");
-    for cfg in &rustdoc_options.cfgs {
-        compiler.arg("--cfg").arg(&cfg);
-    }
-    if !rustdoc_options.check_cfgs.is_empty() {
-        compiler.arg("-Z").arg("unstable-options");
-        for check_cfg in &rustdoc_options.check_cfgs {
-            compiler.arg("--check-cfg").arg(&check_cfg);
-        }
-    }
-    if let Some(sysroot) = rustdoc_options.maybe_sysroot {
-        compiler.arg("--sysroot").arg(sysroot);
-    }
+    add_common_rustc_args(&mut compiler, &rustdoc_options, &target);
     compiler.arg("--edition").arg(&edition.to_string());
     compiler.env("UNSTABLE_RUSTDOC_TEST_PATH", path);
     compiler.env("UNSTABLE_RUSTDOC_TEST_LINE", format!("{}", line as isize - line_offset as isize));
@@ -352,28 +390,9 @@ fn run_test(
         compiler.arg("-Z").arg("unstable-options");
         compiler.arg("-W").arg("unused_crate_dependencies");
     }
-    for lib_str in &rustdoc_options.lib_strs {
-        compiler.arg("-L").arg(&lib_str);
-    }
-    for extern_str in &rustdoc_options.extern_strs {
-        compiler.arg("--extern").arg(&extern_str);
-    }
-    compiler.arg("-Ccodegen-units=1");
-    for codegen_options_str in &rustdoc_options.codegen_options_strs {
-        compiler.arg("-C").arg(&codegen_options_str);
-    }
-    for unstable_option_str in &rustdoc_options.unstable_opts_strs {
-        compiler.arg("-Z").arg(&unstable_option_str);
-    }
     if no_run && !lang_string.compile_fail && rustdoc_options.persist_doctests.is_none() {
         compiler.arg("--emit=metadata");
     }
-    compiler.arg("--target").arg(match target {
-        TargetTriple::TargetTriple(s) => s,
-        TargetTriple::TargetJson { path_for_rustdoc, .. } => {
-            path_for_rustdoc.to_str().expect("target path must be valid unicode").to_string()
-        }
-    });
     if let ErrorOutputType::HumanReadable(kind) = rustdoc_options.error_format {
         let (short, color_config) = kind.unzip();
 
@@ -461,16 +480,84 @@ fn drop(&mut self) {
     }
 
     // Run the code!
-    let mut cmd;
+    let cmd = doctest_command(&output_file, runtool, runtool_args);
+    run_doctest_binary(cmd, &rustdoc_options, lang_string.should_panic)
+}
+
+/// Prints why a doctest failed, below the test harness' failure header.
+fn report_test_failure(err: TestFailure) {
+    match err {
+        TestFailure::CompileError => {
+            eprint!("Couldn't compile the test.");
+        }
+        TestFailure::UnexpectedCompilePass => {
+            eprint!("Test compiled successfully, but it's marked `compile_fail`.");
+        }
+        TestFailure::UnexpectedRunPass => {
+            eprint!("Test executable succeeded, but it's marked `should_panic`.");
+        }
+        TestFailure::MissingErrorCodes(codes) => {
+            eprint!("Some expected error codes were not found: {:?}", codes);
+        }
+        TestFailure::ExecutionError(err) => {
+            eprint!("Couldn't run the test: {err}");
+            if err.kind() == io::ErrorKind::PermissionDenied {
+                eprint!(" - maybe your tempdir is mounted with noexec?");
+            }
+        }
+        TestFailure::ExecutionFailure(out) => {
+            eprintln!("Test executable failed ({reason}).", reason = out.status);
+
+            // FIXME(#12309): An unfortunate side-effect of capturing the test
+            // executable's output is that the relative ordering between the test's
+            // stdout and stderr is lost. However, this is better than the
+            // alternative: if the test executable inherited the parent's I/O
+            // handles the output wouldn't be captured at all, even on success.
+            //
+            // The ordering could be preserved if the test process' stderr was
+            // redirected to stdout, but that functionality does not exist in the
+            // standard library, so it may not be portable enough.
+            let stdout = str::from_utf8(&out.stdout).unwrap_or_default();
+            let stderr = str::from_utf8(&out.stderr).unwrap_or_default();
+
+            if !stdout.is_empty() || !stderr.is_empty() {
+                eprintln!();
+
+                if !stdout.is_empty() {
+                    eprintln!("stdout:\n{stdout}");
+                }
+
+                if !stderr.is_empty() {
+                    eprintln!("stderr:\n{stderr}");
+                }
+            }
+        }
+    }
+}
 
+/// Creates the command running a compiled doctest binary, through the runtool if one was given.
+fn doctest_command(
+    binary: &std::path::Path,
+    runtool: Option<String>,
+    runtool_args: Vec<String>,
+) -> Command {
     if let Some(tool) = runtool {
-        cmd = Command::new(tool);
+        let mut cmd = Command::new(tool);
         cmd.args(runtool_args);
-        cmd.arg(output_file);
+        cmd.arg(binary);
+        cmd
     } else {
-        cmd = Command::new(output_file);
+        Command::new(binary)
     }
-    if let Some(run_directory) = rustdoc_options.test_run_directory {
+}
+
+/// Runs a compiled doctest and checks its exit status against whether it should panic.
+fn run_doctest_binary(
+    mut cmd: Command,
+    rustdoc_options: &RustdocOptions,
+    should_panic: bool,
+) -> Result<(), TestFailure> {
+    if let Some(run_directory) = &rustdoc_options.test_run_directory {
         cmd.current_dir(run_directory);
     }
 
@@ -486,9 +573,9 @@ fn drop(&mut self) {
     match result {
         Err(e) => return Err(TestFailure::ExecutionError(e)),
         Ok(out) => {
-            if lang_string.should_panic && out.status.success() {
+            if should_panic && out.status.success() {
                 return Err(TestFailure::UnexpectedRunPass);
-            } else if !lang_string.should_panic && !out.status.success() {
+            } else if !should_panic && !out.status.success() {
                 return Err(TestFailure::ExecutionFailure(out));
             }
         }
@@ -511,7 +598,6 @@ pub(crate) fn make_test(
     let everything_else = everything_else.trim();
     let mut line_offset = 0;
     let mut prog = String::new();
-    let mut supports_color = false;
 
     if opts.attrs.is_empty() {
         // If there aren't any attributes supplied by #![doc(test(attr(...)))], then allow some
@@ -533,8 +619,98 @@ pub(crate) fn make_test(
     prog.push_str(&crate_attrs);
     prog.push_str(&crates);
 
+    let Some(ParsedSource { already_has_main, already_has_extern_crate, supports_color }) =
+        parse_source(s, crates, everything_else, crate_name, edition)
+    else {
+        // If the parser panicked due to a fatal error, pass the test code through unchanged.
+        // The error will be reported during compilation.
+        return (s.to_owned(), 0, false);
+    };
+
+    // Don't inject `extern crate std` because it's already injected by the
+    // compiler.
+    if !already_has_extern_crate && !opts.no_crate_inject && crate_name != Some("std") {
+        if let Some(crate_name) = crate_name {
+            // Don't inject `extern crate` if the crate is never used.
+            // NOTE: this is terribly inaccurate because it doesn't actually
+            // parse the source, but only has false positives, not false
+            // negatives.
+            if s.contains(crate_name) {
+                // rustdoc implicitly inserts an `extern crate` item for the own crate
+                // which may be unused, so we need to allow the lint.
+                prog.push_str(&format!("#[allow(unused_extern_crates)]\n"));
+
+                prog.push_str(&format!("extern crate r#{crate_name};\n"));
+                line_offset += 1;
+            }
+        }
+    }
+
+    // FIXME: This code cannot yet handle no_std test cases yet
+    if dont_insert_main || already_has_main || prog.contains("![no_std]") {
+        prog.push_str(everything_else);
+    } else {
+        let returns_result = everything_else.trim_end().ends_with("(())");
+        // Give each doctest main function a unique name.
+        // This is for example needed for the tooling around `-C instrument-coverage`.
+        let inner_fn_name = if let Some(test_id) = test_id {
+            format!("_doctest_main_{test_id}")
+        } else {
+            "_inner".into()
+        };
+        let inner_attr = if test_id.is_some() { "#[allow(non_snake_case)] " } else { "" };
+        let (main_pre, main_post) = if returns_result {
+            (
+                format!(
+                    "fn main() {{ {inner_attr}fn {inner_fn_name}() -> Result<(), impl core::fmt::Debug> {{\n",
+                ),
+                format!("\n}} {inner_fn_name}().unwrap() }}"),
+            )
+        } else if test_id.is_some() {
+            (
+                format!("fn main() {{ {inner_attr}fn {inner_fn_name}() {{\n",),
+                format!("\n}} {inner_fn_name}() }}"),
+            )
+        } else {
+            ("fn main() {\n".into(), "\n}".into())
+        };
+        // Note on newlines: We insert a line/newline *before*, and *after*
+        // the doctest and adjust the `line_offset` accordingly.
+        // In the case of `-C instrument-coverage`, this means that the generated
+        // inner `main` function spans from the doctest opening codeblock to the
+        // closing one. For example
+        // /// ``` <- start of the inner main
+        // /// <- code under doctest
+        // /// ``` <- end of the inner main
+        line_offset += 1;
+
+        prog.extend([&main_pre, everything_else, &main_post].iter().cloned());
+    }
+
+    debug!("final doctest:\n{prog}");
+
+    (prog, line_offset, supports_color)
+}
+
+/// What [`parse_source`] found out about a doctest.
+struct ParsedSource {
+    already_has_main: bool,
+    already_has_extern_crate: bool,
+    supports_color: bool,
+}
+
+/// Parses the body of a doctest to find out whether it already has a `main` function and an
+/// `extern crate` for the crate being documented. Returns `None` if the parser hit a fatal error.
+fn parse_source(
+    s: &str,
+    crates: String,
+    everything_else: &str,
+    crate_name: Option<&str>,
+    edition: Edition,
+) -> Option<ParsedSource> {
     // Uses librustc_ast to parse the doctest and find if there's a main fn and the extern
     // crate already is included.
+    let mut supports_color = false;
     let result = rustc_driver::catch_fatal_errors(|| {
         rustc_span::create_session_if_not_set_then(edition, |_| {
             use rustc_errors::emitter::{Emitter, EmitterWriter};
@@ -648,11 +824,8 @@ pub(crate) fn make_test(
             (found_main, found_extern_crate, found_macro)
         })
     });
-    let Ok((already_has_main, already_has_extern_crate, found_macro)) = result
-    else {
-        // If the parser panicked due to a fatal error, pass the test code through unchanged.
-        // The error will be reported during compilation.
-        return (s.to_owned(), 0, false);
+    let Ok((already_has_main, already_has_extern_crate, found_macro)) = result else {
+        return None;
     };
 
     // If a doctest's `fn main` is being masked by a wrapper macro, the parsing loop above won't
@@ -670,69 +843,7 @@ pub(crate) fn make_test(
         already_has_main
     };
 
-    // Don't inject `extern crate std` because it's already injected by the
-    // compiler.
-    if !already_has_extern_crate && !opts.no_crate_inject && crate_name != Some("std") {
-        if let Some(crate_name) = crate_name {
-            // Don't inject `extern crate` if the crate is never used.
-            // NOTE: this is terribly inaccurate because it doesn't actually
-            // parse the source, but only has false positives, not false
-            // negatives.
-            if s.contains(crate_name) {
-                // rustdoc implicitly inserts an `extern crate` item for the own crate
-                // which may be unused, so we need to allow the lint.
-                prog.push_str(&format!("#[allow(unused_extern_crates)]\n"));
-
-                prog.push_str(&format!("extern crate r#{crate_name};\n"));
-                line_offset += 1;
-            }
-        }
-    }
-
-    // FIXME: This code cannot yet handle no_std test cases yet
-    if dont_insert_main || already_has_main || prog.contains("![no_std]") {
-        prog.push_str(everything_else);
-    } else {
-        let returns_result = everything_else.trim_end().ends_with("(())");
-        // Give each doctest main function a unique name.
-        // This is for example needed for the tooling around `-C instrument-coverage`.
-        let inner_fn_name = if let Some(test_id) = test_id {
-            format!("_doctest_main_{test_id}")
-        } else {
-            "_inner".into()
-        };
-        let inner_attr = if test_id.is_some() { "#[allow(non_snake_case)] " } else { "" };
-        let (main_pre, main_post) = if returns_result {
-            (
-                format!(
-                    "fn main() {{ {inner_attr}fn {inner_fn_name}() -> Result<(), impl core::fmt::Debug> {{\n",
-                ),
-                format!("\n}} {inner_fn_name}().unwrap() }}"),
-            )
-        } else if test_id.is_some() {
-            (
-                format!("fn main() {{ {inner_attr}fn {inner_fn_name}() {{\n",),
-                format!("\n}} {inner_fn_name}() }}"),
-            )
-        } else {
-            ("fn main() {\n".into(), "\n}".into())
-        };
-        // Note on newlines: We insert a line/newline *before*, and *after*
-        // the doctest and adjust the `line_offset` accordingly.
-        // In the case of `-C instrument-coverage`, this means that the generated
-        // inner `main` function spans from the doctest opening codeblock to the
-        // closing one. For example
-        // /// ``` <- start of the inner main
-        // /// <- code under doctest
-        // /// ``` <- end of the inner main
-        line_offset += 1;
-
-        prog.extend([&main_pre, everything_else, &main_post].iter().cloned());
-    }
-
-    debug!("final doctest:\n{prog}");
-
-    (prog, line_offset, supports_color)
+    Some(ParsedSource { already_has_main, already_has_extern_crate, supports_color })
 }
 
 fn check_if_attr_is_complete(source: &str, edition: Edition) -> bool {
@@ -927,6 +1038,8 @@ pub(crate) struct Collector {
     visited_tests: FxHashMap<(String, usize), usize>,
     unused_extern_reports: Arc<Mutex<Vec<UnusedExterns>>>,
     compiling_test_count: AtomicUsize,
+    /// Doctests in `tests` that can be compiled into a single binary with `--merge-doctests`.
+    mergeable: Vec<merged::MergeableDoctest>,
 }
 
 impl Collector {
@@ -953,6 +1066,7 @@ pub(crate) fn new(
             visited_tests: FxHashMap::default(),
             unused_extern_reports: Default::default(),
             compiling_test_count: AtomicUsize::new(0),
+            mergeable: Vec::new(),
         }
     }
 
@@ -1053,15 +1167,38 @@ fn add_test(&mut self, test: String, config: LangString, line: usize) {
             )
         };
 
+        let ignore = match config.ignore {
+            Ignore::All => true,
+            Ignore::None => false,
+            Ignore::Some(ref ignores) => ignores.iter().any(|s| target_str.contains(s)),
+        };
+        // Ignored doctests are never compiled, so they must not end up in the merged binary.
+        let mergeable = if self.rustdoc_options.merge_doctests
+            && !ignore
+            && edition == self.rustdoc_options.edition
+            && !self.rustdoc_options.json_unused_externs.is_enabled()
+        {
+            merged::MergeableDoctest::new(
+                self.tests.len(),
+                path.clone(),
+                line,
+                &test,
+                &crate_name,
+                &config,
+                no_run,
+                edition,
+                &test_id,
+            )
+        } else {
+            None
+        };
+        self.mergeable.extend(mergeable);
+
         debug!("creating test {name}: {test}");
         self.tests.push(test::TestDescAndFn {
             desc: test::TestDesc {
                 name: test::DynTestName(name),
-                ignore: match config.ignore {
-                    Ignore::All => true,
-                    Ignore::None => false,
-                    Ignore::Some(ref ignores) => ignores.iter().any(|s| target_str.contains(s)),
-                },
+                ignore,
                 ignore_message: None,
                 source_file: "",
                 start_line: 0,
@@ -1097,54 +1234,7 @@ fn add_test(&mut self, test: String, config: LangString, line: usize) {
                 );
 
                 if let Err(err) = res {
-                    match err {
-                        TestFailure::CompileError => {
-                            eprint!("Couldn't compile the test.");
-                        }
-                        TestFailure::UnexpectedCompilePass => {
-                            eprint!("Test compiled successfully, but it's marked `compile_fail`.");
-                        }
-                        TestFailure::UnexpectedRunPass => {
-                            eprint!("Test executable succeeded, but it's marked `should_panic`.");
-                        }
-                        TestFailure::MissingErrorCodes(codes) => {
-                            eprint!("Some expected error codes were not found: {:?}", codes);
-                        }
-                        TestFailure::ExecutionError(err) => {
-                            eprint!("Couldn't run the test: {err}");
-                            if err.kind() == io::ErrorKind::PermissionDenied {
-                                eprint!(" - maybe your tempdir is mounted with noexec?");
-                            }
-                        }
-                        TestFailure::ExecutionFailure(out) => {
-                            eprintln!("Test executable failed ({reason}).", reason = out.status);
-
-                            // FIXME(#12309): An unfortunate side-effect of capturing the test
-                            // executable's output is that the relative ordering between the test's
-                            // stdout and stderr is lost. However, this is better than the
-                            // alternative: if the test executable inherited the parent's I/O
-                            // handles the output wouldn't be captured at all, even on success.
-                            //
-                            // The ordering could be preserved if the test process' stderr was
-                            // redirected to stdout, but that functionality does not exist in the
-                            // standard library, so it may not be portable enough.
-                            let stdout = str::from_utf8(&out.stdout).unwrap_or_default();
-                            let stderr = str::from_utf8(&out.stderr).unwrap_or_default();
-
-                            if !stdout.is_empty() || !stderr.is_empty() {
-                                eprintln!();
-
-                                if !stdout.is_empty() {
-                                    eprintln!("stdout:\n{stdout}");
-                                }
-
-                                if !stderr.is_empty() {
-                                    eprintln!("stderr:\n{stderr}");
-                                }
-                            }
-                        }
-                    }
-
+                    report_test_failure(err);
                     panic::resume_unwind(Box::new(()));
                 }
                 Ok(())
@@ -1317,5 +1407,7 @@ fn visit_field_def(&mut self, f: &'hir hir::FieldDef<'_>) {
     }
 }
 
+mod merged;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,281 @@
+//! Compiling doctests together into a single binary (`--merge-doctests`).
+//!
+//! Compiling every doctest on its own costs one rustc invocation per example, which dominates the
+//! time spent running the doctests of crates with many examples. Instead, each doctest that does
+//! not need to be a crate of its own is wrapped into a module, and all of these modules are
+//! compiled into one binary whose `main` runs the doctest named on its command line. Every doctest
+//! still runs in its own process, so panics and exit codes are reported per test as before.
+//!
+//! Doctests using crate attributes or `#[macro_use] extern crate`, defining their own `main`, or
+//! marked `compile_fail` or `test_harness` keep being compiled one by one.
+//!
+//! The doctests of each source file are merged into their own binary, in which the code of every
+//! doctest is placed at its line in the file, so that the diagnostics of the merged binary point
+//! at the doctests like the ones of a doctest compiled on its own. Merging all the doctests of the
+//! crate into a single binary instead isn't possible without losing this: a source location can
+//! only map to one file, and the `UNSTABLE_RUSTDOC_TEST_PATH` that the panic and diagnostic paths
+//! are remapped with is set once per rustc invocation. The number of rustc invocations still only
+//! grows with the number of files containing doctests, not with the number of doctests.
+//!
+//! If the merged binary fails to compile, a warning is emitted and its doctests fall back to being
+//! compiled on their own, so that errors are reported against the doctest that caused them.
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_session::Session;
+use rustc_span::edition::Edition;
+use tempfile::Builder as TempFileBuilder;
+
+use super::{
+    add_common_rustc_args, add_exe_suffix, doctest_command, parse_source, partition_source,
+    report_test_failure, run_doctest_binary, DirState, GlobalTestOptions,
+};
+use crate::config::Options as RustdocOptions;
+use crate::html::markdown::LangString;
+
+/// A doctest that is compiled as part of the merged doctest binary.
+pub(super) struct MergeableDoctest {
+    /// Index of the doctest in `Collector::tests`.
+    index: usize,
+    /// The file the doctest is in.
+    path: PathBuf,
+    /// The line of the opening fence of the doctest in `path`.
+    line: usize,
+    /// Name of the module wrapping the doctest, also used to select it at runtime.
+    module: String,
+    /// The `extern crate` items of the doctest, placed in its module.
+    crates: String,
+    /// The rest of the code of the doctest, which becomes the body of its `main` function.
+    body: String,
+    returns_result: bool,
+    no_run: bool,
+    should_panic: bool,
+}
+
+impl MergeableDoctest {
+    /// Wraps `test` into a module of the merged binary, or returns `None` if it has to be compiled
+    /// on its own.
+    pub(super) fn new(
+        index: usize,
+        path: PathBuf,
+        line: usize,
+        test: &str,
+        crate_name: &str,
+        config: &LangString,
+        no_run: bool,
+        edition: Edition,
+        test_id: &str,
+    ) -> Option<MergeableDoctest> {
+        // Before the 2018 edition, paths in expressions are relative to the current module, so
+        // the doctest would not see the crate's `extern crate` from inside its module.
+        if config.compile_fail || config.test_harness || edition < Edition::Edition2018 {
+            return None;
+        }
+
+        let (crate_attrs, everything_else, crates) = partition_source(test, edition);
+        if crate_attrs.lines().any(|line| line.trim_start().starts_with("#!"))
+            || crates.contains("#[macro_use]")
+        {
+            return None;
+        }
+        let everything_else = everything_else.trim();
+        let parsed =
+            parse_source(test, crates.clone(), everything_else, Some(crate_name), edition)?;
+        if parsed.already_has_main {
+            return None;
+        }
+
+        Some(MergeableDoctest {
+            index,
+            path,
+            line,
+            module: format!("_doctest_{test_id}"),
+            crates,
+            body: everything_else.to_owned(),
+            returns_result: everything_else.trim_end().ends_with("(())"),
+            no_run,
+            should_panic: config.should_panic,
+        })
+    }
+}
+
+/// Generates the source of the merged doctest binary of the doctests of a file, sorted by line.
+///
+/// The code of each doctest starts on the line following its opening fence, like in the file,
+/// unless the code of the previous doctests takes more lines than there are before it.
+fn merged_source(
+    doctests: &[MergeableDoctest],
+    crate_name: &str,
+    opts: &GlobalTestOptions,
+) -> String {
+    // The crate-level items all go on the first line, to leave room for the doctests.
+    let mut code = String::new();
+    // See `make_test` for why lints are only allowed when there are no crate-level attributes.
+    if opts.attrs.is_empty() {
+        code.push_str("#![allow(unused)] ");
+    }
+    for attr in &opts.attrs {
+        write!(code, "#![{attr}] ").unwrap();
+    }
+    if !opts.no_crate_inject && crate_name != "std" {
+        write!(code, "#[allow(unused_extern_crates)] extern crate r#{crate_name}; ").unwrap();
+    }
+
+    // The line `code` currently ends on.
+    let mut line = 1;
+    for doctest in doctests {
+        let ret = if doctest.returns_result { " -> Result<(), impl core::fmt::Debug>" } else { "" };
+        let header =
+            format!("mod {} {{\n{}pub(crate) fn main(){ret} {{\n", doctest.module, doctest.crates);
+        let header_lines = header.matches('\n').count();
+        while line + header_lines < doctest.line + 1 {
+            code.push('\n');
+            line += 1;
+        }
+        code.push_str(&header);
+        code.push_str(&doctest.body);
+        code.push_str("\n} }\n");
+        line += header_lines + doctest.body.matches('\n').count() + 2;
+    }
+
+    code.push_str("fn main() {\n");
+    code.push_str(
+        "    let name = ::std::env::args().nth(1).expect(\"expected the name of a doctest\");\n",
+    );
+    code.push_str("    match &*name {\n");
+    for doctest in doctests {
+        let unwrap = if doctest.returns_result { ".unwrap()" } else { "" };
+        writeln!(code, "        {module:?} => {module}::main(){unwrap},", module = doctest.module)
+            .unwrap();
+    }
+    code.push_str("        _ => panic!(\"unknown doctest `{name}`\"),\n");
+    code.push_str("    }\n}\n");
+    code
+}
+
+/// Compiles the doctests of each file of `doctests` into a single binary and makes their entries in
+/// `tests` run it. See the module documentation for why there is a binary per file rather than
+/// one for the whole crate. If the binary of a file doesn't compile, a warning is emitted and the
+/// entries of its doctests are left untouched so they're compiled on their own.
+pub(super) fn merge_doctests(
+    sess: &Session,
+    tests: &mut [test::TestDescAndFn],
+    doctests: Vec<MergeableDoctest>,
+    crate_name: &str,
+    opts: &GlobalTestOptions,
+    rustdoc_options: &RustdocOptions,
+) {
+    let mut files = FxIndexMap::<_, Vec<_>>::default();
+    for doctest in doctests {
+        files.entry(doctest.path.clone()).or_default().push(doctest);
+    }
+    for (index, (path, mut doctests)) in files.into_iter().enumerate() {
+        doctests.sort_by_key(|doctest| doctest.line);
+        merge_file_doctests(sess, tests, index, &path, doctests, crate_name, opts, rustdoc_options);
+    }
+}
+
+/// Compiles the doctests of the file at `path` into a single binary, see [`merge_doctests`].
+fn merge_file_doctests(
+    sess: &Session,
+    tests: &mut [test::TestDescAndFn],
+    index: usize,
+    path: &Path,
+    doctests: Vec<MergeableDoctest>,
+    crate_name: &str,
+    opts: &GlobalTestOptions,
+    rustdoc_options: &RustdocOptions,
+) {
+    let outdir = if let Some(path) = &rustdoc_options.persist_doctests {
+        let path = path.join(format!("merged_doctests_{index}"));
+        if let Err(err) = std::fs::create_dir_all(&path) {
+            eprintln!("Couldn't create directory for doctest executables: {}", err);
+            panic::resume_unwind(Box::new(()));
+        }
+        DirState::Perm(path)
+    } else {
+        DirState::Temp(
+            TempFileBuilder::new()
+                .prefix("rustdoctest")
+                .tempdir()
+                .expect("rustdoc needs a tempdir"),
+        )
+    };
+
+    let source = merged_source(&doctests, crate_name, opts);
+    debug!("merged doctests of {}:\n{source}", path.display());
+
+    let rust_out = add_exe_suffix("rust_out".to_owned(), &rustdoc_options.target);
+    let binary = outdir.path().join(rust_out);
+    let rustc_binary = rustdoc_options
+        .test_builder
+        .as_deref()
+        .unwrap_or_else(|| rustc_interface::util::rustc_path().expect("found rustc"));
+    let mut compiler = Command::new(rustc_binary);
+    compiler.arg("--crate-type").arg("bin").arg("--crate-name").arg("rust_out");
+    add_common_rustc_args(&mut compiler, rustdoc_options, &rustdoc_options.target);
+    compiler.arg("--edition").arg(&rustdoc_options.edition.to_string());
+    // The doctests are at their line in the file, so the lines don't need to be offset.
+    compiler.env("UNSTABLE_RUSTDOC_TEST_PATH", path);
+    compiler.env("UNSTABLE_RUSTDOC_TEST_LINE", "0");
+    compiler.arg("-o").arg(&binary);
+    compiler.arg("-");
+    compiler.stdin(Stdio::piped());
+    compiler.stdout(Stdio::piped());
+    compiler.stderr(Stdio::piped());
+
+    debug!("compiler invocation for merged doctests: {:?}", compiler);
+
+    let output = compiler.spawn().and_then(|mut child| {
+        child.stdin.take().expect("Failed to open stdin").write_all(source.as_bytes())?;
+        child.wait_with_output()
+    });
+    let fallback = match output {
+        Ok(output) if output.status.success() => None,
+        Ok(output) => Some(String::from_utf8_lossy(&output.stderr).into_owned()),
+        Err(err) => Some(format!("couldn't run rustc: {err}")),
+    };
+    if let Some(reason) = fallback {
+        let mut diag = sess.struct_warn(format!(
+            "the merged doctests of `{}` failed to compile, compiling them one by one",
+            path.display()
+        ));
+        if !reason.trim().is_empty() {
+            diag.note(reason.trim_end().to_owned());
+        }
+        diag.emit();
+        return;
+    }
+
+    // Keep the directory holding the binary alive until the last doctest using it has run.
+    let outdir = Arc::new(outdir);
+    for doctest in doctests {
+        let outdir = outdir.clone();
+        let binary = binary.clone();
+        let rustdoc_options = rustdoc_options.clone();
+        tests[doctest.index].testfn = test::DynTestFn(Box::new(move || {
+            let _outdir = outdir;
+            if doctest.no_run {
+                return Ok(());
+            }
+
+            let mut cmd = doctest_command(
+                &binary,
+                rustdoc_options.runtool.clone(),
+                rustdoc_options.runtool_args.clone(),
+            );
+            cmd.arg(&doctest.module);
+            if let Err(err) = run_doctest_binary(cmd, &rustdoc_options, doctest.should_panic) {
+                report_test_failure(err);
+                panic::resume_unwind(Box::new(()));
+            }
+            Ok(())
+        }));
+    }
+}
@@ -1,5 +1,7 @@
 use super::{make_test, GlobalTestOptions};
-use rustc_span::edition::DEFAULT_EDITION;
+use crate::html::markdown::LangString;
+use rustc_span::edition::{Edition, DEFAULT_EDITION};
+use std::path::PathBuf;
 
 #[test]
 fn make_test_basic() {
@@ -302,3 +304,30 @@ fn main() { #[allow(non_snake_case)] fn _doctest_main__some_unique_name() {
         make_test(input, None, false, &opts, DEFAULT_EDITION, Some("_some_unique_name"));
     assert_eq!((output, len), (expected, 2));
 }
+
+#[test]
+fn merged_doctest_compatibility() {
+    use super::merged::MergeableDoctest;
+
+    let mergeable = |input: &str, config: &LangString| {
+        let path = PathBuf::from("src/lib.rs");
+        MergeableDoctest::new(0, path, 1, input, "asdf", config, false, Edition::Edition2021, "id")
+            .is_some()
+    };
+    let config = LangString::default();
+
+    assert!(mergeable("use asdf::qwop;\nassert_eq!(2+2, 4);", &config));
+    assert!(mergeable("extern crate asdf;\nassert_eq!(2+2, 4);", &config));
+
+    // Crate attributes, `#[macro_use]` and custom `main` functions need a crate of their own.
+    assert!(!mergeable("#![feature(asdf)]\nassert_eq!(2+2, 4);", &config));
+    assert!(!mergeable("#[macro_use] extern crate asdf;\nassert_eq!(2+2, 4);", &config));
+    assert!(!mergeable("fn main() {\n    assert_eq!(2+2, 4);\n}", &config));
+
+    let mut compile_fail = LangString::default();
+    compile_fail.compile_fail = true;
+    assert!(!mergeable("let x: u8 = 256;", &compile_fail));
+    let mut test_harness = LangString::default();
+    test_harness.test_harness = true;
+    assert!(!mergeable("#[test]\nfn test() {}", &test_harness));
+}
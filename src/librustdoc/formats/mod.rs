@@ -1,6 +1,7 @@
 pub(crate) mod cache;
 pub(crate) mod item_type;
 pub(crate) mod renderer;
+pub(crate) mod text;
 
 use rustc_hir::def_id::DefId;
 
@@ -0,0 +1,632 @@
+//! Plain-text rendering of cleaned types and item signatures.
+//!
+//! The printers in `html::format` interleave their output with HTML and need a full HTML
+//! rendering [`Context`](crate::html::render::Context), so backends producing other textual
+//! formats use these instead.
+
+use std::fmt::Write;
+
+use rustc_hir as hir;
+use rustc_hir::def::CtorKind;
+use rustc_middle::ty::{self, TyCtxt, Visibility};
+use rustc_span::hygiene::MacroKind;
+use rustc_span::symbol::kw;
+use rustc_target::spec::abi::Abi;
+
+use crate::clean::{self, ItemKind, PrimitiveType, SelfTy, Type};
+
+/// Returns every segment of `path`, e.g. `std::collections::HashMap` for the source of a
+/// re-export.
+pub(crate) fn print_path(path: &clean::Path, tcx: TyCtxt<'_>) -> String {
+    let mut out = String::new();
+    for (i, segment) in path.segments.iter().enumerate() {
+        if i > 0 {
+            out.push_str("::");
+        }
+        if segment.name != kw::PathRoot {
+            out.push_str(segment.name.as_str());
+        }
+        write_generic_args(&mut out, &segment.args, tcx);
+    }
+    out
+}
+
+/// Returns the header of an impl block, e.g. `impl<T: Clone> Clone for Vec<T>`.
+pub(crate) fn print_impl_header(impl_: &clean::Impl, tcx: TyCtxt<'_>) -> String {
+    let mut out = String::new();
+    if impl_.unsafety == hir::Unsafety::Unsafe {
+        out.push_str("unsafe ");
+    }
+    out.push_str("impl");
+    write_generic_params(&mut out, &impl_.generics.params, tcx);
+    out.push(' ');
+    if let Some(trait_) = &impl_.trait_ {
+        if impl_.polarity == ty::ImplPolarity::Negative {
+            out.push('!');
+        }
+        write_path(&mut out, trait_, tcx);
+        out.push_str(" for ");
+    }
+    write_type(&mut out, &impl_.for_, tcx);
+    write_where_clause(&mut out, &impl_.generics, tcx);
+    out
+}
+
+/// Returns the declaration of `item` as it would be written in Rust source code, without
+/// function bodies, private fields or doc comments.
+///
+/// Returns `None` for items that don't have a signature, like modules, primitives and keywords.
+pub(crate) fn print_item_signature(item: &clean::Item, tcx: TyCtxt<'_>) -> Option<String> {
+    let name = item.name?;
+    let mut out = String::new();
+    let vis = visibility(item, tcx);
+
+    match &*item.kind {
+        ItemKind::FunctionItem(f)
+        | ItemKind::MethodItem(f, _)
+        | ItemKind::TyMethodItem(f)
+        | ItemKind::ForeignFunctionItem(f) => {
+            out.push_str(&vis);
+            let header = item.fn_header(tcx);
+            let mut is_async = false;
+            if let Some(header) = header {
+                if header.constness == hir::Constness::Const {
+                    out.push_str("const ");
+                }
+                if header.asyncness == hir::IsAsync::Async {
+                    out.push_str("async ");
+                    is_async = true;
+                }
+                if header.unsafety == hir::Unsafety::Unsafe {
+                    out.push_str("unsafe ");
+                }
+                write_abi(&mut out, header.abi);
+            }
+            write!(out, "fn {name}").unwrap();
+            write_generic_params(&mut out, &f.generics.params, tcx);
+            if is_async {
+                let decl = clean::FnDecl {
+                    inputs: f.decl.inputs.clone(),
+                    output: f.decl.sugared_async_return_type(),
+                    c_variadic: f.decl.c_variadic,
+                };
+                write_fn_decl(&mut out, &decl, tcx);
+            } else {
+                write_fn_decl(&mut out, &f.decl, tcx);
+            }
+            write_where_clause(&mut out, &f.generics, tcx);
+            if matches!(&*item.kind, ItemKind::MethodItem(..)) {
+                out.push_str(" { ... }");
+            } else {
+                out.push(';');
+            }
+        }
+        ItemKind::StructItem(s) => {
+            write!(out, "{vis}struct {name}").unwrap();
+            write_generic_params(&mut out, &s.generics.params, tcx);
+            match s.ctor_kind {
+                Some(CtorKind::Fn) => {
+                    write_tuple_fields(&mut out, &s.fields, tcx);
+                    write_where_clause(&mut out, &s.generics, tcx);
+                    out.push(';');
+                }
+                Some(CtorKind::Const) => {
+                    write_where_clause(&mut out, &s.generics, tcx);
+                    out.push(';');
+                }
+                None => {
+                    write_where_clause(&mut out, &s.generics, tcx);
+                    write_named_fields(&mut out, &s.fields, "", tcx);
+                }
+            }
+        }
+        ItemKind::UnionItem(u) => {
+            write!(out, "{vis}union {name}").unwrap();
+            write_generic_params(&mut out, &u.generics.params, tcx);
+            write_where_clause(&mut out, &u.generics, tcx);
+            write_named_fields(&mut out, &u.fields, "", tcx);
+        }
+        ItemKind::EnumItem(e) => {
+            write!(out, "{vis}enum {name}").unwrap();
+            write_generic_params(&mut out, &e.generics.params, tcx);
+            write_where_clause(&mut out, &e.generics, tcx);
+            out.push_str(" {\n");
+            for variant in e.variants() {
+                let (Some(variant_name), ItemKind::VariantItem(v)) = (variant.name, &*variant.kind)
+                else {
+                    continue;
+                };
+                write!(out, "    {variant_name}").unwrap();
+                match &v.kind {
+                    clean::VariantKind::CLike => {}
+                    clean::VariantKind::Tuple(fields) => write_tuple_fields(&mut out, fields, tcx),
+                    clean::VariantKind::Struct(s) => {
+                        write_named_fields(&mut out, &s.fields, "    ", tcx)
+                    }
+                }
+                out.push_str(",\n");
+            }
+            if e.has_stripped_entries() {
+                out.push_str("    // some variants omitted\n");
+            }
+            out.push('}');
+        }
+        ItemKind::TraitItem(t) => {
+            out.push_str(&vis);
+            if t.unsafety(tcx) == hir::Unsafety::Unsafe {
+                out.push_str("unsafe ");
+            }
+            if t.is_auto(tcx) {
+                out.push_str("auto ");
+            }
+            write!(out, "trait {name}").unwrap();
+            write_generic_params(&mut out, &t.generics.params, tcx);
+            if !t.bounds.is_empty() {
+                out.push_str(": ");
+                write_bounds(&mut out, &t.bounds, tcx);
+            }
+            write_where_clause(&mut out, &t.generics, tcx);
+            out.push_str(" {\n");
+            for assoc in &t.items {
+                if let Some(sig) = print_item_signature(assoc, tcx) {
+                    for line in sig.lines() {
+                        writeln!(out, "    {line}").unwrap();
+                    }
+                }
+            }
+            out.push('}');
+        }
+        ItemKind::TraitAliasItem(ta) => {
+            write!(out, "{vis}trait {name}").unwrap();
+            write_generic_params(&mut out, &ta.generics.params, tcx);
+            out.push_str(" = ");
+            write_bounds(&mut out, &ta.bounds, tcx);
+            write_where_clause(&mut out, &ta.generics, tcx);
+            out.push(';');
+        }
+        ItemKind::TypedefItem(t) | ItemKind::AssocTypeItem(t, _) => {
+            write!(out, "{vis}type {name}").unwrap();
+            write_generic_params(&mut out, &t.generics.params, tcx);
+            write_where_clause(&mut out, &t.generics, tcx);
+            out.push_str(" = ");
+            write_type(&mut out, &t.type_, tcx);
+            out.push(';');
+        }
+        ItemKind::TyAssocTypeItem(generics, bounds) => {
+            write!(out, "type {name}").unwrap();
+            write_generic_params(&mut out, &generics.params, tcx);
+            if !bounds.is_empty() {
+                out.push_str(": ");
+                write_bounds(&mut out, bounds, tcx);
+            }
+            write_where_clause(&mut out, generics, tcx);
+            out.push(';');
+        }
+        ItemKind::OpaqueTyItem(t) => {
+            write!(out, "{vis}type {name}").unwrap();
+            write_generic_params(&mut out, &t.generics.params, tcx);
+            write_where_clause(&mut out, &t.generics, tcx);
+            out.push_str(" = impl ");
+            write_bounds(&mut out, &t.bounds, tcx);
+            out.push(';');
+        }
+        ItemKind::StaticItem(s) | ItemKind::ForeignStaticItem(s) => {
+            write!(out, "{vis}static {}{name}: ", s.mutability.prefix_str()).unwrap();
+            write_type(&mut out, &s.type_, tcx);
+            out.push(';');
+        }
+        ItemKind::ConstantItem(c) => {
+            write!(out, "{vis}const {name}: ").unwrap();
+            write_type(&mut out, &c.type_, tcx);
+            if c.is_literal(tcx) {
+                write!(out, " = {}", c.expr(tcx)).unwrap();
+            } else if let Some(value) = c.value(tcx) {
+                write!(out, " = {value}").unwrap();
+            }
+            out.push(';');
+        }
+        ItemKind::TyAssocConstItem(ty) => {
+            write!(out, "const {name}: ").unwrap();
+            write_type(&mut out, ty, tcx);
+            out.push(';');
+        }
+        ItemKind::AssocConstItem(ty, kind) => {
+            write!(out, "{vis}const {name}: ").unwrap();
+            write_type(&mut out, ty, tcx);
+            write!(out, " = {};", kind.expr(tcx)).unwrap();
+        }
+        ItemKind::ForeignTypeItem => write!(out, "{vis}type {name};").unwrap(),
+        ItemKind::MacroItem(m) => out.push_str(m.source.trim_end()),
+        ItemKind::ProcMacroItem(m) => match m.kind {
+            MacroKind::Bang => write!(out, "{name}!() {{ /* proc-macro */ }}").unwrap(),
+            MacroKind::Attr => write!(out, "#[{name}]").unwrap(),
+            MacroKind::Derive => {
+                write!(out, "#[derive({name})]").unwrap();
+                if !m.helpers.is_empty() {
+                    out.push_str("\n// Helper attributes:");
+                    for helper in &m.helpers {
+                        write!(out, "\n#[{helper}]").unwrap();
+                    }
+                }
+            }
+        },
+        ItemKind::StrippedItem(_)
+        | ItemKind::ModuleItem(_)
+        | ItemKind::ExternCrateItem { .. }
+        | ItemKind::ImportItem(_)
+        | ItemKind::ImplItem(_)
+        | ItemKind::StructFieldItem(_)
+        | ItemKind::VariantItem(_)
+        | ItemKind::PrimitiveItem(_)
+        | ItemKind::KeywordItem => return None,
+    }
+
+    Some(out)
+}
+
+/// Returns the visibility of `item` followed by a space, or nothing if it is inherited.
+fn visibility(item: &clean::Item, tcx: TyCtxt<'_>) -> String {
+    match item.visibility(tcx) {
+        None => String::new(),
+        Some(Visibility::Public) => "pub ".to_owned(),
+        Some(Visibility::Restricted(did)) if did.is_crate_root() => "pub(crate) ".to_owned(),
+        Some(Visibility::Restricted(did)) => format!("pub(in {}) ", tcx.def_path_str(did)),
+    }
+}
+
+fn write_abi(out: &mut String, abi: Abi) {
+    if abi != Abi::Rust {
+        write!(out, "extern {:?} ", abi.name()).unwrap();
+    }
+}
+
+fn write_tuple_fields(out: &mut String, fields: &[clean::Item], tcx: TyCtxt<'_>) {
+    out.push('(');
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        match &*field.kind {
+            ItemKind::StructFieldItem(ty) => {
+                out.push_str(&visibility(field, tcx));
+                write_type(out, ty, tcx);
+            }
+            _ => out.push('_'),
+        }
+    }
+    out.push(')');
+}
+
+fn write_named_fields(out: &mut String, fields: &[clean::Item], indent: &str, tcx: TyCtxt<'_>) {
+    out.push_str(" {\n");
+    let mut has_stripped = false;
+    for field in fields {
+        match (&*field.kind, field.name) {
+            (ItemKind::StructFieldItem(ty), Some(name)) => {
+                write!(out, "{indent}    {}{name}: ", visibility(field, tcx)).unwrap();
+                write_type(out, ty, tcx);
+                out.push_str(",\n");
+            }
+            _ => has_stripped = true,
+        }
+    }
+    if has_stripped {
+        writeln!(out, "{indent}    /* private fields */").unwrap();
+    }
+    write!(out, "{indent}}}").unwrap();
+}
+
+fn write_type(out: &mut String, ty: &Type, tcx: TyCtxt<'_>) {
+    match ty {
+        Type::Path { path } => write_path(out, path, tcx),
+        Type::DynTrait(bounds, lifetime) => {
+            out.push_str("dyn ");
+            for (i, bound) in bounds.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(" + ");
+                }
+                write_poly_trait(out, bound, tcx);
+            }
+            if let Some(lifetime) = lifetime {
+                write!(out, " + {}", lifetime.0).unwrap();
+            }
+        }
+        Type::Generic(name) => out.push_str(name.as_str()),
+        Type::Primitive(PrimitiveType::Unit) => out.push_str("()"),
+        Type::Primitive(PrimitiveType::Never) => out.push('!'),
+        Type::Primitive(prim) => out.push_str(prim.as_sym().as_str()),
+        Type::BareFunction(f) => {
+            write_hrtb(out, &f.generic_params, tcx);
+            if f.unsafety == hir::Unsafety::Unsafe {
+                out.push_str("unsafe ");
+            }
+            write_abi(out, f.abi);
+            out.push_str("fn");
+            write_fn_decl(out, &f.decl, tcx);
+        }
+        Type::Tuple(tys) => {
+            out.push('(');
+            for (i, ty) in tys.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_type(out, ty, tcx);
+            }
+            if tys.len() == 1 {
+                out.push(',');
+            }
+            out.push(')');
+        }
+        Type::Slice(ty) => {
+            out.push('[');
+            write_type(out, ty, tcx);
+            out.push(']');
+        }
+        Type::Array(ty, len) => {
+            out.push('[');
+            write_type(out, ty, tcx);
+            write!(out, "; {len}]").unwrap();
+        }
+        Type::Pat(ty, pat) => {
+            write_type(out, ty, tcx);
+            write!(out, " is {pat}").unwrap();
+        }
+        Type::RawPointer(mutability, ty) => {
+            out.push_str(if mutability.is_mut() { "*mut " } else { "*const " });
+            write_type(out, ty, tcx);
+        }
+        Type::BorrowedRef { lifetime, mutability, type_ } => {
+            out.push('&');
+            if let Some(lifetime) = lifetime {
+                write!(out, "{} ", lifetime.0).unwrap();
+            }
+            out.push_str(mutability.prefix_str());
+            write_type(out, type_, tcx);
+        }
+        Type::QPath(qpath) => {
+            if qpath.should_show_cast || qpath.trait_.is_none() {
+                out.push('<');
+                write_type(out, &qpath.self_type, tcx);
+                if let Some(trait_) = &qpath.trait_ {
+                    out.push_str(" as ");
+                    write_path(out, trait_, tcx);
+                }
+                out.push('>');
+            } else {
+                write_type(out, &qpath.self_type, tcx);
+            }
+            write!(out, "::{}", qpath.assoc.name).unwrap();
+            write_generic_args(out, &qpath.assoc.args, tcx);
+        }
+        Type::Infer => out.push('_'),
+        Type::ImplTrait(bounds) => {
+            out.push_str("impl ");
+            write_bounds(out, bounds, tcx);
+        }
+    }
+}
+
+/// Writes the last segment of `path` with its generic arguments, like the HTML output does for
+/// types and traits.
+fn write_path(out: &mut String, path: &clean::Path, tcx: TyCtxt<'_>) {
+    if let Some(segment) = path.segments.last() {
+        out.push_str(segment.name.as_str());
+        write_generic_args(out, &segment.args, tcx);
+    }
+}
+
+fn write_generic_args(out: &mut String, args: &clean::GenericArgs, tcx: TyCtxt<'_>) {
+    match args {
+        clean::GenericArgs::AngleBracketed { args, bindings } => {
+            if args.is_empty() && bindings.is_empty() {
+                return;
+            }
+            out.push('<');
+            let mut first = true;
+            for arg in args.iter() {
+                if !first {
+                    out.push_str(", ");
+                }
+                first = false;
+                match arg {
+                    clean::GenericArg::Lifetime(lifetime) => out.push_str(lifetime.0.as_str()),
+                    clean::GenericArg::Type(ty) => write_type(out, ty, tcx),
+                    clean::GenericArg::Const(c) => out.push_str(&c.expr(tcx)),
+                    clean::GenericArg::Infer => out.push('_'),
+                }
+            }
+            for binding in bindings.iter() {
+                if !first {
+                    out.push_str(", ");
+                }
+                first = false;
+                out.push_str(binding.assoc.name.as_str());
+                write_generic_args(out, &binding.assoc.args, tcx);
+                match &binding.kind {
+                    clean::TypeBindingKind::Equality { term } => {
+                        out.push_str(" = ");
+                        write_term(out, term, tcx);
+                    }
+                    clean::TypeBindingKind::Constraint { bounds } => {
+                        out.push_str(": ");
+                        write_bounds(out, bounds, tcx);
+                    }
+                }
+            }
+            out.push('>');
+        }
+        clean::GenericArgs::Parenthesized { inputs, output } => {
+            out.push('(');
+            for (i, ty) in inputs.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_type(out, ty, tcx);
+            }
+            out.push(')');
+            if let Some(output) = output {
+                out.push_str(" -> ");
+                write_type(out, output, tcx);
+            }
+        }
+    }
+}
+
+fn write_term(out: &mut String, term: &clean::Term, tcx: TyCtxt<'_>) {
+    match term {
+        clean::Term::Type(ty) => write_type(out, ty, tcx),
+        clean::Term::Constant(c) => out.push_str(&c.expr(tcx)),
+    }
+}
+
+fn write_bounds(out: &mut String, bounds: &[clean::GenericBound], tcx: TyCtxt<'_>) {
+    for (i, bound) in bounds.iter().enumerate() {
+        if i > 0 {
+            out.push_str(" + ");
+        }
+        match bound {
+            clean::GenericBound::TraitBound(poly_trait, modifier) => {
+                match modifier {
+                    hir::TraitBoundModifier::None => {}
+                    hir::TraitBoundModifier::Negative => out.push('!'),
+                    hir::TraitBoundModifier::Maybe => out.push('?'),
+                    hir::TraitBoundModifier::MaybeConst => out.push_str("~const "),
+                }
+                write_poly_trait(out, poly_trait, tcx);
+            }
+            clean::GenericBound::Outlives(lifetime) => out.push_str(lifetime.0.as_str()),
+        }
+    }
+}
+
+fn write_poly_trait(out: &mut String, poly_trait: &clean::PolyTrait, tcx: TyCtxt<'_>) {
+    write_hrtb(out, &poly_trait.generic_params, tcx);
+    write_path(out, &poly_trait.trait_, tcx);
+}
+
+/// Writes the `for<'a>` binder of a higher-ranked type or bound.
+fn write_hrtb(out: &mut String, params: &[clean::GenericParamDef], tcx: TyCtxt<'_>) {
+    if !params.is_empty() {
+        out.push_str("for");
+        write_generic_params(out, params, tcx);
+        out.push(' ');
+    }
+}
+
+fn write_generic_params(out: &mut String, params: &[clean::GenericParamDef], tcx: TyCtxt<'_>) {
+    let mut params = params.iter().filter(|p| !p.is_synthetic_type_param()).peekable();
+    if params.peek().is_none() {
+        return;
+    }
+    out.push('<');
+    for (i, param) in params.enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        match &param.kind {
+            clean::GenericParamDefKind::Lifetime { outlives } => {
+                out.push_str(param.name.as_str());
+                for (i, lifetime) in outlives.iter().enumerate() {
+                    out.push_str(if i == 0 { ": " } else { " + " });
+                    out.push_str(lifetime.0.as_str());
+                }
+            }
+            clean::GenericParamDefKind::Type { bounds, default, .. } => {
+                out.push_str(param.name.as_str());
+                if !bounds.is_empty() {
+                    out.push_str(": ");
+                    write_bounds(out, bounds, tcx);
+                }
+                if let Some(default) = default {
+                    out.push_str(" = ");
+                    write_type(out, default, tcx);
+                }
+            }
+            clean::GenericParamDefKind::Const { ty, default } => {
+                write!(out, "const {}: ", param.name).unwrap();
+                write_type(out, ty, tcx);
+                if let Some(default) = default {
+                    write!(out, " = {default}").unwrap();
+                }
+            }
+        }
+    }
+    out.push('>');
+}
+
+fn write_where_clause(out: &mut String, generics: &clean::Generics, tcx: TyCtxt<'_>) {
+    let predicates: Vec<_> = generics
+        .where_predicates
+        .iter()
+        .filter(|pred| match pred {
+            clean::WherePredicate::BoundPredicate { bounds, .. } => !bounds.is_empty(),
+            _ => true,
+        })
+        .collect();
+    if predicates.is_empty() {
+        return;
+    }
+    out.push_str("\nwhere\n");
+    for pred in predicates {
+        out.push_str("    ");
+        match pred {
+            clean::WherePredicate::BoundPredicate { ty, bounds, bound_params } => {
+                write_hrtb(out, bound_params, tcx);
+                write_type(out, ty, tcx);
+                out.push_str(": ");
+                write_bounds(out, bounds, tcx);
+            }
+            clean::WherePredicate::RegionPredicate { lifetime, bounds } => {
+                write!(out, "{}: ", lifetime.0).unwrap();
+                write_bounds(out, bounds, tcx);
+            }
+            clean::WherePredicate::EqPredicate { lhs, rhs, bound_params } => {
+                write_hrtb(out, bound_params, tcx);
+                write_type(out, lhs, tcx);
+                out.push_str(" == ");
+                write_term(out, rhs, tcx);
+            }
+        }
+        out.push_str(",\n");
+    }
+    // The caller decides what follows the clause.
+    out.pop();
+}
+
+fn write_fn_decl(out: &mut String, decl: &clean::FnDecl, tcx: TyCtxt<'_>) {
+    out.push('(');
+    for (i, arg) in decl.inputs.values.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        match arg.to_self() {
+            Some(SelfTy::SelfValue) => out.push_str("self"),
+            Some(SelfTy::SelfBorrowed(lifetime, mutability)) => {
+                out.push('&');
+                if let Some(lifetime) = lifetime {
+                    write!(out, "{} ", lifetime.0).unwrap();
+                }
+                write!(out, "{}self", mutability.prefix_str()).unwrap();
+            }
+            Some(SelfTy::SelfExplicit(ty)) => {
+                out.push_str("self: ");
+                write_type(out, &ty, tcx);
+            }
+            None => {
+                if arg.is_const {
+                    out.push_str("const ");
+                }
+                write!(out, "{}: ", arg.name).unwrap();
+                write_type(out, &arg.type_, tcx);
+            }
+        }
+    }
+    if decl.c_variadic {
+        out.push_str(", ...");
+    }
+    out.push(')');
+    if let clean::FnRetTy::Return(ty) = &decl.output {
+        out.push_str(" -> ");
+        write_type(out, ty, tcx);
+    }
+}
@@ -1061,6 +1061,10 @@ fn fmt_type<'cx>(
                 write!(f, "]")
             }
         },
+        clean::Pat(ref t, ref pat) => {
+            fmt::Display::fmt(&t.print(cx), f)?;
+            write!(f, " is {pat}")
+        }
         clean::RawPointer(m, ref t) => {
             let m = match m {
                 hir::Mutability::Mut => "mut",
@@ -1225,6 +1225,135 @@ pub(crate) fn plain_text_summary(md: &str, link_names: &[RenderedLink]) -> Strin
     s
 }
 
+/// Rewrites documentation for the Markdown output format.
+///
+/// - Headings are moved one level down, as the item's name is the only top-level heading of a
+///   page.
+/// - Fenced Rust code blocks are tagged as `rust` and have their hidden lines removed.
+/// - Resolved intra-doc links are turned into inline links to their `href`.
+///
+/// Everything else is kept as written.
+pub(crate) fn markdown_for_markdown_output(md: &str, links: &[RenderedLink]) -> String {
+    if md.is_empty() {
+        return String::new();
+    }
+
+    let mut replacer = |broken_link: BrokenLink<'_>| {
+        links
+            .iter()
+            .find(|link| &*link.original_text == &*broken_link.reference)
+            .map(|link| (link.href.as_str().into(), "".into()))
+    };
+
+    let mut p = Parser::new_with_broken_link_callback(md, main_body_opts(), Some(&mut replacer))
+        .into_offset_iter();
+    // Edits to apply to `md`, as the range they replace and the replacement text. They are
+    // pushed in source order and never overlap.
+    let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+    // The range of an intra-doc link, its `href`, its `RenderedLink` and the range of its text.
+    let mut current_link: Option<(
+        Range<usize>,
+        String,
+        Option<&RenderedLink>,
+        Option<Range<usize>>,
+    )> = None;
+
+    while let Some((event, range)) = p.next() {
+        match event {
+            Event::Start(Tag::Heading(level, ..)) => {
+                if (level as u32) < MAX_HEADER_LEVEL && md[range.start..].starts_with('#') {
+                    edits.push((range.start..range.start, "#".to_owned()));
+                }
+            }
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(syntax))) => {
+                if !LangString::parse(&syntax, ErrorCodes::No, false, None).rust {
+                    continue;
+                }
+                let mut code = String::new();
+                for (event, _) in p.by_ref() {
+                    match event {
+                        Event::Text(text) => code.push_str(&text),
+                        _ => break,
+                    }
+                }
+                // Lines of a code block nested in a list or a block quote need the same prefix
+                // as its opening fence.
+                let line_start = md[..range.start].rfind('\n').map_or(0, |i| i + 1);
+                let prefix: String = md[line_start..range.start]
+                    .chars()
+                    .map(|c| if c == '>' || c.is_whitespace() { c } else { ' ' })
+                    .collect();
+                let mut block = String::from("```rust\n");
+                for line in code.lines().filter_map(|line| map_line(line).for_html()) {
+                    block.push_str(&prefix);
+                    block.push_str(&line);
+                    block.push('\n');
+                }
+                block.push_str(&prefix);
+                block.push_str("```");
+                // The range of a code block ends after its closing fence's line break, if any.
+                if md[..range.end].ends_with('\n') {
+                    block.push('\n');
+                }
+                edits.push((range, block));
+            }
+            Event::Start(Tag::Link(kind, dest, _)) => {
+                let link = links.iter().find(|link| &*link.original_text == &*dest);
+                let href = match link {
+                    Some(link) => link.href.clone(),
+                    // Links resolved by `replacer` already point to their `href`.
+                    None if matches!(
+                        kind,
+                        LinkType::ShortcutUnknown
+                            | LinkType::CollapsedUnknown
+                            | LinkType::ReferenceUnknown
+                    ) =>
+                    {
+                        dest.to_string()
+                    }
+                    None => continue,
+                };
+                let link = link.or_else(|| links.iter().find(|link| link.href == href));
+                current_link = Some((range, href, link, None));
+            }
+            Event::End(Tag::Link(..)) => {
+                if let Some((range, href, link, Some(text))) = current_link.take() {
+                    let mut text = &md[text];
+                    // Like in the HTML output, links written as their destination (e.g.
+                    // [`fn@f`]) display the link without its disambiguator.
+                    let new_text;
+                    if let Some(link) = link && text == &*link.original_text {
+                        new_text = if text.starts_with('`') {
+                            format!("`{}`", link.new_text)
+                        } else {
+                            link.new_text.to_string()
+                        };
+                        text = &new_text;
+                    }
+                    edits.push((range, format!("[{text}]({href})")));
+                }
+            }
+            _ => {
+                if let Some((.., text)) = &mut current_link {
+                    let text = text.get_or_insert(range.clone());
+                    text.start = text.start.min(range.start);
+                    text.end = text.end.max(range.end);
+                }
+            }
+        }
+    }
+
+    let mut s = String::with_capacity(md.len());
+    let mut last = 0;
+    for (range, replacement) in edits {
+        s.push_str(&md[last..range.start]);
+        s.push_str(&replacement);
+        last = range.end;
+    }
+    s.push_str(&md[last..]);
+    s
+}
+
 #[derive(Debug)]
 pub(crate) struct MarkdownLink {
     pub kind: LinkType,
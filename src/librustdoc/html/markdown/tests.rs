@@ -1,4 +1,5 @@
-use super::{find_testable_code, plain_text_summary, short_markdown_summary};
+use super::{find_testable_code, markdown_for_markdown_output, plain_text_summary};
+use super::{short_markdown_summary, RenderedLink};
 use super::{ErrorCodes, HeadingOffset, IdMap, Ignore, LangString, Markdown, MarkdownItemInfo};
 use rustc_span::edition::{Edition, DEFAULT_EDITION};
 
@@ -246,6 +247,41 @@ fn t(input: &str, expect: &str) {
     );
 }
 
+#[test]
+fn test_markdown_for_markdown_output() {
+    fn t(input: &str, expect: &str) {
+        let links = [
+            RenderedLink {
+                original_text: "`Vec`".into(),
+                new_text: "Vec".into(),
+                href: "../alloc/vec/struct.Vec.md".into(),
+                tooltip: String::new(),
+            },
+            RenderedLink {
+                original_text: "`fn@f`".into(),
+                new_text: "f".into(),
+                href: "fn.f.md".into(),
+                tooltip: String::new(),
+            },
+        ];
+        let output = markdown_for_markdown_output(input, &links);
+        assert_eq!(output, expect, "original: {}", input);
+    }
+
+    t("", "");
+    t("# Examples\n\ntext", "## Examples\n\ntext");
+    t("###### deepest", "###### deepest");
+    t("```\n# use std::mem;\nmem::drop(1);\n```\n", "```rust\nmem::drop(1);\n```\n");
+    t("```ignore\nlet x;\n```", "```rust\nlet x;\n```");
+    t("```text\n# not hidden\n```", "```text\n# not hidden\n```");
+    t("- item\n\n  ```\n  let x;\n  ```\n", "- item\n\n  ```rust\n  let x;\n  ```\n");
+    t("a [`Vec`] b", "a [`Vec`](../alloc/vec/struct.Vec.md) b");
+    t("a [vector](`Vec`) b", "a [vector](../alloc/vec/struct.Vec.md) b");
+    t("a [`fn@f`] b", "a [`f`](fn.f.md) b");
+    t("a [Rust](https://www.rust-lang.org) b", "a [Rust](https://www.rust-lang.org) b");
+    t("a [unresolved] b", "a [unresolved] b");
+}
+
 #[test]
 fn test_plain_text_summary() {
     fn t(input: &str, expect: &str) {
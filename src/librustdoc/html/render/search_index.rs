@@ -398,6 +398,7 @@ fn get_index_type_id(clean_type: &clean::Type) -> Option<RenderTypeId> {
         clean::BareFunction(_)
         | clean::Generic(_)
         | clean::ImplTrait(_)
+        | clean::Pat(..)
         | clean::Tuple(_)
         | clean::QPath { .. }
         | clean::Infer => None,
@@ -11,6 +11,7 @@
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::symbol::sym;
 use rustc_span::{Pos, Symbol};
+use rustc_target::abi::{TagEncoding, Variants};
 use rustc_target::spec::abi::Abi as RustcAbi;
 
 use rustdoc_json_types::*;
@@ -287,6 +288,7 @@ pub(crate) fn id_from_item(item: &clean::Item, tcx: TyCtxt<'_>) -> Id {
 fn from_clean_item(item: clean::Item, tcx: TyCtxt<'_>) -> ItemEnum {
     use clean::ItemKind::*;
     let name = item.name;
+    let item_id = item.item_id;
     let is_crate = item.is_crate();
     let header = item.fn_header(tcx);
 
@@ -295,10 +297,16 @@ fn from_clean_item(item: clean::Item, tcx: TyCtxt<'_>) -> ItemEnum {
             ItemEnum::Module(Module { is_crate, items: ids(m.items, tcx), is_stripped: false })
         }
         ImportItem(i) => ItemEnum::Import(i.into_tcx(tcx)),
-        StructItem(s) => ItemEnum::Struct(s.into_tcx(tcx)),
-        UnionItem(u) => ItemEnum::Union(u.into_tcx(tcx)),
+        StructItem(s) => {
+            ItemEnum::Struct(Struct { layout: type_layout(item_id, tcx), ..s.into_tcx(tcx) })
+        }
+        UnionItem(u) => {
+            ItemEnum::Union(Union { layout: type_layout(item_id, tcx), ..u.into_tcx(tcx) })
+        }
         StructFieldItem(f) => ItemEnum::StructField(f.into_tcx(tcx)),
-        EnumItem(e) => ItemEnum::Enum(e.into_tcx(tcx)),
+        EnumItem(e) => {
+            ItemEnum::Enum(Enum { layout: type_layout(item_id, tcx), ..e.into_tcx(tcx) })
+        }
         VariantItem(v) => ItemEnum::Variant(v.into_tcx(tcx)),
         FunctionItem(f) => ItemEnum::Function(from_function(f, true, header.unwrap(), tcx)),
         ForeignFunctionItem(f) => ItemEnum::Function(from_function(f, false, header.unwrap(), tcx)),
@@ -373,6 +381,7 @@ fn from_tcx(struct_: clean::Struct, tcx: TyCtxt<'_>) -> Self {
             kind,
             generics: generics.into_tcx(tcx),
             impls: Vec::new(), // Added in JsonRenderer::item
+            layout: None, // Added in from_clean_item
         }
     }
 }
@@ -386,6 +395,7 @@ fn from_tcx(union_: clean::Union, tcx: TyCtxt<'_>) -> Self {
             fields_stripped,
             fields: ids(fields, tcx),
             impls: Vec::new(), // Added in JsonRenderer::item
+            layout: None, // Added in from_clean_item
         }
     }
 }
@@ -543,7 +553,7 @@ pub(crate) fn from_trait_bound_modifier(
 impl FromWithTcx<clean::Type> for Type {
     fn from_tcx(ty: clean::Type, tcx: TyCtxt<'_>) -> Self {
         use clean::Type::{
-            Array, BareFunction, BorrowedRef, Generic, ImplTrait, Infer, Primitive, QPath,
+            Array, BareFunction, BorrowedRef, Generic, ImplTrait, Infer, Pat, Primitive, QPath,
             RawPointer, Slice, Tuple,
         };
 
@@ -559,6 +569,10 @@ fn from_tcx(ty: clean::Type, tcx: TyCtxt<'_>) -> Self {
             Tuple(t) => Type::Tuple(t.into_tcx(tcx)),
             Slice(t) => Type::Slice(Box::new((*t).into_tcx(tcx))),
             Array(t, s) => Type::Array { type_: Box::new((*t).into_tcx(tcx)), len: s.to_string() },
+            Pat(t, p) => Type::Pat {
+                type_: Box::new((*t).into_tcx(tcx)),
+                __pat_unstable_do_not_use: p.to_string(),
+            },
             ImplTrait(g) => Type::ImplTrait(g.into_tcx(tcx)),
             Infer => Type::Infer,
             RawPointer(mutability, type_) => Type::RawPointer {
@@ -713,10 +727,54 @@ fn from_tcx(enum_: clean::Enum, tcx: TyCtxt<'_>) -> Self {
             variants_stripped,
             variants: ids(variants, tcx),
             impls: Vec::new(), // Added in JsonRenderer::item
+            layout: None, // Added in from_clean_item
         }
     }
 }
 
+/// Computes the layout of a struct, enum or union, which fails if it depends on its generic
+/// parameters.
+fn type_layout(item_id: ItemId, tcx: TyCtxt<'_>) -> Option<Layout> {
+    let def_id = item_id.as_def_id()?;
+    let param_env = tcx.param_env(def_id);
+    let ty = tcx.type_of(def_id).subst_identity();
+    let layout = tcx.layout_of(param_env.and(ty)).ok()?;
+
+    let variants = match layout.layout.variants() {
+        Variants::Multiple { variants, tag, tag_encoding, .. } => {
+            let ty::Adt(adt, _) = layout.ty.kind() else { return None };
+            // A niche tag is stored in the fields of a variant, so it is already in its size.
+            let tag_size = match tag_encoding {
+                TagEncoding::Niche { .. } => 0,
+                TagEncoding::Direct => tag.size(&tcx).bytes(),
+            };
+            variants
+                .iter_enumerated()
+                .map(|(variant_idx, variant_layout)| VariantLayout {
+                    name: adt.variant(variant_idx).name.to_string(),
+                    size: variant_layout.size.bytes() - tag_size,
+                    is_uninhabited: variant_layout.abi.is_uninhabited(),
+                })
+                .collect()
+        }
+        Variants::Single { .. } => Vec::new(),
+    };
+    let niche = layout.largest_niche.map(|niche| Niche {
+        offset: niche.offset.bytes(),
+        size: niche.value.size(&tcx).bytes(),
+        available: niche.available(&tcx).to_string(),
+    });
+
+    Some(Layout {
+        size: layout.size.bytes(),
+        align: layout.align.abi.bytes(),
+        is_unsized: layout.abi.is_unsized(),
+        is_uninhabited: layout.abi.is_uninhabited(),
+        variants,
+        niche,
+    })
+}
+
 impl FromWithTcx<clean::Variant> for Variant {
     fn from_tcx(variant: clean::Variant, tcx: TyCtxt<'_>) -> Self {
         use clean::VariantKind::*;
@@ -103,6 +103,7 @@ macro_rules! map {
     }}
 }
 
+mod cfg_sets;
 mod clean;
 mod config;
 mod core;
@@ -116,7 +117,9 @@ macro_rules! map {
 pub mod html;
 mod json;
 pub(crate) mod lint;
+mod man;
 mod markdown;
+mod md;
 mod passes;
 mod scrape_examples;
 mod theme;
@@ -531,6 +534,29 @@ fn opts() -> Vec<RustcOptGroup> {
                 "calculate percentage of public items with documentation",
             )
         }),
+        unstable("coverage-items", |o| {
+            o.optflagmulti(
+                "",
+                "coverage-items",
+                "list undocumented items and items without examples in the --show-coverage JSON",
+            )
+        }),
+        unstable("coverage-fail-under", |o| {
+            o.optopt(
+                "",
+                "coverage-fail-under",
+                "fail if under PERCENT of the items counted by --show-coverage are documented",
+                "PERCENT",
+            )
+        }),
+        unstable("cfg-set", |o| {
+            o.optmulti(
+                "",
+                "cfg-set",
+                "document the crate for each given set of cfgs and target at once",
+                "CFGS",
+            )
+        }),
         unstable("enable-per-target-ignores", |o| {
             o.optflagmulti(
                 "",
@@ -582,6 +608,13 @@ fn opts() -> Vec<RustcOptGroup> {
         unstable("nocapture", |o| {
             o.optflag("", "nocapture", "Don't capture stdout and stderr of tests")
         }),
+        unstable("merge-doctests", |o| {
+            o.optflag(
+                "",
+                "merge-doctests",
+                "Compile the compatible doctests of each file into a single binary",
+            )
+        }),
         unstable("generate-link-to-definition", |o| {
             o.optflag(
                 "",
@@ -770,6 +803,7 @@ fn main_args(at_args: &[String]) -> MainResult {
     // need to move these items separately because we lose them by the time the closure is called,
     // but we can't create the Handler ahead of time because it's not Send
     let show_coverage = options.show_coverage;
+    let coverage_options = options.coverage_options;
     let run_check = options.run_check;
 
     // First, parse the crate and extract all relevant information.
@@ -810,7 +844,13 @@ fn main_args(at_args: &[String]) -> MainResult {
 
             gcx.enter(|tcx| {
                 let (krate, render_opts, mut cache) = sess.time("run_global_ctxt", || {
-                    core::run_global_ctxt(tcx, show_coverage, render_options, output_format)
+                    core::run_global_ctxt(
+                        tcx,
+                        show_coverage,
+                        coverage_options,
+                        render_options,
+                        output_format,
+                    )
                 });
                 info!("finished with rustc");
 
@@ -844,6 +884,12 @@ fn main_args(at_args: &[String]) -> MainResult {
                     config::OutputFormat::Json => sess.time("render_json", || {
                         run_renderer::<json::JsonRenderer<'_>>(krate, render_opts, cache, tcx)
                     }),
+                    config::OutputFormat::Markdown => sess.time("render_markdown", || {
+                        run_renderer::<md::MarkdownRenderer<'_>>(krate, render_opts, cache, tcx)
+                    }),
+                    config::OutputFormat::Man => sess.time("render_man", || {
+                        run_renderer::<man::ManRenderer<'_>>(krate, render_opts, cache, tcx)
+                    }),
                 }
             })
         })
@@ -0,0 +1,364 @@
+//! Rustdoc's man page backend
+//!
+//! This module renders the public items of a crate as section 3 man pages in a `man3` directory,
+//! named after the paths of the items (e.g. `man 3 foo::Point`). The synopsis of a page is the
+//! item's signature, its description the item's documentation, and its "see also" section lists
+//! the targets of the item's intra-doc links.
+
+mod roff;
+
+use std::fmt::Write as _;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rustc_data_structures::fx::FxIndexSet;
+use rustc_hir::def_id::DefId;
+use rustc_middle::ty::TyCtxt;
+
+use crate::clean::{self, ItemKind, RenderedLink};
+use crate::config::RenderOptions;
+use crate::docfs::PathError;
+use crate::error::Error;
+use crate::formats::cache::Cache;
+use crate::formats::item_type::ItemType;
+use crate::formats::text::{print_impl_header, print_item_signature, print_path};
+use crate::formats::{self, FormatRenderer};
+use crate::html::markdown::{markdown_for_markdown_output, plain_text_summary};
+use crate::html::render::{item_ty_to_section, ItemSection};
+use crate::man::roff::{escape, markdown_to_roff, quote};
+use crate::try_err;
+
+/// Separates the segments of an item's path in the name of its man page. `:` isn't allowed in
+/// file names on Windows, where `.` is used instead.
+const PATH_SEPARATOR: &str = if cfg!(windows) { "." } else { "::" };
+
+#[derive(Clone)]
+pub(crate) struct ManRenderer<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    cache: Rc<Cache>,
+    /// The directory where the pages are written to.
+    out_path: PathBuf,
+    /// The name and version of the crate, shown in the footer of every page.
+    source: String,
+}
+
+impl<'tcx> ManRenderer<'tcx> {
+    /// Returns the name of the man page documenting `did`, if it is documented.
+    fn page_name(&self, did: DefId) -> Option<String> {
+        let (fqp, _) =
+            self.cache.paths.get(&did).or_else(|| self.cache.external_paths.get(&did))?;
+        Some(fqp.iter().map(|part| part.as_str()).collect::<Vec<_>>().join(PATH_SEPARATOR))
+    }
+
+    /// Returns the intra-doc links of `item`, pointing to the names of their man pages.
+    fn links(&self, item: &clean::Item) -> Vec<RenderedLink> {
+        let Some(links) = self.cache.intra_doc_links.get(&item.item_id) else {
+            return vec![];
+        };
+        links
+            .iter()
+            .filter_map(|link| {
+                Some(RenderedLink {
+                    original_text: link.link.clone(),
+                    new_text: link.link_text.clone(),
+                    href: self.page_name(link.page_id)?,
+                    tooltip: String::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// Writes the documentation of `item` and records its links in `see_also`.
+    fn write_docs(&self, out: &mut String, item: &clean::Item, see_also: &mut FxIndexSet<String>) {
+        let Some(doc) = item.collapsed_doc_value() else { return };
+        let links = self.links(item);
+        out.push_str(&markdown_to_roff(&markdown_for_markdown_output(&doc, &links)));
+        see_also.extend(links.into_iter().map(|link| link.href));
+    }
+
+    /// Writes `code` in a paragraph without filling.
+    fn write_code(&self, out: &mut String, code: &str) {
+        out.push_str(".PP\n.nf\n");
+        for line in code.lines() {
+            writeln!(out, "{}", escape(line)).unwrap();
+        }
+        out.push_str(".fi\n");
+    }
+
+    /// Writes the signature of `item`, followed by its documentation.
+    fn write_item_body(
+        &self,
+        out: &mut String,
+        item: &clean::Item,
+        see_also: &mut FxIndexSet<String>,
+    ) {
+        if let Some(signature) = print_item_signature(item, self.tcx) {
+            self.write_code(out, &signature);
+        }
+        self.write_docs(out, item, see_also);
+    }
+
+    fn render_page(&self, item: &clean::Item, name: &str) -> String {
+        let mut out = String::new();
+        let mut see_also = FxIndexSet::default();
+
+        let (name_arg, source) = (quote(name), quote(&self.source));
+        writeln!(out, ".TH {name_arg} 3 \"\" {source} \"Rust API Documentation\"").unwrap();
+        out.push_str(".SH NAME\n");
+        let doc = item.collapsed_doc_value().unwrap_or_default();
+        let summary = plain_text_summary(&doc, &item.link_names(&self.cache));
+        if summary.is_empty() {
+            writeln!(out, "{}", escape(name)).unwrap();
+        } else {
+            writeln!(out, "{} \\- {}", escape(name), escape(&summary)).unwrap();
+        }
+
+        if let Some(signature) = print_item_signature(item, self.tcx) {
+            out.push_str(".SH SYNOPSIS\n.nf\n");
+            for line in signature.lines() {
+                writeln!(out, "{}", escape(line)).unwrap();
+            }
+            out.push_str(".fi\n");
+        }
+        if item.collapsed_doc_value().is_some() {
+            out.push_str(".SH DESCRIPTION\n");
+            self.write_docs(&mut out, item, &mut see_also);
+        }
+
+        match &*item.kind {
+            ItemKind::ModuleItem(module) => self.write_module_items(&mut out, &module.items),
+            ItemKind::StructItem(clean::Struct { fields, .. })
+            | ItemKind::UnionItem(clean::Union { fields, .. }) => {
+                self.write_members(&mut out, "FIELDS", fields, &mut see_also);
+            }
+            ItemKind::EnumItem(e) => {
+                self.write_members(&mut out, "VARIANTS", &e.variants.raw, &mut see_also);
+            }
+            ItemKind::TraitItem(t) => {
+                self.write_members(&mut out, "ASSOCIATED ITEMS", &t.items, &mut see_also);
+                let did = item.item_id.expect_def_id();
+                if let Some(implementors) = self.cache.implementors.get(&did) {
+                    let implementors = implementors.iter().collect::<Vec<_>>();
+                    self.write_impl_list(&mut out, "IMPLEMENTORS", &implementors);
+                }
+            }
+            _ => {}
+        }
+        if let Some(did) = item.item_id.as_def_id() {
+            self.write_impls(&mut out, did, &mut see_also);
+        }
+
+        see_also.shift_remove(name);
+        if !see_also.is_empty() {
+            out.push_str(".SH SEE ALSO\n");
+            let see_also = see_also.iter().map(|page| format!(".BR {} (3)", quote(page)));
+            writeln!(out, "{}", see_also.collect::<Vec<_>>().join(",\n")).unwrap();
+        }
+        out
+    }
+
+    /// Lists the items of a module, grouped by kind like in the HTML output.
+    fn write_module_items(&self, out: &mut String, items: &[clean::Item]) {
+        let items = items.iter().filter(|item| !item.is_stripped()).collect::<Vec<_>>();
+        for &section in ItemSection::ALL {
+            let mut entries =
+                items.iter().filter(|item| item_ty_to_section(item.type_()) == section).peekable();
+            if entries.peek().is_none() {
+                continue;
+            }
+            writeln!(out, ".SH {}", quote(&section.name().to_uppercase())).unwrap();
+            for child in entries {
+                out.push_str(".TP\n");
+                match &*child.kind {
+                    ItemKind::ImportItem(import) => {
+                        let path = print_path(&import.source.path, self.tcx);
+                        let import = match import.kind {
+                            clean::ImportKind::Simple(name)
+                                if import.source.path.last() != name =>
+                            {
+                                format!("pub use {path} as {name};")
+                            }
+                            clean::ImportKind::Simple(_) => format!("pub use {path};"),
+                            clean::ImportKind::Glob => format!("pub use {path}::*;"),
+                        };
+                        writeln!(out, ".B {}", quote(&import)).unwrap();
+                    }
+                    ItemKind::ExternCrateItem { src } => {
+                        let name = child.name.unwrap();
+                        let extern_crate = match src {
+                            Some(src) => format!("extern crate {src} as {name};"),
+                            None => format!("extern crate {name};"),
+                        };
+                        writeln!(out, ".B {}", quote(&extern_crate)).unwrap();
+                    }
+                    _ => {
+                        let Some(name) = child.name else { continue };
+                        match child.item_id.as_def_id().and_then(|did| self.page_name(did)) {
+                            Some(page) => writeln!(out, ".BR {} (3)", quote(&page)).unwrap(),
+                            None => writeln!(out, ".B {}", quote(name.as_str())).unwrap(),
+                        }
+                        let doc = child.collapsed_doc_value().unwrap_or_default();
+                        let summary = plain_text_summary(&doc, &child.link_names(&self.cache));
+                        if !summary.is_empty() {
+                            writeln!(out, "{}", escape(&summary)).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Writes a section with the documentation of each named entry of `members`.
+    fn write_members(
+        &self,
+        out: &mut String,
+        title: &str,
+        members: &[clean::Item],
+        see_also: &mut FxIndexSet<String>,
+    ) {
+        let members = members
+            .iter()
+            .filter(|member| member.name.is_some() && !member.is_stripped())
+            .collect::<Vec<_>>();
+        if members.is_empty() {
+            return;
+        }
+        writeln!(out, ".SH {}", quote(title)).unwrap();
+        for member in members {
+            writeln!(out, ".SS {}", quote(member.name.unwrap().as_str())).unwrap();
+            self.write_item_body(out, member, see_also);
+        }
+    }
+
+    /// Writes the inherent implementations of `did` with their items, then the lists of its trait
+    /// implementations.
+    fn write_impls(&self, out: &mut String, did: DefId, see_also: &mut FxIndexSet<String>) {
+        let Some(impls) = self.cache.impls.get(&did) else { return };
+        let (inherent, trait_impls): (Vec<_>, Vec<_>) =
+            impls.iter().partition(|i| i.inner_impl().trait_.is_none());
+
+        if !inherent.is_empty() {
+            out.push_str(".SH IMPLEMENTATIONS\n");
+            for i in inherent {
+                let header = print_impl_header(i.inner_impl(), self.tcx);
+                writeln!(out, ".SS {}", quote(&header)).unwrap();
+                self.write_docs(out, &i.impl_item, see_also);
+                for assoc in i.inner_impl().items.iter().filter(|item| !item.is_stripped()) {
+                    self.write_item_body(out, assoc, see_also);
+                }
+            }
+        }
+
+        let (auto, trait_impls): (Vec<_>, Vec<_>) =
+            trait_impls.into_iter().partition(|i| i.inner_impl().kind.is_auto());
+        let (blanket, concrete): (Vec<_>, Vec<_>) =
+            trait_impls.into_iter().partition(|i| i.inner_impl().kind.is_blanket());
+        for (title, impls) in [
+            ("TRAIT IMPLEMENTATIONS", concrete),
+            ("AUTO TRAIT IMPLEMENTATIONS", auto),
+            ("BLANKET IMPLEMENTATIONS", blanket),
+        ] {
+            self.write_impl_list(out, title, &impls);
+        }
+    }
+
+    fn write_impl_list(&self, out: &mut String, title: &str, impls: &[&formats::Impl]) {
+        if impls.is_empty() {
+            return;
+        }
+        writeln!(out, ".SH {}", quote(title)).unwrap();
+        out.push_str(".nf\n");
+        for i in impls {
+            writeln!(out, "{}", escape(&print_impl_header(i.inner_impl(), self.tcx))).unwrap();
+        }
+        out.push_str(".fi\n");
+    }
+}
+
+impl<'tcx> FormatRenderer<'tcx> for ManRenderer<'tcx> {
+    fn descr() -> &'static str {
+        "man"
+    }
+
+    const RUN_ON_MODULE: bool = false;
+
+    fn init(
+        krate: clean::Crate,
+        options: RenderOptions,
+        cache: Cache,
+        tcx: TyCtxt<'tcx>,
+    ) -> Result<(Self, clean::Crate), Error> {
+        debug!("Initializing man renderer");
+
+        let out_path = options.output.join("man3");
+        try_err!(create_dir_all(&out_path), &out_path);
+        let source = match &cache.crate_version {
+            Some(version) => format!("{} {version}", krate.name(tcx)),
+            None => krate.name(tcx).to_string(),
+        };
+
+        Ok((ManRenderer { tcx, cache: Rc::new(cache), out_path, source }, krate))
+    }
+
+    fn make_child_renderer(&self) -> Self {
+        self.clone()
+    }
+
+    /// Writes the page of `item`, then those of the items it contains.
+    fn item(&mut self, item: clean::Item) -> Result<(), Error> {
+        // Flatten items that recursively store other items. We include orphaned items from
+        // stripped modules and etc that are otherwise reachable.
+        if let ItemKind::StrippedItem(inner) = &*item.kind {
+            for inner_item in inner.inner_items() {
+                self.item(inner_item.clone())?;
+            }
+        }
+        for inner_item in item.kind.inner_items() {
+            self.item(inner_item.clone())?;
+        }
+
+        let has_page = matches!(
+            item.type_(),
+            ItemType::Module
+                | ItemType::Struct
+                | ItemType::Union
+                | ItemType::Enum
+                | ItemType::Function
+                | ItemType::Trait
+                | ItemType::TraitAlias
+                | ItemType::Typedef
+                | ItemType::OpaqueTy
+                | ItemType::Static
+                | ItemType::Constant
+                | ItemType::Macro
+                | ItemType::ProcAttribute
+                | ItemType::ProcDerive
+                | ItemType::Primitive
+                | ItemType::ForeignType
+        );
+        if !has_page || item.is_stripped() {
+            return Ok(());
+        }
+        let Some(name) = item.item_id.as_def_id().and_then(|did| self.page_name(did)) else {
+            return Ok(());
+        };
+        let page = self.render_page(&item, &name);
+        let path = self.out_path.join(format!("{name}.3"));
+        try_err!(write(&path, page), &path);
+        Ok(())
+    }
+
+    fn mod_item_in(&mut self, _item: &clean::Item) -> Result<(), Error> {
+        unreachable!("RUN_ON_MODULE = false should never call mod_item_in")
+    }
+
+    fn after_krate(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cache(&self) -> &Cache {
+        &self.cache
+    }
+}
+
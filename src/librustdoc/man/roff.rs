@@ -0,0 +1,183 @@
+//! Conversion of documentation to roff, using the macros of `man(7)`.
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+
+use crate::html::markdown::main_body_opts;
+
+#[cfg(test)]
+mod tests;
+
+/// Escapes `text` to be printed as is in the middle of a line: backslashes would start an escape
+/// sequence, and `-` would be printed as a hyphen rather than the minus sign used in code.
+fn escape_inline(text: &str) -> String {
+    text.replace('\\', "\\e").replace('-', "\\-")
+}
+
+/// Escapes `text` to be printed as is at the start of a line, where a leading `.` or `'` would
+/// make the line a request.
+pub(super) fn escape(text: &str) -> String {
+    let text = escape_inline(text);
+    if text.starts_with(['.', '\'']) { format!("\\&{text}") } else { text }
+}
+
+/// Escapes `text` to be used as a quoted argument of a macro, like the title of `.SH`.
+pub(super) fn quote(text: &str) -> String {
+    format!("\"{}\"", escape(text).replace('"', "\\(dq"))
+}
+
+struct RoffWriter {
+    out: String,
+    /// The font to restore at the end of the current span of inline code, emphasis or strong
+    /// text.
+    fonts: Vec<&'static str>,
+    /// The number of the next item of each nested list, or `None` for unordered lists.
+    lists: Vec<Option<u64>>,
+    /// Whether the current list item has no content yet, so that its first paragraph follows
+    /// the bullet or number.
+    item_start: bool,
+}
+
+impl RoffWriter {
+    /// Starts a new line unless the output already ends with one.
+    fn line_break(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn request(&mut self, request: &str) {
+        self.line_break();
+        self.out.push_str(request);
+        self.out.push('\n');
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.out.is_empty() || self.out.ends_with('\n') {
+            self.out.push_str(&escape(text));
+        } else {
+            self.out.push_str(&escape_inline(text));
+        }
+    }
+
+    fn push_font(&mut self, font: &'static str) {
+        self.out.push_str(font);
+        self.fonts.push(font);
+    }
+
+    fn pop_font(&mut self) {
+        self.fonts.pop();
+        self.out.push_str(self.fonts.last().copied().unwrap_or("\\fR"));
+    }
+}
+
+/// Converts documentation written in Markdown to roff.
+///
+/// Links are rendered as their text, followed by their destination for links to web pages.
+/// Intra-doc links are expected to be resolved beforehand to the names of man pages, and so are
+/// listed in the "SEE ALSO" section of the page instead.
+pub(super) fn markdown_to_roff(md: &str) -> String {
+    let mut w = RoffWriter {
+        out: String::new(),
+        fonts: Vec::new(),
+        lists: Vec::new(),
+        item_start: false,
+    };
+    let mut link_dest = Vec::new();
+
+    let mut p = Parser::new_ext(md, main_body_opts());
+    while let Some(event) = p.next() {
+        match event {
+            Event::Start(Tag::Paragraph) => {
+                // Further paragraphs of a list item keep its indentation.
+                if w.lists.is_empty() {
+                    w.request(".PP");
+                } else if !w.item_start {
+                    w.request(".IP \"\" 4");
+                }
+                w.item_start = false;
+            }
+            Event::Start(Tag::Heading(..)) => {
+                let mut title = String::new();
+                for event in p.by_ref() {
+                    match event {
+                        Event::Text(text) | Event::Code(text) => title.push_str(&text),
+                        Event::End(Tag::Heading(..)) => break,
+                        _ => {}
+                    }
+                }
+                w.request(&format!(".SS {}", quote(&title)));
+            }
+            Event::Start(Tag::BlockQuote) => w.request(".RS 4"),
+            Event::End(Tag::BlockQuote) => w.request(".RE"),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                if let CodeBlockKind::Indented = kind {
+                    w.request(".PP");
+                }
+                w.request(".RS 4");
+                w.request(".nf");
+                for event in p.by_ref() {
+                    match event {
+                        Event::Text(text) => {
+                            for line in text.lines() {
+                                w.out.push_str(&escape(line));
+                                w.out.push('\n');
+                            }
+                        }
+                        _ => break,
+                    }
+                }
+                w.request(".fi");
+                w.request(".RE");
+            }
+            Event::Start(Tag::List(start)) => {
+                if !w.lists.is_empty() {
+                    w.request(".RS 4");
+                }
+                w.lists.push(start);
+            }
+            Event::End(Tag::List(_)) => {
+                w.lists.pop();
+                if !w.lists.is_empty() {
+                    w.request(".RE");
+                }
+            }
+            Event::Start(Tag::Item) => {
+                match w.lists.last_mut() {
+                    Some(Some(number)) => {
+                        let request = format!(".IP {number}. 4");
+                        *number += 1;
+                        w.request(&request);
+                    }
+                    _ => w.request(".IP \\(bu 4"),
+                }
+                w.item_start = true;
+            }
+            Event::Start(Tag::TableRow | Tag::TableHead) => w.request(".br"),
+            Event::End(Tag::TableCell) => w.out.push('\t'),
+            Event::Start(Tag::Emphasis) => w.push_font("\\fI"),
+            Event::Start(Tag::Strong) => w.push_font("\\fB"),
+            Event::End(Tag::Emphasis | Tag::Strong) => w.pop_font(),
+            Event::Start(Tag::Link(_, dest, _) | Tag::Image(_, dest, _)) => link_dest.push(dest),
+            Event::End(Tag::Link(..) | Tag::Image(..)) => {
+                let dest = link_dest.pop().unwrap();
+                // Autolinks already show their destination.
+                if dest.contains("://") && !w.out.ends_with(&escape_inline(&dest)) {
+                    w.text(&format!(" <{dest}>"));
+                }
+            }
+            Event::Text(text) => w.text(&text),
+            Event::Code(code) => {
+                w.push_font("\\fB");
+                w.text(&code);
+                w.pop_font();
+            }
+            Event::SoftBreak => w.out.push('\n'),
+            Event::HardBreak => w.request(".br"),
+            Event::Rule => w.request(".PP"),
+            Event::TaskListMarker(checked) => w.text(if checked { "[x] " } else { "[ ] " }),
+            _ => {}
+        }
+    }
+    w.line_break();
+    w.out
+}
@@ -0,0 +1,32 @@
+use super::{escape, markdown_to_roff, quote};
+
+#[test]
+fn test_escape() {
+    assert_eq!(escape("a-b"), "a\\-b");
+    assert_eq!(escape("C:\\path"), "C:\\epath");
+    assert_eq!(escape(".hidden"), "\\&.hidden");
+    assert_eq!(escape("'quoted'"), "\\&'quoted'");
+    assert_eq!(quote("say \"hi\""), "\"say \\(dqhi\\(dq\"");
+}
+
+#[test]
+fn test_markdown_to_roff() {
+    fn t(input: &str, expect: &str) {
+        let output = markdown_to_roff(input);
+        assert_eq!(output, expect, "original: {}", input);
+    }
+
+    t("", "");
+    t("hello *world*", ".PP\nhello \\fIworld\\fR\n");
+    t("a `x - 1` b", ".PP\na \\fBx \\- 1\\fR b\n");
+    t("**bold `code`** text", ".PP\n\\fBbold \\fBcode\\fB\\fR text\n");
+    t("first\n.second", ".PP\nfirst\n\\&.second\n");
+    t("# Examples\n\ntext", ".SS \"Examples\"\n.PP\ntext\n");
+    t("```\nlet x = 1;\n\nx.max(2);\n```", ".RS 4\n.nf\nlet x = 1;\n\nx.max(2);\n.fi\n.RE\n");
+    t("- one\n- two", ".IP \\(bu 4\none\n.IP \\(bu 4\ntwo\n");
+    t("3. three\n4. four", ".IP 3. 4\nthree\n.IP 4. 4\nfour\n");
+    t("- one\n\n  more", ".IP \\(bu 4\none\n.IP \"\" 4\nmore\n");
+    t("see [Rust](https://www.rust-lang.org)", ".PP\nsee Rust <https://www.rust\\-lang.org>\n");
+    t("see <https://www.rust-lang.org>", ".PP\nsee https://www.rust\\-lang.org\n");
+    t("see [`Vec`](alloc::vec::Vec)", ".PP\nsee \\fBVec\\fR\n");
+}
@@ -0,0 +1,378 @@
+//! Rustdoc's Markdown backend
+//!
+//! This module renders a crate as Markdown files laid out like the HTML output: every module gets
+//! a directory with an `index.md` listing its items, and every other item gets a
+//! `{type}.{name}.md` page in the directory of its module. Pages contain the item's signature,
+//! its documentation with intra-doc links rewritten to relative links, and its implementations.
+
+use std::fmt::Write as _;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rustc_hir::def_id::DefId;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::hygiene::MacroKind;
+use rustc_span::Symbol;
+
+use crate::clean::types::ExternalLocation;
+use crate::clean::{self, ItemKind, RenderedLink};
+use crate::config::RenderOptions;
+use crate::docfs::PathError;
+use crate::error::Error;
+use crate::formats::cache::Cache;
+use crate::formats::item_type::ItemType;
+use crate::formats::text::{print_impl_header, print_item_signature, print_path};
+use crate::formats::{self, FormatRenderer};
+use crate::html::format::href_relative_parts;
+use crate::html::markdown::{markdown_for_markdown_output, plain_text_summary};
+use crate::html::render::{item_ty_to_section, ItemSection};
+use crate::try_err;
+
+#[derive(Clone)]
+pub(crate) struct MarkdownRenderer<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    cache: Rc<Cache>,
+    /// The path of the module being rendered.
+    current: Vec<Symbol>,
+    /// The directory of the module being rendered.
+    dst: PathBuf,
+    /// Whether the module being rendered is stripped, in which case none of its pages are written.
+    stripped: bool,
+}
+
+impl<'tcx> MarkdownRenderer<'tcx> {
+    /// Returns the link to the page documenting `did`, relative to the directory of the current
+    /// module.
+    ///
+    /// Items of other crates are linked to their HTML documentation, if its location is known.
+    fn href(&self, did: DefId) -> Option<String> {
+        fn module_fqp(shortty: ItemType, fqp: &[Symbol]) -> &[Symbol] {
+            if shortty == ItemType::Module { fqp } else { &fqp[..fqp.len() - 1] }
+        }
+
+        let (fqp, shortty, extension, mut parts) =
+            if let Some(&(ref fqp, shortty)) = self.cache.paths.get(&did) {
+                let parts = href_relative_parts(module_fqp(shortty, fqp), &self.current)
+                    .map(|part| part.to_string())
+                    .collect::<Vec<_>>();
+                (fqp, shortty, "md", parts)
+            } else {
+                let &(ref fqp, shortty) = self.cache.external_paths.get(&did)?;
+                let Some(ExternalLocation::Remote(root)) =
+                    self.cache.extern_locations.get(&did.krate)
+                else {
+                    return None;
+                };
+                let mut parts = vec![root.trim_end_matches('/').to_owned()];
+                parts.extend(module_fqp(shortty, fqp).iter().map(|part| part.to_string()));
+                (fqp, shortty, "html", parts)
+            };
+        match shortty {
+            ItemType::Module => parts.push(format!("index.{extension}")),
+            _ => parts.push(format!("{shortty}.{}.{extension}", fqp.last().unwrap())),
+        }
+        Some(parts.join("/"))
+    }
+
+    /// Returns the resolved intra-doc links of `item`.
+    fn links(&self, item: &clean::Item) -> Vec<RenderedLink> {
+        let Some(links) = self.cache.intra_doc_links.get(&item.item_id) else {
+            return vec![];
+        };
+        links
+            .iter()
+            .filter_map(|link| {
+                let mut href = self.href(link.page_id)?;
+                // Markdown pages have no anchors for the items they document, so fragments are
+                // only kept for links to HTML pages.
+                if let Some(fragment) = &link.fragment && href.ends_with(".html") {
+                    fragment.render(&mut href, self.tcx);
+                }
+                Some(RenderedLink {
+                    original_text: link.link.clone(),
+                    new_text: link.link_text.clone(),
+                    href,
+                    tooltip: String::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// Writes the documentation of `item`, followed by an empty line.
+    fn write_docs(&self, out: &mut String, item: &clean::Item) {
+        if let Some(doc) = item.collapsed_doc_value() {
+            let doc = markdown_for_markdown_output(&doc, &self.links(item));
+            out.push_str(doc.trim_end());
+            out.push_str("\n\n");
+        }
+    }
+
+    /// Writes the signature of `item` in a code block, followed by its documentation.
+    fn write_item_body(&self, out: &mut String, item: &clean::Item) {
+        if let Some(signature) = print_item_signature(item, self.tcx) {
+            writeln!(out, "```rust\n{signature}\n```\n").unwrap();
+        }
+        self.write_docs(out, item);
+    }
+
+    fn render_module(&self, item: &clean::Item) -> String {
+        let mut out = String::new();
+        let title = if item.is_crate() { "Crate" } else { "Module" };
+        writeln!(out, "# {title} `{}`\n", join_path(&self.current)).unwrap();
+        self.write_docs(&mut out, item);
+
+        let ItemKind::ModuleItem(module) = &*item.kind else { unreachable!() };
+        let items = module.items.iter().filter(|item| !item.is_stripped()).collect::<Vec<_>>();
+        for &section in ItemSection::ALL {
+            let mut entries =
+                items.iter().filter(|item| item_ty_to_section(item.type_()) == section).peekable();
+            if entries.peek().is_none() {
+                continue;
+            }
+            writeln!(out, "## {}\n", section.name()).unwrap();
+            for child in entries {
+                match &*child.kind {
+                    ItemKind::ImportItem(import) => {
+                        let path = print_path(&import.source.path, self.tcx);
+                        match import.kind {
+                            clean::ImportKind::Simple(name)
+                                if import.source.path.last() != name =>
+                            {
+                                writeln!(out, "- `pub use {path} as {name};`").unwrap()
+                            }
+                            clean::ImportKind::Simple(_) => {
+                                writeln!(out, "- `pub use {path};`").unwrap()
+                            }
+                            clean::ImportKind::Glob => {
+                                writeln!(out, "- `pub use {path}::*;`").unwrap()
+                            }
+                        }
+                    }
+                    ItemKind::ExternCrateItem { src } => {
+                        let name = child.name.unwrap();
+                        match src {
+                            Some(src) => writeln!(out, "- `extern crate {src} as {name};`"),
+                            None => writeln!(out, "- `extern crate {name};`"),
+                        }
+                        .unwrap()
+                    }
+                    _ => {
+                        let Some(name) = child.name else { continue };
+                        let href = match child.type_() {
+                            ItemType::Module => format!("{name}/index.md"),
+                            ty => format!("{ty}.{name}.md"),
+                        };
+                        write!(out, "- [`{name}`]({href})").unwrap();
+                        let doc = child.collapsed_doc_value().unwrap_or_default();
+                        let summary = plain_text_summary(&doc, &child.link_names(&self.cache));
+                        if !summary.is_empty() {
+                            write!(out, ": {summary}").unwrap();
+                        }
+                        out.push('\n');
+                    }
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn render_item(&self, item: &clean::Item) -> String {
+        let mut out = String::new();
+        let name = item.name.unwrap();
+        let title = match &*item.kind {
+            ItemKind::FunctionItem(_) | ItemKind::ForeignFunctionItem(_) => "Function",
+            ItemKind::TraitItem(_) => "Trait",
+            ItemKind::StructItem(_) => "Struct",
+            ItemKind::UnionItem(_) => "Union",
+            ItemKind::EnumItem(_) => "Enum",
+            ItemKind::TypedefItem(_) => "Type Definition",
+            ItemKind::MacroItem(_) => "Macro",
+            ItemKind::ProcMacroItem(m) => match m.kind {
+                MacroKind::Bang => "Macro",
+                MacroKind::Attr => "Attribute Macro",
+                MacroKind::Derive => "Derive Macro",
+            },
+            ItemKind::PrimitiveItem(_) => "Primitive Type",
+            ItemKind::StaticItem(_) | ItemKind::ForeignStaticItem(_) => "Static",
+            ItemKind::ConstantItem(_) => "Constant",
+            ItemKind::ForeignTypeItem => "Foreign Type",
+            ItemKind::KeywordItem => "Keyword",
+            ItemKind::OpaqueTyItem(_) => "Opaque Type",
+            ItemKind::TraitAliasItem(_) => "Trait Alias",
+            _ => "Item",
+        };
+        match &*item.kind {
+            ItemKind::PrimitiveItem(_) | ItemKind::KeywordItem => {
+                writeln!(out, "# {title} `{name}`\n").unwrap()
+            }
+            _ => writeln!(out, "# {title} `{}::{name}`\n", join_path(&self.current)).unwrap(),
+        }
+        self.write_item_body(&mut out, item);
+
+        match &*item.kind {
+            ItemKind::StructItem(clean::Struct { fields, .. })
+            | ItemKind::UnionItem(clean::Union { fields, .. }) => {
+                self.write_members(&mut out, "Fields", fields);
+            }
+            ItemKind::EnumItem(e) => {
+                self.write_members(&mut out, "Variants", &e.variants.raw);
+            }
+            ItemKind::TraitItem(t) => {
+                self.write_members(&mut out, "Associated Items", &t.items);
+                self.write_implementors(&mut out, item.item_id.expect_def_id());
+            }
+            _ => {}
+        }
+        if let Some(did) = item.item_id.as_def_id() {
+            self.write_impls(&mut out, did);
+        }
+        out
+    }
+
+    /// Writes a section with the documentation of each named entry of `members`.
+    fn write_members(&self, out: &mut String, title: &str, members: &[clean::Item]) {
+        let members = members
+            .iter()
+            .filter(|member| member.name.is_some() && !member.is_stripped())
+            .collect::<Vec<_>>();
+        if members.is_empty() {
+            return;
+        }
+        writeln!(out, "## {title}\n").unwrap();
+        for member in members {
+            writeln!(out, "### `{}`\n", member.name.unwrap()).unwrap();
+            self.write_item_body(out, member);
+        }
+    }
+
+    /// Writes the inherent implementations of `did` with their items, then the list of its trait
+    /// implementations.
+    fn write_impls(&self, out: &mut String, did: DefId) {
+        let Some(impls) = self.cache.impls.get(&did) else { return };
+        let (inherent, trait_impls): (Vec<_>, Vec<_>) =
+            impls.iter().partition(|i| i.inner_impl().trait_.is_none());
+
+        if !inherent.is_empty() {
+            out.push_str("## Implementations\n\n");
+            for i in inherent {
+                writeln!(out, "### `{}`\n", print_impl_header(i.inner_impl(), self.tcx)).unwrap();
+                self.write_docs(out, &i.impl_item);
+                for assoc in i.inner_impl().items.iter().filter(|item| !item.is_stripped()) {
+                    let Some(name) = assoc.name else { continue };
+                    writeln!(out, "#### `{name}`\n").unwrap();
+                    self.write_item_body(out, assoc);
+                }
+            }
+        }
+
+        let (auto, trait_impls): (Vec<_>, Vec<_>) =
+            trait_impls.into_iter().partition(|i| i.inner_impl().kind.is_auto());
+        let (blanket, concrete): (Vec<_>, Vec<_>) =
+            trait_impls.into_iter().partition(|i| i.inner_impl().kind.is_blanket());
+        for (title, impls) in [
+            ("Trait Implementations", concrete),
+            ("Auto Trait Implementations", auto),
+            ("Blanket Implementations", blanket),
+        ] {
+            self.write_impl_list(out, title, &impls);
+        }
+    }
+
+    /// Writes the list of implementations of the trait `did`.
+    fn write_implementors(&self, out: &mut String, did: DefId) {
+        let Some(implementors) = self.cache.implementors.get(&did) else { return };
+        let implementors = implementors.iter().collect::<Vec<_>>();
+        self.write_impl_list(out, "Implementors", &implementors);
+    }
+
+    fn write_impl_list(&self, out: &mut String, title: &str, impls: &[&formats::Impl]) {
+        if impls.is_empty() {
+            return;
+        }
+        writeln!(out, "## {title}\n").unwrap();
+        for i in impls {
+            let header = print_impl_header(i.inner_impl(), self.tcx);
+            match i.trait_did().and_then(|did| self.href(did)) {
+                Some(href) => writeln!(out, "- [`{header}`]({href})").unwrap(),
+                None => writeln!(out, "- `{header}`").unwrap(),
+            }
+        }
+        out.push('\n');
+    }
+
+    fn write_page(&self, path: PathBuf, page: String) -> Result<(), Error> {
+        try_err!(write(&path, page), &path);
+        Ok(())
+    }
+}
+
+fn join_path(path: &[Symbol]) -> String {
+    path.iter().map(|part| part.as_str()).collect::<Vec<_>>().join("::")
+}
+
+impl<'tcx> FormatRenderer<'tcx> for MarkdownRenderer<'tcx> {
+    fn descr() -> &'static str {
+        "markdown"
+    }
+
+    const RUN_ON_MODULE: bool = true;
+
+    fn init(
+        krate: clean::Crate,
+        options: RenderOptions,
+        cache: Cache,
+        tcx: TyCtxt<'tcx>,
+    ) -> Result<(Self, clean::Crate), Error> {
+        debug!("Initializing markdown renderer");
+
+        Ok((
+            MarkdownRenderer {
+                tcx,
+                cache: Rc::new(cache),
+                current: Vec::new(),
+                dst: options.output,
+                stripped: false,
+            },
+            krate,
+        ))
+    }
+
+    fn make_child_renderer(&self) -> Self {
+        self.clone()
+    }
+
+    fn item(&mut self, item: clean::Item) -> Result<(), Error> {
+        if self.stripped
+            || item.is_stripped()
+            || matches!(*item.kind, ItemKind::ImportItem(_) | ItemKind::ImplItem(_))
+        {
+            return Ok(());
+        }
+        let page = self.render_item(&item);
+        let path = self.dst.join(format!("{}.{}.md", item.type_(), item.name.unwrap()));
+        self.write_page(path, page)
+    }
+
+    fn mod_item_in(&mut self, item: &clean::Item) -> Result<(), Error> {
+        let name = item.name.unwrap();
+        self.current.push(name);
+        self.dst.push(name.as_str());
+        self.stripped |= item.is_stripped();
+        if self.stripped {
+            return Ok(());
+        }
+        try_err!(create_dir_all(&self.dst), &self.dst);
+        let page = self.render_module(item);
+        self.write_page(self.dst.join("index.md"), page)
+    }
+
+    fn after_krate(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cache(&self) -> &Cache {
+        &self.cache
+    }
+}
@@ -9,6 +9,7 @@
 use rustc_lint::builtin::MISSING_DOCS;
 use rustc_middle::lint::LintLevelSource;
 use rustc_session::lint;
+use rustc_span::def_id::LOCAL_CRATE;
 use rustc_span::FileName;
 use serde::Serialize;
 
@@ -22,10 +23,16 @@
 };
 
 fn calculate_doc_coverage(krate: clean::Crate, ctx: &mut DocContext<'_>) -> clean::Crate {
-    let mut calc = CoverageCalculator { items: Default::default(), ctx };
+    let mut calc = CoverageCalculator {
+        items: Default::default(),
+        undocumented: Vec::new(),
+        without_examples: Vec::new(),
+        ctx,
+    };
     calc.visit_crate(&krate);
 
     calc.print_results();
+    calc.check_threshold();
 
     krate
 }
@@ -100,8 +107,39 @@ fn add_assign(&mut self, rhs: Self) {
     }
 }
 
+/// An item listed with `--coverage-items`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Serialize, Debug)]
+struct CoverageItem {
+    path: String,
+    kind: &'static str,
+    span: CoverageSpan,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Serialize, Debug)]
+struct CoverageSpan {
+    filename: String,
+    /// The line and column of the first character of the item.
+    begin: (usize, usize),
+    /// The line and column right after the end of the item.
+    end: (usize, usize),
+}
+
+/// The JSON output with `--coverage-items`. Items are sorted by path, so that reports of two
+/// versions of a crate can be diffed.
+#[derive(Serialize)]
+struct CoverageReport<'a> {
+    files: BTreeMap<String, &'a ItemCount>,
+    total: ItemCount,
+    undocumented: &'a [CoverageItem],
+    without_examples: &'a [CoverageItem],
+}
+
 struct CoverageCalculator<'a, 'b> {
     items: BTreeMap<FileName, ItemCount>,
+    /// The items which should have documentation but don't, with `--coverage-items`.
+    undocumented: Vec<CoverageItem>,
+    /// The items which should have a code example but don't, with `--coverage-items`.
+    without_examples: Vec<CoverageItem>,
     ctx: &'a mut DocContext<'b>,
 }
 
@@ -116,24 +154,75 @@ fn limit_filename_len(filename: String) -> String {
 }
 
 impl<'a, 'b> CoverageCalculator<'a, 'b> {
-    fn to_json(&self) -> String {
-        serde_json::to_string(
-            &self
-                .items
-                .iter()
-                .map(|(k, v)| (k.prefer_local().to_string(), v))
-                .collect::<BTreeMap<String, &ItemCount>>(),
-        )
+    fn to_json(&mut self) -> String {
+        let files = self
+            .items
+            .iter()
+            .map(|(k, v)| (k.prefer_local().to_string(), v))
+            .collect::<BTreeMap<String, &ItemCount>>();
+        if !self.ctx.coverage_options.list_items {
+            return serde_json::to_string(&files).expect("failed to convert JSON data to string");
+        }
+        self.undocumented.sort();
+        self.without_examples.sort();
+        serde_json::to_string(&CoverageReport {
+            files,
+            total: self.total(),
+            undocumented: &self.undocumented,
+            without_examples: &self.without_examples,
+        })
         .expect("failed to convert JSON data to string")
     }
 
-    fn print_results(&self) {
+    /// The counts of all the files with items which should be documented.
+    fn total(&self) -> ItemCount {
+        let mut total = ItemCount::default();
+        for count in self.items.values() {
+            if count.percentage().is_some() {
+                total += *count;
+            }
+        }
+        total
+    }
+
+    fn check_threshold(&self) {
+        let Some(fail_under) = self.ctx.coverage_options.fail_under else { return };
+        // A crate without any item to document is fully documented.
+        let percentage = self.total().percentage().unwrap_or(100.0);
+        if percentage < fail_under {
+            self.ctx
+                .sess()
+                .struct_err(format!(
+                    "documentation coverage is {percentage:.1}%, under the required {fail_under}%"
+                ))
+                .emit();
+        }
+    }
+
+    fn coverage_item(&self, i: &clean::Item, span: clean::Span) -> CoverageItem {
+        let sess = self.ctx.sess();
+        let path = match i.item_id.as_def_id() {
+            Some(def_id) if !def_id.is_crate_root() => self.ctx.tcx.def_path_str(def_id),
+            _ => self.ctx.tcx.crate_name(LOCAL_CRATE).to_string(),
+        };
+        let (lo, hi) = (span.lo(sess), span.hi(sess));
+        CoverageItem {
+            path,
+            kind: i.type_().as_str(),
+            span: CoverageSpan {
+                filename: span.filename(sess).prefer_local().to_string(),
+                begin: (lo.line, lo.col.to_usize()),
+                end: (hi.line, hi.col.to_usize()),
+            },
+        }
+    }
+
+    fn print_results(&mut self) {
         let output_format = self.ctx.output_format;
         if output_format.is_json() {
             println!("{}", self.to_json());
             return;
         }
-        let mut total = ItemCount::default();
 
         fn print_table_line() {
             println!("+-{0:->35}-+-{0:->10}-+-{0:->10}-+-{0:->10}-+-{0:->10}-+", "");
@@ -166,11 +255,10 @@ fn print_table_record(
                     percentage,
                     count.examples_percentage().unwrap_or(0.),
                 );
-
-                total += count;
             }
         }
 
+        let total = self.total();
         print_table_line();
         print_table_record(
             "Total",
@@ -256,12 +344,22 @@ fn visit_item(&mut self, i: &clean::Item) {
                 if let Some(span) = i.span(self.ctx.tcx) {
                     let filename = span.filename(self.ctx.sess());
                     debug!("counting {:?} {:?} in {:?}", i.type_(), i.name, filename);
+                    let should_have_doc_examples = should_have_doc_example(self.ctx, i);
                     self.items.entry(filename).or_default().count_item(
                         has_docs,
                         has_doc_example,
-                        should_have_doc_example(self.ctx, i),
+                        should_have_doc_examples,
                         should_have_docs,
                     );
+
+                    if self.ctx.coverage_options.list_items {
+                        if should_have_docs && !has_docs {
+                            self.undocumented.push(self.coverage_item(i, span));
+                        }
+                        if should_have_doc_examples && !has_doc_example {
+                            self.without_examples.push(self.coverage_item(i, span));
+                        }
+                    }
                 }
             }
         }
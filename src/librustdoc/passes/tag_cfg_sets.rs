@@ -0,0 +1,81 @@
+//! Tags the items which only exist in some of the `--cfg-set`s with the cfgs of these sets.
+use std::mem;
+use std::sync::Arc;
+
+use rustc_session::parse::CrateConfig;
+use rustc_span::symbol::sym;
+
+use crate::cfg_sets;
+use crate::clean::cfg::Cfg;
+use crate::clean::{Crate, Item};
+use crate::core::DocContext;
+use crate::fold::DocFolder;
+use crate::passes::Pass;
+
+pub(crate) const TAG_CFG_SETS: Pass = Pass {
+    name: "tag-cfg-sets",
+    run: tag_cfg_sets,
+    description: "tags items that only exist in some `--cfg-set`s with their cfgs",
+};
+
+pub(crate) fn tag_cfg_sets(cr: Crate, cx: &mut DocContext<'_>) -> Crate {
+    let configs = &cx.tcx.sess.parse_sess.cfg_sets;
+    if configs.is_empty() {
+        return cr;
+    }
+    let set_cfgs = cfg_sets::distinguishing_cfgs(&cx.render_options.cfg_sets, configs);
+    CfgSetTagger { configs, set_cfgs, sets: (0..configs.len()).collect() }.fold_crate(cr)
+}
+
+struct CfgSetTagger<'a> {
+    /// The configuration of each set.
+    configs: &'a [CrateConfig],
+    /// The cfgs which tell each set apart from the others.
+    set_cfgs: Vec<Cfg>,
+    /// The sets the parent of the current item exists in.
+    sets: Vec<usize>,
+}
+
+impl DocFolder for CfgSetTagger<'_> {
+    fn fold_item(&mut self, mut item: Item) -> Option<Item> {
+        // The cfgs of the items of other crates are about the configuration these crates were
+        // compiled with, so these items exist wherever their parent does.
+        let sets = if item.item_id.as_def_id().map_or(false, |def_id| def_id.is_local()) {
+            let cfgs = item
+                .attrs
+                .other_attrs
+                .iter()
+                .filter(|attr| attr.has_name(sym::cfg))
+                .filter_map(|attr| match &attr.meta_item_list()?[..] {
+                    [cfg] => Cfg::parse(cfg.meta_item()?).ok(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            self.sets
+                .iter()
+                .copied()
+                .filter(|&set| cfgs.iter().all(|cfg| cfg.holds_in(&self.configs[set])))
+                .collect()
+        } else {
+            self.sets.clone()
+        };
+        // The item was kept because it exists in a set, but its parent doesn't exist there.
+        if sets.is_empty() {
+            return None;
+        }
+
+        let cfg = sets.iter().map(|&set| self.set_cfgs[set].clone()).reduce(|a, b| a | b);
+        if sets.len() < self.configs.len() && let Some(cfg) = cfg && cfg != Cfg::True {
+            let cfg = match item.cfg.take() {
+                Some(existing) => Cfg::clone(&existing) & cfg,
+                None => cfg,
+            };
+            item.cfg = Some(Arc::new(cfg));
+        }
+
+        let parent_sets = mem::replace(&mut self.sets, sets);
+        let result = self.fold_item_recur(item);
+        self.sets = parent_sets;
+        Some(result)
+    }
+}
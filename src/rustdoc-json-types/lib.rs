@@ -8,7 +8,7 @@
 use std::path::PathBuf;
 
 /// rustdoc format-version.
-pub const FORMAT_VERSION: u32 = 25;
+pub const FORMAT_VERSION: u32 = 27;
 
 /// A `Crate` is the root of the emitted JSON blob. It contains all type/documentation information
 /// about the language items in the local crate, as well as info about external items to allow
@@ -287,6 +287,8 @@ pub struct Union {
     pub fields_stripped: bool,
     pub fields: Vec<Id>,
     pub impls: Vec<Id>,
+    /// The memory layout of the union, if it can be computed.
+    pub layout: Option<Layout>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -294,6 +296,8 @@ pub struct Struct {
     pub kind: StructKind,
     pub generics: Generics,
     pub impls: Vec<Id>,
+    /// The memory layout of the struct, if it can be computed.
+    pub layout: Option<Layout>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -330,6 +334,8 @@ pub struct Enum {
     pub variants_stripped: bool,
     pub variants: Vec<Id>,
     pub impls: Vec<Id>,
+    /// The memory layout of the enum, if it can be computed.
+    pub layout: Option<Layout>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -392,6 +398,47 @@ pub struct Discriminant {
     pub value: String,
 }
 
+/// The memory layout of a type, as computed by the compiler for the target the crate was
+/// documented for.
+///
+/// Layouts can only be computed for types which have the same layout whatever their generic
+/// parameters are. Most layout information is **completely unstable** and may even differ between
+/// compilations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Layout {
+    /// The size of the type in bytes. For unsized types, this is the size of their sized prefix.
+    pub size: u64,
+    /// The alignment of the type in bytes.
+    pub align: u64,
+    pub is_unsized: bool,
+    pub is_uninhabited: bool,
+    /// For enums with several variants, the layout of each variant, in declaration order.
+    pub variants: Vec<VariantLayout>,
+    /// The largest niche of the type: a range of invalid values which enums containing the type
+    /// can use to store their discriminant.
+    pub niche: Option<Niche>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VariantLayout {
+    pub name: String,
+    /// The size of the variant in bytes, not counting the discriminant unless it is stored in a
+    /// niche of the variant.
+    pub size: u64,
+    pub is_uninhabited: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Niche {
+    /// The offset of the niche in the type, in bytes.
+    pub offset: u64,
+    /// The size of the niche in bytes.
+    pub size: u64,
+    /// The number of invalid values in the niche. Stored as a string because it can be as large
+    /// as [`u128::MAX`].
+    pub available: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Header {
     #[serde(rename = "const")]
@@ -564,6 +611,15 @@ pub enum Type {
         type_: Box<Type>,
         len: String,
     },
+    /// `u32 is 1..`
+    ///
+    /// The pattern is unstable, so it is only given as a string for now.
+    Pat {
+        #[serde(rename = "type")]
+        type_: Box<Type>,
+        #[doc(hidden)]
+        __pat_unstable_do_not_use: String,
+    },
     /// `impl TraitA + TraitB + ...`
     ImplTrait(Vec<GenericBound>),
     /// `_`
@@ -6,6 +6,14 @@ fn test_struct_info_roundtrip() {
         generics: Generics { params: vec![], where_predicates: vec![] },
         kind: StructKind::Plain { fields: vec![], fields_stripped: false },
         impls: vec![],
+        layout: Some(Layout {
+            size: 16,
+            align: 8,
+            is_unsized: false,
+            is_uninhabited: false,
+            variants: vec![],
+            niche: Some(Niche { offset: 0, size: 8, available: u128::MAX.to_string() }),
+        }),
     });
 
     let struct_json = serde_json::to_string(&s).unwrap();
@@ -22,6 +30,7 @@ fn test_union_info_roundtrip() {
         fields_stripped: false,
         fields: vec![],
         impls: vec![],
+        layout: None,
     });
 
     let union_json = serde_json::to_string(&u).unwrap();
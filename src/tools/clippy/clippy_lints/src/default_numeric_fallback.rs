@@ -6,7 +6,7 @@
 use rustc_errors::Applicability;
 use rustc_hir::{
     intravisit::{walk_expr, walk_stmt, Visitor},
-    Body, Expr, ExprKind, HirId, ItemKind, Lit, Node, Stmt, StmtKind,
+    Body, Expr, ExprKind, HirId, ItemKind, Lit, Node, Stmt, StmtKind, StructTailExpr,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::{
@@ -176,7 +176,7 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
                         }
 
                         // Visit base with no bound.
-                        if let Some(base) = base {
+                        if let StructTailExpr::Base(base) = base {
                             self.ty_bounds.push(ExplicitTyBound(false));
                             self.visit_expr(base);
                             self.ty_bounds.pop();
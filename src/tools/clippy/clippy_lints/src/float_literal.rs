@@ -78,11 +78,15 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                 let digits = count_digits(sym_str);
                 let max = max_digits(fty);
                 let type_suffix = match lit_float_ty {
+                    LitFloatType::Suffixed(ast::FloatTy::F16) => Some("f16"),
                     LitFloatType::Suffixed(ast::FloatTy::F32) => Some("f32"),
                     LitFloatType::Suffixed(ast::FloatTy::F64) => Some("f64"),
+                    LitFloatType::Suffixed(ast::FloatTy::F128) => Some("f128"),
                     LitFloatType::Unsuffixed => None
                 };
                 let (is_whole, mut float_str) = match fty {
+                    // FIXME(f16_f128): the host has no `f16` or `f128` to format these with yet.
+                    FloatTy::F16 | FloatTy::F128 => return,
                     FloatTy::F32 => {
                         let value = sym_str.parse::<f32>().unwrap();
 
@@ -134,8 +138,10 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
 #[must_use]
 fn max_digits(fty: FloatTy) -> u32 {
     match fty {
+        FloatTy::F16 => 3,
         FloatTy::F32 => f32::DIGITS,
         FloatTy::F64 => f64::DIGITS,
+        FloatTy::F128 => 33,
     }
 }
 
@@ -47,6 +47,7 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, method_name: &str, re
             | ExprKind::Match(_, _, _)
             | ExprKind::Closure(_)
             | ExprKind::Ret(_)
+            | ExprKind::Become(_)
             | ExprKind::Break(_, _) => true,
             _ => false,
         },
@@ -411,6 +411,7 @@ fn visit_expr(&mut self, ex: &'tcx hir::Expr<'_>) {
             | hir::ExprKind::Match(..)
             | hir::ExprKind::Repeat(..)
             | hir::ExprKind::Ret(..)
+            | hir::ExprKind::Become(..)
             | hir::ExprKind::Tup(..)
             | hir::ExprKind::Unary(..)
             | hir::ExprKind::Yield(..) => {
@@ -1,5 +1,5 @@
 use clippy_utils::{diagnostics::span_lint_and_sugg, get_parent_expr, path_to_local, source::snippet, ty::is_copy};
-use rustc_hir::{BindingAnnotation, Expr, ExprKind, Node, PatKind, UnOp};
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, Node, PatKind, StructTailExpr, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
@@ -38,7 +38,7 @@
 
 impl LateLintPass<'_> for UnnecessaryStruct {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
-        if let ExprKind::Struct(_, &[], Some(base)) = expr.kind {
+        if let ExprKind::Struct(_, &[], StructTailExpr::Base(base)) = expr.kind {
             if let Some(parent) = get_parent_expr(cx, expr) &&
                 let parent_ty = cx.typeck_results().expr_ty_adjusted(parent) &&
                 parent_ty.is_any_ptr()
@@ -176,6 +176,7 @@ pub fn eq_expr(l: &Expr, r: &Expr) -> bool {
         (Block(lb, ll), Block(rb, rl)) => eq_label(ll, rl) && eq_block(lb, rb),
         (TryBlock(l), TryBlock(r)) => eq_block(l, r),
         (Yield(l), Yield(r)) | (Ret(l), Ret(r)) => eq_expr_opt(l, r),
+        (Become(l), Become(r)) => eq_expr(l, r),
         (Break(ll, le), Break(rl, re)) => eq_label(ll, rl) && eq_expr_opt(le, re),
         (Continue(ll), Continue(rl)) => eq_label(ll, rl),
         (Assign(l1, l2, _), Assign(r1, r2, _)) | (Index(l1, l2), Index(r1, r2)) => eq_expr(l1, r1) && eq_expr(l2, r2),
@@ -209,7 +210,7 @@ pub fn eq_expr(l: &Expr, r: &Expr) -> bool {
                 && eq_fn_decl(lf, rf)
                 && eq_expr(le, re)
         },
-        (Async(lc, lb), Async(rc, rb)) => lc == rc && eq_block(lb, rb),
+        (Gen(lc, lb, lk), Gen(rc, rb, rk)) => lc == rc && eq_block(lb, rb) && lk == rk,
         (Range(lf, lt, ll), Range(rf, rt, rl)) => ll == rl && eq_expr_opt(lf, rf) && eq_expr_opt(lt, rt),
         (AddrOf(lbk, lm, le), AddrOf(rbk, rm, re)) => lbk == rbk && lm == rm && eq_expr(le, re),
         (Path(lq, lp), Path(rq, rp)) => both(lq, rq, eq_qself) && eq_path(lp, rp),
@@ -551,15 +552,27 @@ pub fn eq_struct_field(l: &FieldDef, r: &FieldDef) -> bool {
         && eq_vis(&l.vis, &r.vis)
         && both(&l.ident, &r.ident, |l, r| eq_id(*l, *r))
         && eq_ty(&l.ty, &r.ty)
+        && both(&l.default, &r.default, eq_anon_const)
 }
 
 pub fn eq_fn_sig(l: &FnSig, r: &FnSig) -> bool {
-    eq_fn_decl(&l.decl, &r.decl) && eq_fn_header(&l.header, &r.header)
+    eq_fn_decl(&l.decl, &r.decl)
+        && eq_fn_header(&l.header, &r.header)
+        && both(&l.contract, &r.contract, |l, r| eq_fn_contract(l, r))
+}
+
+pub fn eq_fn_contract(l: &FnContract, r: &FnContract) -> bool {
+    over(&l.requires, &r.requires, eq_contract_clause) && over(&l.ensures, &r.ensures, eq_contract_clause)
+}
+
+pub fn eq_contract_clause(l: &ContractClause, r: &ContractClause) -> bool {
+    eq_expr(&l.closure, &r.closure) && l.text == r.text
 }
 
 pub fn eq_fn_header(l: &FnHeader, r: &FnHeader) -> bool {
     matches!(l.unsafety, Unsafe::No) == matches!(r.unsafety, Unsafe::No)
         && l.asyncness.is_async() == r.asyncness.is_async()
+        && l.genness.is_gen() == r.genness.is_gen()
         && matches!(l.constness, Const::No) == matches!(r.constness, Const::No)
         && eq_ext(&l.ext, &r.ext)
 }
@@ -673,6 +686,7 @@ pub fn eq_ty(l: &Ty, r: &Ty) -> bool {
         (TraitObject(lg, ls), TraitObject(rg, rs)) => ls == rs && over(lg, rg, eq_generic_bound),
         (ImplTrait(_, lg), ImplTrait(_, rg)) => over(lg, rg, eq_generic_bound),
         (Typeof(l), Typeof(r)) => eq_expr(&l.value, &r.value),
+        (Pat(lt, lp), Pat(rt, rp)) => eq_ty(lt, rt) && eq_pat(lp, rp),
         (MacCall(l), MacCall(r)) => eq_mac_call(l, r),
         _ => false,
     }
@@ -161,6 +161,7 @@ fn expr_search_pat(tcx: TyCtxt<'_>, e: &Expr<'_>) -> (Pat, Pat) {
         ExprKind::Continue(Destination { label: Some(name), .. }) => (Pat::Str("continue"), Pat::Sym(name.ident.name)),
         ExprKind::Ret(None) => (Pat::Str("return"), Pat::Str("return")),
         ExprKind::Ret(Some(e)) => (Pat::Str("return"), expr_search_pat(tcx, e).1),
+        ExprKind::Become(e) => (Pat::Str("become"), expr_search_pat(tcx, e).1),
         ExprKind::Struct(path, _, _) => (qpath_search_pat(path).0, Pat::Str("}")),
         ExprKind::Yield(e, YieldSource::Yield) => (Pat::Str("yield"), expr_search_pat(tcx, e).1),
         _ => (Pat::Str(""), Pat::Str("")),
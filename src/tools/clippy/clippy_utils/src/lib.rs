@@ -512,8 +512,10 @@ fn find_primitive_impls<'tcx>(tcx: TyCtxt<'tcx>, name: &str) -> impl Iterator<It
         "u32" => UintSimplifiedType(UintTy::U32),
         "u64" => UintSimplifiedType(UintTy::U64),
         "u128" => UintSimplifiedType(UintTy::U128),
+        "f16" => FloatSimplifiedType(FloatTy::F16),
         "f32" => FloatSimplifiedType(FloatTy::F32),
         "f64" => FloatSimplifiedType(FloatTy::F64),
+        "f128" => FloatSimplifiedType(FloatTy::F128),
         _ => return [].iter().copied(),
     };
 
@@ -910,6 +912,7 @@ pub fn can_move_expr_to_closure_no_visit<'tcx>(
         ExprKind::Break(..)
         | ExprKind::Continue(_)
         | ExprKind::Ret(_)
+        | ExprKind::Become(_)
         | ExprKind::Yield(..)
         | ExprKind::InlineAsm(_) => false,
         // Accessing a field of a local value can only be done if the type isn't
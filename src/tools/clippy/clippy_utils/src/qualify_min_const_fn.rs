@@ -367,6 +367,8 @@ fn check_terminator<'tcx>(
         } => check_operand(tcx, cond, span, body),
 
         TerminatorKind::InlineAsm { .. } => Err((span, "cannot use inline assembly in const fn".into())),
+
+        TerminatorKind::TailCall { .. } => Err((span, "cannot use tail calls in const fn".into())),
     }
 }
 
@@ -266,6 +266,7 @@ fn check_type(&mut self, x: &'a Type) {
             Type::Tuple(tys) => tys.iter().for_each(|ty| self.check_type(ty)),
             Type::Slice(inner) => self.check_type(&**inner),
             Type::Array { type_, len: _ } => self.check_type(&**type_),
+            Type::Pat { type_, __pat_unstable_do_not_use: _ } => self.check_type(&**type_),
             Type::ImplTrait(bounds) => bounds.iter().for_each(|b| self.check_generic_bound(b)),
             Type::Infer => {}
             Type::RawPointer { mutable: _, type_ } => self.check_type(&**type_),
@@ -1,4 +1,5 @@
-use rustc_apfloat::Float;
+use rustc_apfloat::ieee::Double;
+use rustc_apfloat::{Float, FloatConvert, Round};
 use rustc_middle::ty::layout::{HasParamEnv, LayoutOf};
 use rustc_middle::{mir, ty, ty::FloatTy};
 use rustc_target::abi::{Endian, HasDataLayout, Size};
@@ -39,6 +40,18 @@ enum HostFloatOp {
                     Trunc,
                     Sqrt,
                 }
+                impl HostFloatOp {
+                    /// The rounding mode to round to an integer with, for the rounding ops.
+                    fn round(self) -> Option<Round> {
+                        match self {
+                            HostFloatOp::Ceil => Some(Round::TowardPositive),
+                            HostFloatOp::Floor => Some(Round::TowardNegative),
+                            HostFloatOp::Round => Some(Round::NearestTiesToAway),
+                            HostFloatOp::Trunc => Some(Round::TowardZero),
+                            HostFloatOp::Sqrt => None,
+                        }
+                    }
+                }
                 #[derive(Copy, Clone)]
                 enum Op {
                     MirOp(mir::UnOp),
@@ -68,8 +81,10 @@ enum Op {
                             };
                             let op = op.to_scalar();
                             match float_ty {
+                                FloatTy::F16 => Scalar::from_f16(op.to_f16()?.abs()),
                                 FloatTy::F32 => Scalar::from_f32(op.to_f32()?.abs()),
                                 FloatTy::F64 => Scalar::from_f64(op.to_f64()?.abs()),
+                                FloatTy::F128 => Scalar::from_f128(op.to_f128()?.abs()),
                             }
                         }
                         Op::HostOp(host_op) => {
@@ -78,6 +93,32 @@ enum Op {
                             };
                             // FIXME using host floats
                             match float_ty {
+                                // There are no host floats for these, so use apfloat instead.
+                                FloatTy::F16 => {
+                                    let f = op.to_scalar().to_f16()?;
+                                    let res = match host_op.round() {
+                                        Some(round) => f.round_to_integral(round).value,
+                                        None => {
+                                            // `f64` is more than twice as precise as `f16`, so
+                                            // rounding its correctly rounded square root to
+                                            // `f16` gives the correctly rounded result.
+                                            let f: Double = f.convert(&mut false).value;
+                                            let f = f64::from_bits(f.to_bits().try_into().unwrap());
+                                            let res = Double::from_bits(f.sqrt().to_bits().into());
+                                            res.convert(&mut false).value
+                                        }
+                                    };
+                                    Scalar::from_f16(res)
+                                }
+                                FloatTy::F128 => {
+                                    let f = op.to_scalar().to_f128()?;
+                                    let Some(round) = host_op.round() else {
+                                        throw_unsup_format!(
+                                            "`simd_fsqrt` is not supported on `f128` vectors"
+                                        );
+                                    };
+                                    Scalar::from_f128(f.round_to_integral(round).value)
+                                }
                                 FloatTy::F32 => {
                                     let f = f32::from_bits(op.to_scalar().to_u32()?);
                                     let res = match host_op {
@@ -243,6 +284,15 @@ enum Op {
                         span_bug!(this.cur_span(), "{} operand is not a float", intrinsic_name)
                     };
                     let val = match float_ty {
+                        // There are no host floats for these, but apfloat computes this exactly.
+                        FloatTy::F16 => {
+                            let res = a.to_f16()?.mul_add(b.to_f16()?, c.to_f16()?).value;
+                            Scalar::from_f16(res)
+                        }
+                        FloatTy::F128 => {
+                            let res = a.to_f128()?.mul_add(b.to_f128()?, c.to_f128()?).value;
+                            Scalar::from_f128(res)
+                        }
                         FloatTy::F32 => {
                             let a = f32::from_bits(a.to_u32()?);
                             let b = f32::from_bits(b.to_u32()?);
@@ -605,8 +655,10 @@ fn fmax_op<'tcx>(
     let left = left.to_scalar();
     let right = right.to_scalar();
     Ok(match float_ty {
+        FloatTy::F16 => Scalar::from_f16(left.to_f16()?.max(right.to_f16()?)),
         FloatTy::F32 => Scalar::from_f32(left.to_f32()?.max(right.to_f32()?)),
         FloatTy::F64 => Scalar::from_f64(left.to_f64()?.max(right.to_f64()?)),
+        FloatTy::F128 => Scalar::from_f128(left.to_f128()?.max(right.to_f128()?)),
     })
 }
 
@@ -621,7 +673,9 @@ fn fmin_op<'tcx>(
     let left = left.to_scalar();
     let right = right.to_scalar();
     Ok(match float_ty {
+        FloatTy::F16 => Scalar::from_f16(left.to_f16()?.min(right.to_f16()?)),
         FloatTy::F32 => Scalar::from_f32(left.to_f32()?.min(right.to_f32()?)),
         FloatTy::F64 => Scalar::from_f64(left.to_f64()?.min(right.to_f64()?)),
+        FloatTy::F128 => Scalar::from_f128(left.to_f128()?.min(right.to_f128()?)),
     })
 }
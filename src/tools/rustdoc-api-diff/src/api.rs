@@ -0,0 +1,512 @@
+//! The public API of a crate, as the items reachable from its root and what about them matters
+//! for semver.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use rustc_hash::FxHashSet;
+use rustdoc_json_types::{
+    Crate, Function, GenericParamDefKind, Id, Item, ItemEnum, MacroKind, StructKind, Type,
+    VariantKind, Visibility,
+};
+use serde::Serialize;
+
+use crate::render;
+
+/// Identifies an item of the API across versions: [`Id`]s aren't stable, so items are matched by
+/// the path they are reachable at and their kind.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub(crate) struct ItemPath {
+    pub(crate) path: String,
+    pub(crate) kind: &'static str,
+}
+
+impl fmt::Display for ItemPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.kind, self.path)
+    }
+}
+
+pub(crate) type Api = BTreeMap<ItemPath, ApiItem>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ApiItem {
+    /// The declaration of the item, printed as Rust source.
+    pub(crate) signature: String,
+    pub(crate) details: Details,
+    /// The item this one is a member of, for fields, variants, associated items and impls.
+    pub(crate) parent: Option<ItemPath>,
+    /// Whether implementations of the trait this item belongs to have to define it.
+    pub(crate) required: bool,
+    pub(crate) deprecated: bool,
+    pub(crate) docs: Option<String>,
+}
+
+/// The parts of an item that changes are classified by. Changes to anything else in the
+/// signature are assumed to be breaking.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Details {
+    Function(FnDetails),
+    Struct {
+        /// `"unit"`, `"tuple"` or `"plain"`.
+        kind: &'static str,
+        private_fields: bool,
+        non_exhaustive: bool,
+        generics: Generics,
+    },
+    Union {
+        private_fields: bool,
+        generics: Generics,
+    },
+    Enum {
+        non_exhaustive: bool,
+        generics: Generics,
+    },
+    Variant {
+        non_exhaustive: bool,
+    },
+    Trait {
+        is_unsafe: bool,
+        bounds: BTreeSet<String>,
+        generics: Generics,
+    },
+    Constant {
+        type_: String,
+        value: String,
+    },
+    /// A type alias of a pattern type, e.g. `type Percent = u8 is 0..=100`.
+    PatternType {
+        base: String,
+        pattern: String,
+        generics: Generics,
+    },
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FnDetails {
+    pub(crate) inputs: Vec<String>,
+    pub(crate) output: String,
+    pub(crate) generics: Generics,
+    pub(crate) const_: bool,
+    pub(crate) unsafe_: bool,
+    pub(crate) async_: bool,
+    pub(crate) abi: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Generics {
+    pub(crate) params: Vec<Param>,
+    pub(crate) where_clause: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Param {
+    pub(crate) rendered: String,
+    pub(crate) has_default: bool,
+}
+
+/// Collects the items of `krate` which are reachable from its root module.
+pub(crate) fn public_api(krate: &Crate) -> Api {
+    let mut builder = ApiBuilder { krate, api: Api::new(), modules: FxHashSet::default() };
+    let root = &krate.index[&krate.root];
+    builder.item(root, vec![root.name.clone().unwrap()], None);
+    builder.api
+}
+
+struct ApiBuilder<'a> {
+    krate: &'a Crate,
+    api: Api,
+    /// The modules being walked, to not loop forever on glob re-exports of a parent module.
+    modules: FxHashSet<&'a Id>,
+}
+
+fn child(path: &[String], name: &str) -> Vec<String> {
+    let mut path = path.to_vec();
+    path.push(name.to_string());
+    path
+}
+
+fn generics(generics: &rustdoc_json_types::Generics) -> Generics {
+    Generics {
+        params: render::params(generics)
+            .map(|param| Param {
+                rendered: render::generic_param(param),
+                has_default: match &param.kind {
+                    GenericParamDefKind::Lifetime { .. } => false,
+                    GenericParamDefKind::Type { default, .. } => default.is_some(),
+                    GenericParamDefKind::Const { default, .. } => default.is_some(),
+                },
+            })
+            .collect(),
+        where_clause: generics.where_predicates.iter().map(render::where_predicate).collect(),
+    }
+}
+
+fn fn_details(f: &Function) -> FnDetails {
+    FnDetails {
+        inputs: render::inputs(&f.decl),
+        output: render::output(&f.decl),
+        generics: generics(&f.generics),
+        const_: f.header.const_,
+        unsafe_: f.header.unsafe_,
+        async_: f.header.async_,
+        abi: render::abi(&f.header.abi),
+    }
+}
+
+fn is_non_exhaustive(item: &Item) -> bool {
+    item.attrs.iter().any(|attr| attr == "#[non_exhaustive]")
+}
+
+impl<'a> ApiBuilder<'a> {
+    fn module(&mut self, id: &'a Id, items: &'a [Id], path: &[String]) {
+        if !self.modules.insert(id) {
+            return;
+        }
+        for id in items {
+            let Some(item) = self.krate.index.get(id) else { continue };
+            if let ItemEnum::Import(import) = &item.inner {
+                let target = import.id.as_ref().and_then(|id| self.krate.index.get(id));
+                match target {
+                    Some(Item { id, inner: ItemEnum::Module(module), .. }) if import.glob => {
+                        self.module(id, &module.items, path)
+                    }
+                    Some(target) if !import.glob => {
+                        self.item(target, child(path, &import.name), None)
+                    }
+                    // Re-exports of items from other crates can only be compared by their path.
+                    None if !import.glob => self.insert(
+                        item,
+                        &child(path, &import.name),
+                        "re-export",
+                        format!("pub use {};", import.source),
+                        Details::Other,
+                        None,
+                    ),
+                    _ => {}
+                }
+            } else if item.visibility == Visibility::Public {
+                if let Some(name) = &item.name {
+                    self.item(item, child(path, name), None);
+                }
+            }
+        }
+        self.modules.remove(id);
+    }
+
+    fn insert(
+        &mut self,
+        item: &Item,
+        path: &[String],
+        kind: &'static str,
+        signature: String,
+        details: Details,
+        parent: Option<&ItemPath>,
+    ) {
+        let required = parent.is_some_and(|parent| parent.kind == "trait")
+            && match &item.inner {
+                ItemEnum::Function(f) => !f.has_body,
+                ItemEnum::AssocConst { default, .. } => default.is_none(),
+                ItemEnum::AssocType { default, .. } => default.is_none(),
+                _ => false,
+            };
+        self.api.insert(
+            ItemPath { path: path.join("::"), kind },
+            ApiItem {
+                signature,
+                details,
+                parent: parent.cloned(),
+                required,
+                deprecated: item.deprecation.is_some(),
+                docs: item.docs.clone(),
+            },
+        );
+    }
+
+    fn item(&mut self, item: &'a Item, path: Vec<String>, parent: Option<&ItemPath>) {
+        let name = path.last().unwrap();
+        match &item.inner {
+            ItemEnum::Module(module) => {
+                self.insert(item, &path, "module", format!("mod {name}"), Details::Other, parent);
+                self.module(&item.id, &module.items, &path);
+            }
+            ItemEnum::Struct(s) => {
+                let (params, where_clause) = render::generics(&s.generics);
+                let (kind, fields, private_fields) = match &s.kind {
+                    StructKind::Unit => ("unit", Vec::new(), false),
+                    StructKind::Tuple(fields) => (
+                        "tuple",
+                        fields.iter().flatten().collect(),
+                        fields.iter().any(Option::is_none),
+                    ),
+                    StructKind::Plain { fields, fields_stripped } => {
+                        ("plain", fields.iter().collect(), *fields_stripped)
+                    }
+                };
+                let details = Details::Struct {
+                    kind,
+                    private_fields,
+                    non_exhaustive: is_non_exhaustive(item),
+                    generics: generics(&s.generics),
+                };
+                let key = ItemPath { path: path.join("::"), kind: "struct" };
+                let signature = format!("struct {name}{params}{where_clause}");
+                self.insert(item, &path, key.kind, signature, details, parent);
+                self.fields(fields, &path, &key);
+                self.impls(&s.impls, &path, &key);
+            }
+            ItemEnum::Union(u) => {
+                let (params, where_clause) = render::generics(&u.generics);
+                let details = Details::Union {
+                    private_fields: u.fields_stripped,
+                    generics: generics(&u.generics),
+                };
+                let key = ItemPath { path: path.join("::"), kind: "union" };
+                let signature = format!("union {name}{params}{where_clause}");
+                self.insert(item, &path, key.kind, signature, details, parent);
+                self.fields(u.fields.iter().collect(), &path, &key);
+                self.impls(&u.impls, &path, &key);
+            }
+            ItemEnum::Enum(e) => {
+                let (params, where_clause) = render::generics(&e.generics);
+                let details = Details::Enum {
+                    non_exhaustive: is_non_exhaustive(item),
+                    generics: generics(&e.generics),
+                };
+                let key = ItemPath { path: path.join("::"), kind: "enum" };
+                let signature = format!("enum {name}{params}{where_clause}");
+                self.insert(item, &path, key.kind, signature, details, parent);
+                for variant in e.variants.iter().filter_map(|id| self.krate.index.get(id)) {
+                    if let Some(variant_name) = &variant.name {
+                        self.item(variant, child(&path, variant_name), Some(&key));
+                    }
+                }
+                self.impls(&e.impls, &path, &key);
+            }
+            ItemEnum::Variant(variant) => {
+                let field = |id: &Id| match self.krate.index.get(id) {
+                    Some(Item { name, inner: ItemEnum::StructField(ty), .. }) => {
+                        (name.clone().unwrap_or_default(), render::type_(ty))
+                    }
+                    _ => (String::new(), "_".to_string()),
+                };
+                let mut signature = match &variant.kind {
+                    VariantKind::Plain => name.clone(),
+                    VariantKind::Tuple(fields) => {
+                        let fields = fields
+                            .iter()
+                            .map(|id| id.as_ref().map_or("_".to_string(), |id| field(id).1))
+                            .collect::<Vec<_>>();
+                        format!("{name}({})", fields.join(", "))
+                    }
+                    VariantKind::Struct { fields, fields_stripped } => {
+                        let mut fields = fields
+                            .iter()
+                            .map(|id| {
+                                let (name, ty) = field(id);
+                                format!("{name}: {ty}")
+                            })
+                            .collect::<Vec<_>>();
+                        if *fields_stripped {
+                            fields.push("..".to_string());
+                        }
+                        format!("{name} {{ {} }}", fields.join(", "))
+                    }
+                };
+                if let Some(discriminant) = &variant.discriminant {
+                    signature.push_str(&format!(" = {}", discriminant.value));
+                }
+                let details = Details::Variant { non_exhaustive: is_non_exhaustive(item) };
+                self.insert(item, &path, "variant", signature, details, parent);
+            }
+            ItemEnum::StructField(ty) => {
+                let signature = format!("{name}: {}", render::type_(ty));
+                self.insert(item, &path, "field", signature, Details::Other, parent);
+            }
+            ItemEnum::Function(f) => {
+                let kind = if parent.is_some() { "method" } else { "function" };
+                let signature = render::function(name, f);
+                self.insert(item, &path, kind, signature, Details::Function(fn_details(f)), parent);
+            }
+            ItemEnum::Trait(t) => {
+                let (params, where_clause) = render::generics(&t.generics);
+                let bounds = t.bounds.iter().map(render::bound).collect::<Vec<_>>();
+                let signature = format!(
+                    "{}{}trait {name}{params}{}{where_clause}",
+                    if t.is_unsafe { "unsafe " } else { "" },
+                    if t.is_auto { "auto " } else { "" },
+                    if bounds.is_empty() {
+                        String::new()
+                    } else {
+                        format!(": {}", bounds.join(" + "))
+                    },
+                );
+                let details = Details::Trait {
+                    is_unsafe: t.is_unsafe,
+                    bounds: bounds.into_iter().collect(),
+                    generics: generics(&t.generics),
+                };
+                let key = ItemPath { path: path.join("::"), kind: "trait" };
+                self.insert(item, &path, key.kind, signature, details, parent);
+                for assoc in t.items.iter().filter_map(|id| self.krate.index.get(id)) {
+                    if let Some(assoc_name) = &assoc.name {
+                        self.item(assoc, child(&path, assoc_name), Some(&key));
+                    }
+                }
+            }
+            ItemEnum::TraitAlias(t) => {
+                let (params, where_clause) = render::generics(&t.generics);
+                let bounds = render::bounds(&t.params);
+                let signature = format!("trait {name}{params} = {bounds}{where_clause}");
+                self.insert(item, &path, "trait alias", signature, Details::Other, parent);
+            }
+            ItemEnum::Typedef(t) => {
+                let (params, where_clause) = render::generics(&t.generics);
+                let signature =
+                    format!("type {name}{params}{where_clause} = {}", render::type_(&t.type_));
+                let details = match &t.type_ {
+                    Type::Pat { type_, __pat_unstable_do_not_use: pattern } => {
+                        Details::PatternType {
+                            base: render::type_(type_),
+                            pattern: pattern.clone(),
+                            generics: generics(&t.generics),
+                        }
+                    }
+                    _ => Details::Other,
+                };
+                self.insert(item, &path, "type alias", signature, details, parent);
+            }
+            ItemEnum::OpaqueTy(t) => {
+                let (params, where_clause) = render::generics(&t.generics);
+                let signature = format!(
+                    "type {name}{params}{where_clause} = impl {}",
+                    render::bounds(&t.bounds)
+                );
+                self.insert(item, &path, "opaque type", signature, Details::Other, parent);
+            }
+            ItemEnum::Constant(c) => {
+                let type_ = render::type_(&c.type_);
+                let signature = format!("const {name}: {type_} = {}", c.expr);
+                let value = c.value.clone().unwrap_or_else(|| c.expr.clone());
+                self.insert(
+                    item,
+                    &path,
+                    "constant",
+                    signature,
+                    Details::Constant { type_, value },
+                    parent,
+                );
+            }
+            ItemEnum::Static(s) => {
+                let signature = format!(
+                    "static {}{name}: {}",
+                    if s.mutable { "mut " } else { "" },
+                    render::type_(&s.type_)
+                );
+                self.insert(item, &path, "static", signature, Details::Other, parent);
+            }
+            ItemEnum::ForeignType => {
+                self.insert(
+                    item,
+                    &path,
+                    "foreign type",
+                    format!("type {name}"),
+                    Details::Other,
+                    parent,
+                );
+            }
+            ItemEnum::Macro(_) => {
+                let signature = format!("macro_rules! {name}");
+                self.insert(item, &path, "macro", signature, Details::Other, parent);
+            }
+            ItemEnum::ProcMacro(m) => {
+                let (kind, signature) = match m.kind {
+                    MacroKind::Bang => ("macro", format!("{name}!")),
+                    MacroKind::Attr => ("attribute macro", format!("#[{name}]")),
+                    MacroKind::Derive if m.helpers.is_empty() => {
+                        ("derive macro", format!("#[derive({name})]"))
+                    }
+                    MacroKind::Derive => (
+                        "derive macro",
+                        format!(
+                            "#[derive({name})] with helper attributes {}",
+                            m.helpers.join(", ")
+                        ),
+                    ),
+                };
+                self.insert(item, &path, kind, signature, Details::Other, parent);
+            }
+            ItemEnum::AssocConst { type_, default } => {
+                let type_ = render::type_(type_);
+                let signature = format!("const {name}: {type_}");
+                let details =
+                    Details::Constant { type_, value: default.clone().unwrap_or_default() };
+                self.insert(item, &path, "associated constant", signature, details, parent);
+            }
+            // The default of an associated type only matters for whether implementations have
+            // to define it, which is tracked by `ApiItem::required`.
+            ItemEnum::AssocType { generics, bounds, .. } => {
+                let (params, where_clause) = render::generics(generics);
+                let bounds = if bounds.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", render::bounds(bounds))
+                };
+                let signature = format!("type {name}{params}{bounds}{where_clause}");
+                self.insert(item, &path, "associated type", signature, Details::Other, parent);
+            }
+            ItemEnum::ExternCrate { .. }
+            | ItemEnum::Import(_)
+            | ItemEnum::Impl(_)
+            | ItemEnum::Primitive(_) => {}
+        }
+    }
+
+    fn fields(&mut self, fields: Vec<&'a Id>, path: &[String], parent: &ItemPath) {
+        for field in fields.into_iter().filter_map(|id| self.krate.index.get(id)) {
+            if let Some(name) = &field.name {
+                self.item(field, child(path, name), Some(parent));
+            }
+        }
+    }
+
+    /// Adds the items of the inherent impls of a type as its members, and its trait impls as
+    /// items of their own.
+    fn impls(&mut self, impls: &'a [Id], path: &[String], owner: &ItemPath) {
+        for item in impls.iter().filter_map(|id| self.krate.index.get(id)) {
+            let ItemEnum::Impl(impl_) = &item.inner else { continue };
+            // Blanket impls come and go with the trait impls they are conditional on.
+            if impl_.blanket_impl.is_some() {
+                continue;
+            }
+            let Some(trait_) = &impl_.trait_ else {
+                for assoc in impl_.items.iter().filter_map(|id| self.krate.index.get(id)) {
+                    if let (Some(name), Visibility::Public) = (&assoc.name, &assoc.visibility) {
+                        self.item(assoc, child(path, name), Some(owner));
+                    }
+                }
+                continue;
+            };
+            let (params, where_clause) = render::generics(&impl_.generics);
+            let trait_ =
+                format!("{}{}", if impl_.negative { "!" } else { "" }, render::path(trait_));
+            let signature = format!(
+                "{}impl{params} {trait_} for {}{where_clause}",
+                if impl_.is_unsafe { "unsafe " } else { "" },
+                render::type_(&impl_.for_),
+            );
+            self.api.insert(
+                ItemPath { path: format!("{trait_} for {}", owner.path), kind: "impl" },
+                ApiItem {
+                    signature,
+                    details: Details::Other,
+                    parent: Some(owner.clone()),
+                    required: false,
+                    deprecated: false,
+                    docs: item.docs.clone(),
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Comparison of two versions of an API, following the rules of the [SemVer compatibility]
+//! chapter of the Cargo book.
+//!
+//! [SemVer compatibility]: https://doc.rust-lang.org/cargo/reference/semver.html
+
+use std::fmt;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::api::{Api, ApiItem, Details, FnDetails, Generics, ItemPath};
+
+#[cfg(test)]
+mod tests;
+
+/// The version bump a change requires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Level {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Patch => "patch",
+            Level::Minor => "minor",
+            Level::Major => "major",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct Change {
+    pub(crate) level: Level,
+    pub(crate) item: ItemPath,
+    pub(crate) description: String,
+}
+
+/// Lists the changes from `old` to `new`, the most severe first.
+///
+/// Changes to the members of an item which was added or removed are left out, as they are
+/// implied by the change to the item itself.
+pub(crate) fn diff(old: &Api, new: &Api) -> Vec<Change> {
+    let mut differ = Differ { old, changes: Vec::new() };
+    for (path, old_item) in old {
+        match new.get(path) {
+            Some(new_item) => differ.changed(path, old_item, new_item),
+            None if has_parent_in(old_item, new) => differ.report(Level::Major, path, "removed"),
+            None => {}
+        }
+    }
+    for (path, new_item) in new {
+        if !old.contains_key(path) && has_parent_in(new_item, old) {
+            differ.added(path, new_item);
+        }
+    }
+    let mut changes = differ.changes;
+    changes.sort_by(|a, b| b.level.cmp(&a.level).then_with(|| a.item.cmp(&b.item)));
+    changes
+}
+
+/// Whether `item` isn't a member of another item, or is a member of an item that is in `api`.
+fn has_parent_in(item: &ApiItem, api: &Api) -> bool {
+    match &item.parent {
+        Some(parent) => api.contains_key(parent),
+        None => true,
+    }
+}
+
+struct Differ<'a> {
+    /// Used to check whether the items new members were added to allowed it.
+    old: &'a Api,
+    changes: Vec<Change>,
+}
+
+impl Differ<'_> {
+    fn report(&mut self, level: Level, item: &ItemPath, description: impl Into<String>) {
+        self.changes.push(Change { level, item: item.clone(), description: description.into() });
+    }
+
+    fn added(&mut self, path: &ItemPath, item: &ApiItem) {
+        let parent = item.parent.as_ref().and_then(|parent| self.old.get(parent));
+        match (path.kind, parent.map(|parent| &parent.details)) {
+            // Struct literals and patterns have to list every field.
+            (
+                "field",
+                Some(Details::Struct { private_fields: false, non_exhaustive: false, .. }),
+            ) => self.report(Level::Major, path, "added to a struct with only public fields"),
+            // Matches have to handle every variant.
+            ("variant", Some(Details::Enum { non_exhaustive: false, .. })) => {
+                self.report(Level::Major, path, "added to an enum that isn't `#[non_exhaustive]`")
+            }
+            _ if item.required => {
+                self.report(Level::Major, path, "added to a trait without a default")
+            }
+            _ => self.report(Level::Minor, path, "added"),
+        }
+    }
+
+    fn changed(&mut self, path: &ItemPath, old: &ApiItem, new: &ApiItem) {
+        let reported = self.changes.len();
+
+        match (old.required, new.required) {
+            (false, true) => self.report(Level::Major, path, "no longer has a default"),
+            (true, false) => self.report(Level::Minor, path, "now has a default"),
+            _ => {}
+        }
+
+        match (&old.details, &new.details) {
+            (Details::Function(old), Details::Function(new)) => self.function(path, old, new),
+            (
+                Details::Struct { kind, private_fields, non_exhaustive, generics },
+                Details::Struct {
+                    kind: new_kind,
+                    private_fields: new_private_fields,
+                    non_exhaustive: new_non_exhaustive,
+                    generics: new_generics,
+                },
+            ) => {
+                if kind != new_kind {
+                    self.report(
+                        Level::Major,
+                        path,
+                        format!("changed from a {kind} struct to a {new_kind} struct"),
+                    );
+                }
+                self.private_fields(path, *private_fields, *new_private_fields);
+                self.non_exhaustive(path, *non_exhaustive, *new_non_exhaustive);
+                self.generics(path, generics, new_generics);
+            }
+            (
+                Details::Union { private_fields, generics },
+                Details::Union { private_fields: new_private_fields, generics: new_generics },
+            ) => {
+                self.private_fields(path, *private_fields, *new_private_fields);
+                self.generics(path, generics, new_generics);
+            }
+            (
+                Details::Enum { non_exhaustive, generics },
+                Details::Enum { non_exhaustive: new_non_exhaustive, generics: new_generics },
+            ) => {
+                self.non_exhaustive(path, *non_exhaustive, *new_non_exhaustive);
+                self.generics(path, generics, new_generics);
+            }
+            (
+                Details::Variant { non_exhaustive },
+                Details::Variant { non_exhaustive: new_non_exhaustive },
+            ) => {
+                self.non_exhaustive(path, *non_exhaustive, *new_non_exhaustive);
+                if old.signature != new.signature {
+                    self.report(
+                        Level::Major,
+                        path,
+                        format!("changed from `{}` to `{}`", old.signature, new.signature),
+                    );
+                }
+            }
+            (
+                Details::Trait { is_unsafe, bounds, generics },
+                Details::Trait {
+                    is_unsafe: new_is_unsafe,
+                    bounds: new_bounds,
+                    generics: new_generics,
+                },
+            ) => {
+                // Implementations have to be changed both ways.
+                if is_unsafe != new_is_unsafe {
+                    let description =
+                        if *new_is_unsafe { "now unsafe" } else { "no longer unsafe" };
+                    self.report(Level::Major, path, description);
+                }
+                for bound in new_bounds.difference(bounds) {
+                    self.report(Level::Major, path, format!("now requires `{bound}`"));
+                }
+                for bound in bounds.difference(new_bounds) {
+                    self.report(Level::Minor, path, format!("no longer requires `{bound}`"));
+                }
+                self.generics(path, generics, new_generics);
+            }
+            (
+                Details::Constant { type_, value },
+                Details::Constant { type_: new_type, value: new_value },
+            ) => {
+                if type_ != new_type {
+                    self.report(
+                        Level::Major,
+                        path,
+                        format!("type changed from `{type_}` to `{new_type}`"),
+                    );
+                } else if value != new_value {
+                    self.report(
+                        Level::Minor,
+                        path,
+                        format!("value changed from `{value}` to `{new_value}`"),
+                    );
+                }
+            }
+            (
+                Details::PatternType { base, pattern, generics },
+                Details::PatternType {
+                    base: new_base,
+                    pattern: new_pattern,
+                    generics: new_generics,
+                },
+            ) => {
+                // Narrowing the pattern rejects values that were valid, and widening it removes
+                // niches that the layout of other types may rely on, so both are breaking.
+                if base != new_base {
+                    self.report(
+                        Level::Major,
+                        path,
+                        format!("base type changed from `{base}` to `{new_base}`"),
+                    );
+                }
+                if pattern != new_pattern {
+                    self.report(
+                        Level::Major,
+                        path,
+                        format!("pattern changed from `{pattern}` to `{new_pattern}`"),
+                    );
+                }
+                self.generics(path, generics, new_generics);
+            }
+            _ if old.signature != new.signature => self.report(
+                Level::Major,
+                path,
+                format!("changed from `{}` to `{}`", old.signature, new.signature),
+            ),
+            _ => {}
+        }
+
+        match (old.deprecated, new.deprecated) {
+            (false, true) => self.report(Level::Minor, path, "deprecated"),
+            (true, false) => self.report(Level::Patch, path, "no longer deprecated"),
+            _ => {}
+        }
+
+        if self.changes.len() == reported && old.docs != new.docs {
+            self.report(Level::Patch, path, "documentation changed");
+        }
+    }
+
+    fn function(&mut self, path: &ItemPath, old: &FnDetails, new: &FnDetails) {
+        if old.inputs != new.inputs {
+            self.report(
+                Level::Major,
+                path,
+                format!(
+                    "parameters changed from `({})` to `({})`",
+                    old.inputs.join(", "),
+                    new.inputs.join(", ")
+                ),
+            );
+        }
+        if old.output != new.output {
+            let output = |output: &str| {
+                if output.is_empty() {
+                    "()".to_string()
+                } else {
+                    output[" -> ".len()..].to_string()
+                }
+            };
+            self.report(
+                Level::Major,
+                path,
+                format!(
+                    "return type changed from `{}` to `{}`",
+                    output(&old.output),
+                    output(&new.output)
+                ),
+            );
+        }
+        // Functions can't have defaults for their generic parameters, so any change can break
+        // callers turbofishing them or relying on the bounds.
+        if old.generics != new.generics {
+            self.report(
+                Level::Major,
+                path,
+                format!(
+                    "generics changed from `{}` to `{}`",
+                    generics_to_string(&old.generics),
+                    generics_to_string(&new.generics)
+                ),
+            );
+        }
+        match (old.const_, new.const_) {
+            (true, false) => self.report(Level::Major, path, "no longer const"),
+            (false, true) => self.report(Level::Minor, path, "now const"),
+            _ => {}
+        }
+        match (old.unsafe_, new.unsafe_) {
+            (false, true) => self.report(Level::Major, path, "now unsafe"),
+            (true, false) => self.report(Level::Minor, path, "no longer unsafe"),
+            _ => {}
+        }
+        if old.async_ != new.async_ {
+            let description = if new.async_ { "now async" } else { "no longer async" };
+            self.report(Level::Major, path, description);
+        }
+        if old.abi != new.abi {
+            self.report(
+                Level::Major,
+                path,
+                format!("ABI changed from `{}` to `{}`", old.abi.trim_end(), new.abi.trim_end()),
+            );
+        }
+    }
+
+    fn private_fields(&mut self, path: &ItemPath, old: bool, new: bool) {
+        match (old, new) {
+            (false, true) => {
+                self.report(Level::Major, path, "now has private fields, so it can't be built")
+            }
+            (true, false) => self.report(Level::Minor, path, "no longer has private fields"),
+            _ => {}
+        }
+    }
+
+    fn non_exhaustive(&mut self, path: &ItemPath, old: bool, new: bool) {
+        match (old, new) {
+            (false, true) => self.report(Level::Major, path, "now `#[non_exhaustive]`"),
+            (true, false) => self.report(Level::Minor, path, "no longer `#[non_exhaustive]`"),
+            _ => {}
+        }
+    }
+
+    /// Adding generic parameters with defaults to types and traits is allowed, any other change
+    /// is assumed to be breaking.
+    fn generics(&mut self, path: &ItemPath, old: &Generics, new: &Generics) {
+        if old == new {
+            return;
+        }
+        let defaulted_params_added = old.where_clause == new.where_clause
+            && new.params.starts_with(&old.params)
+            && new.params[old.params.len()..].iter().all(|param| param.has_default);
+        let level = if defaulted_params_added { Level::Minor } else { Level::Major };
+        self.report(
+            level,
+            path,
+            format!(
+                "generics changed from `{}` to `{}`",
+                generics_to_string(old),
+                generics_to_string(new)
+            ),
+        );
+    }
+}
+
+fn generics_to_string(generics: &Generics) -> String {
+    let params = generics.params.iter().map(|param| &param.rendered[..]).collect::<Vec<_>>();
+    let mut s = format!("<{}>", params.join(", "));
+    if !generics.where_clause.is_empty() {
+        s.push_str(&format!(" where {}", generics.where_clause.join(", ")));
+    }
+    s
+}
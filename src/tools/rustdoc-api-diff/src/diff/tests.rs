@@ -0,0 +1,272 @@
+use crate::api::{FnDetails, Param};
+
+use super::*;
+
+fn path(kind: &'static str, path: &str) -> ItemPath {
+    ItemPath { path: path.to_owned(), kind }
+}
+
+fn item(signature: &str, details: Details) -> ApiItem {
+    ApiItem {
+        signature: signature.to_owned(),
+        details,
+        parent: None,
+        required: false,
+        deprecated: false,
+        docs: None,
+    }
+}
+
+fn member(signature: &str, details: Details, parent: &ItemPath) -> ApiItem {
+    ApiItem { parent: Some(parent.clone()), ..item(signature, details) }
+}
+
+fn function(inputs: &[&str]) -> Details {
+    Details::Function(FnDetails {
+        inputs: inputs.iter().map(|input| input.to_string()).collect(),
+        output: String::new(),
+        generics: Generics::default(),
+        const_: false,
+        unsafe_: false,
+        async_: false,
+        abi: String::new(),
+    })
+}
+
+fn enum_(non_exhaustive: bool) -> Details {
+    Details::Enum { non_exhaustive, generics: Generics::default() }
+}
+
+fn plain_struct(private_fields: bool) -> Details {
+    Details::Struct {
+        kind: "plain",
+        private_fields,
+        non_exhaustive: false,
+        generics: Generics::default(),
+    }
+}
+
+fn trait_() -> Details {
+    Details::Trait { is_unsafe: false, bounds: Default::default(), generics: Generics::default() }
+}
+
+#[track_caller]
+fn check(old: Api, new: Api, changes: &[(Level, ItemPath, &str)]) {
+    let changes = changes
+        .iter()
+        .map(|(level, item, description)| Change {
+            level: *level,
+            item: item.clone(),
+            description: description.to_string(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(diff(&old, &new), changes);
+}
+
+#[test]
+fn removed_and_added_functions() {
+    let foo = path("function", "krate::foo");
+    let bar = path("function", "krate::bar");
+    check(
+        Api::from_iter([(foo.clone(), item("fn foo()", function(&[])))]),
+        Api::from_iter([(bar.clone(), item("fn bar()", function(&[])))]),
+        &[(Level::Major, foo, "removed"), (Level::Minor, bar, "added")],
+    );
+}
+
+#[test]
+fn changed_function_signature() {
+    let foo = path("function", "krate::foo");
+    let Details::Function(old) = function(&["u8"]) else { unreachable!() };
+    let new = FnDetails { inputs: vec!["u16".to_owned()], const_: true, ..old.clone() };
+    check(
+        Api::from_iter([(foo.clone(), item("fn foo(x: u8)", Details::Function(old)))]),
+        Api::from_iter([(foo.clone(), item("const fn foo(x: u16)", Details::Function(new)))]),
+        &[
+            (Level::Major, foo.clone(), "parameters changed from `(u8)` to `(u16)`"),
+            (Level::Minor, foo, "now const"),
+        ],
+    );
+}
+
+#[test]
+fn new_trait_items() {
+    let tr = path("trait", "krate::Tr");
+    let required = path("method", "krate::Tr::required");
+    let provided = path("method", "krate::Tr::provided");
+    check(
+        Api::from_iter([(tr.clone(), item("trait Tr", trait_()))]),
+        Api::from_iter([
+            (tr.clone(), item("trait Tr", trait_())),
+            (
+                required.clone(),
+                ApiItem { required: true, ..member("fn required()", function(&[]), &tr) },
+            ),
+            (provided.clone(), member("fn provided()", function(&[]), &tr)),
+        ]),
+        &[
+            (Level::Major, required, "added to a trait without a default"),
+            (Level::Minor, provided, "added"),
+        ],
+    );
+}
+
+#[test]
+fn non_exhaustive_enums() {
+    let e = path("enum", "krate::E");
+    let variant = path("variant", "krate::E::B");
+    check(
+        Api::from_iter([(e.clone(), item("enum E", enum_(true)))]),
+        Api::from_iter([
+            (e.clone(), item("enum E", enum_(false))),
+            (variant.clone(), member("B", Details::Variant { non_exhaustive: false }, &e)),
+        ]),
+        &[
+            (Level::Minor, e.clone(), "no longer `#[non_exhaustive]`"),
+            (Level::Minor, variant.clone(), "added"),
+        ],
+    );
+
+    check(
+        Api::from_iter([(e.clone(), item("enum E", enum_(false)))]),
+        Api::from_iter([
+            (e.clone(), item("enum E", enum_(true))),
+            (variant.clone(), member("B", Details::Variant { non_exhaustive: false }, &e)),
+        ]),
+        &[
+            (Level::Major, e, "now `#[non_exhaustive]`"),
+            (Level::Major, variant, "added to an enum that isn't `#[non_exhaustive]`"),
+        ],
+    );
+}
+
+#[test]
+fn new_struct_fields() {
+    let s = path("struct", "krate::S");
+    let field = path("field", "krate::S::y");
+    check(
+        Api::from_iter([(s.clone(), item("struct S", plain_struct(false)))]),
+        Api::from_iter([
+            (s.clone(), item("struct S", plain_struct(false))),
+            (field.clone(), member("y: u8", Details::Other, &s)),
+        ]),
+        &[(Level::Major, field.clone(), "added to a struct with only public fields")],
+    );
+    check(
+        Api::from_iter([(s.clone(), item("struct S", plain_struct(true)))]),
+        Api::from_iter([
+            (s.clone(), item("struct S", plain_struct(true))),
+            (field.clone(), member("y: u8", Details::Other, &s)),
+        ]),
+        &[(Level::Minor, field, "added")],
+    );
+}
+
+#[test]
+fn members_of_removed_items() {
+    let s = path("struct", "krate::S");
+    let field = path("field", "krate::S::x");
+    let clone = path("impl", "Clone for krate::S");
+    check(
+        Api::from_iter([
+            (s.clone(), item("struct S", plain_struct(false))),
+            (field.clone(), member("x: u8", Details::Other, &s)),
+            (clone.clone(), member("impl Clone for S", Details::Other, &s)),
+        ]),
+        Api::new(),
+        &[(Level::Major, s, "removed")],
+    );
+}
+
+#[test]
+fn removed_trait_impl() {
+    let s = path("struct", "krate::S");
+    let send = path("impl", "Send for krate::S");
+    check(
+        Api::from_iter([
+            (s.clone(), item("struct S", plain_struct(true))),
+            (send.clone(), member("impl Send for S", Details::Other, &s)),
+        ]),
+        Api::from_iter([(s.clone(), item("struct S", plain_struct(true)))]),
+        &[(Level::Major, send, "removed")],
+    );
+}
+
+#[test]
+fn defaulted_generic_params() {
+    let s = path("struct", "krate::S");
+    let with_params = |params: &[(&str, bool)]| Details::Struct {
+        kind: "plain",
+        private_fields: true,
+        non_exhaustive: false,
+        generics: Generics {
+            params: params
+                .iter()
+                .map(|(rendered, has_default)| Param {
+                    rendered: rendered.to_string(),
+                    has_default: *has_default,
+                })
+                .collect(),
+            where_clause: vec![],
+        },
+    };
+    check(
+        Api::from_iter([(s.clone(), item("struct S<T>", with_params(&[("T", false)])))]),
+        Api::from_iter([(
+            s.clone(),
+            item("struct S<T, A = Global>", with_params(&[("T", false), ("A = Global", true)])),
+        )]),
+        &[(Level::Minor, s.clone(), "generics changed from `<T>` to `<T, A = Global>`")],
+    );
+    check(
+        Api::from_iter([(s.clone(), item("struct S<T>", with_params(&[("T", false)])))]),
+        Api::from_iter([(
+            s.clone(),
+            item("struct S<T, U>", with_params(&[("T", false), ("U", false)])),
+        )]),
+        &[(Level::Major, s, "generics changed from `<T>` to `<T, U>`")],
+    );
+}
+
+#[test]
+fn documentation_changes() {
+    let foo = path("function", "krate::foo");
+    check(
+        Api::from_iter([(foo.clone(), item("fn foo()", function(&[])))]),
+        Api::from_iter([(
+            foo.clone(),
+            ApiItem { docs: Some("Does foo.".to_owned()), ..item("fn foo()", function(&[])) },
+        )]),
+        &[(Level::Patch, foo, "documentation changed")],
+    );
+}
+
+#[test]
+fn pattern_types() {
+    let percent = path("type alias", "krate::Percent");
+    let pattern_type = |base: &str, pattern: &str| {
+        item(
+            &format!("type Percent = {base} is {pattern}"),
+            Details::PatternType {
+                base: base.to_owned(),
+                pattern: pattern.to_owned(),
+                generics: Generics::default(),
+            },
+        )
+    };
+    check(
+        Api::from_iter([(percent.clone(), pattern_type("u8", "0..=100"))]),
+        Api::from_iter([(percent.clone(), pattern_type("u8", "0..=100"))]),
+        &[],
+    );
+    check(
+        Api::from_iter([(percent.clone(), pattern_type("u8", "0..=100"))]),
+        Api::from_iter([(percent.clone(), pattern_type("u8", "0..=200"))]),
+        &[(Level::Major, percent.clone(), "pattern changed from `0..=100` to `0..=200`")],
+    );
+    check(
+        Api::from_iter([(percent.clone(), pattern_type("u8", "0..=100"))]),
+        Api::from_iter([(percent.clone(), pattern_type("u16", "0..=100"))]),
+        &[(Level::Major, percent, "base type changed from `u8` to `u16`")],
+    );
+}
@@ -0,0 +1,84 @@
+//! Compares the rustdoc JSON output for two versions of a crate, and reports the changes to its
+//! public API along with the version bump they require.
+
+use std::io::{BufWriter, Write};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use fs_err as fs;
+use rustdoc_json_types::{Crate, FORMAT_VERSION};
+use serde::Serialize;
+
+mod api;
+mod diff;
+mod render;
+
+use diff::{Change, Level};
+
+#[derive(Debug, Serialize)]
+struct JsonOutput {
+    old: String,
+    new: String,
+    /// The version bump required by the changes, if there are any.
+    required: Option<Level>,
+    changes: Vec<Change>,
+}
+
+#[derive(Parser)]
+struct Cli {
+    /// The path to the json output for the old version of the crate
+    old: String,
+
+    /// The path to the json output for the new version of the crate
+    new: String,
+
+    /// Fail if the changes require a bigger version bump than this one
+    #[arg(long, value_enum)]
+    allowed: Option<Level>,
+
+    #[arg(long)]
+    json_output: Option<String>,
+}
+
+fn load(path: &str) -> Result<Crate> {
+    let krate: Crate = serde_json::from_str(&fs::read_to_string(path)?)?;
+    if krate.format_version != FORMAT_VERSION {
+        bail!(
+            "{path} has format version {}, but only version {FORMAT_VERSION} is supported",
+            krate.format_version
+        );
+    }
+    Ok(krate)
+}
+
+fn main() -> Result<()> {
+    let Cli { old, new, allowed, json_output } = Cli::parse();
+
+    let old_api = api::public_api(&load(&old)?);
+    let new_api = api::public_api(&load(&new)?);
+    let changes = diff::diff(&old_api, &new_api);
+    let required = changes.iter().map(|change| change.level).max();
+
+    for change in &changes {
+        println!("{}: {} {}", change.level, change.item, change.description);
+    }
+    match required {
+        Some(level) => println!("required version bump: {level}"),
+        None => println!("no changes to the public API"),
+    }
+
+    if let Some(json_output) = json_output {
+        let output = JsonOutput { old, new, required, changes };
+        let mut f = BufWriter::new(fs::File::create(json_output)?);
+        serde_json::to_writer(&mut f, &output)?;
+        f.flush()?;
+    }
+
+    if let (Some(allowed), Some(required)) = (allowed, required) {
+        if required > allowed {
+            bail!("the changes require a {required} version bump, but only {allowed} is allowed");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+//! Printing of types and signatures as Rust source.
+//!
+//! The printed form is what gets compared between the two versions of a crate, as the [`Id`]s
+//! in the JSON output aren't stable across compilations.
+//!
+//! [`Id`]: rustdoc_json_types::Id
+
+use rustdoc_json_types::{
+    Abi, FnDecl, Function, GenericArg, GenericArgs, GenericBound, GenericParamDef,
+    GenericParamDefKind, Generics, Header, Path, Term, TraitBoundModifier, Type, TypeBindingKind,
+    WherePredicate,
+};
+
+pub(crate) fn type_(ty: &Type) -> String {
+    match ty {
+        Type::ResolvedPath(p) => path(p),
+        Type::DynTrait(dyn_trait) => {
+            let mut parts = dyn_trait
+                .traits
+                .iter()
+                .map(|poly| format!("{}{}", hrtb(&poly.generic_params), path(&poly.trait_)))
+                .collect::<Vec<_>>();
+            parts.extend(dyn_trait.lifetime.clone());
+            format!("dyn {}", parts.join(" + "))
+        }
+        Type::Generic(name) | Type::Primitive(name) => name.clone(),
+        Type::FunctionPointer(fn_ptr) => format!(
+            "{}{}fn({}){}",
+            hrtb(&fn_ptr.generic_params),
+            header(&fn_ptr.header),
+            inputs(&fn_ptr.decl).join(", "),
+            output(&fn_ptr.decl),
+        ),
+        Type::Tuple(types) if types.len() == 1 => format!("({},)", type_(&types[0])),
+        Type::Tuple(types) => {
+            format!("({})", types.iter().map(type_).collect::<Vec<_>>().join(", "))
+        }
+        Type::Slice(ty) => format!("[{}]", type_(ty)),
+        Type::Array { type_: ty, len } => format!("[{}; {len}]", type_(ty)),
+        Type::Pat { type_: ty, __pat_unstable_do_not_use: pat } => {
+            format!("{} is {pat}", type_(ty))
+        }
+        Type::ImplTrait(b) => format!("impl {}", bounds(b)),
+        Type::Infer => "_".to_string(),
+        Type::RawPointer { mutable, type_: ty } => {
+            format!("*{} {}", if *mutable { "mut" } else { "const" }, type_(ty))
+        }
+        Type::BorrowedRef { lifetime, mutable, type_: ty } => format!(
+            "&{}{}{}",
+            lifetime.as_ref().map(|lt| format!("{lt} ")).unwrap_or_default(),
+            if *mutable { "mut " } else { "" },
+            type_(ty),
+        ),
+        Type::QualifiedPath { name, args, self_type, trait_ } => {
+            let self_type = match trait_ {
+                Some(trait_) => format!("<{} as {}>", type_(self_type), path(trait_)),
+                None => type_(self_type),
+            };
+            format!("{self_type}::{name}{}", generic_args(args))
+        }
+    }
+}
+
+pub(crate) fn path(p: &Path) -> String {
+    format!("{}{}", p.name, p.args.as_deref().map(generic_args).unwrap_or_default())
+}
+
+fn generic_args(args: &GenericArgs) -> String {
+    match args {
+        GenericArgs::AngleBracketed { args, bindings } => {
+            let mut parts = args
+                .iter()
+                .map(|arg| match arg {
+                    GenericArg::Lifetime(lt) => lt.clone(),
+                    GenericArg::Type(ty) => type_(ty),
+                    GenericArg::Const(c) => c.expr.clone(),
+                    GenericArg::Infer => "_".to_string(),
+                })
+                .collect::<Vec<_>>();
+            parts.extend(bindings.iter().map(|binding| {
+                let name = format!("{}{}", binding.name, generic_args(&binding.args));
+                match &binding.binding {
+                    TypeBindingKind::Equality(t) => format!("{name} = {}", term(t)),
+                    TypeBindingKind::Constraint(b) => format!("{name}: {}", bounds(b)),
+                }
+            }));
+            if parts.is_empty() { String::new() } else { format!("<{}>", parts.join(", ")) }
+        }
+        GenericArgs::Parenthesized { inputs, output } => format!(
+            "({}){}",
+            inputs.iter().map(type_).collect::<Vec<_>>().join(", "),
+            output.as_ref().map(|ty| format!(" -> {}", type_(ty))).unwrap_or_default(),
+        ),
+    }
+}
+
+fn term(t: &Term) -> String {
+    match t {
+        Term::Type(ty) => type_(ty),
+        Term::Constant(c) => c.expr.clone(),
+    }
+}
+
+pub(crate) fn bound(b: &GenericBound) -> String {
+    match b {
+        GenericBound::TraitBound { trait_, generic_params, modifier } => {
+            let modifier = match modifier {
+                TraitBoundModifier::None => "",
+                TraitBoundModifier::Maybe => "?",
+                TraitBoundModifier::MaybeConst => "~const ",
+            };
+            format!("{}{modifier}{}", hrtb(generic_params), path(trait_))
+        }
+        GenericBound::Outlives(lt) => lt.clone(),
+    }
+}
+
+pub(crate) fn bounds(b: &[GenericBound]) -> String {
+    b.iter().map(bound).collect::<Vec<_>>().join(" + ")
+}
+
+fn hrtb(params: &[GenericParamDef]) -> String {
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("for<{}> ", params.iter().map(generic_param).collect::<Vec<_>>().join(", "))
+    }
+}
+
+pub(crate) fn generic_param(param: &GenericParamDef) -> String {
+    match &param.kind {
+        GenericParamDefKind::Lifetime { outlives } if outlives.is_empty() => param.name.clone(),
+        GenericParamDefKind::Lifetime { outlives } => {
+            format!("{}: {}", param.name, outlives.join(" + "))
+        }
+        GenericParamDefKind::Type { bounds: b, default, .. } => {
+            let mut s = param.name.clone();
+            if !b.is_empty() {
+                s.push_str(&format!(": {}", bounds(b)));
+            }
+            if let Some(default) = default {
+                s.push_str(&format!(" = {}", type_(default)));
+            }
+            s
+        }
+        GenericParamDefKind::Const { type_: ty, default } => {
+            let mut s = format!("const {}: {}", param.name, type_(ty));
+            if let Some(default) = default {
+                s.push_str(&format!(" = {default}"));
+            }
+            s
+        }
+    }
+}
+
+/// The generic parameters written in the source, leaving out the ones introduced for
+/// `impl Trait` arguments.
+pub(crate) fn params(generics: &Generics) -> impl Iterator<Item = &GenericParamDef> {
+    generics
+        .params
+        .iter()
+        .filter(|param| !matches!(param.kind, GenericParamDefKind::Type { synthetic: true, .. }))
+}
+
+pub(crate) fn where_predicate(pred: &WherePredicate) -> String {
+    match pred {
+        WherePredicate::BoundPredicate { type_: ty, bounds: b, generic_params } => {
+            format!("{}{}: {}", hrtb(generic_params), type_(ty), bounds(b))
+        }
+        WherePredicate::RegionPredicate { lifetime, bounds: b } => {
+            format!("{lifetime}: {}", bounds(b))
+        }
+        WherePredicate::EqPredicate { lhs, rhs } => format!("{} == {}", type_(lhs), term(rhs)),
+    }
+}
+
+/// Prints `generics` as they appear after the name of an item, and its where clause.
+pub(crate) fn generics(generics: &Generics) -> (String, String) {
+    let params = params(generics).map(generic_param).collect::<Vec<_>>();
+    let params = if params.is_empty() { String::new() } else { format!("<{}>", params.join(", ")) };
+    let where_clause = if generics.where_predicates.is_empty() {
+        String::new()
+    } else {
+        let preds = generics.where_predicates.iter().map(where_predicate).collect::<Vec<_>>();
+        format!(" where {}", preds.join(", "))
+    };
+    (params, where_clause)
+}
+
+pub(crate) fn abi(abi: &Abi) -> String {
+    let (name, unwind) = match abi {
+        Abi::Rust => return String::new(),
+        Abi::C { unwind } => ("C", unwind),
+        Abi::Cdecl { unwind } => ("cdecl", unwind),
+        Abi::Stdcall { unwind } => ("stdcall", unwind),
+        Abi::Fastcall { unwind } => ("fastcall", unwind),
+        Abi::Aapcs { unwind } => ("aapcs", unwind),
+        Abi::Win64 { unwind } => ("win64", unwind),
+        Abi::SysV64 { unwind } => ("sysv64", unwind),
+        Abi::System { unwind } => ("system", unwind),
+        Abi::Other(name) => return format!("extern {name:?} "),
+    };
+    format!("extern \"{name}{}\" ", if *unwind { "-unwind" } else { "" })
+}
+
+fn header(header: &Header) -> String {
+    format!(
+        "{}{}{}{}",
+        if header.const_ { "const " } else { "" },
+        if header.async_ { "async " } else { "" },
+        if header.unsafe_ { "unsafe " } else { "" },
+        abi(&header.abi),
+    )
+}
+
+/// The types of the parameters of a function, ignoring the names (or patterns) of the
+/// parameters, which aren't part of its API.
+pub(crate) fn inputs(decl: &FnDecl) -> Vec<String> {
+    let mut inputs = decl.inputs.iter().map(|(_, ty)| type_(ty)).collect::<Vec<_>>();
+    if decl.c_variadic {
+        inputs.push("...".to_string());
+    }
+    inputs
+}
+
+pub(crate) fn output(decl: &FnDecl) -> String {
+    decl.output.as_ref().map(|ty| format!(" -> {}", type_(ty))).unwrap_or_default()
+}
+
+pub(crate) fn function(name: &str, f: &Function) -> String {
+    let (params, where_clause) = generics(&f.generics);
+    let inputs = f
+        .decl
+        .inputs
+        .iter()
+        .map(|(name, ty)| format!("{name}: {}", type_(ty)))
+        .chain(f.decl.c_variadic.then(|| "...".to_string()))
+        .collect::<Vec<_>>();
+    format!(
+        "{}fn {name}{params}({}){}{where_clause}",
+        header(&f.header),
+        inputs.join(", "),
+        output(&f.decl),
+    )
+}
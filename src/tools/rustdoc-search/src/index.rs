@@ -0,0 +1,303 @@
+//! Loading of the `search-index.js` file written by rustdoc's HTML backend.
+//!
+//! The format is produced by `build_index` in `librustdoc/html/render/search_index.rs`, and read
+//! by `buildIndex` in `librustdoc/html/static/js/search.js`, which this mirrors.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use fs_err as fs;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+
+/// The names of the item types, indexed by the discriminants of `ItemType` in rustdoc.
+pub(crate) const ITEM_TYPES: &[&str] = &[
+    "mod",
+    "externcrate",
+    "import",
+    "struct",
+    "enum",
+    "fn",
+    "type",
+    "static",
+    "trait",
+    "impl",
+    "tymethod",
+    "method",
+    "structfield",
+    "variant",
+    "macro",
+    "primitive",
+    "associatedtype",
+    "constant",
+    "associatedconstant",
+    "union",
+    "foreigntype",
+    "keyword",
+    "existential",
+    "attr",
+    "derive",
+    "traitalias",
+];
+
+pub(crate) fn item_type(name: &str) -> Option<u8> {
+    ITEM_TYPES.iter().position(|ty| *ty == name).map(|ty| ty as u8)
+}
+
+pub(crate) const TY_EXTERN_CRATE: u8 = 1;
+pub(crate) const TY_PRIMITIVE: u8 = 15;
+pub(crate) const TY_KEYWORD: u8 = 21;
+
+#[derive(Debug, Default)]
+pub(crate) struct Index {
+    /// The items of all crates. Each crate is preceded by a row for the crate itself.
+    pub(crate) rows: Vec<Row>,
+    /// For each crate, the rows having an alias, by lowercased alias.
+    pub(crate) aliases: BTreeMap<String, BTreeMap<String, Vec<usize>>>,
+    /// The lowercased names of the types appearing in function signatures.
+    pub(crate) type_names: Vec<String>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Row {
+    pub(crate) krate: String,
+    pub(crate) ty: u8,
+    pub(crate) name: String,
+    /// The path of the module the item is in, or of the module its parent is in.
+    pub(crate) path: String,
+    /// The summary of the documentation, as HTML.
+    pub(crate) desc: String,
+    pub(crate) parent: Option<Parent>,
+    pub(crate) type_: Option<FunctionType>,
+    /// The lowercased name, which queries are matched against.
+    pub(crate) word: String,
+    /// `word` without underscores.
+    pub(crate) normalized_name: String,
+    pub(crate) deprecated: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Parent {
+    pub(crate) ty: u8,
+    pub(crate) name: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct FunctionType {
+    pub(crate) inputs: Vec<TypeRef>,
+    pub(crate) output: Vec<TypeRef>,
+}
+
+/// A type in a function signature.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct TypeRef {
+    /// The lowercased name of the type, or `None` for type parameters, which are only known by
+    /// their bounds in `generics`.
+    pub(crate) name: Option<String>,
+    pub(crate) ty: Option<u8>,
+    pub(crate) generics: Vec<TypeRef>,
+}
+
+/// The data of a crate, as serialized by `CrateData` in rustdoc.
+#[derive(Deserialize)]
+struct RawCrate {
+    doc: String,
+    t: String,
+    n: Vec<String>,
+    q: Vec<(usize, String)>,
+    d: Vec<String>,
+    i: Vec<usize>,
+    f: Vec<RawFunctionType>,
+    c: Vec<usize>,
+    p: Vec<(u8, String)>,
+    #[serde(default)]
+    a: BTreeMap<String, Vec<usize>>,
+}
+
+/// The inputs and, unless there are none, the outputs, or `0` if the signature couldn't be
+/// indexed.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawFunctionType {
+    Signature(Vec<RawTypes>),
+    Missing(IgnoredAny),
+}
+
+/// A single type without generics is written as just its index in `p`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawTypes {
+    One(usize),
+    List(Vec<RawType>),
+}
+
+/// The index of the type in `p` plus one, or `0` for type parameters, and its generics if any.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawType {
+    Path(usize),
+    Generic(usize, Vec<RawType>),
+}
+
+/// Finds the search index in a directory of generated documentation.
+pub(crate) fn find(doc_dir: &Path) -> Result<PathBuf> {
+    let mut candidates = Vec::new();
+    for entry in fs::read_dir(doc_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        // The name contains the `--resource-suffix`, if any.
+        if name.starts_with("search-index") && name.ends_with(".js") {
+            candidates.push((entry.metadata()?.modified()?, entry.path()));
+        }
+    }
+    match candidates.into_iter().max() {
+        Some((_, path)) => Ok(path),
+        None => bail!(
+            "no search index in {}, was it generated by rustdoc's HTML backend?",
+            doc_dir.display()
+        ),
+    }
+}
+
+pub(crate) fn load(path: &Path) -> Result<Index> {
+    parse(&fs::read_to_string(path)?)
+        .with_context(|| format!("invalid search index {}", path.display()))
+}
+
+/// Undoes the escaping done to embed JSON in a JavaScript string literal.
+fn unescape_js(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                // Line continuation.
+                Some('\n') | None => {}
+                Some(c) => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Converts the types of a function signature, with `type_ref` building a type out of its index
+/// in `p` and its generics.
+fn convert_types(
+    raw: RawTypes,
+    type_ref: &mut impl FnMut(usize, Vec<TypeRef>) -> Result<TypeRef>,
+) -> Result<Vec<TypeRef>> {
+    match raw {
+        RawTypes::One(index) => Ok(vec![type_ref(index, Vec::new())?]),
+        RawTypes::List(list) => list.into_iter().map(|raw| convert_type(raw, type_ref)).collect(),
+    }
+}
+
+fn convert_type(
+    raw: RawType,
+    type_ref: &mut impl FnMut(usize, Vec<TypeRef>) -> Result<TypeRef>,
+) -> Result<TypeRef> {
+    match raw {
+        RawType::Path(index) => type_ref(index, Vec::new()),
+        RawType::Generic(index, generics) => {
+            let generics = generics
+                .into_iter()
+                .map(|generic| convert_type(generic, type_ref))
+                .collect::<Result<_>>()?;
+            type_ref(index, generics)
+        }
+    }
+}
+
+pub(crate) fn parse(js: &str) -> Result<Index> {
+    const START: &str = "JSON.parse('";
+    let start = js.find(START).context("`JSON.parse` call not found")? + START.len();
+    let end = start + js[start..].find("');").context("unterminated `JSON.parse` call")?;
+    let crates: BTreeMap<String, RawCrate> = serde_json::from_str(&unescape_js(&js[start..end]))?;
+
+    let mut index = Index::default();
+    let mut type_names = FxHashSet::default();
+    for (krate, raw) in crates {
+        index.rows.push(Row {
+            krate: krate.clone(),
+            ty: TY_EXTERN_CRATE,
+            name: krate.clone(),
+            path: String::new(),
+            desc: raw.doc,
+            parent: None,
+            type_: None,
+            normalized_name: krate.replace('_', ""),
+            word: krate.clone(),
+            deprecated: false,
+        });
+        let first_item = index.rows.len();
+
+        let paths = raw.p.into_iter().map(|(ty, name)| Parent { ty, name }).collect::<Vec<_>>();
+        let mut type_ref = |path_index: usize, generics: Vec<TypeRef>| -> Result<TypeRef> {
+            if path_index == 0 {
+                return Ok(TypeRef { name: None, ty: None, generics });
+            }
+            let path = paths.get(path_index - 1).context("type index out of bounds")?;
+            let name = path.name.to_lowercase();
+            if type_names.insert(name.clone()) {
+                index.type_names.push(name.clone());
+            }
+            Ok(TypeRef { name: Some(name), ty: Some(path.ty), generics })
+        };
+        let item_paths = raw.q.into_iter().collect::<BTreeMap<_, _>>();
+        let deprecated = raw.c;
+        let mut last_path = String::new();
+        let mut functions = raw.f.into_iter();
+        for (i, (ty, name)) in raw.t.bytes().zip(raw.n).enumerate() {
+            let type_ = match functions.next() {
+                Some(RawFunctionType::Signature(signature)) => {
+                    let mut signature = signature.into_iter();
+                    let inputs = match signature.next() {
+                        Some(inputs) => convert_types(inputs, &mut type_ref)?,
+                        None => Vec::new(),
+                    };
+                    let output = match signature.next() {
+                        Some(output) => convert_types(output, &mut type_ref)?,
+                        None => Vec::new(),
+                    };
+                    Some(FunctionType { inputs, output })
+                }
+                Some(RawFunctionType::Missing(_)) | None => None,
+            };
+            if let Some(path) = item_paths.get(&i) {
+                last_path = path.clone();
+            }
+            let word = name.to_lowercase();
+            index.rows.push(Row {
+                krate: krate.clone(),
+                ty: ty.checked_sub(b'A').context("invalid item type")?,
+                name,
+                path: last_path.clone(),
+                desc: raw.d.get(i).cloned().unwrap_or_default(),
+                parent: raw
+                    .i
+                    .get(i)
+                    .and_then(|&parent| parent.checked_sub(1))
+                    .and_then(|parent| paths.get(parent))
+                    .cloned(),
+                type_,
+                normalized_name: word.replace('_', ""),
+                word,
+                deprecated: deprecated.contains(&i),
+            });
+        }
+
+        let aliases = raw
+            .a
+            .into_iter()
+            .map(|(alias, items)| {
+                (alias, items.into_iter().map(|item| item + first_item).collect())
+            })
+            .collect();
+        index.aliases.insert(krate, aliases);
+    }
+    Ok(index)
+}
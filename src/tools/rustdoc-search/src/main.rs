@@ -0,0 +1,134 @@
+//! Searches documentation generated by rustdoc from the command line, using the same index and
+//! query language as the search bar of the HTML pages.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use serde::Serialize;
+
+mod index;
+mod query;
+mod search;
+
+use search::{Results, SearchResult};
+
+#[derive(Parser)]
+struct Cli {
+    /// The directory the documentation was generated in, containing `search-index.js`
+    doc_dir: PathBuf,
+
+    /// The query, for example `Vec::new`, `fn:push` or `u8 -> String`
+    query: String,
+
+    /// Only search the items of this crate
+    #[arg(long = "crate")]
+    krate: Option<String>,
+
+    /// Print the results as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    query: &'a str,
+    error: Option<&'a str>,
+    #[serde(flatten)]
+    results: &'a Results,
+}
+
+/// Turns a summary, which is HTML, into text, keeping inline code in backticks.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        if tag == "code" || tag == "/code" {
+            text.push('`');
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn print_tab(out: &mut impl Write, title: &str, results: &[SearchResult]) -> io::Result<()> {
+    writeln!(out, "{title} ({})", results.len())?;
+    if results.is_empty() {
+        writeln!(out, "    No results :(")?;
+    }
+    for result in results {
+        let mut line = format!("    {} {}{}", result.ty, result.display_path, result.name);
+        if let Some(alias) = &result.alias {
+            line.push_str(&format!(" (alias: {alias})"));
+        }
+        if result.deprecated {
+            line.push_str(" (deprecated)");
+        }
+        let desc = html_to_text(&result.desc);
+        if !desc.is_empty() {
+            line.push_str(&format!(" - {desc}"));
+        }
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out)
+}
+
+fn main() -> Result<()> {
+    let Cli { doc_dir, query, krate, json } = Cli::parse();
+
+    let index = index::load(&index::find(&doc_dir)?)?;
+    let parsed = query::parse_query(&query);
+    let results = search::exec_query(&index, &parsed, krate.as_deref());
+
+    let mut out = io::stdout().lock();
+    if json {
+        let output =
+            JsonOutput { query: &query, error: parsed.error.as_deref(), results: &results };
+        serde_json::to_writer_pretty(&mut out, &output)?;
+        writeln!(out)?;
+        return Ok(());
+    }
+
+    if let Some(error) = &parsed.error {
+        writeln!(out, "Query parser error: \"{error}\".")?;
+        if results.others.is_empty() {
+            return Ok(());
+        }
+    }
+    if let Some(correction) = &results.correction {
+        let name = parsed.elems.iter().chain(&parsed.returned).next().map_or("", |elem| &elem.name);
+        writeln!(
+            out,
+            "Type \"{name}\" not found. Showing results for closest type name \"{correction}\" \
+             instead."
+        )?;
+    }
+    // Queries which failed to parse only look up doc aliases, which go in the names tab.
+    if parsed.found_elems() <= 1 && parsed.returned.is_empty() {
+        print_tab(&mut out, "In Names", &results.others)?;
+        print_tab(&mut out, "In Parameters", &results.in_args)?;
+        print_tab(&mut out, "In Return Types", &results.returned)?;
+    } else {
+        let title = if !parsed.elems.is_empty() && !parsed.returned.is_empty() {
+            "In Function Signatures"
+        } else if !parsed.elems.is_empty() {
+            "In Function Parameters"
+        } else {
+            "In Function Return Types"
+        };
+        print_tab(&mut out, title, &results.others)?;
+    }
+    Ok(())
+}
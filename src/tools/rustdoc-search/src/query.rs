@@ -0,0 +1,504 @@
+//! Parsing of search queries, following `parseQuery` in `librustdoc/html/static/js/search.js`.
+//!
+//! The supported syntax is:
+//!
+//! ```text
+//! ident = *(ALPHA / DIGIT / "_")
+//! path = ident *(DOUBLE-COLON ident) [!]
+//! arg = [type-filter *WS COLON *WS] path [generics]
+//! type-sep = COMMA/WS *(COMMA/WS)
+//! nonempty-arg-list = *(type-sep) arg *(type-sep arg) *(type-sep)
+//! generics = OPEN-ANGLE-BRACKET [ nonempty-arg-list ] *(type-sep)
+//!            CLOSE-ANGLE-BRACKET
+//! return-args = RETURN-ARROW *(type-sep) nonempty-arg-list
+//!
+//! exact-search = [type-filter *WS COLON] [ RETURN-ARROW ] *WS QUOTE ident QUOTE [ generics ]
+//! type-search = [ nonempty-arg-list ] [ return-args ]
+//!
+//! query = *WS (exact-search / type-search) *WS
+//! ```
+//!
+//! where `type-filter` is one of the names of [`ITEM_TYPES`], or `const`.
+
+use crate::index::{item_type, ITEM_TYPES};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Query {
+    pub(crate) original: String,
+    /// The lowercased query.
+    pub(crate) user_query: String,
+    pub(crate) elems: Vec<QueryElement>,
+    /// The elements after `->`.
+    pub(crate) returned: Vec<QueryElement>,
+    /// Whether names have to match exactly, because they were quoted or there is more than one
+    /// element.
+    pub(crate) literal_search: bool,
+    /// The reason the query couldn't be parsed, in which case it has no elements.
+    pub(crate) error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct QueryElement {
+    pub(crate) name: String,
+    pub(crate) path_without_last: Vec<String>,
+    pub(crate) path_last: String,
+    pub(crate) generics: Vec<QueryElement>,
+    pub(crate) type_filter: Option<u8>,
+}
+
+impl Query {
+    /// The number of top-level elements, not counting generics.
+    pub(crate) fn found_elems(&self) -> usize {
+        self.elems.len() + self.returned.len()
+    }
+}
+
+/// Formats an error the way the search page does, with every other part being code.
+fn error(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| if i % 2 == 1 { format!("`{part}`") } else { part.to_string() })
+        .collect()
+}
+
+fn is_whitespace(c: char) -> bool {
+    " \t\n\r".contains(c)
+}
+
+fn is_special_start_character(c: char) -> bool {
+    "<\"".contains(c)
+}
+
+fn is_end_character(c: char) -> bool {
+    ",>-".contains(c)
+}
+
+fn is_stop_character(c: char) -> bool {
+    is_whitespace(c) || is_end_character(c)
+}
+
+fn is_error_character(c: char) -> bool {
+    "()".contains(c)
+}
+
+fn is_ident_character(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric()
+}
+
+fn is_separator_character(c: char) -> bool {
+    c == ',' || is_whitespace_character(c)
+}
+
+fn is_whitespace_character(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
+struct Parser {
+    query: Vec<char>,
+    pos: usize,
+    /// The number of elements, including generics.
+    total_elems: usize,
+    generics_elems: usize,
+    type_filter: Option<String>,
+    literal_search: bool,
+}
+
+impl Parser {
+    fn at(&self, pos: usize) -> Option<char> {
+        self.query.get(pos).copied()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.query[self.pos..].iter().copied().take(s.len()).eq(s.chars())
+    }
+
+    fn slice(&self, start: usize, end: usize) -> String {
+        self.query[start..end].iter().collect()
+    }
+
+    /// Skips a string element between `"`.
+    fn string_elem(&mut self, in_generics: bool) -> Result<(), String> {
+        if in_generics {
+            return Err(error(&["Unexpected ", "\"", " in generics"]));
+        } else if self.literal_search {
+            return Err(error(&["Cannot have more than one literal search element"]));
+        } else if self.total_elems - self.generics_elems > 0 {
+            return Err(error(&["Cannot use literal search when there is more than one element"]));
+        }
+        self.pos += 1;
+        let start = self.pos;
+        let end = self.ident_end_position()?;
+        if self.pos >= self.query.len() {
+            return Err(error(&["Unclosed ", "\""]));
+        } else if self.query[end] != '"' {
+            return Err(error(&[
+                "Unexpected ",
+                &self.query[end].to_string(),
+                " in a string element",
+            ]));
+        } else if start == end {
+            return Err(error(&["Cannot have empty string element"]));
+        }
+        // Skip the closing quote.
+        self.pos += 1;
+        self.literal_search = true;
+        Ok(())
+    }
+
+    fn create_element(
+        &mut self,
+        name: String,
+        generics: Vec<QueryElement>,
+        in_generics: bool,
+    ) -> Result<Option<QueryElement>, String> {
+        if name == "*" || (name.is_empty() && generics.is_empty()) {
+            return Ok(None);
+        }
+        if self.literal_search && self.total_elems - self.generics_elems > 0 {
+            return Err(error(&["You cannot have more than one element if you use quotes"]));
+        }
+        let mut path: Vec<String> = name.split("::").map(str::to_string).collect();
+        if path.len() > 1 {
+            for (i, segment) in path.iter().enumerate() {
+                if segment.is_empty() {
+                    if i == 0 {
+                        return Err(error(&["Paths cannot start with ", "::"]));
+                    } else if i + 1 == path.len() {
+                        return Err(error(&["Paths cannot end with ", "::"]));
+                    }
+                    return Err(error(&["Unexpected ", "::::"]));
+                }
+            }
+        }
+        // In case we only have something like `<p>`, there is no name.
+        if path.len() == 1 && path[0].is_empty() {
+            return Err(error(&["Found generics without a path"]));
+        }
+        self.total_elems += 1;
+        if in_generics {
+            self.generics_elems += 1;
+        }
+        let type_filter = match self.type_filter.take().as_deref() {
+            None => None,
+            Some("const") => item_type("constant"),
+            Some(filter) => {
+                Some(item_type(filter).ok_or_else(|| error(&["Unknown type filter ", filter]))?)
+            }
+        };
+        let path_last = path.pop().unwrap();
+        Ok(Some(QueryElement { name, path_without_last: path, path_last, generics, type_filter }))
+    }
+
+    /// Goes through the characters of an ident, and returns the position after its last one.
+    fn ident_end_position(&mut self) -> Result<usize, String> {
+        let start = self.pos;
+        let mut end = self.pos;
+        let mut found_exclamation = None;
+        while let Some(c) = self.at(self.pos) {
+            if !is_ident_character(c) {
+                if c == '!' {
+                    if found_exclamation.is_some() {
+                        return Err(error(&["Cannot have more than one ", "!", " in an ident"]));
+                    } else if self.at(self.pos + 1).is_some_and(is_ident_character) {
+                        return Err(error(&[
+                            "Unexpected ",
+                            "!",
+                            ": it can only be at the end of an ident",
+                        ]));
+                    }
+                    found_exclamation = Some(self.pos);
+                } else if is_error_character(c) {
+                    return Err(error(&["Unexpected ", &c.to_string()]));
+                } else if is_stop_character(c)
+                    || is_special_start_character(c)
+                    || is_separator_character(c)
+                {
+                    break;
+                } else if c == ':' {
+                    // Paths like `str::string` are allowed.
+                    if !self.starts_with("::") {
+                        break;
+                    }
+                    if found_exclamation.is_some() {
+                        if start + 2 <= end {
+                            return Err(error(&["Cannot have associated items in macros"]));
+                        }
+                        // `!` alone is the never type, which can be in a path.
+                        found_exclamation = None;
+                    }
+                    // Skip the current `:`.
+                    self.pos += 1;
+                } else {
+                    return Err(error(&["Unexpected ", &c.to_string()]));
+                }
+            }
+            self.pos += 1;
+            end = self.pos;
+        }
+        // If `start + 1 == end`, this is the never type.
+        if let Some(exclamation) = found_exclamation {
+            if start + 2 <= end {
+                match self.type_filter.as_deref() {
+                    None => self.type_filter = Some("macro".to_string()),
+                    Some("macro") => {}
+                    Some(filter) => {
+                        return Err(error(&[
+                            "Invalid search type: macro ",
+                            "!",
+                            " and ",
+                            filter,
+                            " both specified",
+                        ]));
+                    }
+                }
+                end = exclamation;
+            }
+        }
+        Ok(end)
+    }
+
+    fn next_elem(
+        &mut self,
+        elems: &mut Vec<QueryElement>,
+        in_generics: bool,
+    ) -> Result<(), String> {
+        let mut generics = Vec::new();
+        let mut start = self.pos;
+        let end = if self.at(self.pos) == Some('"') {
+            start += 1;
+            self.string_elem(in_generics)?;
+            self.pos - 1
+        } else {
+            self.ident_end_position()?
+        };
+        if self.at(self.pos) == Some('<') {
+            if start >= end {
+                return Err(error(&["Found generics without a path"]));
+            }
+            self.pos += 1;
+            self.items_before(&mut generics, Some('>'))?;
+        }
+        if start >= end && generics.is_empty() {
+            return Ok(());
+        }
+        let name = self.slice(start, end);
+        elems.extend(self.create_element(name, generics, in_generics)?);
+        Ok(())
+    }
+
+    /// Parses elements until `end_char`, or the end of the query if it is `None`.
+    fn items_before(
+        &mut self,
+        elems: &mut Vec<QueryElement>,
+        end_char: Option<char>,
+    ) -> Result<(), String> {
+        let mut found_stop_char = true;
+        let mut start = self.pos;
+
+        // Generics don't inherit the type filter of the element they belong to.
+        let old_type_filter = self.type_filter.take();
+
+        while let Some(c) = self.at(self.pos) {
+            if Some(c) == end_char {
+                break;
+            } else if is_separator_character(c) {
+                self.pos += 1;
+                found_stop_char = true;
+                continue;
+            } else if c == ':' && self.starts_with("::") {
+                return Err(error(&["Unexpected ", "::", ": paths cannot start with ", "::"]));
+            } else if c == ':' {
+                if self.type_filter.is_some() {
+                    return Err(error(&["Unexpected ", ":"]));
+                }
+                let Some(type_filter_elem) = elems.pop() else {
+                    return Err(error(&["Expected type filter before ", ":"]));
+                };
+                if self.literal_search {
+                    return Err(error(&["You cannot use quotes on type filter"]));
+                }
+                // The type filter doesn't count as an element since it's a modifier.
+                self.check_extra_type_filter_characters(start)?;
+                self.type_filter = Some(type_filter_elem.name);
+                self.pos += 1;
+                self.total_elems -= 1;
+                self.literal_search = false;
+                found_stop_char = true;
+                continue;
+            } else if is_end_character(c) {
+                let extra = match end_char {
+                    Some('>') => "<".to_string(),
+                    Some(end_char) => end_char.to_string(),
+                    None => "->".to_string(),
+                };
+                return Err(error(&["Unexpected ", &c.to_string(), " after ", &extra]));
+            }
+            if !found_stop_char {
+                let c = c.to_string();
+                return Err(match end_char {
+                    Some(end_char) => error(&[
+                        "Expected ",
+                        ",",
+                        ", ",
+                        " ",
+                        " or ",
+                        &end_char.to_string(),
+                        ", found ",
+                        &c,
+                    ]),
+                    None => error(&["Expected ", ",", " or ", " ", ", found ", &c]),
+                });
+            }
+            let pos_before = self.pos;
+            start = self.pos;
+            self.next_elem(elems, end_char == Some('>'))?;
+            if end_char.is_some() && self.pos >= self.query.len() {
+                return Err(error(&["Unclosed ", "<"]));
+            }
+            // `next_elem` stops right away on a stop character, like in `,,` or `<>`.
+            if pos_before == self.pos {
+                self.pos += 1;
+            }
+            found_stop_char = false;
+        }
+        if self.pos >= self.query.len() && end_char.is_some() {
+            return Err(error(&["Unclosed ", "<"]));
+        }
+        // Skip `end_char`, if we're not at the end already.
+        self.pos += 1;
+
+        self.type_filter = old_type_filter;
+        Ok(())
+    }
+
+    /// Checks that the type filter is made of ident characters, like `<>` doesn't sneak in.
+    fn check_extra_type_filter_characters(&self, start: usize) -> Result<(), String> {
+        for &c in &self.query[start..self.pos] {
+            if !is_ident_character(c) && !is_whitespace_character(c) {
+                return Err(error(&["Unexpected ", &c.to_string(), " in type filter"]));
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_input(&mut self, query: &mut Query) -> Result<(), String> {
+        let mut found_stop_char = true;
+        let mut start = self.pos;
+
+        while let Some(c) = self.at(self.pos) {
+            if is_stop_character(c) {
+                found_stop_char = true;
+                if is_separator_character(c) {
+                    self.pos += 1;
+                    continue;
+                } else if c == '-' || c == '>' {
+                    if self.starts_with("->") {
+                        break;
+                    }
+                    return Err(error(&[
+                        "Unexpected ",
+                        &c.to_string(),
+                        " (did you mean ",
+                        "->",
+                        "?)",
+                    ]));
+                }
+                return Err(error(&["Unexpected ", &c.to_string()]));
+            } else if c == ':' && !self.starts_with("::") {
+                if self.type_filter.is_some() {
+                    return Err(error(&["Unexpected ", ":"]));
+                }
+                let Some(type_filter_elem) = query.elems.pop() else {
+                    return Err(error(&["Expected type filter before ", ":"]));
+                };
+                if self.literal_search {
+                    return Err(error(&["You cannot use quotes on type filter"]));
+                }
+                // The type filter doesn't count as an element since it's a modifier.
+                self.check_extra_type_filter_characters(start)?;
+                self.type_filter = Some(type_filter_elem.name);
+                self.pos += 1;
+                self.total_elems -= 1;
+                self.literal_search = false;
+                found_stop_char = true;
+                continue;
+            }
+            if !found_stop_char {
+                let c = c.to_string();
+                return Err(if self.type_filter.is_some() {
+                    error(&["Expected ", ",", ", ", " ", " or ", "->", ", found ", &c])
+                } else {
+                    error(&["Expected ", ",", ", ", " ", ", ", ":", " or ", "->", ", found ", &c])
+                });
+            }
+            let before = query.elems.len();
+            start = self.pos;
+            self.next_elem(&mut query.elems, false)?;
+            if query.elems.len() == before {
+                // Nothing was added, move on to not get stuck.
+                self.pos += 1;
+            }
+            found_stop_char = false;
+        }
+        if self.type_filter.is_some() {
+            return Err(error(&["Unexpected ", ":", " (expected path after type filter)"]));
+        }
+        while self.pos < self.query.len() {
+            if self.starts_with("->") {
+                self.pos += 2;
+                self.items_before(&mut query.returned, None)?;
+                // Nothing can come afterward!
+                if query.returned.is_empty() {
+                    return Err(error(&["Expected at least one item after ", "->"]));
+                }
+                break;
+            }
+            self.pos += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `query`. On errors, a query without elements is returned, as doc aliases are still
+/// looked up.
+pub(crate) fn parse_query(query: &str) -> Query {
+    let original = query.trim().to_string();
+    let user_query = original.to_lowercase();
+    let empty = Query { original, user_query, ..Query::default() };
+    let mut parser = Parser {
+        query: empty.user_query.chars().collect(),
+        pos: 0,
+        total_elems: 0,
+        generics_elems: 0,
+        type_filter: None,
+        literal_search: false,
+    };
+    let mut query = empty.clone();
+    if let Err(err) = parser.parse_input(&mut query) {
+        return Query { error: Some(err), ..empty };
+    }
+    // A query with more than one element is always a literal search.
+    query.literal_search = parser.literal_search || parser.total_elems > 1;
+    query
+}
+
+/// Whether an item of type `ty` passes the type filter `filter` of a query element.
+pub(crate) fn type_passes_filter(filter: Option<u8>, ty: Option<u8>) -> bool {
+    let Some(filter) = filter else { return true };
+    if Some(filter) == ty {
+        return true;
+    }
+    // Related items also pass.
+    let name = ty.map(|ty| ITEM_TYPES[ty as usize]);
+    match ITEM_TYPES[filter as usize] {
+        "constant" => name == Some("associatedconstant"),
+        "fn" => name == Some("method") || name == Some("tymethod"),
+        "type" => name == Some("primitive") || name == Some("associatedtype"),
+        "trait" => name == Some("traitalias"),
+        _ => false,
+    }
+}
@@ -0,0 +1,104 @@
+use super::*;
+
+fn elem(name: &str, generics: Vec<QueryElement>) -> QueryElement {
+    let mut path = name.split("::").map(str::to_string).collect::<Vec<_>>();
+    let path_last = path.pop().unwrap();
+    QueryElement {
+        name: name.to_string(),
+        path_without_last: path,
+        path_last,
+        generics,
+        type_filter: None,
+    }
+}
+
+fn parse_err(query: &str) -> String {
+    let parsed = parse_query(query);
+    assert!(parsed.elems.is_empty() && parsed.returned.is_empty());
+    parsed.error.unwrap()
+}
+
+#[test]
+fn name() {
+    let query = parse_query("  Vec::New ");
+    assert_eq!(query.original, "Vec::New");
+    assert_eq!(query.user_query, "vec::new");
+    assert_eq!(query.error, None);
+    assert_eq!(query.elems, vec![elem("vec::new", vec![])]);
+    assert!(query.returned.is_empty());
+    assert!(!query.literal_search);
+}
+
+#[test]
+fn type_filter() {
+    let query = parse_query("fn:push");
+    assert_eq!(
+        query.elems,
+        vec![QueryElement { type_filter: item_type("fn"), ..elem("push", vec![]) }]
+    );
+
+    let query = parse_query("const : MAX");
+    assert_eq!(query.elems[0].type_filter, item_type("constant"));
+
+    assert_eq!(parse_err("foo:bar"), "Unknown type filter `foo`");
+    assert_eq!(parse_err(":bar"), "Expected type filter before `:`");
+}
+
+#[test]
+fn signature() {
+    assert_eq!(parse_err("u8, &str -> Vec<T>"), "Unexpected `&`");
+
+    let query = parse_query("u8, str -> Vec<T>");
+    assert_eq!(query.elems, vec![elem("u8", vec![]), elem("str", vec![])]);
+    assert_eq!(query.returned, vec![elem("vec", vec![elem("t", vec![])])]);
+    assert!(query.literal_search);
+    assert_eq!(query.found_elems(), 3);
+
+    let query = parse_query("-> Option<u8, String>");
+    assert!(query.elems.is_empty());
+    assert_eq!(
+        query.returned,
+        vec![elem("option", vec![elem("u8", vec![]), elem("string", vec![])])]
+    );
+    // Generics count as elements.
+    assert!(query.literal_search);
+
+    assert_eq!(parse_err("->"), "Expected at least one item after `->`");
+    assert_eq!(parse_err("Vec<u8"), "Unclosed `<`");
+    assert_eq!(parse_err("<u8>"), "Found generics without a path");
+}
+
+#[test]
+fn literal() {
+    let query = parse_query("\"Vec\"");
+    assert_eq!(query.elems, vec![elem("vec", vec![])]);
+    assert!(query.literal_search);
+
+    assert_eq!(parse_err("\"vec"), "Unclosed `\"`");
+    assert_eq!(parse_err("\"\""), "Cannot have empty string element");
+    assert_eq!(parse_err("\"a\" \"b\""), "Cannot have more than one literal search element");
+}
+
+#[test]
+fn paths() {
+    assert_eq!(parse_err("::vec"), "Paths cannot start with `::`");
+    assert_eq!(parse_err("vec::"), "Paths cannot end with `::`");
+
+    let query = parse_query("println!");
+    assert_eq!(
+        query.elems,
+        vec![QueryElement { type_filter: item_type("macro"), ..elem("println", vec![]) }]
+    );
+    assert_eq!(parse_err("std!::println"), "Cannot have associated items in macros");
+}
+
+#[test]
+fn type_passes() {
+    let fn_ = item_type("fn");
+    assert!(type_passes_filter(None, None));
+    assert!(type_passes_filter(fn_, fn_));
+    assert!(type_passes_filter(fn_, item_type("method")));
+    assert!(!type_passes_filter(fn_, item_type("struct")));
+    assert!(!type_passes_filter(fn_, None));
+    assert!(type_passes_filter(item_type("type"), item_type("primitive")));
+}
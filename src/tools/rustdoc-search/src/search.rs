@@ -0,0 +1,562 @@
+//! Running queries against the search index, following `execQuery` in
+//! `librustdoc/html/static/js/search.js`.
+
+use std::collections::BTreeMap;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use crate::index::{Index, Row, TypeRef, ITEM_TYPES, TY_KEYWORD, TY_PRIMITIVE};
+use crate::query::{type_passes_filter, Query, QueryElement};
+
+#[cfg(test)]
+mod tests;
+
+const MAX_RESULTS: usize = 200;
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct Results {
+    /// The items whose name matches the query, or whose signature matches it for queries
+    /// with several elements or a return type.
+    pub(crate) others: Vec<SearchResult>,
+    /// The functions with a parameter matching the query.
+    pub(crate) in_args: Vec<SearchResult>,
+    /// The functions returning a type matching the query.
+    pub(crate) returned: Vec<SearchResult>,
+    /// The name of the type which was searched for instead of a name matching no type.
+    pub(crate) correction: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct SearchResult {
+    #[serde(rename = "crate")]
+    pub(crate) krate: String,
+    #[serde(rename = "type")]
+    pub(crate) ty: &'static str,
+    /// The path the item is displayed with, ending with `::` unless it is empty.
+    pub(crate) display_path: String,
+    pub(crate) name: String,
+    /// The summary of the documentation, as HTML.
+    pub(crate) desc: String,
+    /// The page of the item, relative to the root of the documentation.
+    pub(crate) href: String,
+    pub(crate) deprecated: bool,
+    /// The alias of the item which was searched for, if it was found by one.
+    pub(crate) alias: Option<String>,
+}
+
+/// A query element with the name of the type it refers to, if any.
+struct TypeQuery {
+    id: Option<String>,
+    type_filter: Option<u8>,
+    generics: Vec<TypeQuery>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Match {
+    id: usize,
+    /// The position of the query in the name of the item, if it is in it.
+    index: Option<usize>,
+    dist: usize,
+    /// The edit distance of the path of the query to the one of the item.
+    path_dist: usize,
+    dont_validate: bool,
+}
+
+/// The matches, by row. Rows are visited in order, so this keeps them in the order they were
+/// found in.
+type Matches = BTreeMap<usize, Match>;
+
+/// The restricted Damerau-Levenshtein distance between `a` and `b`, or `limit + 1` if it is
+/// greater than `limit`.
+pub(crate) fn edit_distance(a: &str, b: &str, limit: usize) -> usize {
+    let mut a = a.chars().collect::<Vec<_>>();
+    let mut b = b.chars().collect::<Vec<_>>();
+    // Make `b` the shorter string.
+    if a.len() < b.len() {
+        std::mem::swap(&mut a, &mut b);
+    }
+    let min_dist = a.len() - b.len();
+    if min_dist > limit {
+        return limit + 1;
+    }
+
+    // Strip the common prefix and suffix.
+    let prefix = a.iter().zip(&b).take_while(|(a, b)| a == b).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a.iter().rev().zip(b.iter().rev()).take_while(|(a, b)| a == b).count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    if b.is_empty() {
+        return min_dist;
+    }
+
+    let mut prev_prev = vec![usize::MAX; b.len() + 1];
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let substitution_cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (prev[j] + 1).min(current[j - 1] + 1).min(prev[j - 1] + substitution_cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                // Transposition.
+                current[j] = current[j].min(prev_prev[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev_prev, &mut prev);
+        std::mem::swap(&mut prev, &mut current);
+    }
+    let distance = prev[b.len()];
+    if distance <= limit { distance } else { limit + 1 }
+}
+
+/// Whether `row`, a type in a signature, matches `elem`, or has a generic which does.
+fn check_type(row: &TypeRef, elem: &TypeQuery) -> bool {
+    if row.name.is_none() {
+        // A type parameter only matches through its bounds.
+        return !row.generics.is_empty() && check_if_in_generics(row, elem);
+    }
+    if row.name == elem.id && type_passes_filter(elem.type_filter, row.ty) {
+        if !elem.generics.is_empty() {
+            return check_generics(row, elem);
+        }
+        return true;
+    }
+    // If the type doesn't match, try "unboxing" it.
+    check_if_in_generics(row, elem)
+}
+
+fn check_if_in_generics(row: &TypeRef, elem: &TypeQuery) -> bool {
+    row.generics.iter().any(|generic| check_type(generic, elem))
+}
+
+/// Whether the generics of `row`, whose name matches `elem`, match the ones of `elem`.
+///
+/// Generics are matched regardless of their order, and `row` can have more of them.
+fn check_generics(row: &TypeRef, elem: &TypeQuery) -> bool {
+    if row.generics.is_empty() || elem.generics.is_empty() {
+        return false;
+    }
+    if row.generics.len() < elem.generics.len() {
+        return false;
+    }
+    fn add_entry<'a>(elems: &mut FxHashMap<&'a str, Vec<&'a TypeRef>>, entry: &'a TypeRef) {
+        match &entry.name {
+            Some(name) => elems.entry(name).or_default().push(entry),
+            // Type parameters are replaced by their bounds.
+            None => entry.generics.iter().for_each(|generic| add_entry(elems, generic)),
+        }
+    }
+    let mut elems = FxHashMap::default();
+    row.generics.iter().for_each(|generic| add_entry(&mut elems, generic));
+
+    let mut handle_generic = |generic: &TypeQuery| {
+        let Some(id) = &generic.id else { return false };
+        let Some(candidates) = elems.get_mut(&id[..]) else { return false };
+        let Some(position) = candidates.iter().position(|candidate| {
+            (generic.generics.is_empty() || check_generics(candidate, generic))
+                && type_passes_filter(generic.type_filter, candidate.ty)
+        }) else {
+            return false;
+        };
+        candidates.remove(position);
+        if candidates.is_empty() {
+            elems.remove(&id[..]);
+        }
+        true
+    };
+    // Generics with a type filter go first, so that the ones without can't use up the types
+    // they need.
+    elem.generics.iter().filter(|generic| generic.type_filter.is_some()).all(&mut handle_generic)
+        && elem.generics.iter().filter(|generic| generic.type_filter.is_none()).all(handle_generic)
+}
+
+/// Finds a type in `types` matching `elem`, skipping the ones already matched by other elements.
+fn find_type(types: &[TypeRef], elem: &TypeQuery, skip_positions: &[usize]) -> Option<usize> {
+    types
+        .iter()
+        .enumerate()
+        .position(|(i, ty)| !skip_positions.contains(&i) && check_type(ty, elem))
+}
+
+fn find_arg(row: &Row, elem: &TypeQuery, skip_positions: &[usize]) -> Option<usize> {
+    find_type(&row.type_.as_ref()?.inputs, elem, skip_positions)
+}
+
+fn check_returned(row: &Row, elem: &TypeQuery, skip_positions: &[usize]) -> Option<usize> {
+    find_type(&row.type_.as_ref()?.output, elem, skip_positions)
+}
+
+/// The edit distance between `contains` and the closest consecutive segments of the path of
+/// `row`.
+fn check_path(contains: &[String], row: &Row, max_edit_distance: usize) -> usize {
+    if contains.is_empty() {
+        return 0;
+    }
+    let mut path = row.path.split("::").map(str::to_string).collect::<Vec<_>>();
+    if let Some(parent) = &row.parent {
+        path.push(parent.name.to_lowercase());
+    }
+    if contains.len() > path.len() {
+        return max_edit_distance + 1;
+    }
+    let mut ret_dist = max_edit_distance + 1;
+    for window in path.windows(contains.len()) {
+        let mut dist_total = 0;
+        let mut aborted = false;
+        for (segment, contained) in window.iter().zip(contains) {
+            let dist = edit_distance(segment, contained, max_edit_distance);
+            if dist > max_edit_distance {
+                aborted = true;
+                break;
+            }
+            dist_total += dist;
+        }
+        if !aborted {
+            // The average, rounded.
+            let average = (2 * dist_total + contains.len()) / (2 * contains.len());
+            ret_dist = ret_dist.min(average);
+        }
+    }
+    ret_dist
+}
+
+/// The path the item is displayed with, and its page.
+fn href_and_path(row: &Row) -> (String, String) {
+    let ty = ITEM_TYPES[row.ty as usize];
+    let name = &row.name;
+    let dir = row.path.replace("::", "/");
+    match (ty, &row.parent) {
+        ("mod", _) => (format!("{}::", row.path), format!("{dir}/{name}/index.html")),
+        ("import", _) => (format!("{}::", row.path), format!("{dir}/index.html#reexport.{name}")),
+        ("primitive" | "keyword", _) => (String::new(), format!("{dir}/{ty}.{name}.html")),
+        ("externcrate", _) => (String::new(), format!("{name}/index.html")),
+        (_, Some(parent)) => {
+            let parent_ty = ITEM_TYPES[parent.ty as usize];
+            if parent_ty == "primitive" {
+                let href = format!("{dir}/{parent_ty}.{}.html#{ty}.{name}", parent.name);
+                (format!("{}::", parent.name), href)
+            } else if ty == "structfield" && parent_ty == "variant" {
+                // The path of fields of variants ends with the name of the enum.
+                let (path, enum_name) = row.path.rsplit_once("::").unwrap_or(("", &row.path));
+                let href = format!(
+                    "{}/enum.{enum_name}.html#variant.{}.field.{name}",
+                    path.replace("::", "/"),
+                    parent.name,
+                );
+                (format!("{path}::{enum_name}::{}::", parent.name), href)
+            } else {
+                let href = format!("{dir}/{parent_ty}.{}.html#{ty}.{name}", parent.name);
+                (format!("{}::{}::", row.path, parent.name), href)
+            }
+        }
+        (_, None) => (format!("{}::", row.path), format!("{dir}/{ty}.{name}.html")),
+    }
+}
+
+fn search_result(row: &Row, alias: Option<&str>) -> SearchResult {
+    let (display_path, href) = href_and_path(row);
+    SearchResult {
+        krate: row.krate.clone(),
+        ty: ITEM_TYPES[row.ty as usize],
+        display_path,
+        name: row.name.clone(),
+        desc: row.desc.clone(),
+        href,
+        deprecated: row.deprecated,
+        alias: alias.map(str::to_string),
+    }
+}
+
+struct Search<'a> {
+    index: &'a Index,
+    query: &'a Query,
+    filter_crate: Option<&'a str>,
+    others: Matches,
+    in_args: Matches,
+    returned: Matches,
+    correction: Option<String>,
+}
+
+impl<'a> Search<'a> {
+    fn add_into_results(
+        literal_search: bool,
+        results: &mut Matches,
+        m: Match,
+        max_edit_distance: usize,
+    ) {
+        let in_bounds = m.dist <= max_edit_distance || m.index.is_some();
+        if m.dist == 0 || (!literal_search && in_bounds) {
+            if let Some(result) = results.get(&m.id) {
+                if result.dont_validate || result.dist <= m.dist {
+                    return;
+                }
+            }
+            results.insert(m.id, Match { dont_validate: literal_search, ..m });
+        }
+    }
+
+    fn add(&mut self, results: fn(&mut Self) -> &mut Matches, m: Match, max_edit_distance: usize) {
+        let literal_search = self.query.literal_search;
+        Self::add_into_results(literal_search, results(self), m, max_edit_distance);
+    }
+
+    fn skip_row(&self, row: &Row) -> bool {
+        self.filter_crate.is_some_and(|krate| row.krate != krate)
+    }
+
+    fn type_query(&mut self, elem: &QueryElement, max_edit_distance: usize) -> TypeQuery {
+        let type_names = &self.index.type_names;
+        let id = if type_names.contains(&elem.name) {
+            Some(elem.name.clone())
+        } else if !self.query.literal_search {
+            // Use the closest type name instead, the last one in alphabetical order on ties.
+            let mut closest: Option<(usize, &String)> = None;
+            for name in type_names {
+                let dist = edit_distance(name, &elem.name, max_edit_distance);
+                let closer = match closest {
+                    Some((closest_dist, closest_name)) => {
+                        dist < closest_dist || (dist == closest_dist && name > closest_name)
+                    }
+                    None => true,
+                };
+                if dist <= max_edit_distance && closer {
+                    closest = Some((dist, name));
+                }
+            }
+            let closest = closest.map(|(_, name)| name.clone());
+            if closest.is_some() {
+                self.correction = closest.clone();
+            }
+            closest
+        } else {
+            None
+        };
+        TypeQuery {
+            id,
+            type_filter: elem.type_filter,
+            generics: elem
+                .generics
+                .iter()
+                .map(|generic| self.type_query(generic, max_edit_distance))
+                .collect(),
+        }
+    }
+
+    /// Matches a query with a single element against the names of items as well as their
+    /// parameters and return types.
+    fn handle_single_arg(
+        &mut self,
+        pos: usize,
+        elem: &QueryElement,
+        type_query: &TypeQuery,
+        max_edit_distance: usize,
+    ) {
+        let row = &self.index.rows[pos];
+        if self.skip_row(row) {
+            return;
+        }
+        let m = Match { id: pos, index: None, dist: 0, path_dist: 0, dont_validate: false };
+        if find_arg(row, type_query, &[]).is_some() {
+            self.add(|s| &mut s.in_args, m, max_edit_distance);
+        }
+        if check_returned(row, type_query, &[]).is_some() {
+            self.add(|s| &mut s.returned, m, max_edit_distance);
+        }
+
+        if !type_passes_filter(elem.type_filter, Some(row.ty)) {
+            return;
+        }
+
+        // Lower indexes are better matches.
+        let index =
+            match (row.normalized_name.find(&elem.path_last), row.word.find(&elem.path_last)) {
+                (Some(row_index), Some(word_index)) => Some(row_index.min(word_index)),
+                (row_index, word_index) => row_index.or(word_index),
+            };
+
+        let mut path_dist = 0;
+        if !elem.path_without_last.is_empty() {
+            path_dist = check_path(&elem.path_without_last, row, max_edit_distance);
+            if path_dist > max_edit_distance {
+                return;
+            }
+        }
+
+        if self.query.literal_search {
+            if row.word == elem.name {
+                let m = Match { index, path_dist, ..m };
+                self.add(|s| &mut s.others, m, max_edit_distance);
+            }
+            return;
+        }
+
+        let dist = edit_distance(&row.word, &elem.path_last, max_edit_distance);
+        if index.is_none() && dist + path_dist > max_edit_distance {
+            return;
+        }
+        self.add(|s| &mut s.others, Match { index, dist, path_dist, ..m }, max_edit_distance);
+    }
+
+    /// Matches a query with several elements against the signatures of functions: each element
+    /// before `->` must match a different parameter, and each one after it a different part of
+    /// the return type.
+    fn handle_args(&mut self, pos: usize, elems: &[TypeQuery], returned: &[TypeQuery]) {
+        let row = &self.index.rows[pos];
+        if self.skip_row(row) {
+            return;
+        }
+        let check_args =
+            |elems: &[TypeQuery], find: fn(&Row, &TypeQuery, &[usize]) -> Option<usize>| {
+                let mut skip_positions = Vec::new();
+                for elem in elems {
+                    match find(row, elem, &skip_positions) {
+                        Some(position) => skip_positions.push(position),
+                        None => return false,
+                    }
+                }
+                true
+            };
+        if !check_args(elems, find_arg) || !check_args(returned, check_returned) {
+            return;
+        }
+        let m = Match { id: pos, index: Some(0), dist: 0, path_dist: 0, dont_validate: false };
+        self.add(|s| &mut s.others, m, usize::MAX);
+    }
+
+    fn run(&mut self) {
+        let query = self.query;
+        let query_len = query
+            .elems
+            .iter()
+            .chain(&query.returned)
+            .map(|elem| elem.name.chars().count())
+            .sum::<usize>();
+        let max_edit_distance = query_len / 3;
+
+        let elems = query
+            .elems
+            .iter()
+            .map(|elem| self.type_query(elem, max_edit_distance))
+            .collect::<Vec<_>>();
+        let returned = query
+            .returned
+            .iter()
+            .map(|elem| self.type_query(elem, max_edit_distance))
+            .collect::<Vec<_>>();
+
+        match (&query.elems[..], &returned[..]) {
+            ([elem], []) => {
+                for pos in 0..self.index.rows.len() {
+                    self.handle_single_arg(pos, elem, &elems[0], max_edit_distance);
+                }
+            }
+            ([], [returned]) => {
+                for pos in 0..self.index.rows.len() {
+                    let row = &self.index.rows[pos];
+                    if !self.skip_row(row) && check_returned(row, returned, &[]).is_some() {
+                        let m = Match {
+                            id: pos,
+                            index: None,
+                            dist: 0,
+                            path_dist: usize::MAX,
+                            dont_validate: false,
+                        };
+                        self.add(|s| &mut s.others, m, max_edit_distance);
+                    }
+                }
+            }
+            ([], []) => {}
+            _ => {
+                for pos in 0..self.index.rows.len() {
+                    self.handle_args(pos, &elems, &returned);
+                }
+            }
+        }
+    }
+
+    /// Sorts `matches` from best to worst, and turns them into results.
+    fn sort_results(&self, matches: &Matches) -> Vec<SearchResult> {
+        let rows = &self.index.rows;
+        let mut matches = matches.values().collect::<Vec<_>>();
+        matches.sort_by(|a, b| {
+            let (row_a, row_b) = (&rows[a.id], &rows[b.id]);
+            // Exact matches go first.
+            (row_a.word != self.query.user_query)
+                .cmp(&(row_b.word != self.query.user_query))
+                // Then the ones with the query in their name, the earliest the better.
+                .then_with(|| a.index.is_none().cmp(&b.index.is_none()))
+                .then_with(|| a.path_dist.cmp(&b.path_dist))
+                .then_with(|| a.index.cmp(&b.index))
+                .then_with(|| a.dist.cmp(&b.dist))
+                .then_with(|| row_a.deprecated.cmp(&row_b.deprecated))
+                .then_with(|| row_a.word.len().cmp(&row_b.word.len()))
+                .then_with(|| row_a.word.cmp(&row_b.word))
+                // Primitive and keyword pages go before the other items with the same name.
+                .then_with(|| {
+                    let is_page = |row: &Row| row.ty == TY_PRIMITIVE || row.ty == TY_KEYWORD;
+                    is_page(row_b).cmp(&is_page(row_a))
+                })
+                // Items without a description go later.
+                .then_with(|| row_a.desc.is_empty().cmp(&row_b.desc.is_empty()))
+                .then_with(|| row_a.ty.cmp(&row_b.ty))
+                .then_with(|| row_a.path.cmp(&row_b.path))
+        });
+
+        let mut duplicates = FxHashSet::default();
+        let mut results = Vec::new();
+        for m in matches {
+            let result = search_result(&rows[m.id], None);
+            if !duplicates.insert(format!("{}{}|{}", result.display_path, result.name, result.ty)) {
+                continue;
+            }
+            results.push(result);
+            if results.len() >= MAX_RESULTS {
+                break;
+            }
+        }
+        results
+    }
+
+    /// Puts the items with the query as a doc alias first.
+    fn handle_aliases(&self, others: &mut Vec<SearchResult>) {
+        let query = self.query.original.replace('"', "");
+        let lower_query = query.to_lowercase();
+        let mut aliases = self
+            .index
+            .aliases
+            .iter()
+            .filter(|(krate, _)| self.filter_crate.is_none() || self.filter_crate == Some(krate))
+            .filter_map(|(_, aliases)| aliases.get(&lower_query))
+            .flatten()
+            .map(|&id| &self.index.rows[id])
+            .collect::<Vec<_>>();
+        aliases.sort_by(|a, b| b.path.cmp(&a.path));
+        for row in aliases {
+            others.insert(0, search_result(row, Some(&query)));
+            others.truncate(MAX_RESULTS);
+        }
+    }
+}
+
+/// Runs `query` against `index`, optionally only looking at the items of `filter_crate`.
+pub(crate) fn exec_query(index: &Index, query: &Query, filter_crate: Option<&str>) -> Results {
+    let mut search = Search {
+        index,
+        query,
+        filter_crate,
+        others: Matches::new(),
+        in_args: Matches::new(),
+        returned: Matches::new(),
+        correction: None,
+    };
+    search.run();
+    let mut results = Results {
+        others: search.sort_results(&search.others),
+        in_args: search.sort_results(&search.in_args),
+        returned: search.sort_results(&search.returned),
+        correction: search.correction.clone(),
+    };
+    search.handle_aliases(&mut results.others);
+    results
+}
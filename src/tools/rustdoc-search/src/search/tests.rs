@@ -0,0 +1,107 @@
+use crate::index;
+use crate::query::parse_query;
+
+use super::*;
+
+/// An index for a crate `foo` with:
+///
+/// ```ignore (illustrative)
+/// /// doc alias `array`
+/// pub struct Vec<T>;
+/// impl<T> Vec<T> {
+///     pub fn new() -> Vec<T>;
+///     #[deprecated]
+///     pub fn len(&self) -> u8;
+/// }
+/// pub fn from_bytes(bytes: u8) -> Vec<u8>;
+/// pub mod vec {}
+/// ```
+const SEARCH_INDEX: &str = r#"var searchIndex = JSON.parse('{\
+"foo":{"doc":"The <code>foo</code> crate","t":"DLFAL","n":["Vec","new","from_bytes","vec","len"],"q":[[0,"foo"]],"d":["A vector.","Creates a vector.","","Vectors.","The length."],"i":[0,1,0,0,1],"f":[0,[[],1],[[2],[[1,[2]]]],0,[[1],2]],"c":[4],"p":[[3,"Vec"],[15,"u8"]],"a":{"array":[0]}}\
+}');
+"#;
+
+fn search(query: &str, filter_crate: Option<&str>) -> Results {
+    let index = index::parse(SEARCH_INDEX).unwrap();
+    exec_query(&index, &parse_query(query), filter_crate)
+}
+
+fn names(results: &[SearchResult]) -> Vec<String> {
+    results
+        .iter()
+        .map(|result| format!("{} {}{}", result.ty, result.display_path, result.name))
+        .collect()
+}
+
+#[test]
+fn distance() {
+    assert_eq!(edit_distance("vec", "vec", 1), 0);
+    assert_eq!(edit_distance("vec", "vex", 1), 1);
+    assert_eq!(edit_distance("vec", "evc", 1), 1);
+    assert_eq!(edit_distance("vector", "vec", 1), 2);
+    assert_eq!(edit_distance("from_bytes", "from_byte", 3), 1);
+}
+
+#[test]
+fn by_name() {
+    let results = search("vec", None);
+    assert_eq!(names(&results.others), ["mod foo::vec", "struct foo::Vec"]);
+    assert_eq!(names(&results.in_args), ["method foo::Vec::len"]);
+    assert_eq!(names(&results.returned), ["method foo::Vec::new", "fn foo::from_bytes"]);
+    assert_eq!(results.correction, None);
+
+    assert_eq!(results.others[0].href, "foo/vec/index.html");
+    assert_eq!(results.others[1].href, "foo/struct.Vec.html");
+    assert_eq!(results.returned[0].href, "foo/struct.Vec.html#method.new");
+    assert_eq!(results.returned[1].href, "foo/fn.from_bytes.html");
+    assert!(results.in_args[0].deprecated);
+
+    let results = search("foo::from", None);
+    assert_eq!(names(&results.others), ["fn foo::from_bytes"]);
+}
+
+#[test]
+fn type_filter() {
+    let results = search("struct:vec", None);
+    assert_eq!(names(&results.others), ["struct foo::Vec"]);
+}
+
+#[test]
+fn literal() {
+    assert_eq!(names(&search("\"from_byte\"", None).others), Vec::<String>::new());
+    assert_eq!(names(&search("from_byte", None).others), ["fn foo::from_bytes"]);
+}
+
+#[test]
+fn by_signature() {
+    assert_eq!(names(&search("u8 -> vec", None).others), ["fn foo::from_bytes"]);
+    assert_eq!(
+        names(&search("-> vec", None).others),
+        ["method foo::Vec::new", "fn foo::from_bytes"]
+    );
+    assert_eq!(names(&search("-> vec<u8>", None).others), ["fn foo::from_bytes"]);
+    assert_eq!(names(&search("vec -> u8", None).others), ["method foo::Vec::len"]);
+    assert_eq!(names(&search("vec, u8", None).others), Vec::<String>::new());
+}
+
+#[test]
+fn correction() {
+    let results = search("vex", None);
+    assert_eq!(results.correction.as_deref(), Some("vec"));
+    assert_eq!(names(&results.in_args), ["method foo::Vec::len"]);
+}
+
+#[test]
+fn aliases() {
+    let results = search("Array", None);
+    assert_eq!(names(&results.others), ["struct foo::Vec"]);
+    assert_eq!(results.others[0].alias.as_deref(), Some("Array"));
+}
+
+#[test]
+fn crate_filter() {
+    let results = search("vec", Some("bar"));
+    assert!(results.others.is_empty() && results.in_args.is_empty() && results.returned.is_empty());
+    assert!(search("array", Some("bar")).others.is_empty());
+    assert_eq!(names(&search("array", Some("foo")).others), ["struct foo::Vec"]);
+}
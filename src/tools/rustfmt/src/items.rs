@@ -178,6 +178,7 @@ pub(crate) struct FnSig<'a> {
     generics: &'a ast::Generics,
     ext: ast::Extern,
     is_async: Cow<'a, ast::Async>,
+    genness: ast::Gen,
     constness: ast::Const,
     defaultness: ast::Defaultness,
     unsafety: ast::Unsafe,
@@ -193,6 +194,7 @@ pub(crate) fn from_method_sig(
         FnSig {
             unsafety: method_sig.header.unsafety,
             is_async: Cow::Borrowed(&method_sig.header.asyncness),
+            genness: method_sig.header.genness,
             constness: method_sig.header.constness,
             defaultness: ast::Defaultness::Final,
             ext: method_sig.header.ext,
@@ -220,6 +222,7 @@ pub(crate) fn from_fn_kind(
                     ext: fn_sig.header.ext,
                     constness: fn_sig.header.constness,
                     is_async: Cow::Borrowed(&fn_sig.header.asyncness),
+                    genness: fn_sig.header.genness,
                     defaultness,
                     unsafety: fn_sig.header.unsafety,
                     visibility: vis,
@@ -236,6 +239,7 @@ fn to_str(&self, context: &RewriteContext<'_>) -> String {
         result.push_str(format_defaultness(self.defaultness));
         result.push_str(format_constness(self.constness));
         result.push_str(format_async(&self.is_async));
+        result.push_str(format_gen(self.genness));
         result.push_str(format_unsafety(self.unsafety));
         result.push_str(&format_extern(
             self.ext,
@@ -1733,7 +1737,8 @@ pub(crate) fn rewrite_struct_field(
     shape: Shape,
     lhs_max_width: usize,
 ) -> Option<String> {
-    if contains_skip(&field.attrs) {
+    // FIXME: format default values of fields instead of leaving them untouched.
+    if contains_skip(&field.attrs) || field.default.is_some() {
         return Some(context.snippet(field.span()).to_owned());
     }
 
@@ -82,6 +82,14 @@ pub(crate) fn format_async(is_async: &ast::Async) -> &'static str {
     }
 }
 
+#[inline]
+pub(crate) fn format_gen(genness: ast::Gen) -> &'static str {
+    match genness {
+        ast::Gen::Yes { .. } => "gen ",
+        ast::Gen::No => "",
+    }
+}
+
 #[inline]
 pub(crate) fn format_constness(constness: ast::Const) -> &'static str {
     match constness {
@@ -472,7 +480,7 @@ pub(crate) fn is_block_expr(context: &RewriteContext<'_>, expr: &ast::Expr, repr
         | ast::ExprKind::If(..)
         | ast::ExprKind::Block(..)
         | ast::ExprKind::ConstBlock(..)
-        | ast::ExprKind::Async(..)
+        | ast::ExprKind::Gen(..)
         | ast::ExprKind::Loop(..)
         | ast::ExprKind::ForLoop(..)
         | ast::ExprKind::TryBlock(..)
@@ -506,6 +514,7 @@ pub(crate) fn is_block_expr(context: &RewriteContext<'_>, expr: &ast::Expr, repr
         | ast::ExprKind::Repeat(..)
         | ast::ExprKind::Ret(..)
         | ast::ExprKind::Yeet(..)
+        | ast::ExprKind::Become(..)
         | ast::ExprKind::Tup(..)
         | ast::ExprKind::Type(..)
         | ast::ExprKind::Yield(None)
@@ -0,0 +1,32 @@
+// compile-flags: -C opt-level=0 -C debug-assertions=no
+#![crate_type = "lib"]
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+// CHECK-LABEL: @count_down
+#[no_mangle]
+pub fn count_down(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    // CHECK: [[RET:%.*]] = musttail call {{.*}}i32 @count_down(i32
+    // CHECK-NEXT: ret i32 [[RET]]
+    become count_down(n - 1)
+}
+
+// CHECK-LABEL: @ping
+#[no_mangle]
+pub fn ping(n: u64) {
+    if n != 0 {
+        // CHECK: musttail call void @pong(i64
+        // CHECK-NEXT: ret void
+        become pong(n - 1)
+    }
+}
+
+#[no_mangle]
+pub fn pong(n: u64) {
+    if n != 0 {
+        become ping(n - 1)
+    }
+}
@@ -0,0 +1,19 @@
+// Check that `f16` and `f128` get their own basic type tags in the v0 mangling scheme.
+
+// compile-flags: -C symbol-mangling-version=v0 -C opt-level=0
+
+#![crate_type = "lib"]
+#![feature(f16, f128)]
+
+#[inline(never)]
+pub fn generic<T>() {}
+
+// CHECK-DAG: define {{.*}}@_RINv{{.*}}7generickE
+pub fn instantiate_f16() {
+    generic::<f16>();
+}
+
+// CHECK-DAG: define {{.*}}@_RINv{{.*}}7genericgE
+pub fn instantiate_f128() {
+    generic::<f128>();
+}
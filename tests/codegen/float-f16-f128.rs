@@ -0,0 +1,33 @@
+// compile-flags: -C no-prepopulate-passes
+// only-x86_64
+
+#![crate_type = "lib"]
+#![feature(f16, f128)]
+
+// CHECK-LABEL: @f16_add(
+#[no_mangle]
+pub fn f16_add(a: f16, b: f16) -> f16 {
+    // CHECK: fadd half %a, %b
+    a + b
+}
+
+// CHECK-LABEL: @f128_add(
+#[no_mangle]
+pub fn f128_add(a: f128, b: f128) -> f128 {
+    // CHECK: fadd fp128 %a, %b
+    a + b
+}
+
+// CHECK-LABEL: @f16_to_f32(
+#[no_mangle]
+pub fn f16_to_f32(a: f16) -> f32 {
+    // CHECK: fpext half %a to float
+    a as f32
+}
+
+// CHECK-LABEL: @f64_to_f128(
+#[no_mangle]
+pub fn f64_to_f128(a: f64) -> f128 {
+    // CHECK: fpext double %a to fp128
+    a as f128
+}
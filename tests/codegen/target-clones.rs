@@ -0,0 +1,24 @@
+// only-x86_64
+// compile-flags: -Copt-level=0
+
+#![crate_type = "lib"]
+#![feature(target_clones)]
+
+// CHECK-LABEL: define{{.*}}i32 @sum(
+// CHECK: load atomic ptr, ptr @{{.*}} monotonic
+// CHECK: call{{.*}}zeroext i1 @{{.*}}features_detected{{.*}}(ptr{{.*}}, i64 {{[0-9]+}})
+// CHECK: store atomic ptr %{{.*}}, ptr @{{.*}} monotonic
+// CHECK: call{{.*}}i32 %{{.*}}(i32 %a, i32 %b)
+
+// CHECK: define internal{{.*}}i32 @sum.avx2(i32 %a, i32 %b) unnamed_addr #[[AVX2:[0-9]+]]
+// CHECK: define internal{{.*}}i32 @sum.sse4.1_popcnt(i32 %a, i32 %b) unnamed_addr #[[SSE:[0-9]+]]
+// CHECK: define internal{{.*}}i32 @sum.default(i32 %a, i32 %b) unnamed_addr #[[DEFAULT:[0-9]+]]
+#[no_mangle]
+#[target_clones("avx2", "sse4.1,popcnt", "default")]
+pub fn sum(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+// CHECK: attributes #[[AVX2]] = {{.*}}"target-features"="{{.*}}+avx2{{.*}}"
+// CHECK: attributes #[[SSE]] = {{.*}}"target-features"="{{.*}}+sse4.1,+popcnt{{.*}}"
+// CHECK-NOT: attributes #[[DEFAULT]] = {{.*}}"target-features"="{{.*}}+avx2
@@ -0,0 +1,16 @@
+// Checks that `#[target_clones]` functions are not inlined, which would bypass the runtime
+// dispatch between their clones.
+//
+// only-x86_64
+
+#![crate_type = "lib"]
+#![feature(target_clones)]
+
+// EMIT_MIR inline_target_clones.not_inlined_target_clones.Inline.diff
+pub fn not_inlined_target_clones() {
+    target_clones();
+}
+
+#[inline]
+#[target_clones("avx2", "default")]
+pub fn target_clones() {}
@@ -0,0 +1,29 @@
+//! Shapes on a [`Plane`](plane::Plane).
+
+/// A point on a [`Plane`](plane::Plane).
+///
+/// # Examples
+///
+/// ```
+/// # use foo::Point;
+/// let p = Point { x: 1, y: 2 };
+/// ```
+pub struct Point {
+    /// The horizontal coordinate.
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    /// Returns the point at the origin.
+    pub fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+}
+
+pub mod plane {
+    /// A plane containing [`Point`](crate::Point)s.
+    pub trait Plane {
+        fn contains(&self, point: &crate::Point) -> bool;
+    }
+}
@@ -0,0 +1,45 @@
+// @is "$.index[*][?(@.name=='Pair')].inner.layout.size" 8
+// @is "$.index[*][?(@.name=='Pair')].inner.layout.align" 4
+// @is "$.index[*][?(@.name=='Pair')].inner.layout.is_unsized" false
+// @is "$.index[*][?(@.name=='Pair')].inner.layout.variants" []
+// @is "$.index[*][?(@.name=='Pair')].inner.layout.niche" null
+pub struct Pair {
+    pub a: u32,
+    pub b: u32,
+}
+
+// @is "$.index[*][?(@.name=='Flag')].inner.layout.size" 1
+// @is "$.index[*][?(@.name=='Flag')].inner.layout.niche.offset" 0
+// @is "$.index[*][?(@.name=='Flag')].inner.layout.niche.size" 1
+// @is "$.index[*][?(@.name=='Flag')].inner.layout.niche.available" '"254"'
+pub struct Flag(pub bool);
+
+// @is "$.index[*][?(@.name=='Variants')].inner.layout.size" 2
+// @is "$.index[*][?(@.name=='Variants')].inner.layout.variants[0].name" '"A"'
+// @is "$.index[*][?(@.name=='Variants')].inner.layout.variants[0].size" 0
+// @is "$.index[*][?(@.name=='Variants')].inner.layout.variants[1].name" '"B"'
+// @is "$.index[*][?(@.name=='Variants')].inner.layout.variants[1].size" 1
+pub enum Variants {
+    A,
+    B(u8),
+}
+
+// @is "$.index[*][?(@.name=='WithNiche')].inner.layout.size" 4
+// @is "$.index[*][?(@.name=='WithNiche')].inner.layout.variants[0].size" 0
+// @is "$.index[*][?(@.name=='WithNiche')].inner.layout.variants[1].size" 4
+pub enum WithNiche {
+    None,
+    Some(std::num::NonZeroU32),
+}
+
+// @is "$.index[*][?(@.name=='Uninhabited')].inner.layout.is_uninhabited" true
+pub enum Uninhabited {}
+
+// @is "$.index[*][?(@.name=='IntOrFloat')].inner.layout.size" 4
+pub union IntOrFloat {
+    pub int: u32,
+    pub float: f32,
+}
+
+// @is "$.index[*][?(@.name=='Generic')].inner.layout" null
+pub struct Generic<T>(pub T);
@@ -0,0 +1,4 @@
+// compile-flags:-Z unstable-options --show-coverage --coverage-fail-under 150
+
+/// Foo
+pub struct Xo;
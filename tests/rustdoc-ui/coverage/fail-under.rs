@@ -0,0 +1,10 @@
+// compile-flags:-Z unstable-options --output-format json --show-coverage --coverage-fail-under 75
+
+//! Crate docs.
+
+/// Documented.
+pub struct Documented;
+
+pub fn undocumented() {}
+
+pub struct Undocumented;
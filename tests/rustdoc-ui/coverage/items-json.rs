@@ -0,0 +1,17 @@
+// check-pass
+// compile-flags:-Z unstable-options --output-format json --show-coverage --coverage-items
+
+//! ```
+//! let x = 12;
+//! ```
+
+/// Documented, without example.
+pub struct Documented;
+
+pub fn undocumented() {}
+
+pub enum Enum {
+    /// Documented variant.
+    A,
+    B,
+}
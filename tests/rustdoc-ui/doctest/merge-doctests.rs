@@ -0,0 +1,50 @@
+// Checks that doctests compiled together with `--merge-doctests` are still run and reported one
+// by one, alongside the doctests that have to be compiled on their own.
+
+// check-pass
+// edition:2021
+// compile-flags:--test -Zunstable-options --merge-doctests
+// normalize-stdout-test: "tests/rustdoc-ui/doctest" -> "$$DIR"
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+
+/// ```
+/// use merge_doctests::Foo;
+/// let _foo = Foo;
+/// ```
+///
+/// ```should_panic
+/// panic!("expected");
+/// ```
+///
+/// ```
+/// # fn main() -> Result<(), std::num::ParseIntError> {
+/// let x: u32 = "4".parse()?;
+/// assert_eq!(x, 4);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// let x: u8 = 256u16;
+/// ```
+///
+/// ```
+/// #![allow(dead_code)]
+/// struct Bar;
+/// ```
+pub struct Foo;
+
+// These doctests only pass when they're compiled into the merged binary of this file, with their
+// code at its line in the file.
+/// ```
+/// assert!(module_path!().starts_with("rust_out::_doctest_"));
+/// assert!(file!().ends_with("merge-doctests.rs"));
+/// assert_eq!(line!(), 42);
+/// ```
+///
+/// ```
+/// # use merge_doctests::Foo;
+/// let _foo = Foo;
+/// assert_eq!(line!(), 48);
+/// ```
+pub struct Merged;
@@ -0,0 +1,64 @@
+// compile-flags: -Zunstable-options --cfg-set alpha --cfg-set beta
+
+#![crate_name = "foo"]
+
+// @has 'foo/index.html'
+// @count - '//*[@class="item-name"][a="everywhere"]/*[@class="stab portability"]' 0
+// @has - '//*[@class="item-name"][a="only_alpha"]/*[@class="stab portability"]' 'alpha'
+// @has - '//*[@class="item-name"]/a[@href="fn.only_beta.html"]' 'only_beta'
+// @has - '//*[@class="item-name"][a="only_beta"]/*[@class="stab portability"]' 'beta'
+// @has - '//*[@class="item-name"]/a[@href="beta_mod/index.html"]' 'beta_mod'
+// @count - '//*[@class="item-name"][a="either"]/*[@class="stab portability"]' 0
+
+// The items of every set are in the list of all items and in the search index.
+// @has 'foo/all.html' '//a[@href="fn.only_alpha.html"]' 'only_alpha'
+// @has - '//a[@href="fn.only_beta.html"]' 'only_beta'
+// @hasraw 'search-index.js' 'only_alpha'
+// @hasraw 'search-index.js' 'only_beta'
+// @hasraw 'search-index.js' 'BetaStruct'
+
+// @has 'foo/fn.everywhere.html'
+// @!has - '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' ''
+pub fn everywhere() {}
+
+// @has 'foo/fn.only_alpha.html' \
+//  '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' \
+//  'Available on alpha only.'
+#[cfg(alpha)]
+pub fn only_alpha() {}
+
+// @has 'foo/fn.only_beta.html' \
+//  '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' \
+//  'Available on beta only.'
+#[cfg(beta)]
+pub fn only_beta() {}
+
+// @has 'foo/fn.either.html'
+// @!has - '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' ''
+#[cfg(any(alpha, beta))]
+pub fn either() {}
+
+// A cfg-pattern holds if it holds in one of the sets, not if each of its cfgs does.
+// @!has 'foo/fn.both.html'
+#[cfg(all(alpha, beta))]
+pub fn both() {}
+
+// @has 'foo/beta_mod/struct.BetaStruct.html' \
+//  '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' \
+//  'Available on beta only.'
+#[cfg(beta)]
+pub mod beta_mod {
+    pub struct BetaStruct;
+
+    // @!has 'foo/beta_mod/struct.AlphaInBeta.html'
+    #[cfg(alpha)]
+    pub struct AlphaInBeta;
+}
+
+// Items with the same name in different sets don't conflict.
+// @has 'foo/index.html' '//*[@class="item-name"][a="Id"]/*[@class="stab portability"]' 'alpha'
+// @has - '//*[@class="item-name"][a="Id"]/*[@class="stab portability"]' 'beta'
+#[cfg(alpha)]
+pub type Id = u8;
+#[cfg(beta)]
+pub type Id = u16;
@@ -80,6 +80,16 @@ fn test_stable_mir(tcx: TyCtxt<'_>) {
         stable_mir::mir::Terminator::Assert { .. } => {}
         other => panic!("{other:?}"),
     }
+    assert_eq!(assert.contract(), stable_mir::FnContract::default());
+
+    let checked = get_item(tcx, &items, (DefKind::Fn, "checked")).unwrap();
+    let contract = checked.contract();
+    assert_eq!(contract.requires.len(), 1);
+    assert_eq!(contract.ensures.len(), 2);
+    for closure in contract.requires.iter().chain(&contract.ensures) {
+        let def_id = rustc_internal::item_def_id(closure);
+        assert_eq!(tcx.def_kind(def_id), DefKind::Closure);
+    }
 }
 
 // Use internal API to find a function in a crate.
@@ -137,6 +147,9 @@ fn generate_input(path: &str) -> std::io::Result<()> {
     write!(
         file,
         r#"
+    #![feature(contracts)]
+    #![allow(incomplete_features)]
+
     mod foo {{
         pub fn bar(i: i32) -> i64 {{
             i as i64
@@ -157,6 +170,13 @@ pub fn drop(_: String) {{}}
 
     pub fn assert(x: i32) -> i32 {{
         x + 1
+    }}
+
+    #[core::contracts::requires(x > 0)]
+    #[core::contracts::ensures(|ret| *ret > x)]
+    #[core::contracts::ensures(|ret| *ret % 2 == 0)]
+    pub fn checked(x: i32) -> i32 {{
+        x * 2
     }}"#
     )?;
     Ok(())
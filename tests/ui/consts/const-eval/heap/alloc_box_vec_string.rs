@@ -0,0 +1,53 @@
+// run-pass
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+#![feature(string_leak)]
+
+const fn squares(n: u32) -> &'static [u32] {
+    let mut v = Vec::new();
+    let mut i = 0;
+    while i < n {
+        v.push(i * i);
+        i += 1;
+    }
+    v.leak()
+}
+
+const fn boxed(x: i32) -> &'static i32 {
+    Box::leak(Box::new(x))
+}
+
+const SQUARES: &[u32] = squares(10);
+
+const BOXED: &i32 = boxed(42);
+
+const GREETING: &str = {
+    let mut s = String::with_capacity(2);
+    s.push_str("hello");
+    s.push_str(", world");
+    s.leak()
+};
+
+// The buffers of the strings are only reachable through a shared reference, so they are interned
+// like the slice itself.
+const NAMES: &[String] = {
+    let mut names = Vec::with_capacity(1);
+    let mut name = String::new();
+    name.push_str("ferris");
+    names.push(name);
+    names.push(String::new());
+    names.leak()
+};
+
+fn main() {
+    assert_eq!(SQUARES, &[0, 1, 4, 9, 16, 25, 36, 49, 64, 81]);
+    assert_eq!(*BOXED, 42);
+    assert_eq!(GREETING, "hello, world");
+    assert_eq!(NAMES.len(), 2);
+    assert_eq!(NAMES[0], "ferris");
+    assert!(NAMES[1].is_empty());
+
+    // The same functions still work at runtime.
+    assert_eq!(squares(3), &[0, 1, 4]);
+    assert_eq!(*boxed(7), 7);
+}
@@ -0,0 +1,21 @@
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+
+const V: Vec<u8> = {
+//~^ ERROR heap allocations are not allowed in the final value of a constant
+    let mut v = Vec::new();
+    v.push(1);
+    v
+};
+
+const B: Box<i32> = Box::new(1);
+//~^ ERROR heap allocations are not allowed in the final value of a constant
+
+static S: String = {
+//~^ ERROR heap allocations are not allowed in the final value of a static
+    let mut s = String::new();
+    s.push_str("static");
+    s
+};
+
+fn main() {}
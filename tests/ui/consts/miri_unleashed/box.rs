@@ -5,7 +5,6 @@
 fn main() {}
 
 static TEST_BAD: &mut i32 = {
+//~^ ERROR mutable references to heap allocations are not allowed in the final value of a static
     &mut *(Box::new(0))
-    //~^ ERROR could not evaluate static initializer
-    //~| NOTE calling non-const function `Box::<i32>::new`
 };
@@ -0,0 +1,8 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+
+fn main() {}
+
+static OWNED: Box<i32> = Box::new(0);
+//~^ ERROR heap allocations are not allowed in the final value of a static
+
+static LEAKED: &i32 = Box::leak(Box::new(0));
@@ -0,0 +1,9 @@
+// compile-flags: -Zcontract-checks
+
+#![feature(contracts)]
+#![allow(incomplete_features)]
+
+#[core::contracts::requires(!items.is_empty())]
+pub fn first<T: Copy>(items: &[T]) -> Option<T> {
+    items.first().copied()
+}
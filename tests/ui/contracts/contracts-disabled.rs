@@ -0,0 +1,17 @@
+// run-pass
+// Without `-Zcontract-checks`, contracts are not evaluated, so violating them does not panic.
+
+#![feature(contracts)]
+#![allow(incomplete_features)]
+
+use std::contracts::{ensures, requires};
+
+#[requires(x > 0)]
+#[ensures(|ret| *ret > 100)]
+fn identity(x: i32) -> i32 {
+    x
+}
+
+fn main() {
+    assert_eq!(identity(0), 0);
+}
@@ -0,0 +1,55 @@
+// run-pass
+// compile-flags: -Zcontract-checks
+
+#![feature(contracts)]
+#![allow(incomplete_features)]
+
+use std::contracts::{ensures, requires};
+
+#[requires(x > 0)]
+#[ensures(|ret| *ret > x)]
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+#[requires(!v.is_empty())]
+#[ensures(|ret| v.contains(ret))]
+fn first_even_or_last<T: Copy + PartialEq>(v: &[T], is_even: impl Fn(T) -> bool) -> T
+where
+    T: std::fmt::Debug,
+{
+    for &x in v {
+        if is_even(x) {
+            return x;
+        }
+    }
+    v[v.len() - 1]
+}
+
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    #[requires(self.count < 10)]
+    #[ensures(|ret| *ret <= 10)]
+    fn bump(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+#[ensures(|ret: &Result<u32, std::num::ParseIntError>| ret.is_ok())]
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    let n = s.parse::<u32>()?;
+    Ok(n)
+}
+
+fn main() {
+    assert_eq!(double(3), 6);
+    assert_eq!(first_even_or_last(&[1, 3, 4, 5], |x| x % 2 == 0), 4);
+    assert_eq!(first_even_or_last(&[1, 3, 5], |x| x % 2 == 0), 5);
+    let mut counter = Counter { count: 0 };
+    assert_eq!(counter.bump(), 1);
+    assert_eq!(parse("12"), Ok(12));
+}
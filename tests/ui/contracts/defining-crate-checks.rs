@@ -0,0 +1,13 @@
+// run-fail
+// aux-build:checked-contracts.rs
+// error-pattern: failed requires check: !items.is_empty()
+// ignore-emscripten no processes
+
+// Contracts are checked according to the `-Zcontract-checks` of the crate defining the
+// function, even for the instances of a generic function in a crate compiled without it.
+
+extern crate checked_contracts;
+
+fn main() {
+    checked_contracts::first::<u8>(&[]);
+}
@@ -0,0 +1,20 @@
+// run-fail
+// compile-flags: -Zcontract-checks
+// error-pattern: failed ensures check: |ret| *ret != 0
+// ignore-emscripten no processes
+
+#![feature(contracts)]
+#![allow(incomplete_features)]
+
+// The postcondition is also checked on early returns.
+#[core::contracts::ensures(|ret| *ret != 0)]
+fn nonzero(x: i32) -> i32 {
+    if x == 0 {
+        return x;
+    }
+    x
+}
+
+fn main() {
+    nonzero(0);
+}
@@ -0,0 +1,7 @@
+// The syntax that the contract attributes expand to is not meant to be written by hand.
+
+fn foo(x: u32) -> u32 contract_requires(|| x > 0, "x > 0") { //~ ERROR contract internal machinery
+    x
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// run-fail
+// compile-flags: -Zcontract-checks
+// error-pattern: failed requires check: x > 0
+// ignore-emscripten no processes
+
+#![feature(contracts)]
+#![allow(incomplete_features)]
+
+#[core::contracts::requires(x > 0)]
+fn positive(x: i32) -> i32 {
+    x
+}
+
+fn main() {
+    positive(0);
+}
@@ -0,0 +1,26 @@
+// edition: 2021
+
+#![feature(contracts)]
+#![allow(incomplete_features)]
+
+use std::contracts::{ensures, requires};
+
+#[requires(true)] //~ ERROR contract attributes can only be applied to functions with a body
+struct S;
+
+trait T {
+    #[ensures(|_| true)] //~ ERROR contract attributes can only be applied to functions with a body
+    fn f(&self);
+}
+
+#[requires(x > 0)] //~ ERROR contracts are not supported on `const` functions
+const fn konst(x: u32) -> u32 {
+    x
+}
+
+#[ensures(|ret| *ret > 0)] //~ ERROR contracts are not supported on `async` functions
+async fn asink() -> u32 {
+    1
+}
+
+fn main() {}
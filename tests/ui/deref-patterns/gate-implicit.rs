@@ -0,0 +1,15 @@
+// gate-test-deref_patterns
+use std::rc::Rc;
+
+enum E {
+    A(u8),
+    B,
+}
+
+fn main() {
+    match Rc::new(E::B) {
+        E::A(_) => {}
+        //~^ ERROR mismatched types
+        _ => {}
+    }
+}
@@ -0,0 +1,23 @@
+#![feature(deref_patterns)]
+#![feature(deref_pure_trait)]
+
+use std::ops::{Deref, DerefPure};
+
+struct MyBox<T>(T);
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+unsafe impl<T> DerefPure for MyBox<T> {}
+
+fn main() {
+    match MyBox(Some(1)) {
+        MyBox(None) => {}
+        Some(_) => {}
+        //~^ ERROR mix of deref patterns and normal constructors
+    }
+}
@@ -0,0 +1,43 @@
+// run-pass
+// Deref patterns of the same match binding by shared and by mutable reference each get the
+// kind of borrow they need.
+#![feature(deref_patterns)]
+
+fn bump_first(mut v: Vec<u32>) -> Vec<u32> {
+    match v {
+        [] => {}
+        [ref mut x, ..] => *x += 1,
+    }
+    v
+}
+
+fn bump_second(mut v: Vec<u32>) -> Vec<u32> {
+    match v {
+        [ref mut x] => *x += 10,
+        [_, ref mut y, ..] => *y += 1,
+        [] => {}
+    }
+    v
+}
+
+fn bump_unless_big(mut v: Vec<u32>) -> Vec<u32> {
+    match v {
+        [x, ..] if x > 100 => {}
+        [ref mut x, ..] => *x += 1,
+        [] => {}
+    }
+    v
+}
+
+fn main() {
+    assert_eq!(bump_first(vec![]), vec![]);
+    assert_eq!(bump_first(vec![1, 2]), vec![2, 2]);
+
+    assert_eq!(bump_second(vec![1]), vec![11]);
+    assert_eq!(bump_second(vec![1, 2, 3]), vec![1, 3, 3]);
+    assert_eq!(bump_second(vec![]), vec![]);
+
+    assert_eq!(bump_unless_big(vec![101]), vec![101]);
+    assert_eq!(bump_unless_big(vec![1, 2]), vec![2, 2]);
+    assert_eq!(bump_unless_big(vec![]), vec![]);
+}
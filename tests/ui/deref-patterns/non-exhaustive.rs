@@ -0,0 +1,26 @@
+#![feature(deref_patterns)]
+#![deny(unreachable_patterns)]
+
+use std::rc::Rc;
+
+fn main() {
+    let x = Rc::new(Some(1));
+    match x {
+        //~^ ERROR non-exhaustive patterns: `None` not covered
+        Some(_) => {}
+    }
+
+    let v = vec![1, 2];
+    match v {
+        //~^ ERROR non-exhaustive patterns: `[_, _, ..]` not covered
+        [] => {}
+        [_] => {}
+    }
+
+    match Rc::new(Some(1)) {
+        Some(_) => {}
+        None => {}
+        _ => {}
+        //~^ ERROR unreachable pattern
+    }
+}
@@ -0,0 +1,69 @@
+// run-pass
+#![feature(deref_patterns)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+enum Expr {
+    Num(i32),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+}
+
+fn eval(e: &Expr) -> i32 {
+    match e {
+        Expr::Num(n) => *n,
+        Expr::Neg(Expr::Num(n)) => -n,
+        Expr::Neg(inner) => -eval(inner),
+        Expr::Add(Expr::Num(0), rhs) | Expr::Add(rhs, Expr::Num(0)) => eval(rhs),
+        Expr::Add(lhs, rhs) => eval(lhs) + eval(rhs),
+    }
+}
+
+fn slice_len(v: &Vec<u8>) -> usize {
+    match v {
+        [] => 0,
+        [_] => 1,
+        [_, rest @ ..] => 1 + rest.len(),
+    }
+}
+
+fn first_word(s: Rc<Option<String>>) -> Option<usize> {
+    match &s {
+        Some(s) => s.split_whitespace().next().map(str::len),
+        None => None,
+    }
+}
+
+fn main() {
+    let e = Expr::Add(
+        Box::new(Expr::Neg(Box::new(Expr::Num(3)))),
+        Box::new(Expr::Add(Box::new(Expr::Num(0)), Box::new(Expr::Num(5)))),
+    );
+    assert_eq!(eval(&e), 2);
+    assert_eq!(eval(&Expr::Neg(Box::new(Expr::Neg(Box::new(Expr::Num(7)))))), 7);
+
+    assert_eq!(slice_len(&vec![]), 0);
+    assert_eq!(slice_len(&vec![1]), 1);
+    assert_eq!(slice_len(&vec![1, 2, 3]), 3);
+
+    assert_eq!(first_word(Rc::new(Some("hello world".to_string()))), Some(5));
+    assert_eq!(first_word(Rc::new(None)), None);
+
+    let arc = Arc::new((1, Some(2)));
+    match arc {
+        (1, Some(n)) => assert_eq!(n, 2),
+        _ => unreachable!(),
+    }
+
+    // Bindings and wildcards bind the smart pointer itself.
+    let b = Box::new(5);
+    match b {
+        x => assert_eq!(*x, 5),
+    }
+    // Anything else matches on the pointee.
+    match Box::new(5) {
+        5 => {}
+        _ => unreachable!(),
+    }
+}
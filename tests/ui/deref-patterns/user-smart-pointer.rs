@@ -0,0 +1,52 @@
+// run-pass
+#![feature(deref_patterns)]
+#![feature(deref_pure_trait)]
+
+use std::ops::{Deref, DerefMut, DerefPure};
+
+struct MyBox<T>(T);
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+unsafe impl<T> DerefPure for MyBox<T> {}
+
+fn main() {
+    let b = MyBox(Some(3));
+    match &b {
+        Some(n) => assert_eq!(*n, 3),
+        None => unreachable!(),
+    }
+
+    // Matching on the smart pointer's own constructor doesn't dereference it.
+    let MyBox(inner) = &b;
+    assert_eq!(*inner, Some(3));
+
+    // `ref mut` bindings go through `DerefMut`.
+    let mut b = MyBox(Some(3));
+    if let Some(ref mut n) = b {
+        *n += 1;
+    }
+    assert_eq!(b.0, Some(4));
+    match &mut b {
+        Some(n) => *n *= 2,
+        None => unreachable!(),
+    }
+    assert_eq!(b.0, Some(8));
+
+    let mut v = vec![1, 2, 3];
+    if let [first, .., last] = &mut v {
+        std::mem::swap(first, last);
+    }
+    assert_eq!(v, [3, 2, 1]);
+}
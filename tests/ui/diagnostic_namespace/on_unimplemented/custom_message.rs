@@ -0,0 +1,23 @@
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(
+    message = "the trait `Handler` is not implemented for `{Self}`",
+    label = "not a handler",
+    note = "handlers must be registered with `#[handler]`"
+)]
+trait Handler {}
+
+#[diagnostic::on_unimplemented(message = "`{Self}` can't be used as a `Route<{T}>`")]
+#[diagnostic::on_unimplemented(label = "missing route")]
+trait Route<T> {}
+
+fn takes_handler(_: impl Handler) {}
+
+fn takes_route(_: impl Route<u8>) {}
+
+fn main() {
+    takes_handler(1_i32);
+    //~^ ERROR the trait `Handler` is not implemented for `i32`
+    takes_route(());
+    //~^ ERROR `()` can't be used as a `Route<u8>`
+}
@@ -0,0 +1,20 @@
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(message = "`{Self}` is not a `{Reader}` of `{B}`")]
+//~^ WARN there is no parameter `B` on trait `Reader`
+trait Reader<A> {}
+
+#[diagnostic::on_unimplemented(label = "{} is missing")]
+//~^ WARN positional format arguments are not allowed here
+trait Writer {}
+
+fn takes_reader(_: impl Reader<u8>) {}
+fn takes_writer(_: impl Writer) {}
+
+fn main() {
+    // Invalid format parameters are printed as they are written.
+    takes_reader(());
+    //~^ ERROR `()` is not a `Reader` of `{B}`
+    takes_writer(());
+    //~^ ERROR the trait bound `(): Writer` is not satisfied
+}
@@ -0,0 +1,31 @@
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(unsupported = "foo")]
+//~^WARN malformed `on_unimplemented` attribute
+trait Foo {}
+
+#[diagnostic::on_unimplemented(message = "Baz", on(Self = "u32", message = "Bar"))]
+//~^WARN malformed `on_unimplemented` attribute
+trait Bar {}
+
+#[diagnostic::on_unimplemented(message = "first", message = "second", parent_label = "x")]
+//~^WARN malformed `on_unimplemented` attribute
+//~|WARN malformed `on_unimplemented` attribute
+trait Baz {}
+
+#[diagnostic::on_unimplemented = "message"]
+//~^WARN malformed `on_unimplemented` attribute
+trait Boom {}
+
+fn takes_foo(_: impl Foo) {}
+fn takes_bar(_: impl Bar) {}
+fn takes_baz(_: impl Baz) {}
+
+fn main() {
+    takes_foo(());
+    //~^ERROR the trait bound `(): Foo` is not satisfied
+    takes_bar(1_u32);
+    //~^ERROR Baz
+    takes_baz(());
+    //~^ERROR first
+}
@@ -0,0 +1,25 @@
+// check-pass
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(message = "Foo")]
+//~^ WARN `#[diagnostic::on_unimplemented]` can only be applied to trait definitions
+pub struct Foo;
+
+#[diagnostic::on_unimplemented(message = "Bar")]
+//~^ WARN `#[diagnostic::on_unimplemented]` can only be applied to trait definitions
+impl Clone for Foo {
+    fn clone(&self) -> Self {
+        Foo
+    }
+}
+
+#[diagnostic::on_unimplemented(message = "Baz")]
+//~^ WARN `#[diagnostic::on_unimplemented]` can only be applied to trait definitions
+fn baz() {}
+
+#[diagnostic::on_unimplemented(message = "Qux")]
+trait Qux {}
+
+fn main() {
+    baz();
+}
@@ -0,0 +1,14 @@
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+const _: () = {
+    become f(); //~ error: `become` outside of function body
+};
+
+static _S: () = {
+    become f(); //~ error: `become` outside of function body
+};
+
+fn f() {}
+
+fn main() {}
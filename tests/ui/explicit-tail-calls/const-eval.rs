@@ -0,0 +1,38 @@
+// run-pass
+// Checks that `become` replaces the current frame in const evaluation, which would otherwise
+// exceed the limit on the number of stack frames.
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+const fn count(n: u64, acc: u64) -> u64 {
+    if n == 0 {
+        return acc;
+    }
+    become count(n - 1, acc + 1)
+}
+
+const fn fill(n: usize, acc: [u64; 8]) -> [u64; 8] {
+    if n == 0 {
+        return acc;
+    }
+    let mut acc = acc;
+    acc[n % 8] += 1;
+    become fill(n - 1, acc)
+}
+
+const fn swap(n: u32, a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    if n == 0 {
+        return a;
+    }
+    become swap(n - 1, b, a)
+}
+
+const COUNT: u64 = count(10_000, 0);
+const FILL: [u64; 8] = fill(8_000, [0; 8]);
+const SWAPPED: [u8; 32] = swap(1_001, [1; 32], [2; 32]);
+
+fn main() {
+    assert_eq!(COUNT, 10_000);
+    assert_eq!(FILL, [1_000; 8]);
+    assert_eq!(SWAPPED, [2; 32]);
+}
@@ -0,0 +1,68 @@
+// run-pass
+// Checks that `become` reuses the frame of the caller, also when arguments or the return value
+// are passed by reference.
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+const DEPTH: u64 = 1_000_000;
+
+fn count(n: u64, acc: u64) -> u64 {
+    if n == 0 {
+        return acc;
+    }
+    become count(n - 1, acc + 1)
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Big([u64; 16]);
+
+fn sum(n: u64, acc: Big) -> Big {
+    if n == 0 {
+        return acc;
+    }
+    let mut acc = acc;
+    acc.0[(n % 16) as usize] += n;
+    become sum(n - 1, acc)
+}
+
+// The arguments are read from the very slots they are passed in.
+fn swap(n: u64, a: Big, b: Big) -> Big {
+    if n == 0 {
+        return a;
+    }
+    become swap(n - 1, b, a)
+}
+
+fn is_even(n: u64, s: String) -> (bool, String) {
+    if n == 0 {
+        return (true, s);
+    }
+    let mut s = s;
+    s.push('e');
+    become is_odd(n - 1, s)
+}
+
+fn is_odd(n: u64, s: String) -> (bool, String) {
+    if n == 0 {
+        return (false, s);
+    }
+    let mut s = s;
+    s.push('o');
+    become is_even(n - 1, s)
+}
+
+fn main() {
+    assert_eq!(count(DEPTH, 0), DEPTH);
+
+    let total = sum(DEPTH, Big([0; 16]));
+    assert_eq!(total.0.iter().sum::<u64>(), DEPTH * (DEPTH + 1) / 2);
+
+    let (a, b) = (Big([1; 16]), Big([2; 16]));
+    assert_eq!(swap(DEPTH, a, b), a);
+    assert_eq!(swap(DEPTH + 1, a, b), b);
+
+    let (even, s) = is_even(DEPTH, String::new());
+    assert!(even);
+    assert_eq!(s.len() as u64, DEPTH);
+    assert!(s.starts_with("eoeo"));
+}
@@ -0,0 +1,14 @@
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+fn f(s: String) {
+    let _t = String::new();
+    become g(s); //~ error: `become` while a value with a destructor is still live
+}
+
+fn g(s: String) {
+    // `s` is moved into the call, so nothing is left to drop.
+    become f(s);
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+fn f() -> u32 {
+    become 1; //~ error: `become` requires a function call
+}
+
+fn g() -> u32 {
+    become { h() } //~ error: `become` requires a function call
+}
+
+fn h() -> u32 {
+    0
+}
+
+fn main() {}
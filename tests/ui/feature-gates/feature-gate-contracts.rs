@@ -0,0 +1,6 @@
+#[core::contracts::requires(x > 0)] //~ ERROR use of unstable library feature 'contracts'
+fn positive(x: i32) -> i32 {
+    x
+}
+
+fn main() {}
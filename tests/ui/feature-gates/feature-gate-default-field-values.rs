@@ -0,0 +1,8 @@
+struct S {
+    a: i32 = 1, //~ ERROR default values on fields are experimental
+    b: i32,
+}
+
+fn main() {
+    let _ = S { b: 2, .. }; //~ ERROR base expression required after `..`
+}
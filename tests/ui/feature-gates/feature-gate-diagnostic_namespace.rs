@@ -0,0 +1,11 @@
+#[diagnostic::non_existing_attribute]
+//~^ ERROR `#[diagnostic]` attribute name space is experimental [E0658]
+pub trait Bar {
+}
+
+#[diagnostic::non_existing_attribute(with_option = "foo")]
+//~^ ERROR `#[diagnostic]` attribute name space is experimental [E0658]
+struct Foo;
+
+fn main() {
+}
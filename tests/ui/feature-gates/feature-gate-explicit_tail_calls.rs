@@ -0,0 +1,9 @@
+pub fn you<T>() -> T {
+    become bottom(); //~ ERROR `become` expression is experimental
+}
+
+pub fn bottom<T>() -> T {
+    become you(); //~ ERROR `become` expression is experimental
+}
+
+fn main() {}
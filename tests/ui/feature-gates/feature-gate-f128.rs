@@ -0,0 +1,10 @@
+fn f128_fn(x: f128) -> f128 {
+    //~^ ERROR the type `f128` is unstable
+    //~| ERROR the type `f128` is unstable
+    x
+}
+
+fn main() {
+    let a: f128 = 1.0; //~ ERROR the type `f128` is unstable
+    let b = 2.0f128; //~ ERROR the type `f128` is unstable
+}
@@ -0,0 +1,10 @@
+fn f16_fn(x: f16) -> f16 {
+    //~^ ERROR the type `f16` is unstable
+    //~| ERROR the type `f16` is unstable
+    x
+}
+
+fn main() {
+    let a: f16 = 1.0; //~ ERROR the type `f16` is unstable
+    let b = 2.0f16; //~ ERROR the type `f16` is unstable
+}
@@ -0,0 +1,5 @@
+fn main() {
+    let _: std::pat::pattern_type!(u32 is 1..);
+    //~^ ERROR use of unstable library feature 'pattern_types'
+    //~| ERROR pattern types are unstable
+}
@@ -0,0 +1,9 @@
+// only-x86_64
+
+#[target_clones("avx2", "default")]
+//~^ the `#[target_clones]` attribute is an experimental feature
+fn foo() {}
+
+fn main() {
+    foo();
+}
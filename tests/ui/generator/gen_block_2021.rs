@@ -0,0 +1,12 @@
+// check-pass
+// edition:2021
+
+// `gen` is only a keyword starting with the 2024 edition.
+
+fn gen() {}
+
+fn main() {
+    let gen = 1;
+    let _ = gen;
+    self::gen();
+}
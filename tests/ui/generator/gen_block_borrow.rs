@@ -0,0 +1,12 @@
+// compile-flags: --edition 2024 -Zunstable-options
+#![feature(gen_blocks)]
+
+fn main() {
+    let _ = gen {
+        let v = vec![1, 2, 3];
+        for x in &v {
+            //~^ ERROR: borrow may still be in use when `gen` block yields
+            yield *x;
+        }
+    };
+}
@@ -0,0 +1,11 @@
+// compile-flags: --edition 2024 -Zunstable-options
+// run-pass
+#![feature(gen_blocks)]
+
+// A `gen` block without any `yield` is an empty iterator.
+
+fn main() {
+    let mut iter = gen {};
+    assert_eq!(Iterator::next(&mut iter), None::<()>);
+    assert_eq!(Iterator::next(&mut iter), None::<()>);
+}
@@ -0,0 +1,13 @@
+// compile-flags: --edition 2024 -Zunstable-options --crate-type lib
+#![feature(gen_blocks)]
+
+pub const gen fn x() {}
+//~^ ERROR functions cannot be both `const` and `gen`
+
+pub async gen fn y() {}
+//~^ ERROR functions cannot be both `async` and `gen`
+
+trait Tr {
+    gen fn z();
+    //~^ ERROR `gen fn` is not supported in traits or trait impls
+}
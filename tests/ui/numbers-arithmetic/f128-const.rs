@@ -0,0 +1,14 @@
+// check-pass
+#![feature(f128)]
+
+// Const evaluation of `f128` does not depend on any runtime support.
+
+const SUM: f128 = 0.1 + 0.2;
+const _: () = assert!(SUM.to_bits() == 0x3ffd3333333333333333333333333334);
+const _: () = assert!((12.5f128).to_bits() == 0x40029000000000000000000000000000);
+const _: () = assert!(f128::MAX.to_bits() == 0x7ffeffffffffffffffffffffffffffff);
+const _: () = assert!(f128::EPSILON.to_bits() == 0x3f8f0000000000000000000000000000);
+const _: () = assert!((1.0f128 / 3.0) as f64 as f128 != 1.0 / 3.0);
+const _: () = assert!(f128::NAN.is_nan());
+
+fn main() {}
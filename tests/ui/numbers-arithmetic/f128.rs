@@ -0,0 +1,59 @@
+// run-pass
+// only-x86_64
+// only-linux
+// `compiler-builtins` does not provide `f128` division and integer conversions yet,
+// so these come from libgcc.
+#![feature(f128)]
+
+const HALF: f128 = 1.0 / 2.0;
+const MAX_BITS: u128 = f128::MAX.to_bits();
+
+#[inline(never)]
+fn ops(a: f128, b: f128) -> [f128; 5] {
+    [a + b, a - b, a * b, a / b, a % b]
+}
+
+fn main() {
+    assert_eq!(HALF.to_bits(), 0x3ffe0000000000000000000000000000);
+    assert_eq!(MAX_BITS, 0x7ffeffffffffffffffffffffffffffff);
+    assert_eq!(f128::from_bits(0x40029000000000000000000000000000), 12.5);
+
+    assert_eq!(ops(7.0, 2.0), [9.0, 5.0, 14.0, 3.5, 1.0]);
+    assert_eq!((0.1f128 + 0.2).to_bits(), 0x3ffd3333333333333333333333333334);
+    assert!((f128::MAX * 2.0).is_infinite());
+    assert!(f128::NAN.is_nan());
+    assert_eq!(-HALF, -0.5f128);
+    assert!(HALF < 1.0 && HALF > 0.0);
+
+    assert_eq!(1.5f64 as f128, 1.5f128);
+    assert_eq!(1.5f128 as f32, 1.5f32);
+    assert_eq!(300u32 as f128, 300.0);
+    assert_eq!(-2.75f128 as i64, -2);
+    assert_eq!(f128::MAX as f64, f64::INFINITY);
+
+    assert_eq!("3.25".parse::<f128>(), Ok(3.25));
+    assert_eq!("-0".parse::<f128>().map(f128::to_bits), Ok(1 << 127));
+    assert_eq!("1.18973149535723176508575932662800702e4932".parse::<f128>(), Ok(f128::MAX));
+    assert_eq!("1.2e4932".parse::<f128>(), Ok(f128::INFINITY));
+    assert_eq!("6e-4966".parse::<f128>().map(f128::to_bits), Ok(1));
+    assert_eq!("1e-4966".parse::<f128>(), Ok(0.0));
+    assert!("-nan".parse::<f128>().unwrap().is_nan());
+    assert!("1.0.0".parse::<f128>().is_err());
+
+    assert_eq!(format!("{}", 0.1f128), "0.1");
+    assert_eq!(format!("{}", 1.0f128 / 3.0), "0.3333333333333333333333333333333333");
+    assert_eq!(format!("{}", core::f128::consts::PI), "3.1415926535897932384626433832795028");
+    assert_eq!(format!("{:?}", 2.0f128), "2.0");
+    assert_eq!(format!("{:?}", f128::MAX), "1.189731495357231765085759326628007e4932");
+    assert_eq!(format!("{:e}", 1024.0f128), "1.024e3");
+    assert_eq!(format!("{:e}", f128::MIN_POSITIVE), "3.3621031431120935062626778173217526e-4932");
+    assert_eq!(format!("{:e}", f128::from_bits(1)), "6e-4966");
+    assert_eq!(format!("{:.3}", 2.0f128 / 3.0), "0.667");
+    assert_eq!(format!("{:.0}", f128::from_bits(1)), "0");
+
+    let tricky = [1.0f128 / 3.0, core::f128::consts::E, f128::MAX, f128::from_bits(1)];
+    for x in tricky {
+        assert_eq!(x.to_string().parse::<f128>().map(f128::to_bits), Ok(x.to_bits()));
+        assert_eq!(format!("{:e}", x).parse::<f128>().map(f128::to_bits), Ok(x.to_bits()));
+    }
+}
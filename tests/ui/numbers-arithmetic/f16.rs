@@ -0,0 +1,35 @@
+// run-pass
+// only-x86_64
+// Arithmetic on `f16` is lowered to soft-float libcalls, which are not yet provided everywhere.
+#![feature(f16)]
+
+const HALF: f16 = 1.0 / 2.0;
+const MAX_BITS: u16 = f16::MAX.to_bits();
+
+#[inline(never)]
+fn ops(a: f16, b: f16) -> [f16; 5] {
+    [a + b, a - b, a * b, a / b, a % b]
+}
+
+fn main() {
+    assert_eq!(HALF.to_bits(), 0x3800);
+    assert_eq!(MAX_BITS, 0x7bff);
+    assert_eq!(f16::from_bits(0x4a40), 12.5);
+
+    assert_eq!(ops(7.0, 2.0), [9.0, 5.0, 14.0, 3.5, 1.0]);
+    assert!((f16::MAX * 2.0).is_infinite());
+    assert!(f16::NAN.is_nan());
+    assert_eq!(-HALF, -0.5f16);
+    assert!(HALF < 1.0 && HALF > 0.0);
+
+    assert_eq!(1.5f32 as f16, 1.5f16);
+    assert_eq!(1.5f16 as f64, 1.5f64);
+    assert_eq!(300u32 as f16, 300.0);
+    assert_eq!(100_000.0f32 as f16, f16::INFINITY);
+
+    assert_eq!("3.25".parse::<f16>(), Ok(3.25));
+    assert_eq!("65520".parse::<f16>(), Ok(f16::INFINITY));
+    assert_eq!(format!("{}", 0.1f16), "0.1");
+    assert_eq!(format!("{:?}", 2.0f16), "2.0");
+    assert_eq!(format!("{:e}", 1024.0f16), "1.024e3");
+}
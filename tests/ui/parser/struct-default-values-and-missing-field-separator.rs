@@ -1,4 +1,5 @@
 // run-rustfix
+#![feature(default_field_values)]
 #![allow(dead_code)]
 
 enum E {
@@ -6,21 +7,21 @@ enum E {
 }
 
 struct S {
-    field1: i32 = 42, //~ ERROR default values on `struct` fields aren't supported
-    field2: E = E::A, //~ ERROR default values on `struct` fields aren't supported
-    field3: i32 = 1 + 2, //~ ERROR default values on `struct` fields aren't supported
-    field4: i32 = { 1 + 2 }, //~ ERROR default values on `struct` fields aren't supported
-    field5: E = foo(42), //~ ERROR default values on `struct` fields aren't supported
-    field6: E = { foo(42) }, //~ ERROR default values on `struct` fields aren't supported
+    field1: i32 = 42,
+    field2: E = E::A,
+    field3: i32 = 1 + 2,
+    field4: i32 = { 1 + 2 },
+    field5: E = foo(42),
+    field6: E = { foo(42) },
 }
 
 struct S1 {
     field1: i32 //~ ERROR expected `,`, or `}`, found `field2`
     field2: E //~ ERROR expected `,`, or `}`, found `field3`
-    field3: i32 = 1 + 2, //~ ERROR default values on `struct` fields aren't supported
-    field4: i32 = { 1 + 2 }, //~ ERROR default values on `struct` fields aren't supported
-    field5: E = foo(42), //~ ERROR default values on `struct` fields aren't supported
-    field6: E = { foo(42) }, //~ ERROR default values on `struct` fields aren't supported
+    field3: i32 = 1 + 2,
+    field4: i32 = { 1 + 2 },
+    field5: E = foo(42),
+    field6: E = { foo(42) },
 }
 
 struct S2 {
@@ -1,4 +1,4 @@
 static mut a: Box<isize> = Box::new(3);
-//~^ ERROR cannot call non-const fn
+//~^ ERROR `Box::<isize>::new` is not yet stable as a const fn
 
 fn main() {}
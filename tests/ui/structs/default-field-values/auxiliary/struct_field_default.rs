@@ -0,0 +1,10 @@
+#![feature(default_field_values)]
+
+pub struct A {
+    pub a: isize = 42,
+}
+
+pub struct B<T> {
+    pub value: Option<T> = None,
+    pub len: usize = 3,
+}
@@ -0,0 +1,14 @@
+// run-pass
+// aux-build:struct_field_default.rs
+#![feature(default_field_values)]
+
+extern crate struct_field_default as xc;
+
+fn main() {
+    let a = xc::A { .. };
+    assert_eq!(a.a, 42);
+
+    let b: xc::B<u8> = xc::B { len: 5, .. };
+    assert_eq!(b.value, None);
+    assert_eq!(b.len, 5);
+}
@@ -0,0 +1,32 @@
+// run-pass
+#![feature(default_field_values)]
+
+// `#[derive(Default)]` uses the default values of fields when they have one, and
+// `Default::default()` otherwise.
+
+#[derive(Default, Debug, PartialEq)]
+struct Config {
+    retries: u32 = 3,
+    verbose: bool = true,
+    name: String,
+    extra: Vec<u8>,
+}
+
+#[derive(Default)]
+struct Generic<T> {
+    value: Option<T> = None,
+    count: usize = 1,
+}
+
+fn main() {
+    let config = Config::default();
+    assert_eq!(config.retries, 3);
+    assert!(config.verbose);
+    assert_eq!(config.name, "");
+    assert!(config.extra.is_empty());
+    assert_eq!(config, Config { name: String::new(), extra: Vec::new(), .. });
+
+    let generic = Generic::<String>::default();
+    assert_eq!(generic.value, None);
+    assert_eq!(generic.count, 1);
+}
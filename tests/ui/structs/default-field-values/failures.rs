@@ -0,0 +1,30 @@
+#![feature(default_field_values)]
+
+struct S {
+    a: i32 = 1,
+    b: i32,
+}
+
+struct T {
+    a: i32 = "not an integer", //~ ERROR mismatched types
+}
+
+union U {
+    a: i32,
+    b: f32,
+}
+
+fn not_const() -> i32 {
+    1
+}
+
+struct NotConst {
+    a: i32 = not_const(), //~ ERROR cannot call non-const fn `not_const` in constants
+}
+
+fn main() {
+    let _ = S { .. }; //~ ERROR missing field `b` in initializer of `S`
+    let _ = S { a: 2, .. }; //~ ERROR missing field `b` in initializer of `S`
+    let _ = S { b: 2 }; //~ ERROR missing field `a` in initializer of `S`
+    let _ = U { a: 1, .. }; //~ ERROR functional record update syntax requires a struct
+}
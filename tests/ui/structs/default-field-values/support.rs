@@ -0,0 +1,50 @@
+// run-pass
+#![feature(default_field_values)]
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    retries: u32 = 3,
+    verbose: bool = false,
+    name: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+struct Wrapper<T> {
+    inner: Option<T> = None,
+    len: usize = 2 * LEN,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle { radius: u32 = 1 },
+    Square { side: u32 = 2, filled: bool },
+}
+
+const LEN: usize = 4;
+
+const fn fourty_two() -> u8 {
+    42
+}
+
+struct Constant {
+    value: u8 = fourty_two(),
+}
+
+fn main() {
+    let config = Config { name: "a", .. };
+    assert_eq!(config, Config { retries: 3, verbose: false, name: "a" });
+
+    let config = Config { retries: 5, name: "b", .. };
+    assert_eq!(config, Config { retries: 5, verbose: false, name: "b" });
+
+    let config = Config { retries: 0, verbose: true, name: "c", .. };
+    assert_eq!(config, Config { retries: 0, verbose: true, name: "c" });
+
+    let wrapper: Wrapper<String> = Wrapper { .. };
+    assert_eq!(wrapper, Wrapper { inner: None, len: 8 });
+
+    assert_eq!(Shape::Circle { .. }, Shape::Circle { radius: 1 });
+    assert_eq!(Shape::Square { filled: true, .. }, Shape::Square { side: 2, filled: true });
+
+    assert_eq!(Constant { .. }.value, 42);
+}
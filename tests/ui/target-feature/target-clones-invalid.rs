@@ -0,0 +1,44 @@
+// only-x86_64
+// build-fail
+
+#![feature(target_clones)]
+
+#[target_clones("avx2")]
+//~^ ERROR `#[target_clones]` requires a `"default"` feature set
+fn missing_default() {}
+
+#[target_clones("avx2", "default", "default")]
+//~^ ERROR `"default"` is listed more than once
+fn duplicate_default() {}
+
+#[target_clones("avx2", "avx2", "default")]
+//~^ ERROR the feature set `avx2` is listed more than once
+fn duplicate_set() {}
+
+#[target_clones("avx2", 1, "default")]
+//~^ ERROR malformed `target_clones` attribute input
+fn not_a_string() {}
+
+#[target_clones("avx3", "default")]
+//~^ ERROR the feature named `avx3` is not valid for this target
+fn unknown_feature() {}
+
+#[inline(always)]
+#[target_clones("avx2", "default")]
+//~^ ERROR cannot use `#[inline(always)]` with `#[target_clones]`
+fn inline_always() {}
+
+#[target_feature(enable = "sse4.2")]
+#[target_clones("avx2", "default")]
+//~^ ERROR cannot use `#[target_feature]` with `#[target_clones]`
+unsafe fn with_target_feature() {}
+
+fn main() {
+    missing_default();
+    duplicate_default();
+    duplicate_set();
+    not_a_string();
+    unknown_feature();
+    inline_always();
+    unsafe { with_target_feature() };
+}
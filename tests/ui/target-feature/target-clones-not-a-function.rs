@@ -0,0 +1,20 @@
+#![feature(target_clones)]
+
+#[target_clones("default")]
+//~^ ERROR attribute should be applied to a function
+struct Foo;
+//~^ NOTE not a function
+
+#[target_clones("default")]
+//~^ ERROR attribute should be applied to a function
+const BAR: u8 = 0;
+//~^ NOTE not a function
+
+trait Baz {
+    #[target_clones("default")]
+    //~^ ERROR attribute should be applied to a function
+    fn baz();
+    //~^ NOTE not a function
+}
+
+fn main() {}
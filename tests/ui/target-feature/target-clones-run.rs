@@ -0,0 +1,34 @@
+// run-pass
+// only-x86_64
+
+#![feature(target_clones)]
+
+#[target_clones("avx2", "sse4.2", "default")]
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+struct Counter(u32);
+
+impl Counter {
+    #[target_clones("popcnt", "default")]
+    fn add_ones(&mut self, x: u64) {
+        self.0 += x.count_ones();
+    }
+}
+
+fn main() {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = [4.0, 3.0, 2.0, 1.0];
+    // The dispatcher caches its choice, so call it more than once.
+    assert_eq!(dot(&a, &b), 20.0);
+    assert_eq!(dot(&a, &b), 20.0);
+
+    let f: fn(&[f32], &[f32]) -> f32 = dot;
+    assert_eq!(f(&a, &a), 30.0);
+
+    let mut counter = Counter(0);
+    counter.add_ones(0b1011);
+    counter.add_ones(u64::MAX);
+    assert_eq!(counter.0, 67);
+}
@@ -0,0 +1,20 @@
+#![feature(pattern_types)]
+#![allow(incomplete_features)]
+
+use std::pat::pattern_type;
+
+type Positive = pattern_type!(u32 is 1..);
+type Small = pattern_type!(i8 is -5..5);
+
+fn main() {
+    let _: Positive = 0;
+    //~^ ERROR literal is not a value of the pattern type `u32 is 1..`
+    let _: Positive = -1;
+    //~^ ERROR literal is not a value of the pattern type `u32 is 1..`
+    let _: Small = 5;
+    //~^ ERROR literal is not a value of the pattern type `i8 is -5..5`
+    let _: Small = -6;
+    //~^ ERROR literal is not a value of the pattern type `i8 is -5..5`
+    let _: Small = 300;
+    //~^ ERROR literal is not a value of the pattern type `i8 is -5..5`
+}
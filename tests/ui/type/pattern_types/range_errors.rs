@@ -0,0 +1,19 @@
+#![feature(pattern_types)]
+#![allow(incomplete_features)]
+
+use std::pat::pattern_type;
+
+fn main() {
+    let _: pattern_type!(f32 is 1.0..);
+    //~^ ERROR pattern types can only restrict integer types, not `f32`
+    let _: pattern_type!(u8 is 'a'..='z');
+    //~^ ERROR the endpoints of the range of a pattern type must be integer literals
+    let _: pattern_type!(u8 is 1..=300);
+    //~^ ERROR literal out of range for `u8`
+    let _: pattern_type!(u8 is -1..=3);
+    //~^ ERROR literal out of range for `u8`
+    let _: pattern_type!(i8 is 5..5);
+    //~^ ERROR this pattern type contains no values of `i8`
+    let _: pattern_type!(i8 is 5..=-5);
+    //~^ ERROR this pattern type contains no values of `i8`
+}
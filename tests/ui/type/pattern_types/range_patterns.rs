@@ -0,0 +1,61 @@
+// run-pass
+
+#![feature(pattern_types)]
+#![allow(incomplete_features)]
+
+use std::mem::size_of;
+use std::pat::{pattern_type, to_base, try_from_base, PatternType};
+
+type Positive = pattern_type!(u32 is 1..);
+type Percent = pattern_type!(u8 is 0..=100);
+type Small = pattern_type!(i8 is -5..5);
+type Wide = pattern_type!(u128 is 10..);
+
+const ONE: Positive = 1;
+
+fn get(x: Positive) -> u32 {
+    to_base(x)
+}
+
+fn round_trip<P: PatternType>(value: P::Base) -> Option<P::Base> {
+    try_from_base::<P>(value).map(to_base)
+}
+
+fn main() {
+    assert_eq!(size_of::<Positive>(), size_of::<u32>());
+    assert_eq!(size_of::<Option<Positive>>(), size_of::<u32>());
+    assert_eq!(size_of::<Option<Percent>>(), size_of::<u8>());
+    assert_eq!(size_of::<Option<Option<Percent>>>(), size_of::<u8>());
+    assert_eq!(size_of::<Option<Small>>(), size_of::<i8>());
+
+    assert_eq!(get(ONE), 1);
+    assert_eq!(get(42), 42);
+    assert_eq!(get(4294967295), u32::MAX);
+
+    let small: Small = -5;
+    assert_eq!(to_base(small), -5);
+
+    let percent: Percent = 100;
+    let some = Some(percent);
+    assert_eq!(some.map(to_base), Some(100));
+
+    assert_eq!(round_trip::<Positive>(0), None);
+    assert_eq!(round_trip::<Positive>(1), Some(1));
+    assert_eq!(round_trip::<Positive>(u32::MAX), Some(u32::MAX));
+
+    assert_eq!(round_trip::<Percent>(0), Some(0));
+    assert_eq!(round_trip::<Percent>(100), Some(100));
+    assert_eq!(round_trip::<Percent>(101), None);
+    assert_eq!(round_trip::<Percent>(u8::MAX), None);
+
+    assert_eq!(round_trip::<Small>(i8::MIN), None);
+    assert_eq!(round_trip::<Small>(-6), None);
+    assert_eq!(round_trip::<Small>(-5), Some(-5));
+    assert_eq!(round_trip::<Small>(4), Some(4));
+    assert_eq!(round_trip::<Small>(5), None);
+    assert_eq!(round_trip::<Small>(i8::MAX), None);
+
+    assert_eq!(round_trip::<Wide>(9), None);
+    assert_eq!(round_trip::<Wide>(10), Some(10));
+    assert_eq!(round_trip::<Wide>(u128::MAX), Some(u128::MAX));
+}
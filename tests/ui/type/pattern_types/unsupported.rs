@@ -0,0 +1,15 @@
+#![feature(pattern_types)]
+#![allow(incomplete_features)]
+
+use std::pat::pattern_type;
+
+const N: u32 = 5;
+
+fn main() {
+    let _: pattern_type!(u32 is _);
+    //~^ ERROR pattern types only support range patterns
+    let _: pattern_type!(u32 is 1);
+    //~^ ERROR pattern types only support range patterns
+    let _: pattern_type!(u32 is 1..N);
+    //~^ ERROR the endpoints of a range in a pattern type must be literals
+}